@@ -58,6 +58,28 @@ pub fn main() -> Result<()> {
                 node::rollback(chain_ctx.config.ledger)
                     .wrap_err("Failed to rollback the Namada node")?;
             }
+            cmds::Ledger::Db(sub) => {
+                let chain_ctx = ctx.take_chain_or_exit();
+                let config = chain_ctx.config.ledger;
+                match sub {
+                    cmds::LedgerDb::ColumnFamilies(_) => {
+                        node::db_column_families(config)
+                    }
+                    cmds::LedgerDb::Get(cmds::LedgerDbGet(args)) => {
+                        node::db_get(config, args)
+                    }
+                    cmds::LedgerDb::Scan(cmds::LedgerDbScan(args)) => {
+                        node::db_scan(config, args)
+                    }
+                    cmds::LedgerDb::VerifyMerkle(
+                        cmds::LedgerDbVerifyMerkle(args),
+                    ) => node::db_verify_merkle(config, args),
+                    cmds::LedgerDb::Compact(cmds::LedgerDbCompact(args)) => {
+                        node::db_compact(config, args)
+                    }
+                }
+                .wrap_err("Failed to run the DB command")?;
+            }
             cmds::Ledger::UpdateDB(cmds::LedgerUpdateDB(args)) => {
                 #[cfg(not(feature = "migrations"))]
                 {
@@ -53,7 +53,8 @@ fn handle_command(cmd: cli::cmds::Namada, raw_sub_cmd: String) -> Result<()> {
         | cli::cmds::Namada::TxUpdateAccount(_)
         | cli::cmds::Namada::TxRevealPk(_)
         | cli::cmds::Namada::TxInitProposal(_)
-        | cli::cmds::Namada::TxVoteProposal(_) => {
+        | cli::cmds::Namada::TxVoteProposal(_)
+        | cli::cmds::Namada::TxUpdateProposal(_) => {
             handle_subcommand("namadac", sub_args)
         }
         cli::cmds::Namada::Wallet(_) => handle_subcommand("namadaw", sub_args),
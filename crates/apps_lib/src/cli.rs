@@ -65,6 +65,7 @@ pub mod cmds {
         TxUpdateAccount(TxUpdateAccount),
         TxInitProposal(TxInitProposal),
         TxVoteProposal(TxVoteProposal),
+        TxUpdateProposal(TxUpdateProposal),
         TxRevealPk(TxRevealPk),
 
         // Generate CLI completions
@@ -88,6 +89,7 @@ pub mod cmds {
                 .subcommand(TxUpdateAccount::def().display_order(2))
                 .subcommand(TxInitProposal::def().display_order(2))
                 .subcommand(TxVoteProposal::def().display_order(2))
+                .subcommand(TxUpdateProposal::def().display_order(2))
                 .subcommand(TxRevealPk::def().display_order(2))
                 .subcommand(Complete::def().display_order(3))
         }
@@ -117,6 +119,8 @@ pub mod cmds {
                 SubCmd::parse(matches).map(Self::TxInitProposal);
             let tx_vote_proposal =
                 SubCmd::parse(matches).map(Self::TxVoteProposal);
+            let tx_update_proposal =
+                SubCmd::parse(matches).map(Self::TxUpdateProposal);
             let tx_reveal_pk = SubCmd::parse(matches).map(Self::TxRevealPk);
             let complete = SubCmd::parse(matches).map(Self::Complete);
             node.or(client)
@@ -133,6 +137,7 @@ pub mod cmds {
                 .or(tx_update_account)
                 .or(tx_init_proposal)
                 .or(tx_vote_proposal)
+                .or(tx_update_proposal)
                 .or(tx_reveal_pk)
                 .or(complete)
         }
@@ -245,12 +250,25 @@ pub mod cmds {
                 .subcommand(TxShieldingTransfer::def().display_order(1))
                 .subcommand(TxUnshieldingTransfer::def().display_order(1))
                 .subcommand(TxIbcTransfer::def().display_order(1))
+                .subcommand(TxRegisterInterchainAccount::def().display_order(1))
+                .subcommand(TxSendInterchainAccountTx::def().display_order(1))
+                .subcommand(TxRegisterRelayerPayee::def().display_order(1))
                 .subcommand(TxUpdateAccount::def().display_order(1))
                 .subcommand(TxInitAccount::def().display_order(1))
                 .subcommand(TxRevealPk::def().display_order(1))
+                // Token factory transactions
+                .subcommand(TxCreateToken::def().display_order(1))
+                .subcommand(TxMintTokens::def().display_order(1))
+                .subcommand(TxBurnTokens::def().display_order(1))
+                .subcommand(TxUpdateToken::def().display_order(1))
+                .subcommand(TxApprove::def().display_order(1))
+                .subcommand(TxTransferFrom::def().display_order(1))
+                .subcommand(TxVestTokens::def().display_order(1))
+                .subcommand(TxGrantFee::def().display_order(1))
                 // Governance transactions
                 .subcommand(TxInitProposal::def().display_order(1))
                 .subcommand(TxVoteProposal::def().display_order(1))
+                .subcommand(TxUpdateProposal::def().display_order(1))
                 // PoS transactions
                 .subcommand(TxBecomeValidator::def().display_order(2))
                 .subcommand(TxInitValidator::def().display_order(2))
@@ -262,6 +280,7 @@ pub mod cmds {
                 .subcommand(Withdraw::def().display_order(2))
                 .subcommand(Redelegate::def().display_order(2))
                 .subcommand(ClaimRewards::def().display_order(2))
+                .subcommand(AutoRestake::def().display_order(2))
                 .subcommand(TxCommissionRateChange::def().display_order(2))
                 .subcommand(TxChangeConsensusKey::def().display_order(2))
                 .subcommand(TxMetadataChange::def().display_order(2))
@@ -293,10 +312,14 @@ pub mod cmds {
                 .subcommand(QueryPgf::def().display_order(5))
                 .subcommand(QueryValidatorState::def().display_order(5))
                 .subcommand(QueryCommissionRate::def().display_order(5))
+                .subcommand(QueryValidatorHistory::def().display_order(5))
+                .subcommand(QueryBondHistory::def().display_order(5))
                 .subcommand(QueryRewards::def().display_order(5))
+                .subcommand(QueryAutoRestake::def().display_order(5))
                 .subcommand(QueryMetaData::def().display_order(5))
                 // Actions
                 .subcommand(SignTx::def().display_order(6))
+                .subcommand(MergeSignatures::def().display_order(6))
                 .subcommand(ShieldedSync::def().display_order(6))
                 .subcommand(GenIbcShieldingTransfer::def().display_order(6))
                 // Utils
@@ -315,6 +338,12 @@ pub mod cmds {
             let tx_unshielding_transfer =
                 Self::parse_with_ctx(matches, TxUnshieldingTransfer);
             let tx_ibc_transfer = Self::parse_with_ctx(matches, TxIbcTransfer);
+            let tx_register_ica =
+                Self::parse_with_ctx(matches, TxRegisterInterchainAccount);
+            let tx_send_ica_tx =
+                Self::parse_with_ctx(matches, TxSendInterchainAccountTx);
+            let tx_register_relayer_payee =
+                Self::parse_with_ctx(matches, TxRegisterRelayerPayee);
             let tx_update_account =
                 Self::parse_with_ctx(matches, TxUpdateAccount);
             let tx_init_account = Self::parse_with_ctx(matches, TxInitAccount);
@@ -329,10 +358,21 @@ pub mod cmds {
             let tx_reactivate_validator =
                 Self::parse_with_ctx(matches, TxReactivateValidator);
             let tx_reveal_pk = Self::parse_with_ctx(matches, TxRevealPk);
+            let tx_create_token = Self::parse_with_ctx(matches, TxCreateToken);
+            let tx_mint_tokens = Self::parse_with_ctx(matches, TxMintTokens);
+            let tx_burn_tokens = Self::parse_with_ctx(matches, TxBurnTokens);
+            let tx_update_token = Self::parse_with_ctx(matches, TxUpdateToken);
+            let tx_approve = Self::parse_with_ctx(matches, TxApprove);
+            let tx_transfer_from =
+                Self::parse_with_ctx(matches, TxTransferFrom);
+            let tx_vest_tokens = Self::parse_with_ctx(matches, TxVestTokens);
+            let tx_grant_fee = Self::parse_with_ctx(matches, TxGrantFee);
             let tx_init_proposal =
                 Self::parse_with_ctx(matches, TxInitProposal);
             let tx_vote_proposal =
                 Self::parse_with_ctx(matches, TxVoteProposal);
+            let tx_update_proposal =
+                Self::parse_with_ctx(matches, TxUpdateProposal);
             let tx_update_steward_commission =
                 Self::parse_with_ctx(matches, TxUpdateStewardCommission);
             let tx_resign_steward =
@@ -348,6 +388,7 @@ pub mod cmds {
             let withdraw = Self::parse_with_ctx(matches, Withdraw);
             let redelegate = Self::parse_with_ctx(matches, Redelegate);
             let claim_rewards = Self::parse_with_ctx(matches, ClaimRewards);
+            let auto_restake = Self::parse_with_ctx(matches, AutoRestake);
             let query_epoch = Self::parse_with_ctx(matches, QueryEpoch);
             let query_next_epoch_info =
                 Self::parse_with_ctx(matches, QueryNextEpochInfo);
@@ -364,6 +405,8 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, QueryBondedStake);
             let query_slashes = Self::parse_with_ctx(matches, QuerySlashes);
             let query_rewards = Self::parse_with_ctx(matches, QueryRewards);
+            let query_auto_restake =
+                Self::parse_with_ctx(matches, QueryAutoRestake);
             let query_delegations =
                 Self::parse_with_ctx(matches, QueryDelegations);
             let query_find_validator =
@@ -382,10 +425,16 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, QueryValidatorState);
             let query_commission =
                 Self::parse_with_ctx(matches, QueryCommissionRate);
+            let query_validator_history =
+                Self::parse_with_ctx(matches, QueryValidatorHistory);
+            let query_bond_history =
+                Self::parse_with_ctx(matches, QueryBondHistory);
             let query_metadata = Self::parse_with_ctx(matches, QueryMetaData);
             let add_to_eth_bridge_pool =
                 Self::parse_with_ctx(matches, AddToEthBridgePool);
             let sign_tx = Self::parse_with_ctx(matches, SignTx);
+            let merge_signatures =
+                Self::parse_with_ctx(matches, MergeSignatures);
             let shielded_sync = Self::parse_with_ctx(matches, ShieldedSync);
             let gen_ibc_shielding =
                 Self::parse_with_ctx(matches, GenIbcShieldingTransfer);
@@ -396,11 +445,23 @@ pub mod cmds {
                 .or(tx_shielding_transfer)
                 .or(tx_unshielding_transfer)
                 .or(tx_ibc_transfer)
+                .or(tx_register_ica)
+                .or(tx_send_ica_tx)
+                .or(tx_register_relayer_payee)
                 .or(tx_update_account)
                 .or(tx_init_account)
                 .or(tx_reveal_pk)
+                .or(tx_create_token)
+                .or(tx_mint_tokens)
+                .or(tx_burn_tokens)
+                .or(tx_update_token)
+                .or(tx_approve)
+                .or(tx_transfer_from)
+                .or(tx_vest_tokens)
+                .or(tx_grant_fee)
                 .or(tx_init_proposal)
                 .or(tx_vote_proposal)
+                .or(tx_update_proposal)
                 .or(tx_become_validator)
                 .or(tx_init_validator)
                 .or(tx_commission_rate_change)
@@ -414,6 +475,7 @@ pub mod cmds {
                 .or(withdraw)
                 .or(redelegate)
                 .or(claim_rewards)
+                .or(auto_restake)
                 .or(add_to_eth_bridge_pool)
                 .or(tx_update_steward_commission)
                 .or(tx_resign_steward)
@@ -428,6 +490,7 @@ pub mod cmds {
                 .or(query_bonded_stake)
                 .or(query_slashes)
                 .or(query_rewards)
+                .or(query_auto_restake)
                 .or(query_delegations)
                 .or(query_find_validator)
                 .or(query_result)
@@ -439,9 +502,12 @@ pub mod cmds {
                 .or(query_pgf)
                 .or(query_validator_state)
                 .or(query_commission)
+                .or(query_validator_history)
+                .or(query_bond_history)
                 .or(query_metadata)
                 .or(query_account)
                 .or(sign_tx)
+                .or(merge_signatures)
                 .or(shielded_sync)
                 .or(gen_ibc_shielding)
                 .or(utils)
@@ -487,6 +553,9 @@ pub mod cmds {
         TxShieldingTransfer(TxShieldingTransfer),
         TxUnshieldingTransfer(TxUnshieldingTransfer),
         TxIbcTransfer(TxIbcTransfer),
+        TxRegisterInterchainAccount(TxRegisterInterchainAccount),
+        TxSendInterchainAccountTx(TxSendInterchainAccountTx),
+        TxRegisterRelayerPayee(TxRegisterRelayerPayee),
         QueryResult(QueryResult),
         TxUpdateAccount(TxUpdateAccount),
         TxInitAccount(TxInitAccount),
@@ -500,11 +569,21 @@ pub mod cmds {
         TxReactivateValidator(TxReactivateValidator),
         TxInitProposal(TxInitProposal),
         TxVoteProposal(TxVoteProposal),
+        TxUpdateProposal(TxUpdateProposal),
         TxRevealPk(TxRevealPk),
+        TxCreateToken(TxCreateToken),
+        TxMintTokens(TxMintTokens),
+        TxBurnTokens(TxBurnTokens),
+        TxUpdateToken(TxUpdateToken),
+        TxApprove(TxApprove),
+        TxTransferFrom(TxTransferFrom),
+        TxVestTokens(TxVestTokens),
+        TxGrantFee(TxGrantFee),
         Bond(Bond),
         Unbond(Unbond),
         Withdraw(Withdraw),
         ClaimRewards(ClaimRewards),
+        AutoRestake(AutoRestake),
         Redelegate(Redelegate),
         AddToEthBridgePool(AddToEthBridgePool),
         TxUpdateStewardCommission(TxUpdateStewardCommission),
@@ -520,6 +599,8 @@ pub mod cmds {
         QueryBonds(QueryBonds),
         QueryBondedStake(QueryBondedStake),
         QueryCommissionRate(QueryCommissionRate),
+        QueryValidatorHistory(QueryValidatorHistory),
+        QueryBondHistory(QueryBondHistory),
         QueryMetaData(QueryMetaData),
         QuerySlashes(QuerySlashes),
         QueryDelegations(QueryDelegations),
@@ -532,7 +613,9 @@ pub mod cmds {
         QueryPgf(QueryPgf),
         QueryValidatorState(QueryValidatorState),
         QueryRewards(QueryRewards),
+        QueryAutoRestake(QueryAutoRestake),
         SignTx(SignTx),
+        MergeSignatures(MergeSignatures),
         ShieldedSync(ShieldedSync),
         GenIbcShieldingTransfer(GenIbcShieldingTransfer),
     }
@@ -882,6 +965,7 @@ pub mod cmds {
         UpdateDB(LedgerUpdateDB),
         QueryDB(LedgerQueryDB),
         RollBack(LedgerRollBack),
+        Db(LedgerDb),
     }
 
     impl SubCmd for Ledger {
@@ -896,12 +980,14 @@ pub mod cmds {
                 let query_db = SubCmd::parse(matches).map(Self::QueryDB);
                 let rollback = SubCmd::parse(matches).map(Self::RollBack);
                 let run_until = SubCmd::parse(matches).map(Self::RunUntil);
+                let db = SubCmd::parse(matches).map(Self::Db);
                 run.or(reset)
                     .or(dump_db)
                     .or(update_db)
                     .or(query_db)
                     .or(rollback)
                     .or(run_until)
+                    .or(db)
                     // The `run` command is the default if no sub-command given
                     .or(Some(Self::Run(LedgerRun(args::LedgerRun {
                         start_time: None,
@@ -925,6 +1011,7 @@ pub mod cmds {
                 .subcommand(LedgerUpdateDB::def())
                 .subcommand(LedgerQueryDB::def())
                 .subcommand(LedgerRollBack::def())
+                .subcommand(LedgerDb::def())
         }
     }
 
@@ -1073,6 +1160,154 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub enum LedgerDb {
+        ColumnFamilies(LedgerDbColumnFamilies),
+        Get(LedgerDbGet),
+        Scan(LedgerDbScan),
+        VerifyMerkle(LedgerDbVerifyMerkle),
+        Compact(LedgerDbCompact),
+    }
+
+    impl SubCmd for LedgerDb {
+        const CMD: &'static str = "db";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).and_then(|matches| {
+                let column_families =
+                    SubCmd::parse(matches).map(Self::ColumnFamilies);
+                let get = SubCmd::parse(matches).map(Self::Get);
+                let scan = SubCmd::parse(matches).map(Self::Scan);
+                let verify_merkle =
+                    SubCmd::parse(matches).map(Self::VerifyMerkle);
+                let compact = SubCmd::parse(matches).map(Self::Compact);
+                column_families
+                    .or(get)
+                    .or(scan)
+                    .or(verify_merkle)
+                    .or(compact)
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .about(wrap!(
+                    "Inspect and repair the ledger node's DB while the ledger \
+                     is not running."
+                ))
+                .subcommand(LedgerDbColumnFamilies::def())
+                .subcommand(LedgerDbGet::def())
+                .subcommand(LedgerDbScan::def())
+                .subcommand(LedgerDbVerifyMerkle::def())
+                .subcommand(LedgerDbCompact::def())
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerDbColumnFamilies;
+
+    impl SubCmd for LedgerDbColumnFamilies {
+        const CMD: &'static str = "column-families";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|_matches| Self)
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD).about(wrap!(
+                "List the DB's column families with their estimated number of \
+                 keys and size."
+            ))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerDbGet(pub args::LedgerDbGet);
+
+    impl SubCmd for LedgerDbGet {
+        const CMD: &'static str = "get";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::LedgerDbGet::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Read the value of a key from the DB, optionally at a \
+                     past height."
+                ))
+                .add_args::<args::LedgerDbGet>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerDbScan(pub args::LedgerDbScan);
+
+    impl SubCmd for LedgerDbScan {
+        const CMD: &'static str = "scan";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::LedgerDbScan::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Read the key-vals under a key prefix from the DB, \
+                     optionally at a past height."
+                ))
+                .add_args::<args::LedgerDbScan>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerDbVerifyMerkle(pub args::LedgerDbVerifyMerkle);
+
+    impl SubCmd for LedgerDbVerifyMerkle {
+        const CMD: &'static str = "verify-merkle";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::LedgerDbVerifyMerkle::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Verify that the merkle tree stores and diffs in the DB \
+                     reproduce the committed merkle root."
+                ))
+                .add_args::<args::LedgerDbVerifyMerkle>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerDbCompact(pub args::LedgerDbCompact);
+
+    impl SubCmd for LedgerDbCompact {
+        const CMD: &'static str = "compact";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::LedgerDbCompact::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Compact the DB's column families."))
+                .add_args::<args::LedgerDbCompact>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub enum Config {
         Gen(ConfigGen),
@@ -1421,6 +1656,84 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct TxRegisterInterchainAccount(
+        pub args::TxRegisterInterchainAccount<args::CliTypes>,
+    );
+
+    impl SubCmd for TxRegisterInterchainAccount {
+        const CMD: &'static str = "ica-register";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxRegisterInterchainAccount(
+                    args::TxRegisterInterchainAccount::parse(matches),
+                )
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Send a signed transaction to register an interchain \
+                     account controlled by the owner on the host chain."
+                ))
+                .add_args::<args::TxRegisterInterchainAccount<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxSendInterchainAccountTx(
+        pub args::TxSendInterchainAccountTx<args::CliTypes>,
+    );
+
+    impl SubCmd for TxSendInterchainAccountTx {
+        const CMD: &'static str = "ica-send";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxSendInterchainAccountTx(
+                    args::TxSendInterchainAccountTx::parse(matches),
+                )
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Send a signed transaction to transfer tokens from the \
+                     owner's interchain account on the host chain."
+                ))
+                .add_args::<args::TxSendInterchainAccountTx<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxRegisterRelayerPayee(
+        pub args::TxRegisterRelayerPayee<args::CliTypes>,
+    );
+
+    impl SubCmd for TxRegisterRelayerPayee {
+        const CMD: &'static str = "ibc-register-payee";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxRegisterRelayerPayee(args::TxRegisterRelayerPayee::parse(
+                    matches,
+                ))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Send a signed transaction to register the payee of the \
+                     relayer fees on a fee-enabled IBC channel."
+                ))
+                .add_args::<args::TxRegisterRelayerPayee<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxUpdateAccount(pub args::TxUpdateAccount<args::CliTypes>);
 
@@ -1685,245 +1998,470 @@ pub mod cmds {
     }
 
     #[derive(Clone, Debug)]
-    pub struct Redelegate(pub args::Redelegate<args::CliTypes>);
+    pub struct AutoRestake(pub args::AutoRestake<args::CliTypes>);
 
-    impl SubCmd for Redelegate {
-        const CMD: &'static str = "redelegate";
+    impl SubCmd for AutoRestake {
+        const CMD: &'static str = "auto-restake";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Redelegate(args::Redelegate::parse(matches)))
+                .map(|matches| AutoRestake(args::AutoRestake::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Redelegate bonded tokens from one validator to another."
+                    "Enable or disable the automatic restaking of a bond's \
+                     rewards at the start of every epoch."
                 ))
-                .add_args::<args::Redelegate<args::CliTypes>>()
+                .add_args::<args::AutoRestake<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryEpoch(pub args::Query<args::CliTypes>);
+    pub struct TxCreateToken(pub args::CreateToken<args::CliTypes>);
 
-    impl SubCmd for QueryEpoch {
-        const CMD: &'static str = "epoch";
+    impl SubCmd for TxCreateToken {
+        const CMD: &'static str = "create-token";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| QueryEpoch(args::Query::parse(matches)))
+                .map(|matches| TxCreateToken(args::CreateToken::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Query the epoch of the last committed block."))
-                .add_args::<args::Query<args::CliTypes>>()
+                .about(wrap!(
+                    "Send a signed transaction to create a new fungible \
+                     token with the token factory."
+                ))
+                .add_args::<args::CreateToken<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryNextEpochInfo(pub args::Query<args::CliTypes>);
+    pub struct TxMintTokens(pub args::MintTokens<args::CliTypes>);
 
-    impl SubCmd for QueryNextEpochInfo {
-        const CMD: &'static str = "next-epoch-info";
+    impl SubCmd for TxMintTokens {
+        const CMD: &'static str = "mint-tokens";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| QueryNextEpochInfo(args::Query::parse(matches)))
+                .map(|matches| TxMintTokens(args::MintTokens::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Query some info to help discern when the next epoch will \
-                     begin."
+                    "Send a signed transaction to mint tokens of a token \
+                     created with the token factory."
                 ))
-                .add_args::<args::Query<args::CliTypes>>()
+                .add_args::<args::MintTokens<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryStatus(pub args::Query<args::CliTypes>);
+    pub struct TxBurnTokens(pub args::BurnTokens<args::CliTypes>);
 
-    impl SubCmd for QueryStatus {
-        const CMD: &'static str = "status";
+    impl SubCmd for TxBurnTokens {
+        const CMD: &'static str = "burn-tokens";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| QueryStatus(args::Query::parse(matches)))
+                .map(|matches| TxBurnTokens(args::BurnTokens::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Query the node's status."))
-                .add_args::<args::Query<args::CliTypes>>()
+                .about(wrap!(
+                    "Send a signed transaction to burn tokens of a token \
+                     created with the token factory."
+                ))
+                .add_args::<args::BurnTokens<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryAccount(pub args::QueryAccount<args::CliTypes>);
+    pub struct TxUpdateToken(pub args::UpdateToken<args::CliTypes>);
 
-    impl SubCmd for QueryAccount {
-        const CMD: &'static str = "query-account";
+    impl SubCmd for TxUpdateToken {
+        const CMD: &'static str = "update-token";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| QueryAccount(args::QueryAccount::parse(matches)))
+                .map(|matches| TxUpdateToken(args::UpdateToken::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Query the substorage space of a specific enstablished \
-                     address."
+                    "Send a signed transaction to update the admin, the \
+                     minter or the metadata of a token created with the \
+                     token factory."
                 ))
-                .add_args::<args::QueryAccount<args::CliTypes>>()
+                .add_args::<args::UpdateToken<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryConversions(pub args::QueryConversions<args::CliTypes>);
+    pub struct TxApprove(pub args::Approve<args::CliTypes>);
 
-    impl SubCmd for QueryConversions {
-        const CMD: &'static str = "conversions";
+    impl SubCmd for TxApprove {
+        const CMD: &'static str = "approve";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).map(|matches| {
-                QueryConversions(args::QueryConversions::parse(matches))
-            })
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| TxApprove(args::Approve::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Query currently applicable conversions."))
-                .add_args::<args::QueryConversions<args::CliTypes>>()
+                .about(wrap!(
+                    "Send a signed transaction to allow another account to \
+                     spend tokens from the owner's balance. An amount of zero \
+                     revokes the allowance."
+                ))
+                .add_args::<args::Approve<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryMaspRewardTokens(pub args::Query<args::CliTypes>);
+    pub struct TxTransferFrom(pub args::TransferFrom<args::CliTypes>);
 
-    impl SubCmd for QueryMaspRewardTokens {
-        const CMD: &'static str = "masp-reward-tokens";
+    impl SubCmd for TxTransferFrom {
+        const CMD: &'static str = "transfer-from";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches.subcommand_matches(Self::CMD).map(|matches| {
-                QueryMaspRewardTokens(args::Query::parse(matches))
+                TxTransferFrom(args::TransferFrom::parse(matches))
             })
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Query the tokens which can earn MASP rewards while \
-                     shielded."
+                    "Send a signed transaction to transfer tokens from an \
+                     owner's balance using a spending allowance granted to \
+                     the spender."
                 ))
-                .add_args::<args::Query<args::CliTypes>>()
+                .add_args::<args::TransferFrom<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryBlock(pub args::Query<args::CliTypes>);
+    pub struct TxVestTokens(pub args::VestTokens<args::CliTypes>);
 
-    impl SubCmd for QueryBlock {
-        const CMD: &'static str = "block";
+    impl SubCmd for TxVestTokens {
+        const CMD: &'static str = "vest-tokens";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| QueryBlock(args::Query::parse(matches)))
+                .map(|matches| TxVestTokens(args::VestTokens::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Query the last committed block."))
-                .add_args::<args::Query<args::CliTypes>>()
+                .about(wrap!(
+                    "Send a signed transaction to grant tokens that are \
+                     locked in the target's balance and released by a \
+                     vesting schedule."
+                ))
+                .add_args::<args::VestTokens<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryBalance(pub args::QueryBalance<args::CliTypes>);
+    pub struct TxGrantFee(pub args::GrantFee<args::CliTypes>);
 
-    impl SubCmd for QueryBalance {
-        const CMD: &'static str = "balance";
+    impl SubCmd for TxGrantFee {
+        const CMD: &'static str = "grant-fee";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| QueryBalance(args::QueryBalance::parse(matches)))
+                .map(|matches| TxGrantFee(args::GrantFee::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Query the token balance of some account."))
-                .add_args::<args::QueryBalance<args::CliTypes>>()
+                .about(wrap!(
+                    "Send a signed transaction to grant, update or revoke a \
+                     fee grant that lets the sponsor pay the fees of the \
+                     grantee's transactions."
+                ))
+                .add_args::<args::GrantFee<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryBonds(pub args::QueryBonds<args::CliTypes>);
+    pub struct Redelegate(pub args::Redelegate<args::CliTypes>);
 
-    impl SubCmd for QueryBonds {
-        const CMD: &'static str = "bonds";
+    impl SubCmd for Redelegate {
+        const CMD: &'static str = "redelegate";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| QueryBonds(args::QueryBonds::parse(matches)))
+                .map(|matches| Redelegate(args::Redelegate::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Query PoS bond(s)."))
-                .add_args::<args::QueryBonds<args::CliTypes>>()
+                .about(wrap!(
+                    "Redelegate bonded tokens from one validator to another."
+                ))
+                .add_args::<args::Redelegate<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryBondedStake(pub args::QueryBondedStake<args::CliTypes>);
+    pub struct QueryEpoch(pub args::Query<args::CliTypes>);
 
-    impl SubCmd for QueryBondedStake {
-        const CMD: &'static str = "bonded-stake";
+    impl SubCmd for QueryEpoch {
+        const CMD: &'static str = "epoch";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).map(|matches| {
-                QueryBondedStake(args::QueryBondedStake::parse(matches))
-            })
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| QueryEpoch(args::Query::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Query PoS bonded stake."))
-                .add_args::<args::QueryBondedStake<args::CliTypes>>()
+                .about(wrap!("Query the epoch of the last committed block."))
+                .add_args::<args::Query<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct SignTx(pub args::SignTx<args::CliTypes>);
+    pub struct QueryNextEpochInfo(pub args::Query<args::CliTypes>);
 
-    impl SubCmd for SignTx {
-        const CMD: &'static str = "sign-tx";
+    impl SubCmd for QueryNextEpochInfo {
+        const CMD: &'static str = "next-epoch-info";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| SignTx(args::SignTx::parse(matches)))
+                .map(|matches| QueryNextEpochInfo(args::Query::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Sign a transaction offline."))
-                .add_args::<args::SignTx<args::CliTypes>>()
-        }
-    }
-
-    #[derive(Clone, Debug)]
+                .about(wrap!(
+                    "Query some info to help discern when the next epoch will \
+                     begin."
+                ))
+                .add_args::<args::Query<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryStatus(pub args::Query<args::CliTypes>);
+
+    impl SubCmd for QueryStatus {
+        const CMD: &'static str = "status";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| QueryStatus(args::Query::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Query the node's status."))
+                .add_args::<args::Query<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryAccount(pub args::QueryAccount<args::CliTypes>);
+
+    impl SubCmd for QueryAccount {
+        const CMD: &'static str = "query-account";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| QueryAccount(args::QueryAccount::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query the substorage space of a specific enstablished \
+                     address."
+                ))
+                .add_args::<args::QueryAccount<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryConversions(pub args::QueryConversions<args::CliTypes>);
+
+    impl SubCmd for QueryConversions {
+        const CMD: &'static str = "conversions";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryConversions(args::QueryConversions::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Query currently applicable conversions."))
+                .add_args::<args::QueryConversions<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryMaspRewardTokens(pub args::Query<args::CliTypes>);
+
+    impl SubCmd for QueryMaspRewardTokens {
+        const CMD: &'static str = "masp-reward-tokens";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryMaspRewardTokens(args::Query::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query the tokens which can earn MASP rewards while \
+                     shielded."
+                ))
+                .add_args::<args::Query<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryBlock(pub args::Query<args::CliTypes>);
+
+    impl SubCmd for QueryBlock {
+        const CMD: &'static str = "block";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| QueryBlock(args::Query::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Query the last committed block."))
+                .add_args::<args::Query<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryBalance(pub args::QueryBalance<args::CliTypes>);
+
+    impl SubCmd for QueryBalance {
+        const CMD: &'static str = "balance";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| QueryBalance(args::QueryBalance::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Query the token balance of some account."))
+                .add_args::<args::QueryBalance<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryBonds(pub args::QueryBonds<args::CliTypes>);
+
+    impl SubCmd for QueryBonds {
+        const CMD: &'static str = "bonds";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| QueryBonds(args::QueryBonds::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Query PoS bond(s)."))
+                .add_args::<args::QueryBonds<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryBondedStake(pub args::QueryBondedStake<args::CliTypes>);
+
+    impl SubCmd for QueryBondedStake {
+        const CMD: &'static str = "bonded-stake";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryBondedStake(args::QueryBondedStake::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Query PoS bonded stake."))
+                .add_args::<args::QueryBondedStake<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct SignTx(pub args::SignTx<args::CliTypes>);
+
+    impl SubCmd for SignTx {
+        const CMD: &'static str = "sign-tx";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| SignTx(args::SignTx::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Sign a transaction offline."))
+                .add_args::<args::SignTx<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct MergeSignatures(pub args::MergeSignatures<args::CliTypes>);
+
+    impl SubCmd for MergeSignatures {
+        const CMD: &'static str = "merge-signatures";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                MergeSignatures(args::MergeSignatures::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Validate the offline signatures of a multisig account \
+                     and attach them to the transaction."
+                ))
+                .add_args::<args::MergeSignatures<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
     pub struct QueryValidatorState(
         pub args::QueryValidatorState<args::CliTypes>,
     );
@@ -1965,6 +2503,54 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryValidatorHistory(
+        pub args::QueryValidatorHistory<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryValidatorHistory {
+        const CMD: &'static str = "validator-history";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryValidatorHistory(args::QueryValidatorHistory::parse(
+                    matches,
+                ))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query a validator's stake, commission rate, state and \
+                     rank over a range of epochs."
+                ))
+                .add_args::<args::QueryValidatorHistory<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryBondHistory(pub args::QueryBondHistory<args::CliTypes>);
+
+    impl SubCmd for QueryBondHistory {
+        const CMD: &'static str = "bond-history";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryBondHistory(args::QueryBondHistory::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query a bond's bonded, unbonding and withdrawable \
+                     amounts over a range of epochs."
+                ))
+                .add_args::<args::QueryBondHistory<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryMetaData(pub args::QueryMetaData<args::CliTypes>);
 
@@ -2028,6 +2614,28 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryAutoRestake(pub args::QueryAutoRestake<args::CliTypes>);
+
+    impl SubCmd for QueryAutoRestake {
+        const CMD: &'static str = "auto-restake-status";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryAutoRestake(args::QueryAutoRestake::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query whether the rewards of a given delegation (or \
+                     self-bond) are automatically restaked."
+                ))
+                .add_args::<args::QueryAutoRestake<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryDelegations(pub args::QueryDelegations<args::CliTypes>);
 
@@ -2264,19 +2872,44 @@ pub mod cmds {
     }
 
     #[derive(Clone, Debug)]
-    pub struct TxRevealPk(pub args::RevealPk<args::CliTypes>);
+    pub struct TxUpdateProposal(pub args::UpdateProposal<args::CliTypes>);
 
-    impl SubCmd for TxRevealPk {
-        const CMD: &'static str = "reveal-pk";
+    impl SubCmd for TxUpdateProposal {
+        const CMD: &'static str = "update-proposal";
 
         fn parse(matches: &ArgMatches) -> Option<Self>
         where
             Self: Sized,
         {
-            matches
-                .subcommand_matches(Self::CMD)
-                .map(|matches| TxRevealPk(args::RevealPk::parse(matches)))
-        }
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxUpdateProposal(args::UpdateProposal::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Cancel or amend a proposal before its voting period \
+                     starts."
+                ))
+                .add_args::<args::UpdateProposal<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxRevealPk(pub args::RevealPk<args::CliTypes>);
+
+    impl SubCmd for TxRevealPk {
+        const CMD: &'static str = "reveal-pk";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| TxRevealPk(args::RevealPk::parse(matches)))
+        }
 
         fn def() -> App {
             App::new(Self::CMD)
@@ -3162,7 +3795,9 @@ pub mod args {
     use namada_sdk::dec::Dec;
     use namada_sdk::ethereum_events::EthAddress;
     use namada_sdk::hash::Hash;
-    use namada_sdk::ibc::core::host::types::identifiers::{ChannelId, PortId};
+    use namada_sdk::ibc::core::host::types::identifiers::{
+        ChannelId, ConnectionId, PortId,
+    };
     use namada_sdk::keccak::KeccakHash;
     use namada_sdk::key::*;
     use namada_sdk::masp::{MaspEpoch, PaymentAddress};
@@ -3171,15 +3806,17 @@ pub mod args {
     use namada_sdk::token::NATIVE_MAX_DECIMAL_PLACES;
     use namada_sdk::tx::data::GasLimit;
     pub use namada_sdk::tx::{
-        TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
+        TX_APPROVE_WASM, TX_AUTO_RESTAKE_WASM, TX_BECOME_VALIDATOR_WASM,
+        TX_BOND_WASM, TX_BRIDGE_POOL_WASM, TX_BURN_TOKENS_WASM,
         TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
-        TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM,
-        TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
-        TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
-        TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
-        TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
-        TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
-        VP_USER_WASM,
+        TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM, TX_CREATE_TOKEN_WASM,
+        TX_DEACTIVATE_VALIDATOR_WASM, TX_GRANT_FEE_WASM, TX_IBC_WASM,
+        TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_MINT_TOKENS_WASM,
+        TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM, TX_RESIGN_STEWARD,
+        TX_REVEAL_PK, TX_TRANSFER_FROM_WASM, TX_TRANSFER_WASM, TX_UNBOND_WASM,
+        TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM, TX_UPDATE_PROPOSAL,
+        TX_UPDATE_STEWARD_COMMISSION, TX_UPDATE_TOKEN_WASM,
+        TX_VEST_TOKENS_WASM, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM, VP_USER_WASM,
     };
     use namada_sdk::{token, DEFAULT_GAS_LIMIT};
 
@@ -3193,7 +3830,9 @@ pub mod args {
     use crate::facade::tendermint_rpc::Url;
     use crate::wrap;
 
+    pub const ACK_FEE: ArgOpt<token::DenominatedAmount> = arg_opt("ack-fee");
     pub const ADDRESS: Arg<WalletAddress> = arg("address");
+    pub const ADMIN: Arg<WalletAddress> = arg("admin");
     pub const ADD_PERSISTENT_PEERS: ArgFlag = flag("add-persistent-peers");
     pub const ALIAS_OPT: ArgOpt<String> = ALIAS.opt();
     pub const ALIAS: Arg<String> = arg("alias");
@@ -3202,6 +3841,7 @@ pub mod args {
     pub const ALLOW_DUPLICATE_IP: ArgFlag = flag("allow-duplicate-ip");
     pub const AMOUNT: Arg<token::DenominatedAmount> = arg("amount");
     pub const ARCHIVE_DIR: ArgOpt<PathBuf> = arg_opt("archive-dir");
+    pub const AUTHORITY: Arg<WalletAddress> = arg("authority");
     pub const AVATAR_OPT: ArgOpt<String> = arg_opt("avatar");
     pub const BALANCE_OWNER: Arg<WalletBalanceOwner> = arg("owner");
     pub const BASE_DIR: ArgDefault<PathBuf> = arg_default(
@@ -3240,15 +3880,20 @@ pub mod args {
     pub const CHAIN_ID_OPT: ArgOpt<ChainId> = CHAIN_ID.opt();
     pub const CHAIN_ID_PREFIX: Arg<ChainIdPrefix> = arg("chain-prefix");
     pub const CHANNEL_ID: Arg<ChannelId> = arg("channel-id");
+    pub const CLIFF_EPOCH_OPT: ArgOpt<Epoch> = arg_opt("cliff-epoch");
     pub const CODE_PATH: Arg<PathBuf> = arg("code-path");
     pub const CODE_PATH_OPT: ArgOpt<PathBuf> = CODE_PATH.opt();
+    pub const CODE_HASHES: ArgMulti<Hash, GlobStar> = arg_multi("code-hashes");
     pub const COMMISSION_RATE: Arg<Dec> = arg("commission-rate");
     pub const COMMISSION_RATE_OPT: ArgOpt<Dec> = COMMISSION_RATE.opt();
+    pub const CONNECTION_ID: Arg<ConnectionId> = arg("connection-id");
     pub const CONSENSUS_TIMEOUT_COMMIT: ArgDefault<Timeout> = arg_default(
         "consensus-timeout-commit",
         DefaultFn(|| Timeout::from_str("1s").unwrap()),
     );
     pub const CONVERSION_TABLE: Arg<PathBuf> = arg("conversion-table");
+    pub const COUNTERPARTY_PAYEE: ArgOpt<String> =
+        arg_opt("counterparty-payee");
     pub const DAEMON_MODE: ArgFlag = flag("daemon");
     pub const DAEMON_MODE_RETRY_DUR: ArgOpt<Duration> = arg_opt("retry-sleep");
     pub const DAEMON_MODE_SUCCESS_DUR: ArgOpt<Duration> =
@@ -3256,12 +3901,18 @@ pub mod args {
     pub const DATA_PATH_OPT: ArgOpt<PathBuf> = arg_opt("data-path");
     pub const DATA_PATH: Arg<PathBuf> = arg("data-path");
     pub const DB_KEY: Arg<String> = arg("db-key");
+    pub const DB_KEY_PREFIX: ArgOpt<String> = arg_opt("db-key-prefix");
     pub const DB_COLUMN_FAMILY: ArgDefault<String> = arg_default(
         "db-column-family",
         DefaultFn(|| storage::SUBSPACE_CF.to_string()),
     );
+    pub const DB_COLUMN_FAMILY_OPT: ArgOpt<String> =
+        arg_opt("db-column-family");
     pub const DECRYPT: ArgFlag = flag("decrypt");
+    pub const DENOM: Arg<String> = arg("denom");
+    pub const DENOMINATION: Arg<u8> = arg("denomination");
     pub const DESCRIPTION_OPT: ArgOpt<String> = arg_opt("description");
+    pub const DISABLE: ArgFlag = flag("disable");
     pub const DISPOSABLE_SIGNING_KEY: ArgFlag = flag("disposable-gas-payer");
     pub const DESTINATION_VALIDATOR: Arg<WalletAddress> =
         arg("destination-validator");
@@ -3284,12 +3935,16 @@ pub mod args {
     );
     pub const ETH_SYNC: ArgFlag = flag("sync");
     pub const EXPIRATION_OPT: ArgOpt<DateTimeUtc> = arg_opt("expiration");
+    pub const EXPIRY_OPT: ArgOpt<Epoch> = arg_opt("expiry");
     pub const EMAIL: Arg<String> = arg("email");
     pub const EMAIL_OPT: ArgOpt<String> = EMAIL.opt();
+    pub const END_EPOCH: Arg<Epoch> = arg("end-epoch");
     pub const FEE_AMOUNT_OPT: ArgOpt<token::DenominatedAmount> =
         arg_opt("gas-price");
     pub const FEE_PAYER_OPT: ArgOpt<WalletPublicKey> = arg_opt("gas-payer");
+    pub const FEE_SPONSOR_OPT: ArgOpt<WalletAddress> = arg_opt("gas-sponsor");
     pub const FILE_PATH: Arg<String> = arg("file");
+    pub const FROM_EPOCH: ArgOpt<Epoch> = arg_opt("from-epoch");
     pub const FORCE: ArgFlag = flag("force");
     pub const GAS_LIMIT: ArgDefault<GasLimit> = arg_default(
         "gas-limit",
@@ -3310,6 +3965,7 @@ pub mod args {
         }),
     );
     pub const GENESIS_BOND_SOURCE: ArgOpt<GenesisAddress> = arg_opt("source");
+    pub const GRANTEE: Arg<WalletAddress> = arg("grantee");
     pub const GENESIS_PATH: Arg<PathBuf> = arg("genesis-path");
     pub const GENESIS_TIME: Arg<DateTimeUtc> = arg("genesis-time");
     pub const GENESIS_VALIDATOR: ArgOpt<String> =
@@ -3329,6 +3985,7 @@ pub mod args {
     pub const IBC_SHIELDING_DATA_PATH: ArgOpt<PathBuf> =
         arg_opt("ibc-shielding-data");
     pub const IBC_MEMO: ArgOpt<String> = arg_opt("ibc-memo");
+    pub const IBC_RELAYER: Arg<WalletAddress> = arg("relayer");
     pub const INPUT_OPT: ArgOpt<PathBuf> = arg_opt("input");
     pub const LEDGER_ADDRESS_ABOUT: &str = textwrap_macros::fill!(
         "Address of a ledger node as \"{scheme}://{host}:{port}\". If the \
@@ -3352,9 +4009,12 @@ pub mod args {
     pub const MAX_ETH_GAS: ArgOpt<u64> = arg_opt("max_eth-gas");
     pub const MEMO_OPT: ArgOpt<String> = arg_opt("memo");
     pub const MIGRATION_PATH: ArgOpt<PathBuf> = arg_opt("migration-path");
+    pub const MINTER_OPT: ArgOpt<WalletAddress> = arg_opt("minter");
     pub const MODE: ArgOpt<String> = arg_opt("mode");
     pub const NET_ADDRESS: Arg<SocketAddr> = arg("net-address");
     pub const NAMADA_START_TIME: ArgOpt<DateTimeUtc> = arg_opt("time");
+    pub const NEW_ADMIN_OPT: ArgOpt<WalletAddress> = arg_opt("new-admin");
+    pub const NEW_MINTER_OPT: ArgOpt<WalletAddress> = arg_opt("new-minter");
     pub const NO_CONVERSIONS: ArgFlag = flag("no-conversions");
     pub const NO_EXPIRATION: ArgFlag = flag("no-expiration");
     pub const NUT: ArgFlag = flag("nut");
@@ -3366,6 +4026,7 @@ pub mod args {
     pub const OWNER_OPT: ArgOpt<WalletAddress> = OWNER.opt();
     pub const PATH: Arg<PathBuf> = arg("path");
     pub const PATH_OPT: ArgOpt<PathBuf> = arg_opt("path");
+    pub const PAYEE_OPT: ArgOpt<WalletAddress> = arg_opt("payee");
     pub const PAYMENT_ADDRESS_TARGET: Arg<WalletPaymentAddr> = arg("target");
     pub const PORT_ID: ArgDefault<PortId> = arg_default(
         "port-id",
@@ -3375,6 +4036,7 @@ pub mod args {
     pub const PROPOSAL_ETH: ArgFlag = flag("eth");
     pub const PROPOSAL_PGF_STEWARD: ArgFlag = flag("pgf-stewards");
     pub const PROPOSAL_PGF_FUNDING: ArgFlag = flag("pgf-funding");
+    pub const PROPOSAL_PARAMETER_CHANGE: ArgFlag = flag("parameter-change");
     pub const PROTOCOL_KEY: ArgOpt<WalletPublicKey> = arg_opt("protocol-key");
     pub const PRE_GENESIS_PATH: ArgOpt<PathBuf> = arg_opt("pre-genesis-path");
     pub const PUBLIC_KEY: Arg<WalletPublicKey> = arg("public-key");
@@ -3398,10 +4060,14 @@ pub mod args {
     pub const RAW_PUBLIC_KEY_HASH: Arg<String> = arg("public-key-hash");
     pub const RAW_PUBLIC_KEY_HASH_OPT: ArgOpt<String> =
         RAW_PUBLIC_KEY_HASH.opt();
+    pub const RAW_AMOUNT: Arg<String> = arg("amount");
     pub const RECEIVER: Arg<String> = arg("receiver");
+    pub const RECV_FEE: ArgOpt<token::DenominatedAmount> = arg_opt("recv-fee");
     pub const REFUND_TARGET: ArgOpt<WalletTransferTarget> =
         arg_opt("refund-target");
     pub const RELAYER: Arg<Address> = arg("relayer");
+    pub const RELAYER_FEE_TOKEN: ArgOpt<WalletAddress> =
+        arg_opt("relayer-fee-token");
     pub const SAFE_MODE: ArgFlag = flag("safe-mode");
     pub const SCHEME: ArgDefault<SchemeType> =
         arg_default("scheme", DefaultFn(|| SchemeType::Ed25519));
@@ -3418,21 +4084,33 @@ pub mod args {
     pub const SOURCE: Arg<WalletAddress> = arg("source");
     pub const SOURCE_OPT: ArgOpt<WalletAddress> = SOURCE.opt();
     pub const SOURCE_VALIDATOR: Arg<WalletAddress> = arg("source-validator");
+    pub const SPENDER: Arg<WalletAddress> = arg("spender");
+    pub const SPONSOR: Arg<WalletAddress> = arg("sponsor");
     pub const SPENDING_KEY_SOURCE: Arg<WalletSpendingKey> = arg("source");
     pub const SPENDING_KEYS: ArgMulti<WalletSpendingKey, GlobStar> =
         arg_multi("spending-keys");
+    pub const START_EPOCH_OPT: ArgOpt<Epoch> = arg_opt("start-epoch");
     pub const STEWARD: Arg<WalletAddress> = arg("steward");
     pub const STORAGE_KEY: Arg<storage::Key> = arg("storage-key");
+    pub const SUPPLY_CAP_OPT: ArgOpt<token::DenominatedAmount> =
+        arg_opt("supply-cap");
     pub const SUSPEND_ACTION: ArgFlag = flag("suspend");
     pub const TARGET: Arg<WalletAddress> = arg("target");
     pub const TEMPLATES_PATH: Arg<PathBuf> = arg("templates-path");
+    pub const TIMEOUT_FEE: ArgOpt<token::DenominatedAmount> =
+        arg_opt("timeout-fee");
     pub const TIMEOUT_HEIGHT: ArgOpt<u64> = arg_opt("timeout-height");
     pub const TIMEOUT_SEC_OFFSET: ArgOpt<u64> = arg_opt("timeout-sec-offset");
     pub const TM_ADDRESS: ArgOpt<String> = arg_opt("tm-address");
+    pub const TO_EPOCH: ArgOpt<Epoch> = arg_opt("to-epoch");
     pub const TOKEN_OPT: ArgOpt<WalletAddress> = TOKEN.opt();
     pub const TOKEN_STR_OPT: ArgOpt<String> = TOKEN_STR.opt();
     pub const TOKEN: Arg<WalletAddress> = arg("token");
     pub const TOKEN_STR: Arg<String> = arg("token");
+    pub const TOKEN_NAME: Arg<String> = arg("name");
+    pub const TOKEN_NAME_OPT: ArgOpt<String> = TOKEN_NAME.opt();
+    pub const TOKEN_SYMBOL: Arg<String> = arg("symbol");
+    pub const TOKEN_SYMBOL_OPT: ArgOpt<String> = TOKEN_SYMBOL.opt();
     pub const TRANSFER_SOURCE: Arg<WalletTransferSource> = arg("source");
     pub const TRANSFER_TARGET: Arg<WalletTransferTarget> = arg("target");
     pub const TRANSPARENT: ArgFlag = flag("transparent");
@@ -3747,6 +4425,159 @@ pub mod args {
         }
     }
 
+    /// Parse an optional hex encoded type hash
+    fn parse_type_hash(matches: &ArgMatches) -> Option<[u8; 32]> {
+        HASH_OPT.parse(matches).map(|hex_hash| {
+            HEXUPPER
+                .decode(hex_hash.to_uppercase().as_bytes())
+                .ok()
+                .and_then(|hash| hash.try_into().ok())
+                .unwrap_or_else(|| {
+                    eprintln!("Invalid type hash {hex_hash}");
+                    safe_exit(1)
+                })
+        })
+    }
+
+    /// Parse a column family
+    fn parse_column_family(cf: &str) -> storage::DbColFam {
+        storage::DbColFam::from_str(cf).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            safe_exit(1)
+        })
+    }
+
+    /// Parse a database key
+    fn parse_db_key(key: String) -> storage::Key {
+        storage::Key::parse(key).unwrap_or_else(|e| {
+            eprintln!("Invalid database key: {e}");
+            safe_exit(1)
+        })
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerDbGet {
+        pub key: storage::Key,
+        pub height: Option<BlockHeight>,
+        pub cf: storage::DbColFam,
+        pub type_hash: Option<[u8; 32]>,
+    }
+
+    impl Args for LedgerDbGet {
+        fn parse(matches: &ArgMatches) -> Self {
+            let key = parse_db_key(DB_KEY.parse(matches));
+            let height = BLOCK_HEIGHT_OPT.parse(matches);
+            let cf = parse_column_family(&DB_COLUMN_FAMILY.parse(matches));
+            let type_hash = parse_type_hash(matches);
+            Self {
+                key,
+                height,
+                cf,
+                type_hash,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(DB_KEY.def().help(wrap!("A database key to read.")))
+                .arg(BLOCK_HEIGHT_OPT.def().help(wrap!(
+                    "The height at which to read the value. Only the subspace \
+                     can be read at a past height. Defaults to the last \
+                     committed height."
+                )))
+                .arg(DB_COLUMN_FAMILY.def().help(wrap!(
+                    "The column family under which the key is kept. Defaults \
+                     to the subspace column family if none is provided."
+                )))
+                .arg(HASH_OPT.def().help(wrap!(
+                    "The hex encoded type hash of the value, used to decode \
+                     it. The raw bytes are printed if none is provided."
+                )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerDbScan {
+        pub prefix: Option<storage::Key>,
+        pub height: Option<BlockHeight>,
+        pub cf: storage::DbColFam,
+        pub type_hash: Option<[u8; 32]>,
+    }
+
+    impl Args for LedgerDbScan {
+        fn parse(matches: &ArgMatches) -> Self {
+            let prefix = DB_KEY_PREFIX.parse(matches).map(parse_db_key);
+            let height = BLOCK_HEIGHT_OPT.parse(matches);
+            let cf = parse_column_family(&DB_COLUMN_FAMILY.parse(matches));
+            let type_hash = parse_type_hash(matches);
+            Self {
+                prefix,
+                height,
+                cf,
+                type_hash,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(DB_KEY_PREFIX.def().help(wrap!(
+                "The key prefix to read. All the keys of the column family \
+                 are read if none is provided."
+            )))
+            .arg(BLOCK_HEIGHT_OPT.def().help(wrap!(
+                "The height at which to read the key-vals. Only the subspace \
+                 can be read at a past height. Defaults to the last committed \
+                 height."
+            )))
+            .arg(DB_COLUMN_FAMILY.def().help(wrap!(
+                "The column family to read. Defaults to the subspace column \
+                 family if none is provided."
+            )))
+            .arg(HASH_OPT.def().help(wrap!(
+                "The hex encoded type hash of the values, used to decode them. \
+                 The raw bytes are printed if none is provided."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerDbVerifyMerkle {
+        pub height: Option<BlockHeight>,
+    }
+
+    impl Args for LedgerDbVerifyMerkle {
+        fn parse(matches: &ArgMatches) -> Self {
+            let height = BLOCK_HEIGHT_OPT.parse(matches);
+            Self { height }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(BLOCK_HEIGHT_OPT.def().help(wrap!(
+                "The height of the merkle tree to verify. Defaults to the last \
+                 committed height."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerDbCompact {
+        pub cf: Option<storage::DbColFam>,
+    }
+
+    impl Args for LedgerDbCompact {
+        fn parse(matches: &ArgMatches) -> Self {
+            let cf = DB_COLUMN_FAMILY_OPT
+                .parse(matches)
+                .map(|cf| parse_column_family(&cf));
+            Self { cf }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(DB_COLUMN_FAMILY_OPT.def().help(wrap!(
+                "The column family to compact. All the column families are \
+                 compacted if none is provided."
+            )))
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct UpdateValidatorLocalConfig {
         pub config_path: PathBuf,
@@ -4765,6 +5596,10 @@ pub mod args {
                 refund_target: chain_ctx.get_opt(&self.refund_target),
                 ibc_shielding_data: self.ibc_shielding_data,
                 ibc_memo: self.ibc_memo,
+                relayer_fee_token: chain_ctx.get_opt(&self.relayer_fee_token),
+                recv_fee: self.recv_fee,
+                ack_fee: self.ack_fee,
+                timeout_fee: self.timeout_fee,
                 gas_spending_keys,
                 tx_code_path: self.tx_code_path.to_path_buf(),
             })
@@ -4791,6 +5626,12 @@ pub mod args {
                         .expect("Failed to decode IBC shielding data")
                 });
             let ibc_memo = IBC_MEMO.parse(matches);
+            let relayer_fee_token = RELAYER_FEE_TOKEN.parse(matches);
+            let recv_fee =
+                RECV_FEE.parse(matches).map(InputAmount::Unvalidated);
+            let ack_fee = ACK_FEE.parse(matches).map(InputAmount::Unvalidated);
+            let timeout_fee =
+                TIMEOUT_FEE.parse(matches).map(InputAmount::Unvalidated);
             let mut gas_spending_keys = vec![];
             if let Some(key) = GAS_SPENDING_KEY.parse(matches) {
                 gas_spending_keys.push(key);
@@ -4809,6 +5650,10 @@ pub mod args {
                 refund_target,
                 ibc_shielding_data,
                 ibc_memo,
+                relayer_fee_token,
+                recv_fee,
+                ack_fee,
+                timeout_fee,
                 gas_spending_keys,
                 tx_code_path,
             }
@@ -4853,6 +5698,36 @@ pub mod args {
                         .def()
                         .help(wrap!("The memo for IBC transfer packet.")),
                 )
+                .arg(RELAYER_FEE_TOKEN.def().help(wrap!(
+                    "The token of the fees paid to the relayers of the packet. \
+                     The fees are escrowed from the transparent source."
+                )))
+                .arg(
+                    RECV_FEE
+                        .def()
+                        .help(wrap!(
+                            "The fee paid to the relayer delivering the packet."
+                        ))
+                        .requires(RELAYER_FEE_TOKEN.name),
+                )
+                .arg(
+                    ACK_FEE
+                        .def()
+                        .help(wrap!(
+                            "The fee paid to the relayer delivering the \
+                             acknowledgement."
+                        ))
+                        .requires(RELAYER_FEE_TOKEN.name),
+                )
+                .arg(
+                    TIMEOUT_FEE
+                        .def()
+                        .help(wrap!(
+                            "The fee paid to the relayer delivering the \
+                             timeout."
+                        ))
+                        .requires(RELAYER_FEE_TOKEN.name),
+                )
                 .arg(GAS_SPENDING_KEY.def().help(wrap!(
                     "The optional spending key that will be used in addition \
                      to the source for gas payment (if this is a shielded \
@@ -4861,6 +5736,204 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<TxRegisterRelayerPayee<SdkTypes>>
+        for TxRegisterRelayerPayee<CliTypes>
+    {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<TxRegisterRelayerPayee<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(TxRegisterRelayerPayee::<SdkTypes> {
+                tx,
+                relayer: chain_ctx.get(&self.relayer),
+                port_id: self.port_id,
+                channel_id: self.channel_id,
+                payee: chain_ctx.get_opt(&self.payee),
+                counterparty_payee: self.counterparty_payee,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for TxRegisterRelayerPayee<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let relayer = IBC_RELAYER.parse(matches);
+            let port_id = PORT_ID.parse(matches);
+            let channel_id = CHANNEL_ID.parse(matches);
+            let payee = PAYEE_OPT.parse(matches);
+            let counterparty_payee = COUNTERPARTY_PAYEE.parse(matches);
+            let tx_code_path = PathBuf::from(TX_IBC_WASM);
+            Self {
+                tx,
+                relayer,
+                port_id,
+                channel_id,
+                payee,
+                counterparty_payee,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(IBC_RELAYER.def().help(wrap!(
+                    "The relayer address. The relayer's key is used to \
+                     produce the signature."
+                )))
+                .arg(PORT_ID.def().help(wrap!("The port ID.")))
+                .arg(CHANNEL_ID.def().help(wrap!("The channel ID.")))
+                .arg(PAYEE_OPT.def().help(wrap!(
+                    "The payee on this chain to which the relayer fees are \
+                     paid."
+                )))
+                .arg(COUNTERPARTY_PAYEE.def().help(wrap!(
+                    "The payee on the counterparty chain to which the receive \
+                     fees are paid."
+                )))
+                .group(
+                    ArgGroup::new("payee_flags")
+                        .args([PAYEE_OPT.name, COUNTERPARTY_PAYEE.name])
+                        .required(true),
+                )
+        }
+    }
+
+    impl CliToSdk<TxRegisterInterchainAccount<SdkTypes>>
+        for TxRegisterInterchainAccount<CliTypes>
+    {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<TxRegisterInterchainAccount<SdkTypes>, Self::Error>
+        {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(TxRegisterInterchainAccount::<SdkTypes> {
+                tx,
+                owner: chain_ctx.get(&self.owner),
+                connection_id: self.connection_id,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for TxRegisterInterchainAccount<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let owner = OWNER.parse(matches);
+            let connection_id = CONNECTION_ID.parse(matches);
+            let tx_code_path = PathBuf::from(TX_IBC_WASM);
+            Self {
+                tx,
+                owner,
+                connection_id,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(OWNER.def().help(wrap!(
+                    "The owner of the interchain account. The owner's key is \
+                     used to produce the signature."
+                )))
+                .arg(
+                    CONNECTION_ID
+                        .def()
+                        .help(wrap!("The connection ID to the host chain.")),
+                )
+        }
+    }
+
+    impl CliToSdk<TxSendInterchainAccountTx<SdkTypes>>
+        for TxSendInterchainAccountTx<CliTypes>
+    {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<TxSendInterchainAccountTx<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(TxSendInterchainAccountTx::<SdkTypes> {
+                tx,
+                owner: chain_ctx.get(&self.owner),
+                connection_id: self.connection_id,
+                receiver: self.receiver,
+                denom: self.denom,
+                amount: self.amount,
+                timeout_sec_offset: self.timeout_sec_offset,
+                ibc_memo: self.ibc_memo,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for TxSendInterchainAccountTx<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let owner = OWNER.parse(matches);
+            let connection_id = CONNECTION_ID.parse(matches);
+            let receiver = RECEIVER.parse(matches);
+            let denom = DENOM.parse(matches);
+            let amount = RAW_AMOUNT.parse(matches);
+            let timeout_sec_offset = TIMEOUT_SEC_OFFSET.parse(matches);
+            let ibc_memo = IBC_MEMO.parse(matches);
+            let tx_code_path = PathBuf::from(TX_IBC_WASM);
+            Self {
+                tx,
+                owner,
+                connection_id,
+                receiver,
+                denom,
+                amount,
+                timeout_sec_offset,
+                ibc_memo,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(OWNER.def().help(wrap!(
+                    "The owner of the interchain account. The owner's key is \
+                     used to produce the signature."
+                )))
+                .arg(
+                    CONNECTION_ID
+                        .def()
+                        .help(wrap!("The connection ID to the host chain.")),
+                )
+                .arg(RECEIVER.def().help(wrap!(
+                    "The receiver address on the host chain as string."
+                )))
+                .arg(DENOM.def().help(wrap!(
+                    "The denomination of the token on the host chain."
+                )))
+                .arg(RAW_AMOUNT.def().help(wrap!(
+                    "The amount to transfer in the smallest unit of the \
+                     denomination."
+                )))
+                .arg(
+                    TIMEOUT_SEC_OFFSET
+                        .def()
+                        .help(wrap!("The timeout as seconds.")),
+                )
+                .arg(IBC_MEMO.def().help(wrap!("The memo for the IBC packet.")))
+        }
+    }
+
     impl CliToSdk<TxInitAccount<SdkTypes>> for TxInitAccount<CliTypes> {
         type Error = std::io::Error;
 
@@ -5577,6 +6650,7 @@ pub mod args {
                 proposal_data,
                 is_pgf_stewards: self.is_pgf_stewards,
                 is_pgf_funding: self.is_pgf_funding,
+                is_parameter_change: self.is_parameter_change,
                 tx_code_path: self.tx_code_path,
             })
         }
@@ -5588,6 +6662,7 @@ pub mod args {
             let proposal_data = DATA_PATH.parse(matches);
             let is_pgf_stewards = PROPOSAL_PGF_STEWARD.parse(matches);
             let is_pgf_funding = PROPOSAL_PGF_FUNDING.parse(matches);
+            let is_parameter_change = PROPOSAL_PARAMETER_CHANGE.parse(matches);
             let tx_code_path = PathBuf::from(TX_INIT_PROPOSAL);
 
             Self {
@@ -5596,6 +6671,7 @@ pub mod args {
                 tx_code_path,
                 is_pgf_stewards,
                 is_pgf_funding,
+                is_parameter_change,
             }
         }
 
@@ -5611,6 +6687,7 @@ pub mod args {
                         .conflicts_with_all([
                             PROPOSAL_PGF_FUNDING.name,
                             PROPOSAL_PGF_STEWARD.name,
+                            PROPOSAL_PARAMETER_CHANGE.name,
                         ]),
                 )
                 .arg(
@@ -5623,6 +6700,7 @@ pub mod args {
                         .conflicts_with_all([
                             PROPOSAL_ETH.name,
                             PROPOSAL_PGF_FUNDING.name,
+                            PROPOSAL_PARAMETER_CHANGE.name,
                         ]),
                 )
                 .arg(
@@ -5635,14 +6713,29 @@ pub mod args {
                         .conflicts_with_all([
                             PROPOSAL_ETH.name,
                             PROPOSAL_PGF_STEWARD.name,
+                            PROPOSAL_PARAMETER_CHANGE.name,
                         ]),
                 )
-        }
-    }
-
-    impl CliToSdk<VoteProposal<SdkTypes>> for VoteProposal<CliTypes> {
-        type Error = std::io::Error;
-
+                .arg(
+                    PROPOSAL_PARAMETER_CHANGE
+                        .def()
+                        .help(wrap!(
+                            "Flag if the proposal is of type \
+                             parameter-change. Used to update protocol \
+                             parameters."
+                        ))
+                        .conflicts_with_all([
+                            PROPOSAL_ETH.name,
+                            PROPOSAL_PGF_STEWARD.name,
+                            PROPOSAL_PGF_FUNDING.name,
+                        ]),
+                )
+        }
+    }
+
+    impl CliToSdk<VoteProposal<SdkTypes>> for VoteProposal<CliTypes> {
+        type Error = std::io::Error;
+
         fn to_sdk(
             self,
             ctx: &mut Context,
@@ -5692,6 +6785,52 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<UpdateProposal<SdkTypes>> for UpdateProposal<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<UpdateProposal<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let proposal_content =
+                self.proposal_content.map(std::fs::read).transpose()?;
+
+            Ok(UpdateProposal::<SdkTypes> {
+                tx,
+                proposal_id: self.proposal_id,
+                proposal_content,
+                tx_code_path: self.tx_code_path,
+            })
+        }
+    }
+
+    impl Args for UpdateProposal<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let proposal_id = PROPOSAL_ID.parse(matches);
+            let proposal_content = DATA_PATH_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_UPDATE_PROPOSAL);
+
+            Self {
+                tx,
+                proposal_id,
+                proposal_content,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(PROPOSAL_ID.def().help(wrap!("The proposal identifier.")))
+                .arg(DATA_PATH_OPT.def().help(wrap!(
+                    "The path to a json file with the new proposal content. \
+                     The proposal is cancelled and its deposit refunded if \
+                     this is not given."
+                )))
+        }
+    }
+
     impl CliToSdk<RevealPk<SdkTypes>> for RevealPk<CliTypes> {
         type Error = std::io::Error;
 
@@ -5895,102 +7034,700 @@ pub mod args {
         }
     }
 
-    impl CliToSdk<QueryPgf<SdkTypes>> for QueryPgf<CliTypes> {
-        type Error = std::convert::Infallible;
+    impl CliToSdk<QueryPgf<SdkTypes>> for QueryPgf<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryPgf<SdkTypes>, Self::Error> {
+            Ok(QueryPgf::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+            })
+        }
+    }
+
+    impl CliToSdk<Withdraw<SdkTypes>> for Withdraw<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<Withdraw<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(Withdraw::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                source: self.source.map(|x| chain_ctx.get(&x)),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for Withdraw<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_WITHDRAW_WASM);
+            Self {
+                tx,
+                validator,
+                source,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!("Validator address.")))
+                .arg(SOURCE_OPT.def().help(wrap!(
+                    "Source address for withdrawing from delegations. For \
+                     withdrawing from self-bonds, this arg does not need to \
+                     be supplied."
+                )))
+        }
+    }
+
+    impl CliToSdk<ClaimRewards<SdkTypes>> for ClaimRewards<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<ClaimRewards<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(ClaimRewards::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                source: self.source.map(|x| chain_ctx.get(&x)),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for ClaimRewards<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CLAIM_REWARDS_WASM);
+            Self {
+                tx,
+                validator,
+                source,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!("Validator address.")))
+                .arg(SOURCE_OPT.def().help(wrap!(
+                    "Source address for claiming rewards for a bond. For \
+                     self-bonds, the validator is also the source."
+                )))
+        }
+    }
+
+    impl CliToSdk<AutoRestake<SdkTypes>> for AutoRestake<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<AutoRestake<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(AutoRestake::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                source: self.source.map(|x| chain_ctx.get(&x)),
+                enabled: self.enabled,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for AutoRestake<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let enabled = !DISABLE.parse(matches);
+            let tx_code_path = PathBuf::from(TX_AUTO_RESTAKE_WASM);
+            Self {
+                tx,
+                validator,
+                source,
+                enabled,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!("Validator address.")))
+                .arg(SOURCE_OPT.def().help(wrap!(
+                    "Source address of the bond. For self-bonds, the \
+                     validator is also the source."
+                )))
+                .arg(DISABLE.def().help(wrap!(
+                    "Disable the automatic restaking of the bond's rewards \
+                     instead of enabling it."
+                )))
+        }
+    }
+
+    impl CliToSdk<CreateToken<SdkTypes>> for CreateToken<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<CreateToken<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(CreateToken::<SdkTypes> {
+                tx,
+                admin: chain_ctx.get(&self.admin),
+                minter: self.minter.map(|x| chain_ctx.get(&x)),
+                name: self.name,
+                symbol: self.symbol,
+                description: self.description,
+                denom: self.denom,
+                supply_cap: self.supply_cap,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for CreateToken<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let admin = ADMIN.parse(matches);
+            let minter = MINTER_OPT.parse(matches);
+            let name = TOKEN_NAME.parse(matches);
+            let symbol = TOKEN_SYMBOL.parse(matches);
+            let description = DESCRIPTION_OPT.parse(matches);
+            let denom = DENOMINATION.parse(matches).into();
+            let supply_cap = SUPPLY_CAP_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CREATE_TOKEN_WASM);
+            Self {
+                tx,
+                admin,
+                minter,
+                name,
+                symbol,
+                description,
+                denom,
+                supply_cap,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(ADMIN.def().help(wrap!(
+                    "The admin of the new token. The token's address is \
+                     derived from the admin's address and the token's symbol."
+                )))
+                .arg(MINTER_OPT.def().help(wrap!(
+                    "The address allowed to mint and burn the token. \
+                     Defaults to the admin."
+                )))
+                .arg(TOKEN_NAME.def().help(wrap!("The name of the token.")))
+                .arg(
+                    TOKEN_SYMBOL
+                        .def()
+                        .help(wrap!("The ticker symbol of the token.")),
+                )
+                .arg(
+                    DESCRIPTION_OPT
+                        .def()
+                        .help(wrap!("An optional description of the token.")),
+                )
+                .arg(
+                    DENOMINATION.def().help(wrap!(
+                        "The number of decimal places of the token."
+                    )),
+                )
+                .arg(SUPPLY_CAP_OPT.def().help(wrap!(
+                    "An optional cap on the total supply of the token in \
+                     decimal."
+                )))
+        }
+    }
+
+    impl CliToSdk<MintTokens<SdkTypes>> for MintTokens<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<MintTokens<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(MintTokens::<SdkTypes> {
+                tx,
+                token: chain_ctx.get(&self.token),
+                authority: chain_ctx.get(&self.authority),
+                target: chain_ctx.get(&self.target),
+                amount: self.amount,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for MintTokens<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let token = TOKEN.parse(matches);
+            let authority = AUTHORITY.parse(matches);
+            let target = TARGET.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let tx_code_path = PathBuf::from(TX_MINT_TOKENS_WASM);
+            Self {
+                tx,
+                token,
+                authority,
+                target,
+                amount,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(TOKEN.def().help(wrap!("The token address.")))
+                .arg(AUTHORITY.def().help(wrap!(
+                    "The admin or the minter of the token. The authority's \
+                     key is used to produce the signature."
+                )))
+                .arg(TARGET.def().help(wrap!(
+                    "The account address receiving the minted tokens."
+                )))
+                .arg(AMOUNT.def().help(wrap!("The amount to mint in decimal.")))
+        }
+    }
+
+    impl CliToSdk<BurnTokens<SdkTypes>> for BurnTokens<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<BurnTokens<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(BurnTokens::<SdkTypes> {
+                tx,
+                token: chain_ctx.get(&self.token),
+                authority: chain_ctx.get(&self.authority),
+                source: self.source.map(|x| chain_ctx.get(&x)),
+                amount: self.amount,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for BurnTokens<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let token = TOKEN.parse(matches);
+            let authority = AUTHORITY.parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let tx_code_path = PathBuf::from(TX_BURN_TOKENS_WASM);
+            Self {
+                tx,
+                token,
+                authority,
+                source,
+                amount,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(TOKEN.def().help(wrap!("The token address.")))
+                .arg(AUTHORITY.def().help(wrap!(
+                    "The admin or the minter of the token. The authority's \
+                     key is used to produce the signature."
+                )))
+                .arg(SOURCE_OPT.def().help(wrap!(
+                    "The account address whose tokens are burned. The \
+                     source's key must also sign the transaction. Defaults \
+                     to the authority."
+                )))
+                .arg(AMOUNT.def().help(wrap!("The amount to burn in decimal.")))
+        }
+    }
+
+    impl CliToSdk<UpdateToken<SdkTypes>> for UpdateToken<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<UpdateToken<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(UpdateToken::<SdkTypes> {
+                tx,
+                token: chain_ctx.get(&self.token),
+                admin: chain_ctx.get(&self.admin),
+                new_admin: self.new_admin.map(|x| chain_ctx.get(&x)),
+                new_minter: self.new_minter.map(|x| chain_ctx.get(&x)),
+                name: self.name,
+                symbol: self.symbol,
+                description: self.description,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for UpdateToken<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let token = TOKEN.parse(matches);
+            let admin = ADMIN.parse(matches);
+            let new_admin = NEW_ADMIN_OPT.parse(matches);
+            let new_minter = NEW_MINTER_OPT.parse(matches);
+            let name = TOKEN_NAME_OPT.parse(matches);
+            let symbol = TOKEN_SYMBOL_OPT.parse(matches);
+            let description = DESCRIPTION_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_UPDATE_TOKEN_WASM);
+            Self {
+                tx,
+                token,
+                admin,
+                new_admin,
+                new_minter,
+                name,
+                symbol,
+                description,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(TOKEN.def().help(wrap!("The token address.")))
+                .arg(ADMIN.def().help(wrap!(
+                    "The current admin of the token. The admin's key is used \
+                     to produce the signature."
+                )))
+                .arg(
+                    NEW_ADMIN_OPT
+                        .def()
+                        .help(wrap!("The new admin of the token.")),
+                )
+                .arg(
+                    NEW_MINTER_OPT
+                        .def()
+                        .help(wrap!("The new minter of the token.")),
+                )
+                .arg(
+                    TOKEN_NAME_OPT
+                        .def()
+                        .help(wrap!("The new name of the token.")),
+                )
+                .arg(
+                    TOKEN_SYMBOL_OPT
+                        .def()
+                        .help(wrap!("The new ticker symbol of the token.")),
+                )
+                .arg(
+                    DESCRIPTION_OPT
+                        .def()
+                        .help(wrap!("The new description of the token.")),
+                )
+        }
+    }
+
+    impl CliToSdk<Approve<SdkTypes>> for Approve<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<Approve<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(Approve::<SdkTypes> {
+                tx,
+                token: chain_ctx.get(&self.token),
+                owner: chain_ctx.get(&self.owner),
+                spender: chain_ctx.get(&self.spender),
+                amount: self.amount,
+                expiry: self.expiry,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for Approve<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let token = TOKEN.parse(matches);
+            let owner = OWNER.parse(matches);
+            let spender = SPENDER.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let expiry = EXPIRY_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_APPROVE_WASM);
+            Self {
+                tx,
+                token,
+                owner,
+                spender,
+                amount,
+                expiry,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(TOKEN.def().help(wrap!("The token address.")))
+                .arg(OWNER.def().help(wrap!(
+                    "The account granting the allowance. The owner's key is \
+                     used to produce the signature."
+                )))
+                .arg(SPENDER.def().help(wrap!(
+                    "The account allowed to spend the owner's tokens."
+                )))
+                .arg(AMOUNT.def().help(wrap!(
+                    "The allowance amount in decimal. Replaces any existing \
+                     allowance. An amount of zero revokes it."
+                )))
+                .arg(EXPIRY_OPT.def().help(wrap!(
+                    "The last epoch in which the allowance can be spent. \
+                     Without it, the allowance never expires."
+                )))
+        }
+    }
+
+    impl CliToSdk<TransferFrom<SdkTypes>> for TransferFrom<CliTypes> {
+        type Error = std::io::Error;
 
         fn to_sdk(
             self,
             ctx: &mut Context,
-        ) -> Result<QueryPgf<SdkTypes>, Self::Error> {
-            Ok(QueryPgf::<SdkTypes> {
-                query: self.query.to_sdk(ctx)?,
+        ) -> Result<TransferFrom<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(TransferFrom::<SdkTypes> {
+                tx,
+                token: chain_ctx.get(&self.token),
+                spender: chain_ctx.get(&self.spender),
+                owner: chain_ctx.get(&self.owner),
+                target: chain_ctx.get(&self.target),
+                amount: self.amount,
+                tx_code_path: self.tx_code_path.to_path_buf(),
             })
         }
     }
 
-    impl CliToSdk<Withdraw<SdkTypes>> for Withdraw<CliTypes> {
+    impl Args for TransferFrom<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let token = TOKEN.parse(matches);
+            let spender = SPENDER.parse(matches);
+            let owner = OWNER.parse(matches);
+            let target = TARGET.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let tx_code_path = PathBuf::from(TX_TRANSFER_FROM_WASM);
+            Self {
+                tx,
+                token,
+                spender,
+                owner,
+                target,
+                amount,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(TOKEN.def().help(wrap!("The token address.")))
+                .arg(SPENDER.def().help(wrap!(
+                    "The account holding the allowance. The spender's key is \
+                     used to produce the signature."
+                )))
+                .arg(
+                    OWNER.def().help(wrap!(
+                        "The account whose tokens are transferred."
+                    )),
+                )
+                .arg(TARGET.def().help(wrap!("The target account address.")))
+                .arg(
+                    AMOUNT
+                        .def()
+                        .help(wrap!("The amount to transfer in decimal.")),
+                )
+        }
+    }
+
+    impl CliToSdk<VestTokens<SdkTypes>> for VestTokens<CliTypes> {
         type Error = std::io::Error;
 
         fn to_sdk(
             self,
             ctx: &mut Context,
-        ) -> Result<Withdraw<SdkTypes>, Self::Error> {
+        ) -> Result<VestTokens<SdkTypes>, Self::Error> {
             let tx = self.tx.to_sdk(ctx)?;
             let chain_ctx = ctx.borrow_chain_or_exit();
 
-            Ok(Withdraw::<SdkTypes> {
+            Ok(VestTokens::<SdkTypes> {
                 tx,
-                validator: chain_ctx.get(&self.validator),
-                source: self.source.map(|x| chain_ctx.get(&x)),
+                token: chain_ctx.get(&self.token),
+                source: chain_ctx.get(&self.source),
+                target: chain_ctx.get(&self.target),
+                amount: self.amount,
+                start_epoch: self.start_epoch,
+                cliff_epoch: self.cliff_epoch,
+                end_epoch: self.end_epoch,
                 tx_code_path: self.tx_code_path.to_path_buf(),
             })
         }
     }
 
-    impl Args for Withdraw<CliTypes> {
+    impl Args for VestTokens<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
-            let validator = VALIDATOR.parse(matches);
-            let source = SOURCE_OPT.parse(matches);
-            let tx_code_path = PathBuf::from(TX_WITHDRAW_WASM);
+            let token = TOKEN.parse(matches);
+            let source = SOURCE.parse(matches);
+            let target = TARGET.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let start_epoch = START_EPOCH_OPT.parse(matches);
+            let cliff_epoch = CLIFF_EPOCH_OPT.parse(matches);
+            let end_epoch = END_EPOCH.parse(matches);
+            let tx_code_path = PathBuf::from(TX_VEST_TOKENS_WASM);
             Self {
                 tx,
-                validator,
+                token,
                 source,
+                target,
+                amount,
+                start_epoch,
+                cliff_epoch,
+                end_epoch,
                 tx_code_path,
             }
         }
 
         fn def(app: App) -> App {
             app.add_args::<Tx<CliTypes>>()
-                .arg(VALIDATOR.def().help(wrap!("Validator address.")))
-                .arg(SOURCE_OPT.def().help(wrap!(
-                    "Source address for withdrawing from delegations. For \
-                     withdrawing from self-bonds, this arg does not need to \
-                     be supplied."
+                .arg(TOKEN.def().help(wrap!("The token address.")))
+                .arg(SOURCE.def().help(wrap!(
+                    "The account granting the tokens. The source's key is \
+                     used to produce the signature."
+                )))
+                .arg(
+                    TARGET.def().help(wrap!(
+                        "The account receiving the vesting tokens."
+                    )),
+                )
+                .arg(AMOUNT.def().help(wrap!(
+                    "The total amount of vesting tokens in decimal."
+                )))
+                .arg(START_EPOCH_OPT.def().help(wrap!(
+                    "The epoch from which the tokens are released linearly. \
+                     Defaults to the current epoch."
+                )))
+                .arg(CLIFF_EPOCH_OPT.def().help(wrap!(
+                    "The epoch before which no tokens are released. Defaults \
+                     to the start epoch. Set it to the end epoch to release \
+                     all the tokens at once."
+                )))
+                .arg(END_EPOCH.def().help(wrap!(
+                    "The epoch from which all the tokens are released."
                 )))
         }
     }
 
-    impl CliToSdk<ClaimRewards<SdkTypes>> for ClaimRewards<CliTypes> {
+    impl CliToSdk<GrantFee<SdkTypes>> for GrantFee<CliTypes> {
         type Error = std::io::Error;
 
         fn to_sdk(
             self,
             ctx: &mut Context,
-        ) -> Result<ClaimRewards<SdkTypes>, Self::Error> {
+        ) -> Result<GrantFee<SdkTypes>, Self::Error> {
             let tx = self.tx.to_sdk(ctx)?;
             let chain_ctx = ctx.borrow_chain_or_exit();
 
-            Ok(ClaimRewards::<SdkTypes> {
+            Ok(GrantFee::<SdkTypes> {
                 tx,
-                validator: chain_ctx.get(&self.validator),
-                source: self.source.map(|x| chain_ctx.get(&x)),
+                token: chain_ctx.get(&self.token),
+                sponsor: chain_ctx.get(&self.sponsor),
+                grantee: chain_ctx.get(&self.grantee),
+                amount: self.amount,
+                allowed_code_hashes: self.allowed_code_hashes,
+                expiry: self.expiry,
                 tx_code_path: self.tx_code_path.to_path_buf(),
             })
         }
     }
 
-    impl Args for ClaimRewards<CliTypes> {
+    impl Args for GrantFee<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
-            let validator = VALIDATOR.parse(matches);
-            let source = SOURCE_OPT.parse(matches);
-            let tx_code_path = PathBuf::from(TX_CLAIM_REWARDS_WASM);
+            let token = TOKEN.parse(matches);
+            let sponsor = SPONSOR.parse(matches);
+            let grantee = GRANTEE.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let allowed_code_hashes = CODE_HASHES.parse(matches);
+            let expiry = EXPIRY_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_GRANT_FEE_WASM);
             Self {
                 tx,
-                validator,
-                source,
+                token,
+                sponsor,
+                grantee,
+                amount,
+                allowed_code_hashes,
+                expiry,
                 tx_code_path,
             }
         }
 
         fn def(app: App) -> App {
             app.add_args::<Tx<CliTypes>>()
-                .arg(VALIDATOR.def().help(wrap!("Validator address.")))
-                .arg(SOURCE_OPT.def().help(wrap!(
-                    "Source address for claiming rewards for a bond. For \
-                     self-bonds, the validator is also the source."
+                .arg(TOKEN.def().help(wrap!("The token of the fees.")))
+                .arg(SPONSOR.def().help(wrap!(
+                    "The account paying the fees. The sponsor's key is used \
+                     to produce the signature."
+                )))
+                .arg(GRANTEE.def().help(wrap!(
+                    "The account whose transaction fees are paid."
+                )))
+                .arg(AMOUNT.def().help(wrap!(
+                    "The total amount of fees that can be paid in decimal. \
+                     Replaces any existing grant. An amount of zero revokes \
+                     it."
+                )))
+                .arg(CODE_HASHES.def().help(wrap!(
+                    "The code hashes of the transactions whose fees can be \
+                     paid. Without them, the fees of any transaction can be \
+                     paid."
+                )))
+                .arg(EXPIRY_OPT.def().help(wrap!(
+                    "The last epoch in which the grant can be spent. Without \
+                     it, the grant never expires."
                 )))
         }
     }
@@ -6584,6 +8321,66 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<MergeSignatures<SdkTypes>> for MergeSignatures<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<MergeSignatures<SdkTypes>, Self::Error> {
+            let query = self.query.infallible_to_sdk(ctx);
+            let tx_data = std::fs::read(self.tx_data)?;
+            let signatures = self
+                .signatures
+                .iter()
+                .map(std::fs::read)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(MergeSignatures::<SdkTypes> {
+                query,
+                tx_data,
+                owner: ctx.borrow_chain_or_exit().get(&self.owner),
+                signatures,
+                output_folder: self.output_folder,
+            })
+        }
+    }
+
+    impl Args for MergeSignatures<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let tx_path = TX_PATH.parse(matches);
+            let owner = OWNER.parse(matches);
+            let signatures = SIGNATURES.parse(matches);
+            let output_folder = OUTPUT_FOLDER_PATH.parse(matches);
+            Self {
+                query,
+                tx_data: tx_path,
+                owner,
+                signatures,
+                output_folder,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(TX_PATH.def().help(wrap!(
+                    "The path to the tx file with the serialized tx."
+                )))
+                .arg(
+                    OWNER.def().help(wrap!("The address of the account owner")),
+                )
+                .arg(SIGNATURES.def().help(wrap!(
+                    "List of file paths containing a serialized signature of \
+                     a member of the account."
+                )))
+                .arg(OUTPUT_FOLDER_PATH.def().help(wrap!(
+                    "The output folder path where the merged tx will be \
+                     stored."
+                )))
+        }
+    }
+
     impl Args for ShieldedSync<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let ledger_address = CONFIG_RPC_LEDGER_ADDRESS.parse(matches);
@@ -6760,6 +8557,108 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QueryValidatorHistory<SdkTypes>>
+        for QueryValidatorHistory<CliTypes>
+    {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryValidatorHistory<SdkTypes>, Self::Error> {
+            Ok(QueryValidatorHistory::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                validator: ctx.borrow_chain_or_exit().get(&self.validator),
+                from_epoch: self.from_epoch,
+                to_epoch: self.to_epoch,
+            })
+        }
+    }
+
+    impl Args for QueryValidatorHistory<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let from_epoch = FROM_EPOCH.parse(matches);
+            let to_epoch = TO_EPOCH.parse(matches);
+            Self {
+                query,
+                validator,
+                from_epoch,
+                to_epoch,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!(
+                    "The validator's address whose history to query."
+                )))
+                .arg(FROM_EPOCH.def().help(wrap!(
+                    "The first epoch to query (the oldest epoch whose data is \
+                     still available, if not specified)."
+                )))
+                .arg(TO_EPOCH.def().help(wrap!(
+                    "The last epoch to query (the pipeline epoch, if not \
+                     specified)."
+                )))
+        }
+    }
+
+    impl CliToSdk<QueryBondHistory<SdkTypes>> for QueryBondHistory<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryBondHistory<SdkTypes>, Self::Error> {
+            let query = self.query.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            Ok(QueryBondHistory::<SdkTypes> {
+                query,
+                owner: chain_ctx.get(&self.owner),
+                validator: chain_ctx.get(&self.validator),
+                from_epoch: self.from_epoch,
+                to_epoch: self.to_epoch,
+            })
+        }
+    }
+
+    impl Args for QueryBondHistory<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let owner = OWNER.parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let from_epoch = FROM_EPOCH.parse(matches);
+            let to_epoch = TO_EPOCH.parse(matches);
+            Self {
+                query,
+                owner,
+                validator,
+                from_epoch,
+                to_epoch,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(OWNER.def().help(wrap!(
+                    "The owner account address whose bond to query."
+                )))
+                .arg(VALIDATOR.def().help(wrap!(
+                    "The validator's address of the bond to query."
+                )))
+                .arg(FROM_EPOCH.def().help(wrap!(
+                    "The first epoch to query (the oldest epoch whose data is \
+                     still available, if not specified)."
+                )))
+                .arg(TO_EPOCH.def().help(wrap!(
+                    "The last epoch to query (the pipeline epoch, if not \
+                     specified)."
+                )))
+        }
+    }
+
     impl CliToSdk<QueryMetaData<SdkTypes>> for QueryMetaData<CliTypes> {
         type Error = std::convert::Infallible;
 
@@ -6862,6 +8761,47 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QueryAutoRestake<SdkTypes>> for QueryAutoRestake<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryAutoRestake<SdkTypes>, Self::Error> {
+            Ok(QueryAutoRestake::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                validator: ctx.borrow_chain_or_exit().get(&self.validator),
+                source: self.source.map(|x| ctx.borrow_chain_or_exit().get(&x)),
+            })
+        }
+    }
+
+    impl Args for QueryAutoRestake<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            Self {
+                query,
+                source,
+                validator,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(SOURCE_OPT.def().help(wrap!(
+                    "Source address of the bond. For self-bonds, this arg \
+                     does not need to be supplied."
+                )))
+                .arg(
+                    VALIDATOR
+                        .def()
+                        .help(wrap!("Validator address of the bond.")),
+                )
+        }
+    }
+
     impl Args for QueryDelegations<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let query = Query::parse(matches);
@@ -7032,6 +8972,7 @@ pub mod args {
                     .chain_id
                     .or_else(|| Some(ctx.config.ledger.chain_id.clone())),
                 wrapper_fee_payer: self.wrapper_fee_payer.map(|x| ctx.get(&x)),
+                fee_sponsor: self.fee_sponsor.map(|x| ctx.get(&x)),
                 memo: self.memo,
                 use_device: self.use_device,
             })
@@ -7154,6 +9095,10 @@ pub mod args {
                     ))
                     .conflicts_with(DISPOSABLE_SIGNING_KEY.name),
             )
+            .arg(FEE_SPONSOR_OPT.def().help(wrap!(
+                "The address of an account sponsoring the fees of the gas \
+                 payer with a fee grant."
+            )))
             .arg(USE_DEVICE.def().help(wrap!(
                 "Use an attached hardware wallet device to sign the \
                  transaction."
@@ -7188,6 +9133,7 @@ pub mod args {
             let password = None;
             let memo = MEMO_OPT.parse(matches).map(String::into_bytes);
             let wrapper_fee_payer = FEE_PAYER_OPT.parse(matches);
+            let fee_sponsor = FEE_SPONSOR_OPT.parse(matches);
             let output_folder = OUTPUT_FOLDER_PATH.parse(matches);
             let use_device = USE_DEVICE.parse(matches);
             let no_expiration = NO_EXPIRATION.parse(matches);
@@ -7219,6 +9165,7 @@ pub mod args {
                 password,
                 chain_id,
                 wrapper_fee_payer,
+                fee_sponsor,
                 output_folder,
                 memo,
                 use_device,
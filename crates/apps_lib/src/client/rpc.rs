@@ -27,10 +27,12 @@ use namada_sdk::hash::Hash;
 use namada_sdk::io::Io;
 use namada_sdk::key::*;
 use namada_sdk::masp::{BalanceOwner, MaspEpoch, MaspTokenRewardData};
-use namada_sdk::parameters::{storage as param_storage, EpochDuration};
+use namada_sdk::parameters::{
+    storage as param_storage, BaseFeeParams, EpochDuration,
+};
 use namada_sdk::proof_of_stake::types::{
-    CommissionPair, Slash, ValidatorMetaData, ValidatorState,
-    ValidatorStateInfo, WeightedValidator,
+    BondEpochData, CommissionPair, Slash, ValidatorEpochData,
+    ValidatorMetaData, ValidatorState, ValidatorStateInfo, WeightedValidator,
 };
 use namada_sdk::proof_of_stake::PosParams;
 use namada_sdk::queries::{Client, RPC};
@@ -589,6 +591,30 @@ pub async fn query_protocol_parameters(
         "",
         governance_parameters.min_proposal_grace_epochs
     );
+    display_line!(
+        context.io(),
+        "{:4}Default proposals tally weighting: {}",
+        "",
+        governance_parameters.tally_weightings.default
+    );
+    display_line!(
+        context.io(),
+        "{:4}PGF steward proposals tally weighting: {}",
+        "",
+        governance_parameters.tally_weightings.pgf_steward
+    );
+    display_line!(
+        context.io(),
+        "{:4}PGF funding proposals tally weighting: {}",
+        "",
+        governance_parameters.tally_weightings.pgf_payment
+    );
+    display_line!(
+        context.io(),
+        "{:4}Conviction epochs: {}",
+        "",
+        governance_parameters.conviction_epochs
+    );
 
     let pgf_parameters = query_pgf_parameters(context.client()).await;
     display_line!(context.io(), "\nPublic Goods Funding Parameters");
@@ -665,10 +691,47 @@ pub async fn query_protocol_parameters(
             .await
             .expect("Parameter should be defined.");
     display_line!(context.io(), "{:4}Gas cost table:", "");
-    for (token, gas_cost) in gas_cost_table {
+    for (token, gas_cost) in &gas_cost_table {
         display_line!(context.io(), "{:8}{}: {:?}", "", token, gas_cost);
     }
 
+    let key = param_storage::get_base_fee_params_key();
+    let base_fee_params: Option<BaseFeeParams> =
+        query_storage_value(context.client(), &key).await.ok();
+    match base_fee_params {
+        Some(base_fee_params) => {
+            display_line!(
+                context.io(),
+                "{:4}Base fee target block gas percentage: {}",
+                "",
+                base_fee_params.target_block_gas_percentage
+            );
+            display_line!(
+                context.io(),
+                "{:4}Base fee max change denominator: {}",
+                "",
+                base_fee_params.max_change_denominator
+            );
+            display_line!(context.io(), "{:4}Base fee table:", "");
+            for token in gas_cost_table.keys() {
+                let base_fee = rpc::query_base_fee(context.client(), token)
+                    .await
+                    .expect("Base fee should be defined.")
+                    .unwrap_or_default();
+                display_line!(
+                    context.io(),
+                    "{:8}{}: {:?}",
+                    "",
+                    token,
+                    base_fee
+                );
+            }
+        }
+        None => {
+            display_line!(context.io(), "{:4}Base fee market: disabled", "");
+        }
+    }
+
     display_line!(context.io(), "PoS parameters");
     let pos_params = query_pos_parameters(context.client()).await;
     display_line!(
@@ -1269,6 +1332,109 @@ pub async fn query_and_print_commission_rate(
     }
 }
 
+/// Query and print a validator's stake, commission rate, state and rank over a
+/// range of epochs
+pub async fn query_and_print_validator_history(
+    context: &impl Namada,
+    args: args::QueryValidatorHistory,
+) {
+    let validator = args.validator;
+    let history = namada_sdk::rpc::query_validator_history(
+        context.client(),
+        &validator,
+        args.from_epoch.unwrap_or_default(),
+        args.to_epoch.unwrap_or(Epoch(u64::MAX)),
+    )
+    .await
+    .unwrap();
+
+    if history.is_empty() {
+        display_line!(
+            context.io(),
+            "No data found for validator {validator} in the given epochs."
+        );
+        return;
+    }
+    display_line!(context.io(), "History of validator {validator}:");
+    for ValidatorEpochData {
+        epoch,
+        stake,
+        commission_rate,
+        state,
+        rank,
+    } in history
+    {
+        let state = match state {
+            Some(ValidatorState::Consensus) => "consensus",
+            Some(ValidatorState::BelowCapacity) => "below-capacity",
+            Some(ValidatorState::BelowThreshold) => "below-threshold",
+            Some(ValidatorState::Inactive) => "inactive",
+            Some(ValidatorState::Jailed) => "jailed",
+            None => "n/a",
+        };
+        display_line!(
+            context.io(),
+            "  Epoch {epoch}: stake {}, commission rate {}, state {state}, \
+             rank {}",
+            stake
+                .map(|stake| stake.to_string_native())
+                .unwrap_or_else(|| "n/a".to_string()),
+            commission_rate
+                .map(|rate| rate.to_string())
+                .unwrap_or_else(|| "n/a".to_string()),
+            rank.map(|rank| rank.to_string())
+                .unwrap_or_else(|| "n/a".to_string()),
+        );
+    }
+}
+
+/// Query and print a bond's bonded, unbonding and withdrawable amounts over a
+/// range of epochs
+pub async fn query_and_print_bond_history(
+    context: &impl Namada,
+    args: args::QueryBondHistory,
+) {
+    let owner = args.owner;
+    let validator = args.validator;
+    let history = namada_sdk::rpc::query_bond_history(
+        context.client(),
+        &owner,
+        &validator,
+        args.from_epoch.unwrap_or_default(),
+        args.to_epoch.unwrap_or(Epoch(u64::MAX)),
+    )
+    .await
+    .unwrap();
+
+    if history.is_empty() {
+        display_line!(
+            context.io(),
+            "No data found for the bond from {owner} to {validator} in the \
+             given epochs."
+        );
+        return;
+    }
+    display_line!(
+        context.io(),
+        "History of the bond from {owner} to {validator}:"
+    );
+    for BondEpochData {
+        epoch,
+        bonded,
+        unbonding,
+        withdrawable,
+    } in history
+    {
+        display_line!(
+            context.io(),
+            "  Epoch {epoch}: bonded {}, unbonding {}, withdrawable {}",
+            bonded.to_string_native(),
+            unbonding.to_string_native(),
+            withdrawable.to_string_native(),
+        );
+    }
+}
+
 /// Query PoS validator's metadata
 pub async fn query_and_print_metadata(
     context: &impl Namada,
@@ -1529,6 +1695,34 @@ pub async fn query_and_print_rewards<N: Namada>(
     );
 }
 
+pub async fn query_and_print_auto_restake<N: Namada>(
+    context: &N,
+    args: args::QueryAutoRestake,
+) {
+    let validator = args.validator;
+    let source = args.source.unwrap_or_else(|| validator.clone());
+
+    let auto_restake = namada_sdk::rpc::query_auto_restake(
+        context.client(),
+        &source,
+        &validator,
+    )
+    .await
+    .unwrap();
+    match auto_restake {
+        Some(epoch) => display_line!(
+            context.io(),
+            "Rewards of the bond {source} -> {validator} are automatically \
+             restaked since epoch {epoch}"
+        ),
+        None => display_line!(
+            context.io(),
+            "Rewards of the bond {source} -> {validator} are not \
+             automatically restaked"
+        ),
+    }
+}
+
 pub async fn query_delegations<N: Namada>(
     context: &N,
     args: args::QueryDelegations,
@@ -2062,5 +2256,6 @@ pub async fn compute_proposal_votes<C: namada_sdk::queries::Client + Sync>(
         validator_voting_power,
         delegators_vote,
         delegator_voting_power,
+        ..Default::default()
     }
 }
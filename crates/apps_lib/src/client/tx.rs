@@ -10,7 +10,8 @@ use namada_sdk::address::{Address, ImplicitAddress};
 use namada_sdk::args::TxBecomeValidator;
 use namada_sdk::collections::HashSet;
 use namada_sdk::governance::cli::onchain::{
-    DefaultProposal, PgfFundingProposal, PgfStewardProposal,
+    DefaultProposal, ParameterChangeProposal, PgfFundingProposal,
+    PgfStewardProposal,
 };
 use namada_sdk::ibc::convert_masp_tx_to_ibc_memo;
 use namada_sdk::io::Io;
@@ -18,10 +19,14 @@ use namada_sdk::key::*;
 use namada_sdk::rpc::{InnerTxResult, TxBroadcastData, TxResponse};
 use namada_sdk::state::EPOCH_SWITCH_BLOCKS_DELAY;
 use namada_sdk::tx::data::compute_inner_tx_hash;
-use namada_sdk::tx::{CompressedAuthorization, Section, Signer, Tx};
+use namada_sdk::tx::{
+    CompressedAuthorization, Section, SignatureIndex, Signer, Tx,
+};
 use namada_sdk::wallet::alias::{validator_address, validator_consensus_key};
 use namada_sdk::wallet::{Wallet, WalletIo};
-use namada_sdk::{display_line, edisplay_line, error, signing, tx, Namada};
+use namada_sdk::{
+    display_line, edisplay_line, error, signing, token, tx, Namada,
+};
 use rand::rngs::OsRng;
 use tokio::sync::RwLock;
 
@@ -313,6 +318,180 @@ where
     Ok(None)
 }
 
+pub async fn submit_create_token<N: Namada>(
+    namada: &N,
+    args: args::CreateToken,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        let cmt = tx.first_commitments().unwrap().to_owned();
+        let wrapper_hash = tx.wrapper_hash();
+        let response = namada.submit(tx, &args.tx).await?;
+        if response
+            .is_applied_and_valid(wrapper_hash.as_ref(), &cmt)
+            .is_some()
+        {
+            let token = token::factory_token(&args.admin, &args.symbol);
+            display_line!(
+                namada.io(),
+                "The token {} was created with the address {}.",
+                args.symbol,
+                token
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn submit_mint_tokens<N: Namada>(
+    namada: &N,
+    args: args::MintTokens,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_burn_tokens<N: Namada>(
+    namada: &N,
+    args: args::BurnTokens,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_update_token<N: Namada>(
+    namada: &N,
+    args: args::UpdateToken,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_approve<N: Namada>(
+    namada: &N,
+    args: args::Approve,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_transfer_from<N: Namada>(
+    namada: &N,
+    args: args::TransferFrom,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_vest_tokens<N: Namada>(
+    namada: &N,
+    args: args::VestTokens,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_grant_fee<N: Namada>(
+    namada: &N,
+    args: args::GrantFee,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_change_consensus_key(
     namada: &impl Namada,
     args: args::ConsensusKeyChange,
@@ -896,6 +1075,69 @@ where
     Ok(())
 }
 
+pub async fn submit_register_interchain_account<N: Namada>(
+    namada: &N,
+    args: args::TxRegisterInterchainAccount,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    submit_reveal_aux(namada, args.tx.clone(), &args.owner).await?;
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_send_interchain_account_tx<N: Namada>(
+    namada: &N,
+    args: args::TxSendInterchainAccountTx,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    submit_reveal_aux(namada, args.tx.clone(), &args.owner).await?;
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_register_relayer_payee<N: Namada>(
+    namada: &N,
+    args: args::TxRegisterRelayerPayee,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    submit_reveal_aux(namada, args.tx.clone(), &args.relayer).await?;
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_init_proposal<N: Namada>(
     namada: &N,
     args: args::InitProposal,
@@ -954,6 +1196,36 @@ where
             .await?;
 
         tx::build_pgf_stewards_proposal(namada, &args, proposal).await?
+    } else if args.is_parameter_change {
+        let proposal =
+            ParameterChangeProposal::try_from(args.proposal_data.as_ref())
+                .map_err(|e| {
+                    error::TxSubmitError::FailedGovernaneProposalDeserialize(
+                        e.to_string(),
+                    )
+                })?;
+        let author_balance = namada_sdk::rpc::get_token_balance(
+            namada.client(),
+            &namada.native_token(),
+            &proposal.proposal.author,
+        )
+        .await
+        .unwrap();
+        let proposal = proposal
+            .validate(
+                &governance_parameters,
+                current_epoch,
+                author_balance,
+                args.tx.force,
+            )
+            .map_err(|e| {
+                error::TxSubmitError::InvalidProposal(e.to_string())
+            })?;
+
+        submit_reveal_aux(namada, args.tx.clone(), &proposal.proposal.author)
+            .await?;
+
+        tx::build_parameter_change_proposal(namada, &args, proposal).await?
     } else {
         let proposal = DefaultProposal::try_from(args.proposal_data.as_ref())
             .map_err(|e| {
@@ -1016,6 +1288,26 @@ where
     Ok(())
 }
 
+pub async fn submit_update_proposal<N: Namada>(
+    namada: &N,
+    args: args::UpdateProposal,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx_builder, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx_builder);
+    } else {
+        sign(namada, &mut tx_builder, &args.tx, signing_data).await?;
+
+        namada.submit(tx_builder, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn sign_tx<N: Namada>(
     namada: &N,
     args::SignTx {
@@ -1097,6 +1389,87 @@ where
     Ok(())
 }
 
+pub async fn merge_signatures<N: Namada>(
+    namada: &N,
+    args::MergeSignatures {
+        query: _,
+        tx_data,
+        owner,
+        signatures,
+        output_folder,
+    }: args::MergeSignatures,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let mut tx = if let Ok(transaction) = Tx::deserialize(tx_data.as_ref()) {
+        transaction
+    } else {
+        edisplay_line!(namada.io(), "Couldn't decode the transaction.");
+        safe_exit(1)
+    };
+    let signatures = signatures
+        .iter()
+        .map(|bytes| {
+            SignatureIndex::deserialize(bytes).map_err(|err| {
+                error::Error::Other(format!("Invalid signature file: {err}"))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let account = namada_sdk::rpc::get_account_info(namada.client(), &owner)
+        .await?
+        .ok_or_else(|| {
+            error::Error::from(error::TxSubmitError::InvalidAccount(
+                owner.encode(),
+            ))
+        })?;
+
+    let merged = signing::merge_signatures(
+        &tx,
+        &owner,
+        &account.public_keys_map,
+        account.threshold,
+        signatures,
+    )?;
+    tx.add_signatures(merged.added.clone());
+
+    let filename = format!("{}.tx", tx.header_hash());
+    let output_path = match &output_folder {
+        Some(path) => path.join(filename),
+        None => filename.into(),
+    };
+    let out = File::create(&output_path)
+        .expect("Should be able to create the transaction file.");
+    serde_json::to_writer_pretty(out, &tx.serialize())
+        .expect("Should be able to write to file.");
+    display_line!(
+        namada.io(),
+        "Attached {} new signature(s), {} of the {} required by {} are \
+         collected. Transaction serialized to {}.",
+        merged.added.len(),
+        merged.count(),
+        merged.threshold,
+        owner,
+        output_path.display()
+    );
+    if merged.is_complete() {
+        display_line!(
+            namada.io(),
+            "The signature threshold is met. The transaction can be \
+             submitted with `tx --tx-path {} --owner {}`.",
+            output_path.display(),
+            owner
+        );
+    } else {
+        display_line!(
+            namada.io(),
+            "{} more signature(s) are needed.",
+            merged.missing()
+        );
+    }
+    Ok(())
+}
+
 pub async fn submit_reveal_pk<N: Namada>(
     namada: &N,
     args: args::RevealPk,
@@ -1203,6 +1576,26 @@ where
     Ok(())
 }
 
+pub async fn submit_auto_restake<N: Namada>(
+    namada: &N,
+    args: args::AutoRestake,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_redelegate<N: Namada>(
     namada: &N,
     args: args::Redelegate,
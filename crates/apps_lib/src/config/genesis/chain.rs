@@ -310,6 +310,7 @@ impl Finalized {
             minimum_gas_price,
             max_tx_bytes,
             is_native_token_transferable,
+            base_fee_params,
             ..
         } = self.parameters.parameters.clone();
 
@@ -359,6 +360,7 @@ impl Finalized {
                 })
                 .collect(),
             is_native_token_transferable,
+            base_fee_params,
         }
     }
 
@@ -418,6 +420,8 @@ impl Finalized {
             max_proposal_content_size,
             min_proposal_grace_epochs,
             max_proposal_latency,
+            conviction_epochs,
+            tally_weightings,
         } = self.parameters.gov_params.clone();
         namada_sdk::governance::parameters::GovernanceParameters {
             min_proposal_fund: Amount::native_whole(min_proposal_fund),
@@ -427,6 +431,8 @@ impl Finalized {
             min_proposal_grace_epochs,
             min_proposal_voting_period,
             max_proposal_latency,
+            tally_weightings,
+            conviction_epochs,
         }
     }
 
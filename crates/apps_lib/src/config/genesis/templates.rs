@@ -14,6 +14,8 @@ use namada_sdk::dec::Dec;
 use namada_sdk::eth_bridge::storage::parameters::{
     Contracts, Erc20WhitelistEntry, MinimumConfirmations,
 };
+use namada_sdk::governance::utils::TallyWeightings;
+use namada_sdk::parameters::BaseFeeParams;
 use namada_sdk::token::{
     Amount, DenominatedAmount, Denomination, NATIVE_MAX_DECIMAL_PLACES,
 };
@@ -299,6 +301,9 @@ pub struct ChainParams<T: TemplateValidation> {
     pub gas_scale: u64,
     /// Map of the cost per gas unit for every token allowed for fee payment
     pub minimum_gas_price: T::GasMinimums,
+    /// The base fee market parameters. The market is disabled if not set.
+    #[serde(default)]
+    pub base_fee_params: Option<BaseFeeParams>,
 }
 
 impl ChainParams<Unvalidated> {
@@ -321,6 +326,7 @@ impl ChainParams<Unvalidated> {
             masp_fee_payment_gas_limit,
             gas_scale,
             minimum_gas_price,
+            base_fee_params,
         } = self;
         let mut min_gas_prices = BTreeMap::default();
         for (token, amount) in minimum_gas_price.into_iter() {
@@ -366,6 +372,7 @@ impl ChainParams<Unvalidated> {
             masp_fee_payment_gas_limit,
             gas_scale,
             minimum_gas_price: min_gas_prices,
+            base_fee_params,
         })
     }
 }
@@ -449,6 +456,11 @@ pub struct GovernanceParams {
     pub min_proposal_grace_epochs: u64,
     /// Maximum number of epochs between current epoch and start epochs
     pub max_proposal_latency: u64,
+    /// Number of epochs after which a bond carries its full voting power in
+    /// conviction weighted tallies
+    pub conviction_epochs: u64,
+    /// The weighting of the voting power used to tally each type of proposal
+    pub tally_weightings: TallyWeightings,
 }
 
 #[derive(
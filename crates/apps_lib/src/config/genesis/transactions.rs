@@ -82,6 +82,7 @@ fn get_tx_args(use_device: bool) -> TxArgs {
         wallet_alias_force: false,
         fee_amount: None,
         wrapper_fee_payer: None,
+        fee_sponsor: None,
         fee_token: genesis_fee_token_address(),
         gas_limit: 0.into(),
         expiration: Default::default(),
@@ -13,6 +13,7 @@ use std::path::{Path, PathBuf};
 use directories::ProjectDirs;
 use namada_sdk::chain::ChainId;
 use namada_sdk::collections::HashMap;
+use namada_sdk::events::log;
 use namada_sdk::storage::BlockHeight;
 use namada_sdk::time::Rfc3339String;
 use serde::{Deserialize, Serialize};
@@ -47,6 +48,25 @@ pub struct Config {
 pub struct ValidatorLocalConfig {
     pub accepted_gas_tokens:
         HashMap<namada_sdk::address::Address, namada_sdk::token::Amount>,
+    /// The order in which the txs from the mempool are included in the
+    /// proposed blocks
+    #[serde(default)]
+    pub tx_ordering: TxOrdering,
+}
+
+/// The policy used by a block proposer to order the txs from the mempool
+#[derive(
+    Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TxOrdering {
+    /// Keep the order in which the txs were received by the mempool.
+    #[default]
+    Fifo,
+    /// Take the txs in rounds of one tx per fee payer, keeping the order of
+    /// the txs of each fee payer, and order each round by the gas price
+    /// relative to the base fee of the fee token.
+    FeePriority,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -92,6 +112,31 @@ pub struct ActionAtHeight {
     pub action: Action,
 }
 
+/// The policy for pruning the historical state (the diffs and the block
+/// data of past heights) from the DB.
+#[derive(
+    Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case", tag = "mode")]
+pub enum PruningPolicy {
+    /// Keep the full history.
+    #[default]
+    Archive,
+    /// Keep the history of (at least) the given number of last blocks. The
+    /// history is pruned up to the start of the epoch of the oldest kept
+    /// block, so that the merkle tree can be restored at every kept height.
+    KeepLastBlocks {
+        /// The number of blocks to keep
+        blocks: NonZeroU64,
+    },
+    /// Prune the history once every given number of epochs, keeping (at
+    /// least) as many full epochs.
+    KeepEveryNthEpoch {
+        /// The number of epochs between two prunings
+        epochs: NonZeroU64,
+    },
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Ledger {
     pub genesis_time: Rfc3339String,
@@ -128,6 +173,17 @@ pub struct Shell {
     /// When set, indicates after how many blocks a new snapshot
     /// will be taken (counting from the first block)
     pub blocks_between_snapshots: Option<NonZeroU64>,
+    /// The policy for pruning the historical state from the DB
+    #[serde(default)]
+    pub pruning: PruningPolicy,
+    /// The events of the blocks older than this number of the last blocks
+    /// are pruned from the event log
+    #[serde(default = "default_event_log_retention_blocks")]
+    pub event_log_retention_blocks: u64,
+}
+
+const fn default_event_log_retention_blocks() -> u64 {
+    log::DEFAULT_RETENTION_BLOCKS
 }
 
 impl Ledger {
@@ -157,6 +213,8 @@ impl Ledger {
                 action_at_height: None,
                 tendermint_mode: mode,
                 blocks_between_snapshots: None,
+                pruning: PruningPolicy::Archive,
+                event_log_retention_blocks: log::DEFAULT_RETENTION_BLOCKS,
             },
             cometbft: tendermint_config,
             ethereum_bridge: ethereum_bridge::ledger::Config::default(),
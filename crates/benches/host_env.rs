@@ -1,6 +1,10 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use namada_apps_lib::account::AccountPublicKeysMap;
 use namada_apps_lib::collections::{HashMap, HashSet};
+use namada_apps_lib::hash::HashAlgorithm;
+use namada_apps_lib::key::{
+    common, ed25519, secp256k1, RefTo, SecretKey, SigScheme,
+};
 use namada_apps_lib::storage::DB;
 use namada_apps_lib::token::{Amount, Transfer};
 use namada_apps_lib::tx::Authorization;
@@ -321,6 +325,66 @@ fn storage_write(c: &mut Criterion) {
     group.finish();
 }
 
+fn host_hashing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("host_hashing");
+
+    for algorithm in [
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Keccak256,
+        HashAlgorithm::Blake2b256,
+    ] {
+        for data_len in [0, 32, 256, 1_024, 8_192, 65_536] {
+            // Extract the throughput, together with the wall-time, so that we
+            // can than invert it to calculate the desired metric (time/byte)
+            group.throughput(criterion::Throughput::Bytes(data_len));
+            let data: Vec<u8> = (0..data_len).map(|_| rand::random()).collect();
+
+            group.bench_function(
+                format!("{algorithm:?}, bytes: {data_len}"),
+                |b| b.iter(|| algorithm.digest(&data)),
+            );
+        }
+    }
+
+    group.finish();
+}
+
+fn signature_verification(c: &mut Criterion) {
+    let mut group = c.benchmark_group("signature_verification");
+    let data: Vec<u8> = (0..1_024).map(|_| rand::random()).collect();
+
+    for (scheme, keypair) in [
+        (
+            "ed25519",
+            ed25519::SigScheme::from_bytes([1; 32])
+                .try_to_sk::<common::SecretKey>()
+                .unwrap(),
+        ),
+        (
+            "secp256k1",
+            secp256k1::SigScheme::from_bytes([1; 32])
+                .try_to_sk::<common::SecretKey>()
+                .unwrap(),
+        ),
+    ] {
+        let public_key = keypair.ref_to();
+        let signature = common::SigScheme::sign(&keypair, &data);
+
+        group.bench_function(scheme, |b| {
+            b.iter(|| {
+                common::SigScheme::verify_signature(
+                    &public_key,
+                    &data,
+                    &signature,
+                )
+                .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     host_env,
     tx_section_signature_validation,
@@ -330,5 +394,7 @@ criterion_group!(
     storage_read,
     write_log_write,
     storage_write,
+    host_hashing,
+    signature_verification,
 );
 criterion_main!(host_env);
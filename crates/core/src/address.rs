@@ -17,8 +17,9 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
 use crate::ethereum_events::EthAddress;
-use crate::ibc::IbcTokenHash;
+use crate::ibc::{IbcTokenHash, InterchainAccountHash};
 use crate::key::PublicKeyHash;
+use crate::token::FactoryTokenHash;
 use crate::{impl_display_and_from_str_via_format, key, string_encoding};
 
 /// The length of an established [`Address`] encoded with Borsh.
@@ -146,6 +147,16 @@ impl From<raw::Address<'_, raw::Validated>> for Address {
             raw::Discriminant::ReplayProtection => {
                 Address::Internal(InternalAddress::ReplayProtection)
             }
+            raw::Discriminant::FactoryToken => {
+                Address::Internal(InternalAddress::FactoryToken(
+                    FactoryTokenHash(*raw_addr.data()),
+                ))
+            }
+            raw::Discriminant::InterchainAccount => {
+                Address::Internal(InternalAddress::InterchainAccount(
+                    InterchainAccountHash(*raw_addr.data()),
+                ))
+            }
         }
     }
 }
@@ -252,6 +263,22 @@ impl<'addr> From<&'addr Address> for raw::Address<'addr, raw::Validated> {
                 .validate()
                 .expect("This raw address is valid")
             }
+            Address::Internal(InternalAddress::FactoryToken(
+                FactoryTokenHash(hash),
+            )) => {
+                raw::Address::from_discriminant(raw::Discriminant::FactoryToken)
+                    .with_data_array_ref(hash)
+                    .validate()
+                    .expect("This raw address is valid")
+            }
+            Address::Internal(InternalAddress::InterchainAccount(
+                InterchainAccountHash(hash),
+            )) => raw::Address::from_discriminant(
+                raw::Discriminant::InterchainAccount,
+            )
+            .with_data_array_ref(hash)
+            .validate()
+            .expect("This raw address is valid"),
         }
     }
 }
@@ -590,6 +617,10 @@ pub enum InternalAddress {
     /// Address with temporary storage is used to pass data from txs to VPs
     /// which is never committed to DB
     TempStorage,
+    /// User-issued token created with the token factory
+    FactoryToken(FactoryTokenHash),
+    /// Account on this chain controlled by a counterparty chain over IBC
+    InterchainAccount(InterchainAccountHash),
 }
 
 impl Display for InternalAddress {
@@ -613,6 +644,10 @@ impl Display for InternalAddress {
                 Self::Masp => "MASP".to_string(),
                 Self::ReplayProtection => "ReplayProtection".to_string(),
                 Self::TempStorage => "TempStorage".to_string(),
+                Self::FactoryToken(hash) => format!("FactoryToken: {}", hash),
+                Self::InterchainAccount(hash) => {
+                    format!("InterchainAccount: {}", hash)
+                }
             }
         )
     }
@@ -836,6 +871,8 @@ pub mod testing {
             InternalAddress::Masp => {}
             InternalAddress::Multitoken => {}
             InternalAddress::ReplayProtection => {}
+            InternalAddress::FactoryToken(_) => {}
+            InternalAddress::InterchainAccount(_) => {}
             InternalAddress::TempStorage => {} /* Add new addresses in the
                                                 * `prop_oneof` below. */
         };
@@ -855,6 +892,8 @@ pub mod testing {
             Just(InternalAddress::Masp),
             Just(InternalAddress::ReplayProtection),
             Just(InternalAddress::TempStorage),
+            arb_factory_token(),
+            arb_interchain_account(),
         ]
     }
 
@@ -879,6 +918,18 @@ pub mod testing {
         })
     }
 
+    fn arb_factory_token() -> impl Strategy<Value = InternalAddress> {
+        proptest::array::uniform20(proptest::num::u8::ANY).prop_map(|hash| {
+            InternalAddress::FactoryToken(FactoryTokenHash(hash))
+        })
+    }
+
+    fn arb_interchain_account() -> impl Strategy<Value = InternalAddress> {
+        proptest::array::uniform20(proptest::num::u8::ANY).prop_map(|hash| {
+            InternalAddress::InterchainAccount(InterchainAccountHash(hash))
+        })
+    }
+
     fn arb_erc20() -> impl Strategy<Value = InternalAddress> {
         proptest::array::uniform20(proptest::num::u8::ANY).prop_map(|addr| {
             InternalAddress::Erc20(crate::ethereum_events::EthAddress(addr))
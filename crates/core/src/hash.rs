@@ -10,6 +10,7 @@ use data_encoding::HEXUPPER;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
+use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use thiserror::Error;
@@ -174,6 +175,56 @@ impl Hash {
     }
 }
 
+/// Hash algorithms that can be computed by the host environment on behalf of
+/// the wasm guest code.
+// =================================================================
+// __WARNING__: Take extreme care when changing these values, as they
+// are part of the interface between the host and the wasm guests.
+// =================================================================
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    TryFromPrimitive,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub enum HashAlgorithm {
+    /// SHA-256
+    Sha256 = 0,
+    /// Keccak-256, as used by Ethereum
+    Keccak256 = 1,
+    /// BLAKE2b with a 256 bits output
+    Blake2b256 = 2,
+}
+
+impl HashAlgorithm {
+    /// Compute the digest of some bytes with this algorithm
+    pub fn digest(self, data: impl AsRef<[u8]>) -> [u8; HASH_LENGTH] {
+        match self {
+            Self::Sha256 => Hash::sha256(data).0,
+            Self::Keccak256 => KeccakHasher::hash(data).into(),
+            Self::Blake2b256 => {
+                let digest = blake2b_simd::Params::new()
+                    .hash_length(HASH_LENGTH)
+                    .hash(data.as_ref());
+                let mut output = [0u8; HASH_LENGTH];
+                output.copy_from_slice(digest.as_bytes());
+                output
+            }
+        }
+    }
+}
+
 impl From<Hash> for crate::tendermint::Hash {
     fn from(hash: Hash) -> Self {
         Self::Sha256(hash.0)
@@ -365,4 +416,26 @@ pub mod tests {
             let _: Hash = hex_hash.try_into().unwrap();
         }
     }
+
+    #[test]
+    fn test_hash_algorithm_digests() {
+        let data = b"abc";
+        assert_eq!(
+            HEXUPPER.encode(&HashAlgorithm::Sha256.digest(data)),
+            "BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD"
+        );
+        assert_eq!(
+            HEXUPPER.encode(&HashAlgorithm::Keccak256.digest(data)),
+            "4E03657AEA45A94FC7D47BA826C8D667C0D1E6E33A64A036EC44F58FA12D6C45"
+        );
+        assert_eq!(
+            HEXUPPER.encode(&HashAlgorithm::Blake2b256.digest(data)),
+            "BDDD813C634239723171EF3FEE98579B94964E3BB1CB3E427262C8C068D52319"
+        );
+        assert_eq!(
+            HashAlgorithm::try_from(1_u8).unwrap(),
+            HashAlgorithm::Keccak256
+        );
+        assert!(HashAlgorithm::try_from(3_u8).is_err());
+    }
 }
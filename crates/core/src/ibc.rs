@@ -53,6 +53,33 @@ impl FromStr for IbcTokenHash {
     }
 }
 
+/// Interchain account hash derived from the host connection and the
+/// controller port.
+#[derive(
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[repr(transparent)]
+pub struct InterchainAccountHash(pub [u8; HASH_LEN]);
+
+impl Display for InterchainAccountHash {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", HEXLOWER.encode(&self.0))
+    }
+}
+
 /// IBC transaction data section hash
 pub type IbcTxDataHash = Hash;
 
@@ -5,6 +5,7 @@ use std::collections::BTreeMap;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
+use serde::{Deserialize, Serialize};
 
 use super::address::Address;
 use super::chain::ProposalBytes;
@@ -55,6 +56,83 @@ pub struct Parameters {
     pub minimum_gas_price: BTreeMap<Address, token::Amount>,
     /// Enable the native token transfer if it is true
     pub is_native_token_transferable: bool,
+    /// The base fee market parameters. The base fee market is disabled if
+    /// they are not set.
+    pub base_fee_params: Option<BaseFeeParams>,
+}
+
+/// Parameters of the base fee market. The base fee of every fee token is
+/// adjusted after each block by the gas used in the block, and it's never
+/// lower than the minimum gas price of the token.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct BaseFeeParams {
+    /// The percentage of the max block gas targeted by the base fee
+    pub target_block_gas_percentage: u64,
+    /// The inverse of the max change of the base fee in a block. The base
+    /// fee changes by `1 / max_change_denominator` when a block is empty or
+    /// full.
+    pub max_change_denominator: u64,
+}
+
+impl Default for BaseFeeParams {
+    fn default() -> Self {
+        Self {
+            target_block_gas_percentage: 50,
+            max_change_denominator: 8,
+        }
+    }
+}
+
+impl BaseFeeParams {
+    /// Compute the base fee of the next block from the base fee of the last
+    /// block and its used gas. The base fee is not lower than the minimum
+    /// gas price.
+    pub fn next_base_fee(
+        &self,
+        base_fee: token::Amount,
+        minimum_gas_price: token::Amount,
+        block_gas: u64,
+        max_block_gas: u64,
+    ) -> Option<token::Amount> {
+        let target = max_block_gas
+            .checked_mul(self.target_block_gas_percentage.min(100))?
+            .checked_div(100)?;
+        let base_fee = base_fee.max(minimum_gas_price);
+        if target == 0 || block_gas == target {
+            return Some(base_fee);
+        }
+        let change = |gas_delta: u64| {
+            base_fee
+                .checked_mul(gas_delta)?
+                .checked_div_u64(target)?
+                .checked_div_u64(self.max_change_denominator)
+        };
+        let next_base_fee = if block_gas > target {
+            // The base fee rises by at least one unit on a congested block
+            let change = change(block_gas.checked_sub(target)?)?
+                .max(token::Amount::from_u64(1));
+            base_fee.checked_add(change)?
+        } else {
+            let change = change(target.checked_sub(block_gas)?)?;
+            base_fee.checked_sub(change)?
+        };
+        Some(next_base_fee.max(minimum_gas_price))
+    }
 }
 
 /// Epoch duration. A new epoch begins as soon as both the `min_num_of_blocks`
@@ -98,6 +176,7 @@ impl Default for Parameters {
             gas_scale: 100_000_000,
             minimum_gas_price: Default::default(),
             is_native_token_transferable: true,
+            base_fee_params: None,
         }
     }
 }
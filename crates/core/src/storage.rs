@@ -115,6 +115,9 @@ pub const STATE_CF: &str = "state";
 pub const BLOCK_CF: &str = "block";
 /// Replay protection column family name
 pub const REPLAY_PROTECTION_CF: &str = "replay_protection";
+/// Events index column family name. The events index is local to a node, so
+/// it's not one of the [`DbColFam`]s that make up the snapshots of the state.
+pub const EVENTS_CF: &str = "events";
 
 impl DbColFam {
     /// Get the name of the column family
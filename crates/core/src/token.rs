@@ -1,11 +1,14 @@
 //! A basic fungible token
 
 use std::cmp::Ordering;
+use std::collections::BTreeSet;
 use std::fmt::Display;
 use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
-use data_encoding::BASE32HEX_NOPAD;
+use data_encoding::{
+    DecodePartial, BASE32HEX_NOPAD, HEXLOWER, HEXLOWER_PERMISSIVE,
+};
 use ethabi::ethereum_types::U256;
 use ibc::apps::transfer::types::Amount as IbcAmount;
 use namada_macros::BorshDeserializer;
@@ -14,8 +17,10 @@ use namada_migrations::*;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::address::HASH_LEN;
 use crate::arith::{self, checked, CheckedAdd, CheckedSub};
 use crate::dec::{Dec, POS_DECIMAL_PRECISION};
+use crate::hash::Hash;
 use crate::storage;
 use crate::storage::{DbKeySeg, KeySeg};
 use crate::uint::{self, Uint, I256};
@@ -412,6 +417,211 @@ impl From<Denomination> for u8 {
     }
 }
 
+/// Hash of a user-issued token, derived from its issuer and symbol.
+#[derive(
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[repr(transparent)]
+pub struct FactoryTokenHash(pub [u8; HASH_LEN]);
+
+impl Display for FactoryTokenHash {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", HEXLOWER.encode(&self.0))
+    }
+}
+
+impl FromStr for FactoryTokenHash {
+    type Err = DecodePartial;
+
+    fn from_str(h: &str) -> Result<Self, Self::Err> {
+        let mut output = [0u8; HASH_LEN];
+        HEXLOWER_PERMISSIVE.decode_mut(h.as_ref(), &mut output)?;
+        Ok(FactoryTokenHash(output))
+    }
+}
+
+/// Descriptive metadata of a token issued with the token factory.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct TokenMetadata {
+    /// Human readable name of the token
+    pub name: String,
+    /// Ticker symbol of the token
+    pub symbol: String,
+    /// Optional description of the token
+    pub description: Option<String>,
+}
+
+/// A bounded amount of a token that an owner allows a spender to transfer out
+/// of the owner's balance.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct Allowance {
+    /// The remaining amount that can be spent
+    pub amount: Amount,
+    /// The last epoch in which the allowance can be spent. Never expires if
+    /// not set.
+    pub expiry: Option<storage::Epoch>,
+}
+
+impl Allowance {
+    /// Check if the allowance has expired at the given epoch
+    pub fn is_expired(&self, current_epoch: storage::Epoch) -> bool {
+        self.expiry.is_some_and(|expiry| current_epoch > expiry)
+    }
+}
+
+/// A bounded amount of a token that a sponsor allows to be spent on the
+/// wrapper fees of a grantee.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct FeeGrant {
+    /// The remaining amount that can be spent on fees
+    pub amount: Amount,
+    /// The code hashes of the txs whose fees can be paid. Any tx is allowed
+    /// if empty.
+    pub allowed_code_hashes: BTreeSet<Hash>,
+    /// The last epoch in which the grant can be spent. Never expires if not
+    /// set.
+    pub expiry: Option<storage::Epoch>,
+}
+
+impl FeeGrant {
+    /// Check if the grant has expired at the given epoch
+    pub fn is_expired(&self, current_epoch: storage::Epoch) -> bool {
+        self.expiry.is_some_and(|expiry| current_epoch > expiry)
+    }
+
+    /// Check if the grant can pay the fees of a tx with the given code hash
+    pub fn is_code_allowed(&self, code_hash: &Hash) -> bool {
+        self.allowed_code_hashes.is_empty()
+            || self.allowed_code_hashes.contains(code_hash)
+    }
+}
+
+/// A schedule locking an amount of tokens in an owner's balance, which is
+/// released linearly from its start epoch to its end epoch. Nothing is
+/// released before the cliff epoch. A cliff at the end epoch releases all the
+/// tokens at once.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct VestingSchedule {
+    /// The total amount of tokens subject to vesting
+    pub amount: Amount,
+    /// The epoch from which the tokens start vesting
+    pub start_epoch: storage::Epoch,
+    /// The epoch before which no tokens are released
+    pub cliff_epoch: storage::Epoch,
+    /// The epoch from which all the tokens are released
+    pub end_epoch: storage::Epoch,
+}
+
+impl VestingSchedule {
+    /// Check that the schedule's epochs are ordered and that its amount is
+    /// not zero
+    pub fn is_valid(&self) -> bool {
+        !self.amount.is_zero()
+            && self.start_epoch <= self.cliff_epoch
+            && self.cliff_epoch <= self.end_epoch
+    }
+
+    /// Get the amount of tokens that are still locked at the given epoch.
+    /// Returns `None` on overflow.
+    pub fn locked_amount(
+        &self,
+        current_epoch: storage::Epoch,
+    ) -> Option<Amount> {
+        if current_epoch >= self.end_epoch {
+            return Some(Amount::zero());
+        }
+        if current_epoch < self.cliff_epoch {
+            return Some(self.amount);
+        }
+        // The cliff is before the end epoch, so the vesting period is not
+        // empty
+        let vesting_epochs =
+            self.end_epoch.0.checked_sub(self.start_epoch.0)?;
+        let remaining_epochs = self.end_epoch.0.checked_sub(current_epoch.0)?;
+        self.amount
+            .checked_mul(remaining_epochs)?
+            .checked_div_u64(vesting_epochs)
+    }
+
+    /// Check if all the tokens have been released at the given epoch
+    pub fn is_fully_vested(&self, current_epoch: storage::Epoch) -> bool {
+        current_epoch >= self.end_epoch
+    }
+}
+
 /// An amount with its denomination.
 #[derive(
     Debug,
@@ -1069,6 +1279,7 @@ mod tests {
     use assert_matches::assert_matches;
 
     use super::*;
+    use crate::storage::Epoch;
 
     #[test]
     fn test_token_display() {
@@ -1297,6 +1508,53 @@ mod tests {
         assert_eq!(c.checked_sub(c).unwrap(), g);
     }
 
+    #[test]
+    fn test_vesting_schedule_locked_amount() {
+        let schedule = VestingSchedule {
+            amount: Amount::native_whole(100),
+            start_epoch: Epoch(10),
+            cliff_epoch: Epoch(12),
+            end_epoch: Epoch(20),
+        };
+        assert!(schedule.is_valid());
+        assert_eq!(
+            schedule.locked_amount(Epoch(0)),
+            Some(Amount::native_whole(100))
+        );
+        assert_eq!(
+            schedule.locked_amount(Epoch(11)),
+            Some(Amount::native_whole(100))
+        );
+        assert_eq!(
+            schedule.locked_amount(Epoch(12)),
+            Some(Amount::native_whole(80))
+        );
+        assert_eq!(
+            schedule.locked_amount(Epoch(15)),
+            Some(Amount::native_whole(50))
+        );
+        assert_eq!(schedule.locked_amount(Epoch(20)), Some(Amount::zero()));
+        assert!(!schedule.is_fully_vested(Epoch(19)));
+        assert!(schedule.is_fully_vested(Epoch(20)));
+
+        // A cliff at the end releases all the tokens at once
+        let cliff = VestingSchedule {
+            cliff_epoch: Epoch(20),
+            ..schedule
+        };
+        assert_eq!(
+            cliff.locked_amount(Epoch(19)),
+            Some(Amount::native_whole(100))
+        );
+        assert_eq!(cliff.locked_amount(Epoch(20)), Some(Amount::zero()));
+
+        let invalid = VestingSchedule {
+            cliff_epoch: Epoch(21),
+            ..schedule
+        };
+        assert!(!invalid.is_valid());
+    }
+
     #[test]
     fn test_denominated_amt_ord() {
         let denom_1 = DenominatedAmount {
@@ -85,6 +85,17 @@ pub const STORAGE_DELETE_GAS_PER_BYTE: u64 =
     MEMORY_ACCESS_GAS_PER_BYTE + 69_634 + PHYSICAL_STORAGE_LATENCY_PER_BYTE;
 /// The cost of verifying a single signature of a transaction
 pub const VERIFY_TX_SIG_GAS: u64 = 594_290;
+/// The cost of verifying a single signature over some arbitrary data,
+/// excluding the cost of hashing the data
+pub const VERIFY_SIG_GAS: u64 = 597_450;
+/// The fixed cost of hashing some data in the host environment
+pub const HASH_FIXED_GAS: u64 = 2_180;
+/// The cost of hashing data with SHA-256, per byte
+pub const SHA256_GAS_PER_BYTE: u64 = 46;
+/// The cost of hashing data with Keccak-256, per byte
+pub const KECCAK256_GAS_PER_BYTE: u64 = 72;
+/// The cost of hashing data with BLAKE2b-256, per byte
+pub const BLAKE2B256_GAS_PER_BYTE: u64 = 27;
 /// The cost for requesting one more page in wasm (64KiB)
 #[allow(clippy::cast_possible_truncation)] // const in u32 range
 pub const WASM_MEMORY_PAGE_GAS: u32 =
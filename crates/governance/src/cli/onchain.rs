@@ -13,11 +13,12 @@ use serde::{Deserialize, Serialize};
 use super::validation::{
     is_valid_activation_epoch, is_valid_author_balance, is_valid_content,
     is_valid_default_proposal_data, is_valid_end_epoch,
-    is_valid_pgf_funding_data, is_valid_pgf_stewards_data,
-    is_valid_proposal_period, is_valid_start_epoch, ProposalValidation,
+    is_valid_parameter_change_data, is_valid_pgf_funding_data,
+    is_valid_pgf_stewards_data, is_valid_proposal_period, is_valid_start_epoch,
+    ProposalValidation,
 };
 use crate::parameters::GovernanceParameters;
-use crate::storage::proposal::PGFTarget;
+use crate::storage::proposal::{PGFTarget, ParameterChange, ParameterModule};
 
 #[derive(
     Debug,
@@ -194,6 +195,93 @@ impl TryFrom<&[u8]> for PgfStewardProposal {
     }
 }
 
+/// Parameter change proposal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterChangeProposal {
+    /// The proposal data
+    pub proposal: OnChainProposal,
+    /// The parameter change proposal extra data
+    pub data: Vec<ParameterChangeData>,
+}
+
+/// A parameter change of a parameter change proposal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterChangeData {
+    /// The module owning the parameter
+    pub module: ParameterModule,
+    /// The name of the parameter field
+    pub field: String,
+    /// The new value of the parameter
+    pub value: serde_json::Value,
+}
+
+impl From<ParameterChangeData> for ParameterChange {
+    fn from(value: ParameterChangeData) -> Self {
+        ParameterChange {
+            module: value.module,
+            field: value.field,
+            value: value.value.to_string(),
+        }
+    }
+}
+
+impl ParameterChangeProposal {
+    /// Validate a parameter change proposal
+    pub fn validate(
+        self,
+        governance_parameters: &GovernanceParameters,
+        current_epoch: Epoch,
+        balance: token::Amount,
+        force: bool,
+    ) -> Result<Self, ProposalValidation> {
+        if force {
+            return Ok(self);
+        }
+        is_valid_start_epoch(
+            self.proposal.voting_start_epoch,
+            current_epoch,
+            governance_parameters.min_proposal_voting_period,
+        )?;
+        is_valid_end_epoch(
+            self.proposal.voting_start_epoch,
+            self.proposal.voting_end_epoch,
+            current_epoch,
+            governance_parameters.min_proposal_voting_period,
+            governance_parameters.min_proposal_voting_period,
+            governance_parameters.max_proposal_period,
+        )?;
+        is_valid_activation_epoch(
+            self.proposal.activation_epoch,
+            self.proposal.voting_end_epoch,
+            governance_parameters.min_proposal_grace_epochs,
+        )?;
+        is_valid_proposal_period(
+            self.proposal.voting_start_epoch,
+            self.proposal.activation_epoch,
+            governance_parameters.max_proposal_period,
+        )?;
+        is_valid_author_balance(
+            balance,
+            governance_parameters.min_proposal_fund,
+        )?;
+        is_valid_content(
+            &self.proposal.content,
+            governance_parameters.max_proposal_content_size,
+        )?;
+        is_valid_parameter_change_data(&self.data)?;
+
+        Ok(self)
+    }
+}
+
+impl TryFrom<&[u8]> for ParameterChangeProposal {
+    type Error = serde_json::Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        serde_json::from_slice(value)
+    }
+}
+
 /// PGF funding proposal
 #[derive(
     Debug,
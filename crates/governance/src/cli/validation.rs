@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use namada_core::address::Address;
 use namada_core::arith::{self, checked};
@@ -6,7 +6,9 @@ use namada_core::storage::Epoch;
 use namada_core::token;
 use thiserror::Error;
 
-use super::onchain::{PgfFunding, StewardsUpdate};
+use super::onchain::{ParameterChangeData, PgfFunding, StewardsUpdate};
+use crate::storage::proposal::ParameterChange;
+use crate::vp::MAX_PARAMETER_CHANGES;
 
 /// This enum represents proposal data
 #[derive(Debug, Error)]
@@ -64,6 +66,9 @@ pub enum ProposalValidation {
     /// The PGF funding data is not valid
     #[error("invalid proposal extra data: cannot be empty.")]
     InvalidPgfFundingExtraData,
+    /// The parameter change data is not valid
+    #[error("Invalid proposal extra data: {0}.")]
+    InvalidParameterChangeExtraData(String),
     #[error("Arithmetic {0}.")]
     Arith(#[from] arith::Error),
 }
@@ -239,3 +244,36 @@ pub fn is_valid_pgf_funding_data(
         Err(ProposalValidation::InvalidPgfFundingExtraData)
     }
 }
+
+pub fn is_valid_parameter_change_data(
+    data: &[ParameterChangeData],
+) -> Result<(), ProposalValidation> {
+    if data.is_empty() || data.len() > MAX_PARAMETER_CHANGES {
+        return Err(ProposalValidation::InvalidParameterChangeExtraData(
+            format!(
+                "expected between 1 and {MAX_PARAMETER_CHANGES} changes, got \
+                 {}",
+                data.len()
+            ),
+        ));
+    }
+    let mut fields = BTreeSet::new();
+    for change in data {
+        if !fields.insert((&change.module, &change.field)) {
+            return Err(ProposalValidation::InvalidParameterChangeExtraData(
+                format!(
+                    "parameter {}.{} is changed more than once",
+                    change.module, change.field
+                ),
+            ));
+        }
+        ParameterChange::from(change.clone())
+            .validate()
+            .map_err(|err| {
+                ProposalValidation::InvalidParameterChangeExtraData(
+                    err.to_string(),
+                )
+            })?;
+    }
+    Ok(())
+}
@@ -36,6 +36,20 @@ pub mod types {
     pub const NEW_PROPOSAL: EventType =
         namada_events::event_type!(GovernanceEvent, PROPOSAL_SUBDOMAIN, "new");
 
+    /// Proposal cancelled by its author.
+    pub const PROPOSAL_CANCELLED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "cancelled"
+    );
+
+    /// Proposal content amended by its author.
+    pub const PROPOSAL_AMENDED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "amended"
+    );
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -96,6 +110,22 @@ impl GovernanceEvent {
             kind: ProposalEventKind::Rejected { has_proposal_code },
         }
     }
+
+    /// Event for a proposal cancelled by its author
+    pub fn cancelled_proposal(proposal_id: u64) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::Cancelled,
+        }
+    }
+
+    /// Event for a proposal whose content was amended by its author
+    pub fn amended_proposal(proposal_id: u64) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::Amended,
+        }
+    }
 }
 
 /// Proposal event kinds
@@ -118,6 +148,10 @@ pub enum ProposalEventKind {
         /// Does the proposal contain code?
         has_proposal_code: bool,
     },
+    /// Proposal cancelled before its voting period
+    Cancelled,
+    /// Proposal content amended before its voting period
+    Amended,
 }
 
 impl From<GovernanceEvent> for Event {
@@ -159,6 +193,18 @@ impl From<GovernanceEvent> for Event {
                 );
                 (event_type, attributes)
             }
+            ProposalEventKind::Cancelled => {
+                let event_type = types::PROPOSAL_CANCELLED;
+                let attributes =
+                    updated_governance_proposal_attributes(proposal_id);
+                (event_type, attributes)
+            }
+            ProposalEventKind::Amended => {
+                let event_type = types::PROPOSAL_AMENDED;
+                let attributes =
+                    updated_governance_proposal_attributes(proposal_id);
+                (event_type, attributes)
+            }
         };
 
         let mut event = Self::new(event_type, EventLevel::Block);
@@ -201,6 +247,14 @@ fn new_governance_proposal_attributes(
     attrs
 }
 
+/// Return the attributes of a governance proposal updated by its author.
+#[inline]
+fn updated_governance_proposal_attributes(id: u64) -> BTreeMap<String, String> {
+    let mut attrs = BTreeMap::new();
+    attrs.with_attribute(ProposalId(id));
+    attrs
+}
+
 impl EventToEmit for GovernanceEvent {
     const DOMAIN: &'static str = "governance";
 }
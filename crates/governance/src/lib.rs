@@ -37,9 +37,15 @@ pub mod vp;
 use namada_state::{StorageRead, StorageWrite};
 pub use namada_systems::governance::*;
 use parameters::GovernanceParameters;
-pub use storage::proposal::{InitProposalData, ProposalType, VoteProposalData};
+pub use storage::proposal::{
+    InitProposalData, ProposalType, ProposalUpdate, UpdateProposalData,
+    VoteProposalData,
+};
 pub use storage::vote::ProposalVote;
-pub use storage::{init_proposal, is_proposal_accepted, vote_proposal};
+pub use storage::{
+    amend_proposal, cancel_proposal, init_proposal, is_proposal_accepted,
+    vote_proposal,
+};
 
 /// The governance internal address
 pub const ADDRESS: Address = address::GOV;
@@ -4,8 +4,11 @@ use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 use namada_state::{StorageRead, StorageResult, StorageWrite};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use super::storage::keys as goverance_storage;
+use crate::utils::{TallyWeighting, TallyWeightings};
 
 #[derive(
     Clone,
@@ -18,6 +21,8 @@ use super::storage::keys as goverance_storage;
     BorshSerialize,
     BorshDeserialize,
     BorshDeserializer,
+    Serialize,
+    Deserialize,
 )]
 /// Governance parameter structure
 pub struct GovernanceParameters {
@@ -36,6 +41,11 @@ pub struct GovernanceParameters {
     pub min_proposal_grace_epochs: u64,
     /// Maximum number of epochs between current epoch and start epoch
     pub max_proposal_latency: u64,
+    /// The weighting of the voting power used to tally each type of proposal
+    pub tally_weightings: TallyWeightings,
+    /// Number of epochs after which a bond carries its full voting power in
+    /// conviction weighted tallies
+    pub conviction_epochs: u64,
 }
 
 impl Default for GovernanceParameters {
@@ -48,13 +58,77 @@ impl Default for GovernanceParameters {
             max_proposal_content_size: 10_000,
             min_proposal_grace_epochs: 6,
             max_proposal_latency: 30,
+            tally_weightings: TallyWeightings {
+                default: TallyWeighting::Stake,
+                pgf_steward: TallyWeighting::Stake,
+                pgf_payment: TallyWeighting::Stake,
+            },
+            conviction_epochs: 12,
         }
     }
 }
 
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum ValidationError {
+    #[error("Minimum proposal voting period must be > 0")]
+    VotingPeriodZero,
+    #[error(
+        "Maximum proposal period must be >= the minimum voting period plus \
+         the minimum grace epochs. Got maximum period: {0}, minimum voting \
+         period and grace epochs: {1}"
+    )]
+    ProposalPeriodTooShort(u64, u64),
+    #[error(
+        "Maximum proposal latency must be >= the minimum voting period, got {0}"
+    )]
+    ProposalLatencyTooShort(u64),
+}
+
 impl GovernanceParameters {
+    /// Validate governance parameters values. Returns an empty list if the
+    /// values are valid.
+    #[must_use]
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = vec![];
+
+        if self.min_proposal_voting_period == 0 {
+            errors.push(ValidationError::VotingPeriodZero);
+        }
+
+        let min_proposal_period = self
+            .min_proposal_voting_period
+            .saturating_add(self.min_proposal_grace_epochs);
+        if self.max_proposal_period < min_proposal_period {
+            errors.push(ValidationError::ProposalPeriodTooShort(
+                self.max_proposal_period,
+                min_proposal_period,
+            ));
+        }
+
+        if self.max_proposal_latency < self.min_proposal_voting_period {
+            errors.push(ValidationError::ProposalLatencyTooShort(
+                self.max_proposal_latency,
+            ));
+        }
+
+        errors
+    }
+
     /// Initialize governance parameters into storage
     pub fn init_storage<S>(&self, storage: &mut S) -> StorageResult<()>
+    where
+        S: StorageRead + StorageWrite,
+    {
+        self.write_storage(storage)?;
+
+        let counter_key = goverance_storage::get_counter_key();
+        storage.write(&counter_key, u64::MIN)
+    }
+
+    /// Write governance parameters into storage, leaving the proposal
+    /// counter untouched
+    pub fn write_storage<S>(&self, storage: &mut S) -> StorageResult<()>
     where
         S: StorageRead + StorageWrite,
     {
@@ -66,6 +140,8 @@ impl GovernanceParameters {
             max_proposal_content_size,
             min_proposal_grace_epochs,
             max_proposal_latency,
+            tally_weightings,
+            conviction_epochs,
         } = self;
 
         let min_proposal_fund_key =
@@ -101,7 +177,12 @@ impl GovernanceParameters {
             goverance_storage::get_max_proposal_latency_key();
         storage.write(&max_proposal_latency_key, max_proposal_latency)?;
 
-        let counter_key = goverance_storage::get_counter_key();
-        storage.write(&counter_key, u64::MIN)
+        let tally_weightings_key =
+            goverance_storage::get_tally_weightings_key();
+        storage.write(&tally_weightings_key, tally_weightings)?;
+
+        let conviction_epochs_key =
+            goverance_storage::get_conviction_epochs_key();
+        storage.write(&conviction_epochs_key, conviction_epochs)
     }
 }
@@ -8,6 +8,7 @@ use namada_macros::BorshDeserializer;
 use namada_migrations::*;
 use namada_state::{StorageRead, StorageResult, StorageWrite};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use super::storage::keys as pgf_storage;
 use super::storage::steward::StewardDetail;
@@ -49,7 +50,38 @@ impl Default for PgfParameters {
     }
 }
 
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum ValidationError {
+    #[error("PGF inflation rate must be between 0 and 1, got {0}")]
+    InvalidPgfInflationRate(Dec),
+    #[error("PGF stewards inflation rate must be between 0 and 1, got {0}")]
+    InvalidStewardsInflationRate(Dec),
+}
+
 impl PgfParameters {
+    /// Validate PGF parameters values. Returns an empty list if the values are
+    /// valid.
+    #[must_use]
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = vec![];
+
+        let is_valid_rate =
+            |rate: &Dec| !rate.is_negative() && *rate <= Dec::one();
+        if !is_valid_rate(&self.pgf_inflation_rate) {
+            errors.push(ValidationError::InvalidPgfInflationRate(
+                self.pgf_inflation_rate,
+            ));
+        }
+        if !is_valid_rate(&self.stewards_inflation_rate) {
+            errors.push(ValidationError::InvalidStewardsInflationRate(
+                self.stewards_inflation_rate,
+            ));
+        }
+
+        errors
+    }
+
     /// Initialize governance parameters into storage
     pub fn init_storage<S>(&self, storage: &mut S) -> StorageResult<()>
     where
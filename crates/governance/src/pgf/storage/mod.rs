@@ -89,6 +89,8 @@ where
     let pgf_inflation_rate_key = pgf_keys::get_pgf_inflation_rate_key();
     let stewards_inflation_rate_key =
         pgf_keys::get_steward_inflation_rate_key();
+    let maximum_number_of_stewards_key =
+        pgf_keys::get_maximum_number_of_pgf_steward_key();
 
     let pgf_inflation_rate: Dec = storage
         .read(&pgf_inflation_rate_key)?
@@ -96,10 +98,14 @@ where
     let stewards_inflation_rate: Dec = storage
         .read(&stewards_inflation_rate_key)?
         .expect("Parameter should be defined.");
+    let maximum_number_of_stewards: u64 = storage
+        .read(&maximum_number_of_stewards_key)?
+        .expect("Parameter should be defined.");
 
     Ok(PgfParameters {
         pgf_inflation_rate,
         stewards_inflation_rate,
+        maximum_number_of_stewards,
         ..Default::default()
     })
 }
@@ -18,6 +18,7 @@ struct Keys {
     funds: &'static str,
     proposal_code: &'static str,
     committing_epoch: &'static str,
+    tally_weighting: &'static str,
     min_fund: &'static str,
     max_code_size: &'static str,
     min_period: &'static str,
@@ -25,6 +26,8 @@ struct Keys {
     max_content: &'static str,
     max_latency: &'static str,
     min_grace_epochs: &'static str,
+    tally_weightings: &'static str,
+    conviction_epochs: &'static str,
     counter: &'static str,
     pending: &'static str,
     result: &'static str,
@@ -199,6 +202,24 @@ pub fn is_proposal_type_key(key: &Key) -> bool {
     }
 }
 
+/// Check if key is proposal tally weighting key
+pub fn is_tally_weighting_key(key: &Key) -> bool {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(tally_weighting),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && tally_weighting == Keys::VALUES.tally_weighting =>
+        {
+            id.parse::<u64>().is_ok()
+        }
+        _ => false,
+    }
+}
+
 /// Check if key is counter key
 pub fn is_counter_key(key: &Key) -> bool {
     matches!(&key.segments[..], [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(counter)] if addr == &ADDRESS && counter == Keys::VALUES.counter)
@@ -271,6 +292,24 @@ pub fn is_min_grace_epochs_key(key: &Key) -> bool {
                     && min_grace_epochs_param == Keys::VALUES.min_grace_epochs)
 }
 
+/// Check if key is a tally weightings param key
+pub fn is_tally_weightings_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+                    DbKeySeg::AddressSeg(addr),
+                    DbKeySeg::StringSeg(tally_weightings_param),
+                ] if addr == &ADDRESS
+                    && tally_weightings_param == Keys::VALUES.tally_weightings)
+}
+
+/// Check if key is a conviction epochs param key
+pub fn is_conviction_epochs_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+                    DbKeySeg::AddressSeg(addr),
+                    DbKeySeg::StringSeg(conviction_param),
+                ] if addr == &ADDRESS
+                    && conviction_param == Keys::VALUES.conviction_epochs)
+}
+
 /// Check if key is parameter key
 pub fn is_parameter_key(key: &Key) -> bool {
     is_min_proposal_fund_key(key)
@@ -279,6 +318,8 @@ pub fn is_parameter_key(key: &Key) -> bool {
         || is_min_proposal_voting_period_key(key)
         || is_max_proposal_period_key(key)
         || is_min_grace_epochs_key(key)
+        || is_tally_weightings_key(key)
+        || is_conviction_epochs_key(key)
 }
 
 /// Check if key is start epoch or end epoch key
@@ -342,6 +383,20 @@ pub fn get_min_proposal_grace_epochs_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get tally weightings key
+pub fn get_tally_weightings_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.tally_weightings.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get conviction epochs key
+pub fn get_conviction_epochs_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.conviction_epochs.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get key of proposal ids counter
 pub fn get_counter_key() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -412,6 +467,15 @@ pub fn get_activation_epoch_key(id: u64) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get proposal tally weighting key
+pub fn get_tally_weighting_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.tally_weighting.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get the proposal committing key prefix
 pub fn get_commiting_proposals_prefix(epoch: u64) -> Key {
     proposal_prefix()
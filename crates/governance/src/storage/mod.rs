@@ -25,7 +25,7 @@ use crate::storage::proposal::{
     InitProposalData, ProposalType, StorageProposal, VoteProposalData,
 };
 use crate::storage::vote::ProposalVote;
-use crate::utils::{ProposalResult, Vote};
+use crate::utils::{ProposalResult, TallyWeighting, TallyWeightings, Vote};
 use crate::ADDRESS as governance_address;
 
 /// A proposal creation transaction.
@@ -78,6 +78,12 @@ where
         governance_keys::get_activation_epoch_key(proposal_id);
     storage.write(&activation_epoch_key, data.activation_epoch)?;
 
+    // the weighting is frozen for the lifetime of the proposal
+    let tally_weighting_key =
+        governance_keys::get_tally_weighting_key(proposal_id);
+    let tally_weighting = get_tally_weightings(storage)?.get(&data.r#type);
+    storage.write(&tally_weighting_key, tally_weighting)?;
+
     storage.write(
         &counter_key,
         proposal_id
@@ -131,6 +137,115 @@ where
     Ok(())
 }
 
+/// A proposal cancellation transaction. All the proposal data is removed from
+/// storage and the deposit is refunded to its author.
+pub fn cancel_proposal<S, TransToken>(
+    storage: &mut S,
+    proposal_id: u64,
+    author: &Address,
+) -> StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+    TransToken: trans_token::Write<S>,
+{
+    check_proposal_update(storage, proposal_id, author)?;
+
+    let activation_epoch_key =
+        governance_keys::get_activation_epoch_key(proposal_id);
+    let activation_epoch: Epoch = storage
+        .read(&activation_epoch_key)?
+        .ok_or(StorageError::new_const("Missing proposal activation epoch"))?;
+    let funds_key = governance_keys::get_funds_key(proposal_id);
+    let funds: token::Amount = storage.read(&funds_key)?.unwrap_or_default();
+
+    for key in [
+        governance_keys::get_content_key(proposal_id),
+        governance_keys::get_author_key(proposal_id),
+        governance_keys::get_proposal_type_key(proposal_id),
+        governance_keys::get_proposal_code_key(proposal_id),
+        governance_keys::get_voting_start_epoch_key(proposal_id),
+        governance_keys::get_voting_end_epoch_key(proposal_id),
+        activation_epoch_key,
+        governance_keys::get_tally_weighting_key(proposal_id),
+        funds_key,
+        governance_keys::get_committing_proposals_key(
+            proposal_id,
+            activation_epoch.0,
+        ),
+    ] {
+        if storage.has_key(&key)? {
+            storage.delete(&key)?;
+        }
+    }
+
+    TransToken::transfer(
+        storage,
+        &storage.get_native_token()?,
+        &governance_address,
+        author,
+        funds,
+    )
+}
+
+/// A proposal amendment transaction. The content of the proposal is replaced
+/// with the given one.
+pub fn amend_proposal<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    author: &Address,
+    content: Vec<u8>,
+) -> StorageResult<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    check_proposal_update(storage, proposal_id, author)?;
+
+    let content_key = governance_keys::get_content_key(proposal_id);
+    // The content should have been already encoded with borsh
+    storage.write_bytes(&content_key, content)
+}
+
+/// Check that the proposal exists, that it belongs to the given author and
+/// that its voting period hasn't started yet.
+fn check_proposal_update<S>(
+    storage: &S,
+    proposal_id: u64,
+    author: &Address,
+) -> StorageResult<()>
+where
+    S: StorageRead,
+{
+    let author_key = governance_keys::get_author_key(proposal_id);
+    let proposal_author: Address =
+        storage.read(&author_key)?.ok_or_else(|| {
+            StorageError::new_alloc(format!(
+                "Proposal {proposal_id} does not exist"
+            ))
+        })?;
+    if &proposal_author != author {
+        return Err(StorageError::new_alloc(format!(
+            "Proposal {proposal_id} can only be updated by its author \
+             {proposal_author}"
+        )));
+    }
+
+    let voting_start_epoch_key =
+        governance_keys::get_voting_start_epoch_key(proposal_id);
+    let voting_start_epoch: Epoch =
+        storage.read(&voting_start_epoch_key)?.ok_or(
+            StorageError::new_const("Missing proposal voting start epoch"),
+        )?;
+    let current_epoch = storage.get_block_epoch()?;
+    if current_epoch >= voting_start_epoch {
+        return Err(StorageError::new_alloc(format!(
+            "Proposal {proposal_id} can't be updated anymore, its voting \
+             period started at epoch {voting_start_epoch}"
+        )));
+    }
+
+    Ok(())
+}
+
 /// Write the proposal result to storage.
 pub fn write_proposal_result<S>(
     storage: &mut S,
@@ -293,6 +408,10 @@ where
     let max_proposal_latency: u64 =
         storage.read(&key)?.expect("Parameter should be defined.");
 
+    let tally_weightings = get_tally_weightings(storage)?;
+
+    let conviction_epochs = get_conviction_epochs(storage)?;
+
     Ok(GovernanceParameters {
         min_proposal_fund,
         max_proposal_code_size,
@@ -301,6 +420,8 @@ where
         max_proposal_content_size,
         min_proposal_grace_epochs,
         max_proposal_latency,
+        tally_weightings,
+        conviction_epochs,
     })
 }
 
@@ -315,6 +436,42 @@ where
     Ok(max_proposal_period)
 }
 
+/// Get governance "tally_weightings" parameter
+pub fn get_tally_weightings<S>(storage: &S) -> StorageResult<TallyWeightings>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_tally_weightings_key();
+    let tally_weightings: TallyWeightings =
+        storage.read(&key)?.expect("Parameter should be defined.");
+    Ok(tally_weightings)
+}
+
+/// Get governance "conviction_epochs" parameter
+pub fn get_conviction_epochs<S>(storage: &S) -> StorageResult<u64>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_conviction_epochs_key();
+    let conviction_epochs: u64 =
+        storage.read(&key)?.expect("Parameter should be defined.");
+    Ok(conviction_epochs)
+}
+
+/// Get the weighting of the voting power used to tally a proposal. Proposals
+/// without a stored weighting are tallied by stake.
+pub fn get_proposal_tally_weighting<S>(
+    storage: &S,
+    proposal_id: u64,
+) -> StorageResult<TallyWeighting>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_tally_weighting_key(proposal_id);
+    let tally_weighting: Option<TallyWeighting> = storage.read(&key)?;
+    Ok(tally_weighting.unwrap_or_default())
+}
+
 /// Get governance proposal result stored in storage if proposal ended
 pub fn get_proposal_result<S>(
     storage: &S,
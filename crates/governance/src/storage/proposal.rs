@@ -11,13 +11,15 @@ use namada_core::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use super::vote::ProposalVote;
 use crate::cli::onchain::{
-    DefaultProposal, PgfAction, PgfContinuous, PgfFundingProposal, PgfRetro,
-    PgfSteward, PgfStewardProposal, StewardsUpdate,
+    DefaultProposal, ParameterChangeProposal, PgfAction, PgfContinuous,
+    PgfFundingProposal, PgfRetro, PgfSteward, PgfStewardProposal,
+    StewardsUpdate,
 };
 use crate::utils::{ProposalStatus, TallyType};
 
@@ -86,6 +88,57 @@ pub struct VoteProposalData {
     pub voter: Address,
 }
 
+/// A tx data type to update a proposal before its voting period starts
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct UpdateProposalData {
+    /// The proposal id
+    pub id: u64,
+    /// The proposal author address
+    pub author: Address,
+    /// The update to apply to the proposal
+    pub update: ProposalUpdate,
+}
+
+/// An update of a proposal by its author
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub enum ProposalUpdate {
+    /// Remove the proposal and refund its deposit to the author
+    Cancel,
+    /// Replace the proposal content with the one of the extra data section
+    /// with the given hash
+    Amend(Hash),
+}
+
+impl UpdateProposalData {
+    /// Get the hash of the extra data section holding the amended content
+    pub fn get_section_content_hash(&self) -> Option<Hash> {
+        match self.update {
+            ProposalUpdate::Amend(hash) => Some(hash),
+            ProposalUpdate::Cancel => None,
+        }
+    }
+}
+
 impl TryFrom<DefaultProposal> for InitProposalData {
     type Error = ProposalError;
 
@@ -122,6 +175,27 @@ impl TryFrom<PgfStewardProposal> for InitProposalData {
     }
 }
 
+impl TryFrom<ParameterChangeProposal> for InitProposalData {
+    type Error = ProposalError;
+
+    fn try_from(value: ParameterChangeProposal) -> Result<Self, Self::Error> {
+        let changes = value
+            .data
+            .into_iter()
+            .map(ParameterChange::from)
+            .collect::<BTreeSet<ParameterChange>>();
+
+        Ok(InitProposalData {
+            content: Hash::default(),
+            author: value.proposal.author,
+            r#type: ProposalType::ParameterChange(changes),
+            voting_start_epoch: value.proposal.voting_start_epoch,
+            voting_end_epoch: value.proposal.voting_end_epoch,
+            activation_epoch: value.proposal.activation_epoch,
+        })
+    }
+}
+
 impl TryFrom<PgfFundingProposal> for InitProposalData {
     type Error = ProposalError;
 
@@ -213,6 +287,179 @@ pub enum ProposalType {
     PGFSteward(BTreeSet<AddRemove<Address>>),
     /// PGF funding proposal
     PGFPayment(BTreeSet<PGFAction>),
+    /// Native parameter change proposal
+    ParameterChange(BTreeSet<ParameterChange>),
+}
+
+/// A module whose parameters can be changed by a parameter change proposal
+#[derive(
+    Debug,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ParameterModule {
+    /// Proof-of-stake parameters
+    Pos,
+    /// Governance parameters
+    Governance,
+    /// Public goods funding parameters
+    Pgf,
+    /// Shielded rewards parameters of the given token
+    Masp(Address),
+}
+
+impl Display for ParameterModule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterModule::Pos => write!(f, "pos"),
+            ParameterModule::Governance => write!(f, "governance"),
+            ParameterModule::Pgf => write!(f, "pgf"),
+            ParameterModule::Masp(token) => write!(f, "masp({token})"),
+        }
+    }
+}
+
+/// The change of a single parameter of a module
+#[derive(
+    Debug,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct ParameterChange {
+    /// The module owning the parameter
+    pub module: ParameterModule,
+    /// The name of the parameter field
+    pub field: String,
+    /// The JSON encoded new value of the parameter
+    pub value: String,
+}
+
+impl Display for ParameterChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}: {}", self.module, self.field, self.value)
+    }
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, Error)]
+pub enum ParameterChangeError {
+    #[error("Unknown parameter {0}.{1}")]
+    UnknownField(ParameterModule, String),
+    #[error(
+        "Parameter {0}.{1} cannot be changed by a parameter change proposal"
+    )]
+    ReadOnlyField(ParameterModule, String),
+    #[error("Invalid value for parameter {0}.{1}: {2}")]
+    InvalidValue(ParameterModule, String, String),
+    #[error("Invalid {0} parameters: {1}")]
+    InvalidParameters(ParameterModule, String),
+}
+
+impl ParameterChange {
+    /// Parameters that cannot be changed by a parameter change proposal,
+    /// either because they are managed by other proposals or because they
+    /// are read only. The PoS epoch offsets are read only because the data
+    /// already recorded at these offsets would become inconsistent.
+    const READ_ONLY_FIELDS: [(ParameterModule, &'static str); 6] = [
+        (ParameterModule::Pos, "rewards_gain_p"),
+        (ParameterModule::Pos, "rewards_gain_d"),
+        (ParameterModule::Pos, "pipeline_len"),
+        (ParameterModule::Pos, "unbonding_len"),
+        (ParameterModule::Pos, "cubic_slashing_window_length"),
+        (ParameterModule::Pgf, "stewards"),
+    ];
+
+    /// Check if the parameter can be changed by a parameter change proposal
+    pub fn is_governable(&self) -> bool {
+        !Self::READ_ONLY_FIELDS.iter().any(|(module, field)| {
+            module == &self.module && *field == self.field
+        })
+    }
+
+    /// Validate the change on submission of a proposal: the parameter must be
+    /// governable and its new value must be valid JSON. The value is checked
+    /// against the parameters of the module when the proposal is executed.
+    pub fn validate(&self) -> Result<(), ParameterChangeError> {
+        if !self.is_governable() {
+            return Err(ParameterChangeError::ReadOnlyField(
+                self.module.clone(),
+                self.field.clone(),
+            ));
+        }
+        serde_json::from_str::<serde_json::Value>(&self.value)
+            .map(|_| ())
+            .map_err(|err| {
+                ParameterChangeError::InvalidValue(
+                    self.module.clone(),
+                    self.field.clone(),
+                    err.to_string(),
+                )
+            })
+    }
+
+    /// Apply the changes targeting the given module to its current
+    /// parameters. Changes of other modules are ignored.
+    pub fn apply<'a, T>(
+        module: &ParameterModule,
+        params: &T,
+        changes: impl IntoIterator<Item = &'a ParameterChange>,
+    ) -> Result<T, ParameterChangeError>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let invalid_params = |err: serde_json::Error| {
+            ParameterChangeError::InvalidParameters(
+                module.clone(),
+                err.to_string(),
+            )
+        };
+        let params = serde_json::to_value(params).map_err(invalid_params)?;
+        let serde_json::Value::Object(mut fields) = params else {
+            return Err(ParameterChangeError::InvalidParameters(
+                module.clone(),
+                "expected a structure of fields".to_string(),
+            ));
+        };
+        for change in changes {
+            if &change.module != module {
+                continue;
+            }
+            let field = fields.get_mut(&change.field).ok_or_else(|| {
+                ParameterChangeError::UnknownField(
+                    module.clone(),
+                    change.field.clone(),
+                )
+            })?;
+            *field = serde_json::from_str(&change.value).map_err(|err| {
+                ParameterChangeError::InvalidValue(
+                    module.clone(),
+                    change.field.clone(),
+                    err.to_string(),
+                )
+            })?;
+        }
+        serde_json::from_value(serde_json::Value::Object(fields))
+            .map_err(invalid_params)
+    }
 }
 
 /// An add or remove action for PGF
@@ -360,6 +607,11 @@ impl ProposalType {
         matches!(self, ProposalType::DefaultWithWasm(_))
     }
 
+    /// Check if the proposal type is a parameter change
+    pub fn is_parameter_change(&self) -> bool {
+        matches!(self, ProposalType::ParameterChange(_))
+    }
+
     fn format_data(&self) -> String {
         match self {
             ProposalType::DefaultWithWasm(hash) => format!("Hash: {}", &hash),
@@ -378,6 +630,13 @@ impl ProposalType {
                     .map(|action| format!("\n  {}", &action))
                     .join("")
             ),
+            ProposalType::ParameterChange(changes) => format!(
+                "Changes:{}",
+                changes
+                    .iter()
+                    .map(|change| format!("\n  {}", &change))
+                    .join("")
+            ),
         }
     }
 }
@@ -389,6 +648,7 @@ impl Display for ProposalType {
             ProposalType::DefaultWithWasm(_) => write!(f, "Default with Wasm"),
             ProposalType::PGFSteward(_) => write!(f, "PGF steward"),
             ProposalType::PGFPayment(_) => write!(f, "PGF funding"),
+            ProposalType::ParameterChange(_) => write!(f, "Parameter change"),
         }
     }
 }
@@ -655,6 +915,31 @@ pub mod testing {
         ]
     }
 
+    /// Generate an arbitrary parameter module
+    pub fn arb_parameter_module() -> impl Strategy<Value = ParameterModule> {
+        prop_oneof![
+            Just(ParameterModule::Pos),
+            Just(ParameterModule::Governance),
+            Just(ParameterModule::Pgf),
+            arb_non_internal_address().prop_map(ParameterModule::Masp),
+        ]
+    }
+
+    prop_compose! {
+        /// Generate an arbitrary parameter change
+        pub fn arb_parameter_change()(
+            module in arb_parameter_module(),
+            field in "[a-z_]{1,32}",
+            value: u64,
+        ) -> ParameterChange {
+            ParameterChange {
+                module,
+                field,
+                value: value.to_string(),
+            }
+        }
+    }
+
     /// Generate an arbitrary proposal type
     pub fn arb_proposal_type() -> impl Strategy<Value = ProposalType> {
         prop_oneof![
@@ -667,6 +952,8 @@ pub mod testing {
             .prop_map(ProposalType::PGFSteward),
             collection::btree_set(arb_pgf_action(), 0..10)
                 .prop_map(ProposalType::PGFPayment),
+            collection::btree_set(arb_parameter_change(), 1..10)
+                .prop_map(ProposalType::ParameterChange),
         ]
     }
 
@@ -705,4 +992,128 @@ pub mod testing {
             }
         }
     }
+
+    /// Generate an arbitrary proposal update
+    pub fn arb_proposal_update() -> impl Strategy<Value = ProposalUpdate> {
+        prop_oneof![
+            Just(ProposalUpdate::Cancel),
+            arb_hash().prop_map(ProposalUpdate::Amend),
+        ]
+    }
+
+    prop_compose! {
+        /// Generate an arbitrary update proposal
+        pub fn arb_update_proposal()(
+            id: u64,
+            author in arb_non_internal_address(),
+            update in arb_proposal_update(),
+        ) -> UpdateProposalData {
+            UpdateProposalData {
+                id,
+                author,
+                update,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parameters::GovernanceParameters;
+
+    fn change(
+        module: ParameterModule,
+        field: &str,
+        value: &str,
+    ) -> ParameterChange {
+        ParameterChange {
+            module,
+            field: field.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_parameter_changes() {
+        let params = GovernanceParameters::default();
+        let changes = [
+            change(
+                ParameterModule::Governance,
+                "min_proposal_voting_period",
+                "7",
+            ),
+            change(ParameterModule::Pgf, "min_proposal_voting_period", "1"),
+        ];
+
+        let updated = ParameterChange::apply(
+            &ParameterModule::Governance,
+            &params,
+            &changes,
+        )
+        .unwrap();
+        assert_eq!(updated.min_proposal_voting_period, 7);
+        assert_eq!(updated.max_proposal_period, params.max_proposal_period);
+
+        let unknown = [change(ParameterModule::Governance, "foo", "1")];
+        assert!(matches!(
+            ParameterChange::apply(
+                &ParameterModule::Governance,
+                &params,
+                &unknown
+            ),
+            Err(ParameterChangeError::UnknownField(_, _))
+        ));
+
+        let invalid = [change(
+            ParameterModule::Governance,
+            "min_proposal_voting_period",
+            "\"seven\"",
+        )];
+        assert!(matches!(
+            ParameterChange::apply(
+                &ParameterModule::Governance,
+                &params,
+                &invalid
+            ),
+            Err(ParameterChangeError::InvalidValue(_, _, _))
+        ));
+    }
+
+    #[test]
+    fn test_parameter_change_is_governable() {
+        assert!(
+            change(ParameterModule::Pos, "max_validator_slots", "3")
+                .is_governable()
+        );
+        assert!(
+            !change(ParameterModule::Pos, "pipeline_len", "3").is_governable()
+        );
+        assert!(
+            !change(ParameterModule::Pos, "unbonding_len", "3").is_governable()
+        );
+        assert!(
+            !change(ParameterModule::Pos, "cubic_slashing_window_length", "3")
+                .is_governable()
+        );
+        assert!(!change(ParameterModule::Pgf, "stewards", "[]").is_governable());
+    }
+
+    #[test]
+    fn test_parameter_change_validate() {
+        assert!(
+            change(ParameterModule::Pos, "max_validator_slots", "3")
+                .validate()
+                .is_ok()
+        );
+        assert!(matches!(
+            change(ParameterModule::Pos, "pipeline_len", "3").validate(),
+            Err(ParameterChangeError::ReadOnlyField(..))
+        ));
+        assert!(matches!(
+            change(ParameterModule::Pos, "max_validator_slots", "three")
+                .validate(),
+            Err(ParameterChangeError::InvalidValue(..))
+        ));
+    }
 }
@@ -1,3 +1,4 @@
+use std::cmp;
 use std::fmt::Display;
 use std::str::FromStr;
 
@@ -11,6 +12,7 @@ use namada_core::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
+use serde::{Deserialize, Serialize};
 
 use super::storage::proposal::ProposalType;
 use super::storage::vote::ProposalVote;
@@ -93,10 +95,123 @@ impl TallyType {
             (ProposalType::PGFPayment(_), false) => {
                 TallyType::OneHalfOverOneThird
             }
+            (ProposalType::ParameterChange(_), _) => TallyType::TwoThirds,
         }
     }
 }
 
+/// Describes how the voting power of delegators is weighted in the tally of a
+/// proposal.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub enum TallyWeighting {
+    /// The voting power is the bonded stake
+    #[default]
+    Stake,
+    /// The voting power of every bond grows linearly with the number of
+    /// epochs for which it has contributed to the stake. A bond carries its
+    /// full stake after the `conviction_epochs` governance parameter, and up
+    /// to [`MAX_CONVICTION_MULTIPLIER`] times its stake afterwards. The total
+    /// voting power is weighted the same way.
+    Conviction,
+}
+
+impl Display for TallyWeighting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TallyWeighting::Stake => write!(f, "stake"),
+            TallyWeighting::Conviction => write!(f, "conviction"),
+        }
+    }
+}
+
+/// The weighting of the voting power used to tally each type of proposal
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct TallyWeightings {
+    /// The weighting of default proposals, with or without wasm code, and of
+    /// parameter change proposals
+    pub default: TallyWeighting,
+    /// The weighting of PGF steward proposals
+    pub pgf_steward: TallyWeighting,
+    /// The weighting of PGF funding proposals
+    pub pgf_payment: TallyWeighting,
+}
+
+impl TallyWeightings {
+    /// The weighting used to tally the given type of proposal
+    pub fn get(&self, proposal_type: &ProposalType) -> TallyWeighting {
+        match proposal_type {
+            ProposalType::Default
+            | ProposalType::DefaultWithWasm(_)
+            | ProposalType::ParameterChange(_) => self.default,
+            ProposalType::PGFSteward(_) => self.pgf_steward,
+            ProposalType::PGFPayment(_) => self.pgf_payment,
+        }
+    }
+}
+
+/// The max multiple of its stake that a bond carries as voting power in
+/// conviction weighted tallies.
+pub const MAX_CONVICTION_MULTIPLIER: u64 = 2;
+
+/// Compute the voting power of bonds weighted by their conviction, given as
+/// pairs of the first epoch in which a bond contributed to the stake and its
+/// (slashed) amount. A bond carries its amount multiplied by the number of
+/// epochs it has contributed to the stake by the given epoch over
+/// `conviction_epochs`, up to [`MAX_CONVICTION_MULTIPLIER`] times its amount.
+/// Without conviction epochs, the bonds carry their amount.
+pub fn conviction_voting_power(
+    bonds: impl IntoIterator<Item = (Epoch, token::Amount)>,
+    epoch: Epoch,
+    conviction_epochs: u64,
+) -> Result<VotePower, arith::Error> {
+    let max_age = checked!(conviction_epochs * MAX_CONVICTION_MULTIPLIER)?;
+    let mut voting_power = VotePower::zero();
+    for (start, amount) in bonds {
+        if start > epoch {
+            continue;
+        }
+        if conviction_epochs == 0 {
+            checked!(voting_power += amount)?;
+            continue;
+        }
+        let age = cmp::min(checked!(epoch.0 - start.0)?, max_age);
+        let conviction =
+            checked!(Dec::from(age) / Dec::from(conviction_epochs))?;
+        checked!(voting_power += amount.mul_floor(conviction)?)?;
+    }
+    Ok(voting_power)
+}
+
 /// The result of a proposal
 #[derive(
     Copy, Clone, Debug, BorshSerialize, BorshDeserialize, BorshDeserializer,
@@ -206,6 +321,8 @@ pub struct ProposalResult {
     pub result: TallyResult,
     /// The type of tally required for this proposal
     pub tally_type: TallyType,
+    /// The weighting of the voting power in the tally
+    pub tally_weighting: TallyWeighting,
     /// The total voting power during the proposal tally
     pub total_voting_power: VotePower,
     /// The total voting power from yay votes
@@ -263,14 +380,15 @@ impl Display for ProposalResult {
             f,
             "{} with {} yay votes, {} nay votes and {} abstain votes, total \
              voting power: {}, threshold (fraction) of total voting power \
-             needed to tally: {} ({})",
+             needed to tally: {} ({}), voting power weighted by {}",
             self.result,
             self.total_yay_power.to_string_native(),
             self.total_nay_power.to_string_native(),
             self.total_abstain_power.to_string_native(),
             self.total_voting_power.to_string_native(),
             threshold.to_string_native(),
-            thresh_frac
+            thresh_frac,
+            self.tally_weighting,
         )
     }
 }
@@ -287,6 +405,8 @@ pub struct ProposalVotes {
     pub delegators_vote: HashMap<Address, ProposalVote>,
     /// Map from delegator address to the corresponding validator voting power
     pub delegator_voting_power: HashMap<Address, HashMap<Address, VotePower>>,
+    /// The weighting of the voting power of validators and delegators
+    pub tally_weighting: TallyWeighting,
 }
 
 impl ProposalVotes {
@@ -414,6 +534,7 @@ pub fn compute_proposal_result(
     Ok(ProposalResult {
         result: tally_result,
         tally_type,
+        tally_weighting: votes.tally_weighting,
         total_voting_power,
         total_yay_power: yay_voting_power,
         total_nay_power: nay_voting_power,
@@ -1457,4 +1578,101 @@ mod test {
             5.into()
         ));
     }
+
+    #[test]
+    fn test_conviction_voting_power() {
+        let bonds = [
+            (Epoch(0), token::Amount::from_u64(40)),
+            (Epoch(6), token::Amount::from_u64(40)),
+            (Epoch(12), token::Amount::from_u64(20)),
+        ];
+
+        // Only the epochs since the start of each bond count
+        assert_eq!(
+            conviction_voting_power(bonds, Epoch(6), 12).unwrap(),
+            token::Amount::from_u64(20)
+        );
+        // The oldest bond has reached its full stake
+        assert_eq!(
+            conviction_voting_power(bonds, Epoch(12), 12).unwrap(),
+            token::Amount::from_u64(60)
+        );
+        // The older bonds carry more than their stake
+        assert_eq!(
+            conviction_voting_power(bonds, Epoch(18), 12).unwrap(),
+            token::Amount::from_u64(110)
+        );
+        // The multiplier of every bond is capped
+        assert_eq!(
+            conviction_voting_power(bonds, Epoch(48), 12).unwrap(),
+            token::Amount::from_u64(200)
+        );
+        // Without conviction epochs, the bonds carry their stake
+        assert_eq!(
+            conviction_voting_power(bonds, Epoch(0), 0).unwrap(),
+            token::Amount::from_u64(100)
+        );
+    }
+
+    #[test]
+    fn test_proposal_conviction_weighted_delegators() {
+        let epoch = Epoch(24);
+        let conviction_epochs = 12;
+        let self_bond = (Epoch(0), token::Amount::from_u64(80));
+        let delegation = (Epoch(18), token::Amount::from_u64(20));
+
+        let mut proposal_votes = ProposalVotes {
+            tally_weighting: TallyWeighting::Conviction,
+            ..Default::default()
+        };
+
+        // The validator carries the weighted voting power of all its bonds
+        let validator_address = address::testing::established_address_1();
+        let validator_voting_power = conviction_voting_power(
+            [self_bond, delegation],
+            epoch,
+            conviction_epochs,
+        )
+        .unwrap();
+        assert_eq!(validator_voting_power, token::Amount::from_u64(170));
+        proposal_votes.add_validator(
+            &validator_address,
+            validator_voting_power,
+            ProposalVote::Yay,
+        );
+
+        // A delegator voting against its validator with its weighted voting
+        // power
+        let delegator_address = address::testing::established_address_2();
+        let delegator_voting_power =
+            conviction_voting_power([delegation], epoch, conviction_epochs)
+                .unwrap();
+        assert_eq!(delegator_voting_power, token::Amount::from_u64(10));
+        proposal_votes.add_delegator(
+            &delegator_address,
+            &validator_address,
+            delegator_voting_power,
+            ProposalVote::Nay,
+        );
+
+        // The total voting power is weighted the same way
+        let proposal_result = compute_proposal_result(
+            proposal_votes,
+            validator_voting_power,
+            TallyType::OneHalfOverOneThird,
+        )
+        .unwrap();
+
+        assert_eq!(proposal_result.tally_weighting, TallyWeighting::Conviction);
+        assert_eq!(
+            proposal_result.total_yay_power,
+            token::Amount::from_u64(160)
+        );
+        assert_eq!(
+            proposal_result.total_nay_power,
+            token::Amount::from_u64(10)
+        );
+        assert_eq!(proposal_result.total_abstain_power, token::Amount::zero());
+        assert!(matches!(proposal_result.result, TallyResult::Passed));
+    }
 }
@@ -23,7 +23,9 @@ use self::utils::ReadType;
 use crate::address::{Address, InternalAddress};
 use crate::storage::proposal::{AddRemove, PGFAction, ProposalType};
 use crate::storage::{is_proposal_accepted, keys as gov_storage};
-use crate::utils::is_valid_validator_voting_period;
+use crate::utils::{
+    is_valid_validator_voting_period, TallyWeighting, TallyWeightings,
+};
 use crate::ProposalVote;
 
 /// for handling Governance NativeVP errors
@@ -35,6 +37,9 @@ pub const ADDRESS: Address = Address::Internal(InternalAddress::Governance);
 /// The maximum number of item in a pgf proposal
 pub const MAX_PGF_ACTIONS: usize = 20;
 
+/// The maximum number of changes in a parameter change proposal
+pub const MAX_PARAMETER_CHANGES: usize = 20;
+
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum Error {
@@ -114,6 +119,10 @@ where
             .into());
         }
 
+        // The proposals cancelled or amended by their author in this tx
+        let mut cancelled_proposals = BTreeSet::new();
+        let mut amended_proposals = BTreeSet::new();
+
         // Check action authorization
         for action in actions {
             match action {
@@ -140,6 +149,33 @@ where
                             ));
                         }
                     }
+                    GovAction::CancelProposal { id, author } => {
+                        if !verifiers.contains(&author) {
+                            tracing::info!(
+                                "Unauthorized GovAction::CancelProposal"
+                            );
+                            return Err(Error::Unauthorized(
+                                "CancelProposal",
+                                author,
+                            ));
+                        }
+                        self.is_valid_proposal_update(id, &author)?;
+                        self.is_valid_proposal_cancellation(id, keys_changed)?;
+                        cancelled_proposals.insert(id);
+                    }
+                    GovAction::AmendProposal { id, author } => {
+                        if !verifiers.contains(&author) {
+                            tracing::info!(
+                                "Unauthorized GovAction::AmendProposal"
+                            );
+                            return Err(Error::Unauthorized(
+                                "AmendProposal",
+                                author,
+                            ));
+                        }
+                        self.is_valid_proposal_update(id, &author)?;
+                        amended_proposals.insert(id);
+                    }
                 },
                 _ => {
                     // Other actions are not relevant to Governance VP
@@ -153,13 +189,32 @@ where
             let proposal_id = gov_storage::get_proposal_id(key);
             let key_type = KeyType::from_key::<TokenKeys>(key, &native_token);
 
+            // The keys of a cancelled proposal must all be deleted
+            let cancelled_proposal_id = match key_type {
+                KeyType::PROPOSAL_COMMIT => {
+                    gov_storage::get_commit_proposal_id(key)
+                }
+                KeyType::BALANCE
+                | KeyType::COUNTER
+                | KeyType::PARAMETER
+                | KeyType::UNKNOWN_GOVERNANCE
+                | KeyType::UNKNOWN => None,
+                _ => proposal_id,
+            }
+            .filter(|id| cancelled_proposals.contains(id));
+
             let result = match (key_type, proposal_id) {
+                _ if cancelled_proposal_id.is_some() => {
+                    self.is_valid_cancelled_key(key)
+                }
                 (KeyType::VOTE, Some(proposal_id)) => {
                     self.is_valid_vote_key(proposal_id, key, verifiers)
                 }
-                (KeyType::CONTENT, Some(proposal_id)) => {
-                    self.is_valid_content_key(proposal_id)
-                }
+                (KeyType::CONTENT, Some(proposal_id)) => self
+                    .is_valid_content_key(
+                        proposal_id,
+                        amended_proposals.contains(&proposal_id),
+                    ),
                 (KeyType::TYPE, Some(proposal_id)) => {
                     self.is_valid_proposal_type(proposal_id)
                 }
@@ -181,12 +236,19 @@ where
                 (KeyType::AUTHOR, Some(proposal_id)) => {
                     self.is_valid_author(proposal_id, verifiers)
                 }
+                (KeyType::TALLY_WEIGHTING, Some(proposal_id)) => {
+                    self.is_valid_tally_weighting(proposal_id)
+                }
                 (KeyType::COUNTER, _) => self.is_valid_counter(set_count),
                 (KeyType::PROPOSAL_COMMIT, _) => {
                     self.is_valid_proposal_commit()
                 }
                 (KeyType::PARAMETER, _) => self.is_valid_parameter(tx_data),
-                (KeyType::BALANCE, _) => self.is_valid_balance(&native_token),
+                (KeyType::BALANCE, _) => self.is_valid_balance(
+                    &native_token,
+                    set_count,
+                    &cancelled_proposals,
+                ),
                 (KeyType::UNKNOWN_GOVERNANCE, _) => {
                     Err(native_vp::Error::new_alloc(format!(
                         "Unkown governance key change: {key}"
@@ -253,6 +315,7 @@ where
                 gov_storage::get_voting_start_epoch_key(counter),
                 gov_storage::get_voting_end_epoch_key(counter),
                 gov_storage::get_activation_epoch_key(counter),
+                gov_storage::get_tally_weighting_key(counter),
             ]);
 
             // Check that expected set is a subset of the actual one
@@ -381,27 +444,47 @@ where
         Ok(())
     }
 
-    /// Validate a content key
-    pub fn is_valid_content_key(&self, proposal_id: u64) -> Result<()> {
+    /// Validate a content key. The content of an existing proposal can only
+    /// be replaced by an amendment.
+    pub fn is_valid_content_key(
+        &self,
+        proposal_id: u64,
+        is_amendment: bool,
+    ) -> Result<()> {
         let content_key: storage::Key =
             gov_storage::get_content_key(proposal_id);
         let max_content_length_parameter_key =
             gov_storage::get_max_proposal_content_key();
 
         let has_pre_content: bool = self.ctx.has_key_pre(&content_key)?;
-        if has_pre_content {
+        if has_pre_content && !is_amendment {
             return Err(native_vp::Error::new_alloc(format!(
                 "Proposal with id {proposal_id} already had content written \
                  to storage."
             ))
             .into());
         }
+        if !has_pre_content && is_amendment {
+            return Err(native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} has no content to amend."
+            ))
+            .into());
+        }
 
         let max_content_length: usize =
             self.force_read(&max_content_length_parameter_key, ReadType::Pre)?;
         // Check the byte length
-        let post_content_bytes =
-            self.ctx.read_bytes_post(&content_key)?.unwrap_or_default();
+        let post_content_bytes = match self.ctx.read_bytes_post(&content_key)? {
+            Some(bytes) => bytes,
+            None if is_amendment => {
+                return Err(native_vp::Error::new_alloc(format!(
+                    "The content of the proposal with id {proposal_id} \
+                     cannot be removed by an amendment."
+                ))
+                .into());
+            }
+            None => vec![],
+        };
 
         let is_valid = post_content_bytes.len() <= max_content_length;
         if !is_valid {
@@ -577,6 +660,37 @@ where
                     .into()
                 })
             }
+            ProposalType::ParameterChange(changes) => {
+                let is_valid_total_changes = !changes.is_empty()
+                    && changes.len() <= MAX_PARAMETER_CHANGES;
+                if !is_valid_total_changes {
+                    return Err(native_vp::Error::new_alloc(format!(
+                        "Expected between 1 and {MAX_PARAMETER_CHANGES} \
+                         parameter changes, got {}",
+                        changes.len()
+                    ))
+                    .into());
+                }
+
+                // a parameter cannot be changed more than once
+                let total_changed_fields = changes
+                    .iter()
+                    .map(|change| (&change.module, &change.field))
+                    .collect::<BTreeSet<_>>()
+                    .len();
+                if total_changed_fields != changes.len() {
+                    return Err(native_vp::Error::new_const(
+                        "Non-unique parameter changes",
+                    )
+                    .into());
+                }
+
+                changes.iter().try_for_each(|change| {
+                    change.validate().map_err(|err| {
+                        native_vp::Error::new_alloc(err.to_string()).into()
+                    })
+                })
+            }
             // Default proposal condition are checked already for all other
             // proposals.
             // default_with_wasm proposal needs to check only for valid code
@@ -631,6 +745,40 @@ where
         Ok(())
     }
 
+    /// Validate a tally weighting key
+    pub fn is_valid_tally_weighting(&self, proposal_id: u64) -> Result<()> {
+        let tally_weighting_key =
+            gov_storage::get_tally_weighting_key(proposal_id);
+        let proposal_type_key = gov_storage::get_proposal_type_key(proposal_id);
+        let tally_weightings_key = gov_storage::get_tally_weightings_key();
+
+        let has_pre_tally_weighting =
+            self.ctx.has_key_pre(&tally_weighting_key)?;
+        if has_pre_tally_weighting {
+            return Err(native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} already had a tally weighting \
+                 written to storage in its slot.",
+            ))
+            .into());
+        }
+
+        let tally_weighting: TallyWeighting =
+            self.force_read(&tally_weighting_key, ReadType::Post)?;
+        let proposal_type: ProposalType =
+            self.force_read(&proposal_type_key, ReadType::Post)?;
+        let tally_weightings: TallyWeightings =
+            self.force_read(&tally_weightings_key, ReadType::Pre)?;
+
+        let expected_tally_weighting = tally_weightings.get(&proposal_type);
+        (tally_weighting == expected_tally_weighting).ok_or_else(|| {
+            native_vp::Error::new_alloc(format!(
+                "Expected the {proposal_type} proposal to be tallied by \
+                 {expected_tally_weighting}, but got {tally_weighting}",
+            ))
+            .into()
+        })
+    }
+
     /// Validate an activation_epoch key
     pub fn is_valid_activation_epoch(&self, proposal_id: u64) -> Result<()> {
         let start_epoch_key =
@@ -938,7 +1086,12 @@ where
     }
 
     /// Validate a balance key
-    fn is_valid_balance(&self, native_token_address: &Address) -> Result<()> {
+    fn is_valid_balance(
+        &self,
+        native_token_address: &Address,
+        set_count: u64,
+        cancelled_proposals: &BTreeSet<u64>,
+    ) -> Result<()> {
         let balance_key =
             TokenKeys::balance_key(native_token_address, self.ctx.address);
         let min_funds_parameter_key = gov_storage::get_min_proposal_fund_key();
@@ -951,6 +1104,30 @@ where
         let post_balance: token::Amount =
             self.force_read(&balance_key, ReadType::Post)?;
 
+        // The deposits of the cancelled proposals are refunded to their
+        // authors
+        let mut refunds = token::Amount::zero();
+        for proposal_id in cancelled_proposals {
+            let funds_key = gov_storage::get_funds_key(*proposal_id);
+            let funds: token::Amount =
+                self.force_read(&funds_key, ReadType::Pre)?;
+            refunds = checked!(refunds + funds)?;
+        }
+        if set_count == 0 && !refunds.is_zero() {
+            let expected_post_balance =
+                checked!(pre_balance.unwrap_or_default() - refunds)?;
+            return (post_balance == expected_post_balance).ok_or_else(|| {
+                native_vp::Error::new_alloc(format!(
+                    "Expected the balance {} after refunding the deposits of \
+                     the cancelled proposals, but got {}",
+                    expected_post_balance.native_denominated(),
+                    post_balance.native_denominated()
+                ))
+                .into()
+            });
+        }
+        let post_balance = checked!(post_balance + refunds)?;
+
         let balance_is_valid = if let Some(pre_balance) = pre_balance {
             post_balance > pre_balance
                 && checked!(post_balance - pre_balance)
@@ -969,6 +1146,91 @@ where
         })
     }
 
+    /// Validate that a proposal can be updated by the given author, i.e. that
+    /// it has been authored by them and that its voting period hasn't started
+    pub fn is_valid_proposal_update(
+        &self,
+        proposal_id: u64,
+        author: &Address,
+    ) -> Result<()> {
+        let author_key = gov_storage::get_author_key(proposal_id);
+        let voting_start_epoch_key =
+            gov_storage::get_voting_start_epoch_key(proposal_id);
+
+        let pre_author: Address =
+            self.force_read(&author_key, ReadType::Pre)?;
+        if &pre_author != author {
+            return Err(native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} can only be updated by its \
+                 author {pre_author}, not by {author}"
+            ))
+            .into());
+        }
+
+        let current_epoch = self.ctx.get_block_epoch()?;
+        let pre_voting_start_epoch: Epoch =
+            self.force_read(&voting_start_epoch_key, ReadType::Pre)?;
+        (current_epoch < pre_voting_start_epoch).ok_or_else(|| {
+            native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} can't be updated after the \
+                 start of its voting period. Current epoch: \
+                 {current_epoch}, start: {pre_voting_start_epoch}."
+            ))
+            .into()
+        })
+    }
+
+    /// Validate that all the keys of a cancelled proposal have been changed.
+    /// Each of these keys is then checked to have been deleted.
+    pub fn is_valid_proposal_cancellation(
+        &self,
+        proposal_id: u64,
+        keys_changed: &BTreeSet<storage::Key>,
+    ) -> Result<()> {
+        let activation_epoch: Epoch = self.force_read(
+            &gov_storage::get_activation_epoch_key(proposal_id),
+            ReadType::Pre,
+        )?;
+        let code_key = gov_storage::get_proposal_code_key(proposal_id);
+
+        let mut mandatory_keys = BTreeSet::from([
+            gov_storage::get_content_key(proposal_id),
+            gov_storage::get_author_key(proposal_id),
+            gov_storage::get_proposal_type_key(proposal_id),
+            gov_storage::get_funds_key(proposal_id),
+            gov_storage::get_voting_start_epoch_key(proposal_id),
+            gov_storage::get_voting_end_epoch_key(proposal_id),
+            gov_storage::get_activation_epoch_key(proposal_id),
+            gov_storage::get_tally_weighting_key(proposal_id),
+            gov_storage::get_committing_proposals_key(
+                proposal_id,
+                activation_epoch.0,
+            ),
+        ]);
+        if self.ctx.has_key_pre(&code_key)? {
+            mandatory_keys.insert(code_key);
+        }
+
+        keys_changed.is_superset(&mandatory_keys).ok_or_else(|| {
+            native_vp::Error::new_alloc(format!(
+                "Proposal with id {proposal_id} was not entirely removed on \
+                 cancellation."
+            ))
+            .into()
+        })
+    }
+
+    /// Validate a key of a cancelled proposal
+    fn is_valid_cancelled_key(&self, key: &storage::Key) -> Result<()> {
+        let has_post_key = self.ctx.has_key_post(key)?;
+        (!has_post_key).ok_or_else(|| {
+            native_vp::Error::new_alloc(format!(
+                "Key {key} of a cancelled proposal must be removed"
+            ))
+            .into()
+        })
+    }
+
     /// Validate a author key
     pub fn is_valid_author(
         &self,
@@ -1175,6 +1437,8 @@ enum KeyType {
     #[allow(non_camel_case_types)]
     AUTHOR,
     #[allow(non_camel_case_types)]
+    TALLY_WEIGHTING,
+    #[allow(non_camel_case_types)]
     PARAMETER,
     #[allow(non_camel_case_types)]
     UNKNOWN_GOVERNANCE,
@@ -1207,6 +1471,8 @@ impl KeyType {
             KeyType::FUNDS
         } else if gov_storage::is_author_key(key) {
             KeyType::AUTHOR
+        } else if gov_storage::is_tally_weighting_key(key) {
+            KeyType::TALLY_WEIGHTING
         } else if gov_storage::is_counter_key(key) {
             KeyType::COUNTER
         } else if gov_storage::is_parameter_key(key) {
@@ -1260,9 +1526,10 @@ mod test {
     use crate::storage::keys::{
         get_activation_epoch_key, get_author_key, get_committing_proposals_key,
         get_content_key, get_counter_key, get_funds_key, get_proposal_type_key,
-        get_vote_proposal_key, get_voting_end_epoch_key,
-        get_voting_start_epoch_key,
+        get_tally_weighting_key, get_vote_proposal_key,
+        get_voting_end_epoch_key, get_voting_start_epoch_key,
     };
+    use crate::utils::TallyWeighting;
     use crate::{ProposalType, ProposalVote, ADDRESS};
 
     type CA = WasmCacheRwAccess;
@@ -1407,6 +1674,7 @@ mod test {
         let author_key = get_author_key(proposal_id);
         let proposal_type_key = get_proposal_type_key(proposal_id);
         let funds_key = get_funds_key(proposal_id);
+        let tally_weighting_key = get_tally_weighting_key(proposal_id);
         let commiting_key =
             get_committing_proposals_key(proposal_id, activation_epoch);
 
@@ -1419,6 +1687,7 @@ mod test {
             voting_start_epoch_key.clone(),
             voting_end_epoch_key.clone(),
             activation_epoch_key.clone(),
+            tally_weighting_key.clone(),
             commiting_key.clone(),
         ])
     }
@@ -1454,6 +1723,36 @@ mod test {
             .expect("write failed");
     }
 
+    fn cancel_proposal<S>(
+        state: &mut S,
+        proposal_id: u64,
+        funds: u64,
+        activation_epoch: u64,
+        author: &Address,
+    ) -> BTreeSet<Key>
+    where
+        S: State + namada_tx::action::Write,
+    {
+        let mut keys_changed = get_proposal_keys(proposal_id, activation_epoch);
+        keys_changed.remove(&get_counter_key());
+
+        transfer(state, &ADDRESS, author, funds);
+
+        state
+            .push_action(Action::Gov(GovAction::CancelProposal {
+                id: proposal_id,
+                author: author.clone(),
+            }))
+            .unwrap();
+
+        for key in &keys_changed {
+            state.write_log_mut().delete(key).unwrap();
+        }
+        keys_changed.insert(balance_key(&nam(), &ADDRESS));
+
+        keys_changed
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn init_proposal<S>(
         state: &mut S,
@@ -1475,6 +1774,7 @@ mod test {
         let author_key = get_author_key(proposal_id);
         let proposal_type_key = get_proposal_type_key(proposal_id);
         let funds_key = get_funds_key(proposal_id);
+        let tally_weighting_key = get_tally_weighting_key(proposal_id);
         let commiting_key =
             get_committing_proposals_key(proposal_id, activation_epoch);
 
@@ -1527,6 +1827,13 @@ mod test {
                 token::Amount::native_whole(funds).serialize_to_vec(),
             )
             .unwrap();
+        state
+            .write_log_mut()
+            .write(
+                &tally_weighting_key,
+                TallyWeighting::Stake.serialize_to_vec(),
+            )
+            .unwrap();
         if !no_commiting_key {
             state
                 .write_log_mut()
@@ -1631,6 +1938,309 @@ mod test {
         assert_eq!(counter, 1);
     }
 
+    #[test]
+    fn test_governance_invalid_tally_weighting_failed() {
+        let mut state = init_storage();
+
+        let proposal_id = 0;
+        let activation_epoch = 19;
+
+        let keys_changed = get_proposal_keys(proposal_id, activation_epoch);
+
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::vp_cache();
+
+        let tx_index = TxIndex::default();
+
+        let signer = keypair_1();
+        let signer_address = Address::from(&signer.clone().ref_to());
+        let verifiers = BTreeSet::from([signer_address.clone()]);
+
+        initialize_account_balance(
+            &mut state,
+            &signer_address.clone(),
+            token::Amount::native_whole(510),
+        );
+        initialize_account_balance(
+            &mut state,
+            &ADDRESS,
+            token::Amount::native_whole(0),
+        );
+        state.commit_block().unwrap();
+
+        let tx_code = vec![];
+        let tx_data = vec![];
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(tx_code, None));
+        tx.set_data(Data::new(tx_data));
+        tx.add_section(Section::Authorization(Authorization::new(
+            vec![tx.header_hash()],
+            [(0, keypair_1())].into_iter().collect(),
+            None,
+        )));
+
+        init_proposal(
+            &mut state,
+            proposal_id,
+            500,
+            3,
+            9,
+            19,
+            &signer_address,
+            false,
+        );
+
+        // Default proposals are tallied by stake in the default parameters
+        state
+            .write_log_mut()
+            .write(
+                &get_tally_weighting_key(proposal_id),
+                TallyWeighting::Conviction.serialize_to_vec(),
+            )
+            .unwrap();
+
+        let batched_tx = tx.batch_ref_first_tx().unwrap();
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            batched_tx.tx,
+            batched_tx.cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache,
+        );
+
+        let governance_vp = GovernanceVp::new(ctx);
+        assert_matches!(
+            governance_vp.validate_tx(&batched_tx, &keys_changed, &verifiers),
+            Err(_)
+        );
+    }
+
+    /// Validate the changed keys of the tx with the governance VP
+    fn validate_gov_tx(
+        state: &TestState,
+        tx: &Tx,
+        keys_changed: &BTreeSet<Key>,
+        verifiers: &BTreeSet<Address>,
+        vp_wasm_cache: VpCache<CA>,
+    ) -> super::Result<()> {
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let tx_index = TxIndex::default();
+
+        let batched_tx = tx.batch_ref_first_tx().unwrap();
+        let ctx = Ctx::new(
+            &ADDRESS,
+            state,
+            batched_tx.tx,
+            batched_tx.cmt,
+            &tx_index,
+            &gas_meter,
+            keys_changed,
+            verifiers,
+            vp_wasm_cache,
+        );
+
+        let governance_vp = GovernanceVp::new(ctx);
+        governance_vp.validate_tx(&batched_tx, keys_changed, verifiers)
+    }
+
+    /// Initialize and commit a valid proposal of the address of `keypair_1`,
+    /// whose voting period starts in epoch 3. Returns the state, a tx signed
+    /// by the author and the author's address.
+    fn init_committed_proposal(
+        proposal_id: u64,
+        activation_epoch: u64,
+        vp_wasm_cache: VpCache<CA>,
+    ) -> (TestState, Tx, Address) {
+        let mut state = init_storage();
+
+        let keys_changed = get_proposal_keys(proposal_id, activation_epoch);
+
+        let signer = keypair_1();
+        let signer_address = Address::from(&signer.clone().ref_to());
+        let verifiers = BTreeSet::from([signer_address.clone()]);
+
+        initialize_account_balance(
+            &mut state,
+            &signer_address.clone(),
+            token::Amount::native_whole(510),
+        );
+        initialize_account_balance(
+            &mut state,
+            &ADDRESS,
+            token::Amount::native_whole(0),
+        );
+        state.commit_block().unwrap();
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(vec![]));
+        tx.add_section(Section::Authorization(Authorization::new(
+            vec![tx.header_hash()],
+            [(0, signer)].into_iter().collect(),
+            None,
+        )));
+
+        init_proposal(
+            &mut state,
+            proposal_id,
+            500,
+            3,
+            9,
+            activation_epoch,
+            &signer_address,
+            false,
+        );
+
+        assert_matches!(
+            validate_gov_tx(
+                &state,
+                &tx,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache
+            ),
+            Ok(_)
+        );
+
+        state.write_log_mut().commit_batch();
+        state.commit_block().unwrap();
+
+        (state, tx, signer_address)
+    }
+
+    #[test]
+    fn test_governance_cancel_proposal_accepted() {
+        let proposal_id = 0;
+        let activation_epoch = 19;
+
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::vp_cache();
+        let (mut state, tx, signer_address) = init_committed_proposal(
+            proposal_id,
+            activation_epoch,
+            vp_wasm_cache.clone(),
+        );
+        let verifiers = BTreeSet::from([signer_address.clone()]);
+
+        // The voting period hasn't started yet
+        let keys_changed = cancel_proposal(
+            &mut state,
+            proposal_id,
+            500,
+            activation_epoch,
+            &signer_address,
+        );
+
+        assert_matches!(
+            validate_gov_tx(
+                &state,
+                &tx,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache
+            ),
+            Ok(_)
+        );
+
+        state.write_log_mut().commit_batch();
+        state.commit_block().unwrap();
+
+        let author_balance_key = balance_key(&nam(), &signer_address);
+        let amount: token::Amount =
+            state.read(&author_balance_key).unwrap().unwrap();
+        assert_eq!(amount, token::Amount::native_whole(510));
+    }
+
+    #[test]
+    fn test_governance_cancel_proposal_after_voting_start_failed() {
+        let proposal_id = 0;
+        let activation_epoch = 19;
+
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::vp_cache();
+        let (mut state, tx, signer_address) = init_committed_proposal(
+            proposal_id,
+            activation_epoch,
+            vp_wasm_cache.clone(),
+        );
+        let verifiers = BTreeSet::from([signer_address.clone()]);
+
+        let height = state.in_mem().get_block_height().0 + (7 * 2);
+
+        update_epoch_to(&mut state, 7, height);
+
+        let keys_changed = cancel_proposal(
+            &mut state,
+            proposal_id,
+            500,
+            activation_epoch,
+            &signer_address,
+        );
+
+        assert_matches!(
+            validate_gov_tx(
+                &state,
+                &tx,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache
+            ),
+            Err(_)
+        );
+    }
+
+    #[test]
+    fn test_governance_amend_proposal_accepted() {
+        let proposal_id = 0;
+        let activation_epoch = 19;
+
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::vp_cache();
+        let (mut state, tx, signer_address) = init_committed_proposal(
+            proposal_id,
+            activation_epoch,
+            vp_wasm_cache.clone(),
+        );
+        let verifiers = BTreeSet::from([signer_address.clone()]);
+
+        let content_key = get_content_key(proposal_id);
+        state
+            .push_action(Action::Gov(GovAction::AmendProposal {
+                id: proposal_id,
+                author: signer_address.clone(),
+            }))
+            .unwrap();
+        state
+            .write_log_mut()
+            .write(&content_key, vec![5, 6, 7, 8])
+            .unwrap();
+
+        let keys_changed = BTreeSet::from([content_key]);
+
+        assert_matches!(
+            validate_gov_tx(
+                &state,
+                &tx,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache
+            ),
+            Ok(_)
+        );
+    }
+
     #[test]
     fn test_governance_proposal_not_enough_funds_failed() {
         let mut state = init_storage();
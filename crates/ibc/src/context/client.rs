@@ -6,17 +6,33 @@ use ibc::clients::tendermint::types::{
     ClientState as TmClientStateType, ConsensusState as TmConsensusStateType,
 };
 use ibc::core::client::types::error::ClientError;
+use ibc::core::host::types::identifiers::ClientType;
 use ibc::primitives::proto::Any;
 use ibc_derive::{IbcClientState, IbcConsensusState};
 #[cfg(any(test, feature = "testing"))]
-use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    MockClientState, MOCK_CLIENT_TYPE,
+};
 #[cfg(any(test, feature = "testing"))]
 use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use namada_state::{StorageRead, StorageResult};
 use namada_systems::parameters;
 use prost::Message;
 
 use super::common::IbcCommonContext;
+use super::solomachine::{
+    ClientState as SmClientState, ConsensusState as SmConsensusState,
+    SOLO_MACHINE_CLIENT_STATE_TYPE_URL, SOLO_MACHINE_CONSENSUS_STATE_TYPE_URL,
+};
+use super::wasm_client::{
+    ClientState as WasmClientState, ConsensusState as WasmConsensusState,
+    WASM_CLIENT_STATE_TYPE_URL, WASM_CONSENSUS_STATE_TYPE_URL,
+};
 use super::IbcContext;
+use crate::storage::{allowed_client_type_key, allowed_wasm_checksum_key};
+
+/// The client type of the Tendermint client which is always allowed
+pub const TENDERMINT_CLIENT_TYPE: &str = "07-tendermint";
 
 /// ClientState for light clients
 #[derive(Clone, Debug, IbcClientState)]
@@ -26,6 +42,12 @@ pub enum AnyClientState {
     /// Tendermint client state
     Tendermint(TmClientState),
 
+    /// Solo machine client state
+    SoloMachine(SmClientState),
+
+    /// Wasm client state wrapping a registered light client
+    Wasm(WasmClientState),
+
     #[cfg(any(test, feature = "testing"))]
     /// Mock client state for testing
     Mock(MockClientState),
@@ -49,6 +71,16 @@ impl TryFrom<AnyClientState> for TmClientState {
     fn try_from(any: AnyClientState) -> Result<Self, Self::Error> {
         match any {
             AnyClientState::Tendermint(cs) => Ok(cs),
+            AnyClientState::SoloMachine(_) => {
+                Err(ClientError::UnknownConsensusStateType {
+                    consensus_state_type: "solomachine".to_string(),
+                })
+            }
+            AnyClientState::Wasm(_) => {
+                Err(ClientError::UnknownConsensusStateType {
+                    consensus_state_type: "wasm".to_string(),
+                })
+            }
             #[cfg(any(test, feature = "testing"))]
             AnyClientState::Mock(_) => {
                 Err(ClientError::UnknownConsensusStateType {
@@ -59,6 +91,45 @@ impl TryFrom<AnyClientState> for TmClientState {
     }
 }
 
+impl From<SmClientState> for AnyClientState {
+    fn from(cs: SmClientState) -> Self {
+        Self::SoloMachine(cs)
+    }
+}
+
+impl TryFrom<AnyClientState> for SmClientState {
+    type Error = ClientError;
+
+    fn try_from(any: AnyClientState) -> Result<Self, Self::Error> {
+        match any {
+            AnyClientState::SoloMachine(cs) => Ok(cs),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: "The type should be solo machine"
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+impl From<WasmClientState> for AnyClientState {
+    fn from(cs: WasmClientState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
+impl TryFrom<AnyClientState> for WasmClientState {
+    type Error = ClientError;
+
+    fn try_from(any: AnyClientState) -> Result<Self, Self::Error> {
+        match any {
+            AnyClientState::Wasm(cs) => Ok(cs),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: "The type should be wasm".to_string(),
+            }),
+        }
+    }
+}
+
 #[cfg(any(test, feature = "testing"))]
 impl From<MockClientState> for AnyClientState {
     fn from(cs: MockClientState) -> Self {
@@ -77,6 +148,16 @@ impl TryFrom<AnyClientState> for MockClientState {
                     consensus_state_type: "tendermint".to_string(),
                 })
             }
+            AnyClientState::SoloMachine(_) => {
+                Err(ClientError::UnknownConsensusStateType {
+                    consensus_state_type: "solomachine".to_string(),
+                })
+            }
+            AnyClientState::Wasm(_) => {
+                Err(ClientError::UnknownConsensusStateType {
+                    consensus_state_type: "wasm".to_string(),
+                })
+            }
             AnyClientState::Mock(cs) => Ok(cs),
         }
     }
@@ -86,6 +167,8 @@ impl From<AnyClientState> for Any {
     fn from(client_state: AnyClientState) -> Self {
         match client_state {
             AnyClientState::Tendermint(cs) => cs.into(),
+            AnyClientState::SoloMachine(cs) => cs.into(),
+            AnyClientState::Wasm(cs) => cs.into(),
             #[cfg(any(test, feature = "testing"))]
             AnyClientState::Mock(cs) => cs.into(),
         }
@@ -101,6 +184,14 @@ impl TryFrom<Any> for AnyClientState {
             return Ok(cs.into());
         }
 
+        if client_state.type_url == SOLO_MACHINE_CLIENT_STATE_TYPE_URL {
+            return Ok(SmClientState::try_from(client_state)?.into());
+        }
+
+        if client_state.type_url == WASM_CLIENT_STATE_TYPE_URL {
+            return Ok(WasmClientState::try_from(client_state)?.into());
+        }
+
         let cs = TmClientState::try_from(client_state).map_err(|_| {
             ClientError::ClientSpecific {
                 description: "Unknown client state".to_string(),
@@ -116,6 +207,12 @@ pub enum AnyConsensusState {
     /// Tendermint consensus state
     Tendermint(TmConsensusState),
 
+    /// Solo machine consensus state
+    SoloMachine(SmConsensusState),
+
+    /// Wasm consensus state wrapping a registered light client
+    Wasm(WasmConsensusState),
+
     #[cfg(any(test, feature = "testing"))]
     /// Mock consensus state for testing
     Mock(MockConsensusState),
@@ -139,6 +236,16 @@ impl TryFrom<AnyConsensusState> for TmConsensusStateType {
     fn try_from(any: AnyConsensusState) -> Result<Self, Self::Error> {
         match any {
             AnyConsensusState::Tendermint(c) => Ok(c.inner().clone()),
+            AnyConsensusState::SoloMachine(_) => {
+                Err(ClientError::UnknownConsensusStateType {
+                    consensus_state_type: "solomachine".to_string(),
+                })
+            }
+            AnyConsensusState::Wasm(_) => {
+                Err(ClientError::UnknownConsensusStateType {
+                    consensus_state_type: "wasm".to_string(),
+                })
+            }
             #[cfg(any(test, feature = "testing"))]
             AnyConsensusState::Mock(_) => {
                 Err(ClientError::UnknownConsensusStateType {
@@ -149,6 +256,45 @@ impl TryFrom<AnyConsensusState> for TmConsensusStateType {
     }
 }
 
+impl From<SmConsensusState> for AnyConsensusState {
+    fn from(cs: SmConsensusState) -> Self {
+        Self::SoloMachine(cs)
+    }
+}
+
+impl TryFrom<AnyConsensusState> for SmConsensusState {
+    type Error = ClientError;
+
+    fn try_from(any: AnyConsensusState) -> Result<Self, Self::Error> {
+        match any {
+            AnyConsensusState::SoloMachine(cs) => Ok(cs),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: "The type should be solo machine"
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+impl From<WasmConsensusState> for AnyConsensusState {
+    fn from(cs: WasmConsensusState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
+impl TryFrom<AnyConsensusState> for WasmConsensusState {
+    type Error = ClientError;
+
+    fn try_from(any: AnyConsensusState) -> Result<Self, Self::Error> {
+        match any {
+            AnyConsensusState::Wasm(cs) => Ok(cs),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: "The type should be wasm".to_string(),
+            }),
+        }
+    }
+}
+
 #[cfg(any(test, feature = "testing"))]
 impl From<MockConsensusState> for AnyConsensusState {
     fn from(cs: MockConsensusState) -> Self {
@@ -162,7 +308,6 @@ impl TryFrom<AnyConsensusState> for TmConsensusState {
     fn try_from(any: AnyConsensusState) -> Result<Self, Self::Error> {
         match any {
             AnyConsensusState::Tendermint(cs) => Ok(cs),
-            #[cfg(any(test, feature = "testing"))]
             _ => Err(ClientError::UnknownConsensusStateType {
                 consensus_state_type: "Only Tendermint client state type is \
                                        supported"
@@ -191,6 +336,8 @@ impl From<AnyConsensusState> for Any {
     fn from(consensus_state: AnyConsensusState) -> Self {
         match consensus_state {
             AnyConsensusState::Tendermint(cs) => cs.into(),
+            AnyConsensusState::SoloMachine(cs) => cs.into(),
+            AnyConsensusState::Wasm(cs) => cs.into(),
             #[cfg(any(test, feature = "testing"))]
             AnyConsensusState::Mock(cs) => cs.into(),
         }
@@ -206,6 +353,14 @@ impl TryFrom<Any> for AnyConsensusState {
             return Ok(cs.into());
         }
 
+        if consensus_state.type_url == SOLO_MACHINE_CONSENSUS_STATE_TYPE_URL {
+            return Ok(SmConsensusState::try_from(consensus_state)?.into());
+        }
+
+        if consensus_state.type_url == WASM_CONSENSUS_STATE_TYPE_URL {
+            return Ok(WasmConsensusState::try_from(consensus_state)?.into());
+        }
+
         let cs = TmConsensusState::try_from(consensus_state).map_err(|_| {
             ClientError::ClientSpecific {
                 description: "Unknown consensus state".to_string(),
@@ -226,3 +381,28 @@ impl TryFrom<Vec<u8>> for AnyConsensusState {
             .try_into()
     }
 }
+
+/// Check if the client type is allowed. The Tendermint client is always
+/// allowed and the other client types should be allowed by governance.
+pub fn is_client_type_allowed<S: StorageRead>(
+    storage: &S,
+    client_type: &ClientType,
+) -> StorageResult<bool> {
+    if client_type.as_str() == TENDERMINT_CLIENT_TYPE {
+        return Ok(true);
+    }
+    #[cfg(any(test, feature = "testing"))]
+    if client_type.as_str() == MOCK_CLIENT_TYPE {
+        return Ok(true);
+    }
+    storage.has_key(&allowed_client_type_key(client_type))
+}
+
+/// Check if the checksum of the light client wrapped by the wasm client is
+/// allowed by governance
+pub fn is_wasm_checksum_allowed<S: StorageRead>(
+    storage: &S,
+    checksum: &[u8],
+) -> StorageResult<bool> {
+    storage.has_key(&allowed_wasm_checksum_key(checksum))
+}
@@ -0,0 +1,591 @@
+//! IBC middleware for the relayer fee incentivisation
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::error::{ChannelError, PacketError};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::router::module::Module;
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
+use ibc::primitives::Signer;
+use namada_core::address::Address;
+use namada_core::token::Amount;
+use namada_state::StorageWrite;
+
+use super::common::IbcCommonContext;
+use super::transfer_mod::ModuleWrapper;
+use crate::fee::{
+    self, decode_fee_version, read_channel_fees, read_packet_fees, Fee,
+    FeeError, IncentivizedAcknowledgement, Metadata, PacketFee,
+};
+use crate::storage::fees_in_escrow_key;
+use crate::IBC_ESCROW_ADDRESS;
+
+/// IBC middleware for the relayer fee incentivisation (ICS-29). It wraps an
+/// application module to negotiate the fee version, to wrap the
+/// acknowledgement and to pay the escrowed fees on fee-enabled channels.
+#[derive(Debug)]
+pub struct FeeModule<C, M>
+where
+    C: IbcCommonContext,
+{
+    ctx: Rc<RefCell<C>>,
+    module: M,
+}
+
+impl<C, M> FeeModule<C, M>
+where
+    C: IbcCommonContext,
+    M: ModuleWrapper,
+{
+    /// Wrap the application module
+    pub fn new(ctx: Rc<RefCell<C>>, module: M) -> Self {
+        Self { ctx, module }
+    }
+
+    fn is_fee_enabled(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<bool, FeeError> {
+        fee::is_fee_enabled(self.ctx.borrow().storage(), port_id, channel_id)
+            .map_err(FeeError::Storage)
+    }
+
+    fn set_fee_enabled(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), FeeError> {
+        fee::set_fee_enabled(
+            self.ctx.borrow_mut().storage_mut(),
+            port_id,
+            channel_id,
+        )
+        .map_err(FeeError::Storage)
+    }
+
+    /// Get the application version from the counterparty version. The
+    /// counterparty of a fee-enabled channel should agree on the fee version.
+    fn counterparty_app_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<Version, FeeError> {
+        match decode_fee_version(counterparty_version)? {
+            Some(metadata) => Ok(metadata.app_version()),
+            None if self.is_fee_enabled(port_id, channel_id)? => {
+                Err(FeeError::InvalidMetadata(format!(
+                    "The counterparty version {counterparty_version} should \
+                     have the fee version"
+                )))
+            }
+            None => Ok(counterparty_version.clone()),
+        }
+    }
+
+    /// Get the application acknowledgement and the incentivized
+    /// acknowledgement if the channel is fee-enabled
+    fn unwrap_acknowledgement(
+        &self,
+        packet: &Packet,
+        ack: &Acknowledgement,
+    ) -> Result<(Acknowledgement, Option<IncentivizedAcknowledgement>), FeeError>
+    {
+        if !self.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a)? {
+            return Ok((ack.clone(), None));
+        }
+        let incentivized = IncentivizedAcknowledgement::decode(ack)?;
+        Ok((incentivized.app_acknowledgement()?, Some(incentivized)))
+    }
+
+    /// Get the payee of the relayer on the channel. It returns `None` if the
+    /// relayer isn't a Namada address.
+    fn relayer_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Address>, FeeError> {
+        let Ok(relayer) = Address::decode(relayer.as_ref()) else {
+            return Ok(None);
+        };
+        fee::payee(self.ctx.borrow().storage(), channel_id, &relayer)
+            .map(Some)
+            .map_err(FeeError::Storage)
+    }
+
+    /// Pay the receive fee and the ack fee for the acknowledged packet
+    fn pay_fees_on_ack(
+        &mut self,
+        packet: &Packet,
+        forward_relayer_address: &str,
+        relayer: &Signer,
+    ) -> Result<(), FeeError> {
+        // The receive fee is refunded if the forward relayer didn't register
+        // its payee on Namada
+        let recv_payee = Address::decode(forward_relayer_address).ok();
+        let ack_payee = self.relayer_payee(&packet.chan_id_on_a, relayer)?;
+        self.pay_fees(packet, |fee| {
+            vec![
+                (recv_payee.clone(), fee.recv_fee),
+                (ack_payee.clone(), fee.ack_fee),
+            ]
+        })
+    }
+
+    /// Pay the timeout fee for the packet which timed out
+    fn pay_fees_on_timeout(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), FeeError> {
+        let timeout_payee =
+            self.relayer_payee(&packet.chan_id_on_a, relayer)?;
+        self.pay_fees(packet, |fee| {
+            vec![(timeout_payee.clone(), fee.timeout_fee)]
+        })
+    }
+
+    /// Pay the escrowed fees for the packet to the payees and refund the
+    /// rest to the refund addresses
+    fn pay_fees(
+        &mut self,
+        packet: &Packet,
+        payments: impl Fn(&Fee) -> Vec<(Option<Address>, Amount)>,
+    ) -> Result<(), FeeError> {
+        let mut ctx = self.ctx.borrow_mut();
+        let packet_fees = read_packet_fees(
+            ctx.storage(),
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        )
+        .map_err(FeeError::Storage)?;
+        if packet_fees.is_empty() {
+            return Ok(());
+        }
+        for PacketFee {
+            fee,
+            refund_address,
+        } in packet_fees
+        {
+            let mut rest = fee.total()?;
+            for (payee, amount) in payments(&fee) {
+                let Some(payee) = payee else {
+                    continue;
+                };
+                rest = rest.checked_sub(amount).ok_or_else(|| {
+                    FeeError::InvalidFee(
+                        "The payment exceeds the escrowed fee".to_string(),
+                    )
+                })?;
+                ctx.transfer_token(
+                    &IBC_ESCROW_ADDRESS,
+                    &payee,
+                    &fee.token,
+                    amount,
+                )
+                .map_err(FeeError::Storage)?;
+            }
+            ctx.transfer_token(
+                &IBC_ESCROW_ADDRESS,
+                &refund_address,
+                &fee.token,
+                rest,
+            )
+            .map_err(FeeError::Storage)?;
+        }
+        let key = fees_in_escrow_key(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        );
+        ctx.storage_mut().delete(&key).map_err(FeeError::Storage)
+    }
+
+    /// Refund all the fees escrowed for the packets sent over the closed
+    /// channel
+    fn refund_fees_on_close(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), FeeError> {
+        let mut ctx = self.ctx.borrow_mut();
+        let channel_fees =
+            read_channel_fees(ctx.storage(), port_id, channel_id)
+                .map_err(FeeError::Storage)?;
+        for (key, packet_fees) in channel_fees {
+            for PacketFee {
+                fee,
+                refund_address,
+            } in packet_fees
+            {
+                ctx.transfer_token(
+                    &IBC_ESCROW_ADDRESS,
+                    &refund_address,
+                    &fee.token,
+                    fee.total()?,
+                )
+                .map_err(FeeError::Storage)?;
+            }
+            ctx.storage_mut().delete(&key).map_err(FeeError::Storage)?;
+        }
+        Ok(())
+    }
+}
+
+impl<C, M> ModuleWrapper for FeeModule<C, M>
+where
+    C: IbcCommonContext + Debug,
+    M: ModuleWrapper,
+{
+    fn as_module(&self) -> &dyn Module {
+        self
+    }
+
+    fn as_module_mut(&mut self) -> &mut dyn Module {
+        self
+    }
+
+    fn module_id(&self) -> ModuleId {
+        self.module.module_id()
+    }
+
+    fn port_id(&self) -> PortId {
+        self.module.port_id()
+    }
+}
+
+impl<C, M> Module for FeeModule<C, M>
+where
+    C: IbcCommonContext + Debug,
+    M: ModuleWrapper,
+{
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let Some(metadata) =
+            decode_fee_version(version).map_err(into_channel_error)?
+        else {
+            return self.module.on_chan_open_init_validate(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                version,
+            );
+        };
+        self.module.on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &metadata.app_version(),
+        )?;
+        Ok(version.clone())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let Some(metadata) =
+            decode_fee_version(version).map_err(into_channel_error)?
+        else {
+            return self.module.on_chan_open_init_execute(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                version,
+            );
+        };
+        let (extras, app_version) = self.module.on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &metadata.app_version(),
+        )?;
+        self.set_fee_enabled(port_id, channel_id)
+            .map_err(into_channel_error)?;
+        Ok((extras, Metadata::new(&app_version).to_version()))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let Some(metadata) = decode_fee_version(counterparty_version)
+            .map_err(into_channel_error)?
+        else {
+            return self.module.on_chan_open_try_validate(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                counterparty_version,
+            );
+        };
+        let app_version = self.module.on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &metadata.app_version(),
+        )?;
+        Ok(Metadata::new(&app_version).to_version())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let Some(metadata) = decode_fee_version(counterparty_version)
+            .map_err(into_channel_error)?
+        else {
+            return self.module.on_chan_open_try_execute(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                counterparty_version,
+            );
+        };
+        let (extras, app_version) = self.module.on_chan_open_try_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &metadata.app_version(),
+        )?;
+        self.set_fee_enabled(port_id, channel_id)
+            .map_err(into_channel_error)?;
+        Ok((extras, Metadata::new(&app_version).to_version()))
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        let app_version = self
+            .counterparty_app_version(port_id, channel_id, counterparty_version)
+            .map_err(into_channel_error)?;
+        self.module
+            .on_chan_open_ack_validate(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let app_version = self
+            .counterparty_app_version(port_id, channel_id, counterparty_version)
+            .map_err(into_channel_error)?;
+        self.module
+            .on_chan_open_ack_execute(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.module
+            .on_chan_open_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.module
+            .on_chan_open_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.module.on_chan_close_init_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let extras = self
+            .module
+            .on_chan_close_init_execute(port_id, channel_id)?;
+        self.refund_fees_on_close(port_id, channel_id)
+            .map_err(into_channel_error)?;
+        Ok(extras)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.module
+            .on_chan_close_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let extras = self
+            .module
+            .on_chan_close_confirm_execute(port_id, channel_id)?;
+        self.refund_fees_on_close(port_id, channel_id)
+            .map_err(into_channel_error)?;
+        Ok(extras)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        let (extras, app_ack) =
+            self.module.on_recv_packet_execute(packet, relayer);
+        let ack = fee::wrap_acknowledgement(
+            self.ctx.borrow().storage(),
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            relayer,
+            app_ack,
+        );
+        match ack {
+            Ok(ack) => (extras, ack),
+            Err(e) => (
+                ModuleExtras::empty(),
+                AcknowledgementStatus::error(
+                    StatusValue::new(FeeError::Storage(e).to_string())
+                        .expect("The error message shouldn't be empty"),
+                )
+                .into(),
+            ),
+        }
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        let (app_ack, _) = self
+            .unwrap_acknowledgement(packet, acknowledgement)
+            .map_err(into_packet_error)?;
+        self.module
+            .on_acknowledgement_packet_validate(packet, &app_ack, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let app_ack = self
+            .unwrap_acknowledgement(packet, acknowledgement)
+            .and_then(|(app_ack, incentivized)| {
+                if let Some(incentivized) = incentivized {
+                    self.pay_fees_on_ack(
+                        packet,
+                        &incentivized.forward_relayer_address,
+                        relayer,
+                    )?;
+                }
+                Ok(app_ack)
+            });
+        match app_ack {
+            Ok(app_ack) => self
+                .module
+                .on_acknowledgement_packet_execute(packet, &app_ack, relayer),
+            Err(e) => (ModuleExtras::empty(), Err(into_packet_error(e))),
+        }
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.module.on_timeout_packet_validate(packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        if let Err(e) = self.pay_fees_on_timeout(packet, relayer) {
+            return (ModuleExtras::empty(), Err(into_packet_error(e)));
+        }
+        self.module.on_timeout_packet_execute(packet, relayer)
+    }
+}
+
+fn into_channel_error(error: FeeError) -> ChannelError {
+    ChannelError::AppModule {
+        description: error.to_string(),
+    }
+}
+
+fn into_packet_error(error: FeeError) -> PacketError {
+    PacketError::AppModule {
+        description: error.to_string(),
+    }
+}
@@ -0,0 +1,857 @@
+//! IBC modules for interchain accounts
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use ibc::apps::transfer::types::ack_success_b64;
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::error::{ChannelError, PacketError};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::router::module::Module;
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
+use ibc::primitives::Signer;
+use namada_core::address::Address;
+use namada_core::storage::Key;
+use namada_core::token::Amount;
+use namada_state::{StorageRead, StorageWrite};
+use namada_systems::trans_token;
+
+use super::common::IbcCommonContext;
+use super::transfer_mod::ModuleWrapper;
+use crate::ica::{
+    controller_port_owner, decode_host_message, host_port_id,
+    interchain_account_address, read_host_params, IcaError,
+    InterchainAccountPacketData, Metadata, RegisteredAccount,
+    CONTROLLER_MODULE_ID_STR, CONTROLLER_PORT_PREFIX, HOST_MODULE_ID_STR,
+};
+use crate::storage::{ica_controller_account_key, ica_host_account_key};
+
+/// IBC module for the interchain account host
+#[derive(Debug)]
+pub struct InterchainAccountHostModule<C, Token>
+where
+    C: IbcCommonContext,
+{
+    ctx: Rc<RefCell<C>>,
+    _marker: PhantomData<Token>,
+}
+
+impl<C, Token> InterchainAccountHostModule<C, Token>
+where
+    C: IbcCommonContext,
+    Token: trans_token::Keys,
+{
+    /// Make a new module
+    pub fn new(ctx: Rc<RefCell<C>>) -> Self {
+        Self {
+            ctx,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Validate the channel opening requested by the controller and return
+    /// the metadata with the interchain account address
+    fn validate_open_try(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Metadata, IcaError> {
+        if order != Order::Ordered {
+            return Err(IcaError::InvalidChannel(
+                "The channel should be ordered".to_string(),
+            ));
+        }
+        let conn_id = single_connection_hop(connection_hops)?;
+        let controller_port_id = counterparty.port_id();
+        if controller_port_owner(controller_port_id).is_none() {
+            return Err(IcaError::InvalidChannel(format!(
+                "The counterparty port {controller_port_id} isn't a \
+                 controller port"
+            )));
+        }
+        let ctx = self.ctx.borrow();
+        let params =
+            read_host_params(ctx.storage()).map_err(IcaError::Storage)?;
+        if !params.enabled {
+            return Err(IcaError::HostDisabled);
+        }
+
+        let mut metadata = Metadata::from_str(counterparty_version.as_str())?;
+        metadata.validate()?;
+        if metadata.host_connection_id != conn_id.to_string() {
+            return Err(IcaError::InvalidMetadata(format!(
+                "The host connection ID {} should be {conn_id}",
+                metadata.host_connection_id
+            )));
+        }
+        let conn = ctx
+            .connection_end(conn_id)
+            .map_err(|e| IcaError::Context(Box::new(e)))?;
+        if conn
+            .counterparty()
+            .connection_id()
+            .map(|id| id.to_string())
+            .as_ref()
+            != Some(&metadata.controller_connection_id)
+        {
+            return Err(IcaError::InvalidMetadata(format!(
+                "The controller connection ID {} doesn't match",
+                metadata.controller_connection_id
+            )));
+        }
+
+        let key = ica_host_account_key(conn_id, controller_port_id);
+        if ctx.storage().has_key(&key).map_err(IcaError::Storage)? {
+            return Err(IcaError::InvalidChannel(format!(
+                "The interchain account for {controller_port_id} already has \
+                 an active channel on {conn_id}"
+            )));
+        }
+
+        metadata.address =
+            interchain_account_address(conn_id, controller_port_id).to_string();
+        Ok(metadata)
+    }
+
+    /// Remove the registration of the interchain account with the closed
+    /// channel so that the controller can open a new channel
+    fn close_channel(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), IcaError> {
+        let channel = self
+            .ctx
+            .borrow()
+            .channel_end(port_id, channel_id)
+            .map_err(|e| IcaError::Context(Box::new(e)))?;
+        let conn_id = single_connection_hop(channel.connection_hops())?;
+        let key =
+            ica_host_account_key(conn_id, channel.counterparty().port_id());
+        remove_registration(&mut *self.ctx.borrow_mut(), &key, channel_id)
+    }
+
+    /// Execute the messages in the packet with the interchain account
+    fn execute_tx(&mut self, packet: &Packet) -> Result<(), IcaError> {
+        let params = read_host_params(self.ctx.borrow().storage())
+            .map_err(IcaError::Storage)?;
+        if !params.enabled {
+            return Err(IcaError::HostDisabled);
+        }
+        let channel = self
+            .ctx
+            .borrow()
+            .channel_end(&packet.port_id_on_b, &packet.chan_id_on_b)
+            .map_err(|e| IcaError::Context(Box::new(e)))?;
+        let conn_id = single_connection_hop(channel.connection_hops())?;
+        let account = interchain_account_address(conn_id, &packet.port_id_on_a);
+
+        let packet_data =
+            InterchainAccountPacketData::from_bytes(&packet.data)?;
+        let mut transfers = vec![];
+        for message in packet_data.messages()? {
+            transfers.extend(decode_host_message(&message, &account, &params)?);
+        }
+
+        // Check the balances before any transfer not to execute the messages
+        // partially
+        let mut debits: BTreeMap<&Address, Amount> = BTreeMap::new();
+        for transfer in &transfers {
+            let debit = debits.entry(&transfer.token).or_default();
+            *debit = debit.checked_add(transfer.amount).ok_or_else(|| {
+                IcaError::InvalidPacketData("Amount overflow".to_string())
+            })?;
+        }
+        for (token, amount) in debits {
+            let balance: Amount = self
+                .ctx
+                .borrow()
+                .storage()
+                .read(&Token::balance_key(token, &account))
+                .map_err(IcaError::Storage)?
+                .unwrap_or_default();
+            if balance < amount {
+                return Err(IcaError::InsufficientBalance(format!(
+                    "Token {token}, balance {}, required {}",
+                    balance.to_string_native(),
+                    amount.to_string_native()
+                )));
+            }
+        }
+
+        let mut ctx = self.ctx.borrow_mut();
+        for transfer in transfers {
+            ctx.transfer_token(
+                &account,
+                &transfer.target,
+                &transfer.token,
+                transfer.amount,
+            )
+            .map_err(IcaError::Storage)?;
+        }
+        Ok(())
+    }
+}
+
+impl<C, Token> ModuleWrapper for InterchainAccountHostModule<C, Token>
+where
+    C: IbcCommonContext + Debug,
+    Token: trans_token::Keys + Debug,
+{
+    fn as_module(&self) -> &dyn Module {
+        self
+    }
+
+    fn as_module_mut(&mut self) -> &mut dyn Module {
+        self
+    }
+
+    fn module_id(&self) -> ModuleId {
+        ModuleId::new(HOST_MODULE_ID_STR.to_string())
+    }
+
+    fn port_id(&self) -> PortId {
+        host_port_id()
+    }
+}
+
+impl<C, Token> Module for InterchainAccountHostModule<C, Token>
+where
+    C: IbcCommonContext + Debug,
+    Token: trans_token::Keys + Debug,
+{
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "The host can't open a channel".to_string(),
+        )))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "The host can't open a channel".to_string(),
+        )))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let metadata = self
+            .validate_open_try(
+                order,
+                connection_hops,
+                counterparty,
+                counterparty_version,
+            )
+            .map_err(into_channel_error)?;
+        Ok(Version::new(metadata.to_string()))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let metadata = self
+            .validate_open_try(
+                order,
+                connection_hops,
+                counterparty,
+                counterparty_version,
+            )
+            .map_err(into_channel_error)?;
+        let conn_id = single_connection_hop(connection_hops)
+            .map_err(into_channel_error)?;
+        let key = ica_host_account_key(conn_id, counterparty.port_id());
+        let account = RegisteredAccount {
+            channel_id: channel_id.to_string(),
+            address: metadata.address.clone(),
+        };
+        self.ctx
+            .borrow_mut()
+            .storage_mut()
+            .write(&key, account)
+            .map_err(|e| into_channel_error(IcaError::Storage(e)))?;
+        Ok((ModuleExtras::empty(), Version::new(metadata.to_string())))
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "The host can't open a channel".to_string(),
+        )))
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "The host can't open a channel".to_string(),
+        )))
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "An interchain account channel can't be closed".to_string(),
+        )))
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "An interchain account channel can't be closed".to_string(),
+        )))
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.close_channel(port_id, channel_id)
+            .map_err(into_channel_error)?;
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        let ack = match self.execute_tx(packet) {
+            Ok(()) => AcknowledgementStatus::success(ack_success_b64()),
+            Err(e) => AcknowledgementStatus::error(
+                StatusValue::new(e.to_string())
+                    .expect("The error message shouldn't be empty"),
+            ),
+        };
+        (ModuleExtras::empty(), ack.into())
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Err(into_packet_error(IcaError::InvalidChannel(
+            "The host doesn't send any packet".to_string(),
+        )))
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (
+            ModuleExtras::empty(),
+            Err(into_packet_error(IcaError::InvalidChannel(
+                "The host doesn't send any packet".to_string(),
+            ))),
+        )
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Err(into_packet_error(IcaError::InvalidChannel(
+            "The host doesn't send any packet".to_string(),
+        )))
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (
+            ModuleExtras::empty(),
+            Err(into_packet_error(IcaError::InvalidChannel(
+                "The host doesn't send any packet".to_string(),
+            ))),
+        )
+    }
+}
+
+/// IBC module for the interchain account controller. This module is routed
+/// from all the ports with the controller prefix.
+#[derive(Debug)]
+pub struct InterchainAccountControllerModule<C>
+where
+    C: IbcCommonContext,
+{
+    ctx: Rc<RefCell<C>>,
+}
+
+impl<C> InterchainAccountControllerModule<C>
+where
+    C: IbcCommonContext,
+{
+    /// Make a new module
+    pub fn new(ctx: Rc<RefCell<C>>) -> Self {
+        Self { ctx }
+    }
+
+    fn validate_open_init(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(), IcaError> {
+        if order != Order::Ordered {
+            return Err(IcaError::InvalidChannel(
+                "The channel should be ordered".to_string(),
+            ));
+        }
+        let conn_id = single_connection_hop(connection_hops)?;
+        if controller_port_owner(port_id).is_none() {
+            return Err(IcaError::InvalidChannel(format!(
+                "The port {port_id} isn't a controller port"
+            )));
+        }
+        if *counterparty.port_id() != host_port_id() {
+            return Err(IcaError::InvalidChannel(format!(
+                "The counterparty port {} isn't the host port",
+                counterparty.port_id()
+            )));
+        }
+        let metadata = Metadata::from_str(version.as_str())?;
+        metadata.validate()?;
+        if metadata.controller_connection_id != conn_id.to_string() {
+            return Err(IcaError::InvalidMetadata(format!(
+                "The controller connection ID {} should be {conn_id}",
+                metadata.controller_connection_id
+            )));
+        }
+        let key = ica_controller_account_key(conn_id, port_id);
+        if self
+            .ctx
+            .borrow()
+            .storage()
+            .has_key(&key)
+            .map_err(IcaError::Storage)?
+        {
+            return Err(IcaError::InvalidChannel(format!(
+                "The interchain account for {port_id} already has an active \
+                 channel on {conn_id}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate the channel opening acknowledged by the host and return the
+    /// key and the registered account
+    fn validate_open_ack(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(Key, RegisteredAccount), IcaError> {
+        let ctx = self.ctx.borrow();
+        let channel = ctx
+            .channel_end(port_id, channel_id)
+            .map_err(|e| IcaError::Context(Box::new(e)))?;
+        let conn_id = single_connection_hop(channel.connection_hops())?;
+        let metadata = Metadata::from_str(counterparty_version.as_str())?;
+        metadata.validate()?;
+        if metadata.controller_connection_id != conn_id.to_string() {
+            return Err(IcaError::InvalidMetadata(format!(
+                "The controller connection ID {} should be {conn_id}",
+                metadata.controller_connection_id
+            )));
+        }
+        if metadata.address.is_empty() {
+            return Err(IcaError::InvalidMetadata(
+                "The host didn't set the interchain account address"
+                    .to_string(),
+            ));
+        }
+        let key = ica_controller_account_key(conn_id, port_id);
+        if ctx.storage().has_key(&key).map_err(IcaError::Storage)? {
+            return Err(IcaError::InvalidChannel(format!(
+                "The interchain account for {port_id} already has an active \
+                 channel on {conn_id}"
+            )));
+        }
+        let account = RegisteredAccount {
+            channel_id: channel_id.to_string(),
+            address: metadata.address,
+        };
+        Ok((key, account))
+    }
+
+    /// Remove the registration of the interchain account with the closed
+    /// channel so that the owner can register again
+    fn close_channel(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), IcaError> {
+        let channel = self
+            .ctx
+            .borrow()
+            .channel_end(port_id, channel_id)
+            .map_err(|e| IcaError::Context(Box::new(e)))?;
+        let conn_id = single_connection_hop(channel.connection_hops())?;
+        let key = ica_controller_account_key(conn_id, port_id);
+        remove_registration(&mut *self.ctx.borrow_mut(), &key, channel_id)
+    }
+}
+
+impl<C> ModuleWrapper for InterchainAccountControllerModule<C>
+where
+    C: IbcCommonContext + Debug,
+{
+    fn as_module(&self) -> &dyn Module {
+        self
+    }
+
+    fn as_module_mut(&mut self) -> &mut dyn Module {
+        self
+    }
+
+    fn module_id(&self) -> ModuleId {
+        ModuleId::new(CONTROLLER_MODULE_ID_STR.to_string())
+    }
+
+    fn port_id(&self) -> PortId {
+        PortId::from_str(CONTROLLER_PORT_PREFIX)
+            .expect("The controller port prefix should be a valid port ID")
+    }
+}
+
+impl<C> Module for InterchainAccountControllerModule<C>
+where
+    C: IbcCommonContext + Debug,
+{
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.validate_open_init(
+            order,
+            connection_hops,
+            port_id,
+            counterparty,
+            version,
+        )
+        .map_err(into_channel_error)?;
+        Ok(version.clone())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.validate_open_init(
+            order,
+            connection_hops,
+            port_id,
+            counterparty,
+            version,
+        )
+        .map_err(into_channel_error)?;
+        Ok((ModuleExtras::empty(), version.clone()))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "The controller doesn't accept a channel opening".to_string(),
+        )))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "The controller doesn't accept a channel opening".to_string(),
+        )))
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.validate_open_ack(port_id, channel_id, counterparty_version)
+            .map_err(into_channel_error)?;
+        Ok(())
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let (key, account) = self
+            .validate_open_ack(port_id, channel_id, counterparty_version)
+            .map_err(into_channel_error)?;
+        self.ctx
+            .borrow_mut()
+            .storage_mut()
+            .write(&key, account)
+            .map_err(|e| into_channel_error(IcaError::Storage(e)))?;
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "The controller doesn't accept a channel opening".to_string(),
+        )))
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "The controller doesn't accept a channel opening".to_string(),
+        )))
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "An interchain account channel can't be closed".to_string(),
+        )))
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(into_channel_error(IcaError::InvalidChannel(
+            "An interchain account channel can't be closed".to_string(),
+        )))
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.close_channel(port_id, channel_id)
+            .map_err(into_channel_error)?;
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        let ack = AcknowledgementStatus::error(
+            StatusValue::new("The controller doesn't receive any packet")
+                .expect("The error message shouldn't be empty"),
+        );
+        (ModuleExtras::empty(), ack.into())
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        // The ordered channel is closed by the timeout
+        let result = self
+            .close_channel(&packet.port_id_on_a, &packet.chan_id_on_a)
+            .map_err(into_packet_error);
+        (ModuleExtras::empty(), result)
+    }
+}
+
+fn single_connection_hop(
+    connection_hops: &[ConnectionId],
+) -> Result<&ConnectionId, IcaError> {
+    match connection_hops {
+        [conn_id] => Ok(conn_id),
+        _ => Err(IcaError::InvalidChannel(
+            "The channel should have a single connection hop".to_string(),
+        )),
+    }
+}
+
+fn remove_registration<C>(
+    ctx: &mut C,
+    key: &Key,
+    channel_id: &ChannelId,
+) -> Result<(), IcaError>
+where
+    C: IbcCommonContext,
+{
+    let account: Option<RegisteredAccount> =
+        ctx.storage().read(key).map_err(IcaError::Storage)?;
+    if account.is_some_and(|acc| acc.channel_id == channel_id.to_string()) {
+        ctx.storage_mut().delete(key).map_err(IcaError::Storage)?;
+    }
+    Ok(())
+}
+
+fn into_channel_error(error: IcaError) -> ChannelError {
+    ChannelError::AppModule {
+        description: error.to_string(),
+    }
+}
+
+fn into_packet_error(error: IcaError) -> PacketError {
+    PacketError::AppModule {
+        description: error.to_string(),
+    }
+}
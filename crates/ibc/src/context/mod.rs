@@ -3,12 +3,16 @@
 pub mod client;
 pub mod common;
 pub mod execution;
+pub mod fee_mod;
+pub mod ica_mod;
 pub mod nft_transfer;
 pub mod nft_transfer_mod;
 pub mod router;
+pub mod solomachine;
 pub mod storage;
 pub mod token_transfer;
 pub mod transfer_mod;
+pub mod wasm_client;
 pub mod validation;
 
 use std::cell::RefCell;
@@ -9,6 +9,7 @@ use ibc::core::router::types::module::ModuleId;
 use namada_core::collections::HashMap;
 
 use super::super::ModuleWrapper;
+use crate::ica::{is_controller_port, CONTROLLER_MODULE_ID_STR};
 
 /// IBC router
 #[derive(Debug, Default)]
@@ -51,6 +52,13 @@ impl<'a> Router for IbcRouter<'a> {
     }
 
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
-        self.ports.get(port_id).cloned()
+        self.ports.get(port_id).cloned().or_else(|| {
+            // The interchain account controller module owns all the ports
+            // with the controller prefix
+            let module_id = ModuleId::new(CONTROLLER_MODULE_ID_STR.to_string());
+            (is_controller_port(port_id)
+                && self.modules.contains_key(&module_id))
+            .then_some(module_id)
+        })
     }
 }
@@ -0,0 +1,1087 @@
+//! Solo machine light client (ICS-06)
+//!
+//! A solo machine is a single signer, e.g. a wallet or an off-chain service,
+//! whose public key is the consensus state of the client. A header signed by
+//! the current key rotates the key and increments the sequence, i.e. the
+//! revision height of the client. The membership of a value at a path is
+//! proved by a signature of the current key over the sign bytes of the
+//! sequence, the path and the value. The keys and the signatures are Namada
+//! keys and signatures.
+//!
+//! The proof verification can't update the client state, so the sequence is
+//! incremented after a message whose proofs were verified by the client is
+//! executed. All the proofs of a message are signed at the same sequence and
+//! they can't be replayed for another message. Signing different values for
+//! the same path at the same sequence is misbehaviour which freezes the
+//! client.
+
+use std::str::FromStr;
+
+use ibc::core::client::context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc::core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc::core::client::context::{
+    ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
+};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::{Height, Status};
+use ibc::core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ClientId, ClientType};
+use ibc::core::host::types::path::{
+    ClientConsensusStatePath, ClientStatePath, Path,
+};
+use ibc::primitives::proto::Any;
+use ibc::primitives::Timestamp;
+use namada_core::borsh::{BorshDeserialize, BorshSerializeExt};
+use namada_core::key::{common, SigScheme};
+use prost::Message;
+
+use super::wasm_client::LightClient;
+
+/// The client type of the solo machine client
+pub const SOLO_MACHINE_CLIENT_TYPE: &str = "06-solomachine";
+/// The type URL of the solo machine client state
+pub const SOLO_MACHINE_CLIENT_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.ClientState";
+/// The type URL of the solo machine consensus state
+pub const SOLO_MACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.ConsensusState";
+/// The type URL of the solo machine header
+pub const SOLO_MACHINE_HEADER_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.Header";
+/// The type URL of the solo machine misbehaviour
+pub const SOLO_MACHINE_MISBEHAVIOUR_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.Misbehaviour";
+/// The type URL of a Namada public key
+pub const PUBLIC_KEY_TYPE_URL: &str = "/namada.key.common.PublicKey";
+/// The path signed with the header data
+const SENTINEL_HEADER_PATH: &[u8] = b"solomachine:header";
+
+/// The protobuf messages of the solo machine client
+pub mod raw {
+    use ibc::primitives::proto::Any;
+    use prost::Message;
+
+    /// The client state
+    #[derive(Clone, PartialEq, Message)]
+    pub struct ClientState {
+        /// The sequence of the client
+        #[prost(uint64, tag = "1")]
+        pub sequence: u64,
+        /// Whether the client is frozen by misbehaviour
+        #[prost(bool, tag = "2")]
+        pub is_frozen: bool,
+        /// The current consensus state
+        #[prost(message, optional, tag = "3")]
+        pub consensus_state: Option<ConsensusState>,
+    }
+
+    /// The consensus state
+    #[derive(Clone, PartialEq, Message)]
+    pub struct ConsensusState {
+        /// The public key of the solo machine
+        #[prost(message, optional, tag = "1")]
+        pub public_key: Option<Any>,
+        /// The diversifier to distinguish the solo machine clients using the
+        /// same key
+        #[prost(string, tag = "2")]
+        pub diversifier: String,
+        /// The timestamp in nanoseconds
+        #[prost(uint64, tag = "3")]
+        pub timestamp: u64,
+    }
+
+    /// The header to rotate the key
+    #[derive(Clone, PartialEq, Message)]
+    pub struct Header {
+        /// The timestamp in nanoseconds
+        #[prost(uint64, tag = "1")]
+        pub timestamp: u64,
+        /// The signature over the header data
+        #[prost(bytes = "vec", tag = "2")]
+        pub signature: Vec<u8>,
+        /// The new public key
+        #[prost(message, optional, tag = "3")]
+        pub new_public_key: Option<Any>,
+        /// The new diversifier
+        #[prost(string, tag = "4")]
+        pub new_diversifier: String,
+    }
+
+    /// The misbehaviour of two signatures for the same path at the same
+    /// sequence
+    #[derive(Clone, PartialEq, Message)]
+    pub struct Misbehaviour {
+        /// The sequence of the signatures
+        #[prost(uint64, tag = "1")]
+        pub sequence: u64,
+        /// The first signature
+        #[prost(message, optional, tag = "2")]
+        pub signature_one: Option<SignatureAndData>,
+        /// The second signature
+        #[prost(message, optional, tag = "3")]
+        pub signature_two: Option<SignatureAndData>,
+    }
+
+    /// A signature with the signed path and data
+    #[derive(Clone, PartialEq, Message)]
+    pub struct SignatureAndData {
+        /// The signature
+        #[prost(bytes = "vec", tag = "1")]
+        pub signature: Vec<u8>,
+        /// The signed path
+        #[prost(bytes = "vec", tag = "2")]
+        pub path: Vec<u8>,
+        /// The signed data
+        #[prost(bytes = "vec", tag = "3")]
+        pub data: Vec<u8>,
+        /// The timestamp of the signature in nanoseconds
+        #[prost(uint64, tag = "4")]
+        pub timestamp: u64,
+    }
+
+    /// The proof of a membership
+    #[derive(Clone, PartialEq, Message)]
+    pub struct TimestampedSignatureData {
+        /// The signature
+        #[prost(bytes = "vec", tag = "1")]
+        pub signature_data: Vec<u8>,
+        /// The timestamp of the signature in nanoseconds
+        #[prost(uint64, tag = "2")]
+        pub timestamp: u64,
+    }
+
+    /// The bytes signed by the solo machine
+    #[derive(Clone, PartialEq, Message)]
+    pub struct SignBytes {
+        /// The sequence of the client
+        #[prost(uint64, tag = "1")]
+        pub sequence: u64,
+        /// The timestamp of the signature in nanoseconds
+        #[prost(uint64, tag = "2")]
+        pub timestamp: u64,
+        /// The diversifier
+        #[prost(string, tag = "3")]
+        pub diversifier: String,
+        /// The signed path
+        #[prost(bytes = "vec", tag = "4")]
+        pub path: Vec<u8>,
+        /// The signed data
+        #[prost(bytes = "vec", tag = "5")]
+        pub data: Vec<u8>,
+    }
+
+    /// The data signed with a header
+    #[derive(Clone, PartialEq, Message)]
+    pub struct HeaderData {
+        /// The new public key
+        #[prost(message, optional, tag = "1")]
+        pub new_pub_key: Option<Any>,
+        /// The new diversifier
+        #[prost(string, tag = "2")]
+        pub new_diversifier: String,
+    }
+}
+
+/// Consensus state of the solo machine client
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsensusState {
+    /// The public key of the solo machine
+    pub public_key: common::PublicKey,
+    /// The diversifier
+    pub diversifier: String,
+    /// The timestamp
+    pub timestamp: Timestamp,
+    /// The solo machine has no commitment root
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    /// Make a new consensus state
+    pub fn new(
+        public_key: common::PublicKey,
+        diversifier: String,
+        timestamp: Timestamp,
+    ) -> Self {
+        Self {
+            public_key,
+            diversifier,
+            timestamp,
+            root: CommitmentRoot::from_bytes(&[]),
+        }
+    }
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl TryFrom<raw::ConsensusState> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: raw::ConsensusState) -> Result<Self, Self::Error> {
+        let public_key = decode_public_key(raw.public_key)?;
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp)
+            .map_err(|e| client_error(format!("Invalid timestamp: {e}")))?;
+        Ok(Self::new(public_key, raw.diversifier, timestamp))
+    }
+}
+
+impl From<ConsensusState> for raw::ConsensusState {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Self {
+            public_key: Some(encode_public_key(&consensus_state.public_key)),
+            diversifier: consensus_state.diversifier,
+            timestamp: consensus_state.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        if any.type_url != SOLO_MACHINE_CONSENSUS_STATE_TYPE_URL {
+            return Err(client_error(format!(
+                "Unexpected type URL of the consensus state: {}",
+                any.type_url
+            )));
+        }
+        raw::ConsensusState::decode(&any.value[..])
+            .map_err(|e| client_error(e.to_string()))?
+            .try_into()
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: SOLO_MACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: raw::ConsensusState::from(consensus_state).encode_to_vec(),
+        }
+    }
+}
+
+/// Client state of the solo machine client
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState {
+    /// The sequence of the client
+    pub sequence: u64,
+    /// Whether the client is frozen by misbehaviour
+    pub is_frozen: bool,
+    /// The current consensus state
+    pub consensus_state: ConsensusState,
+}
+
+impl ClientState {
+    /// Make a new client state
+    pub fn new(sequence: u64, consensus_state: ConsensusState) -> Self {
+        Self {
+            sequence,
+            is_frozen: false,
+            consensus_state,
+        }
+    }
+
+    /// The height of the sequence
+    fn height(sequence: u64) -> Height {
+        Height::new(0, sequence).expect("The sequence shouldn't be zero")
+    }
+
+    /// Verify the signature of the current key over the sign bytes
+    fn verify_signature(
+        &self,
+        sequence: u64,
+        timestamp: u64,
+        path: Vec<u8>,
+        data: Vec<u8>,
+        signature: &[u8],
+    ) -> Result<(), ClientError> {
+        if timestamp < self.consensus_state.timestamp.nanoseconds() {
+            return Err(client_error(format!(
+                "The signature timestamp {timestamp} is older than the \
+                 consensus state"
+            )));
+        }
+        let sign_bytes = raw::SignBytes {
+            sequence,
+            timestamp,
+            diversifier: self.consensus_state.diversifier.clone(),
+            path,
+            data,
+        }
+        .encode_to_vec();
+        let signature = common::Signature::try_from_slice(signature)
+            .map_err(|e| client_error(format!("Invalid signature: {e}")))?;
+        common::SigScheme::verify_signature(
+            &self.consensus_state.public_key,
+            &sign_bytes,
+            &signature,
+        )
+        .map_err(|e| client_error(e.to_string()))
+    }
+
+    /// Verify the proof of the value at the path
+    fn verify_proof(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        if self.is_frozen {
+            return Err(client_error("The client is frozen".to_string()));
+        }
+        let proof_bytes: Vec<u8> = proof.clone().into();
+        let proof = raw::TimestampedSignatureData::decode(&proof_bytes[..])
+            .map_err(|e| client_error(format!("Invalid proof: {e}")))?;
+        self.verify_signature(
+            self.sequence,
+            proof.timestamp,
+            prefixed_path(prefix, &path),
+            value,
+            &proof.signature_data,
+        )
+    }
+
+    /// Verify the header or the misbehaviour
+    fn verify_message(&self, client_message: &Any) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            SOLO_MACHINE_HEADER_TYPE_URL => {
+                self.verify_header(&Header::try_from(client_message.clone())?)
+            }
+            SOLO_MACHINE_MISBEHAVIOUR_TYPE_URL => {
+                self.verify_misbehaviour(&decode_misbehaviour(client_message)?)
+            }
+            _ => Err(client_error(format!(
+                "Unexpected client message type: {}",
+                client_message.type_url
+            ))),
+        }
+    }
+
+    /// Get the client state updated by the verified header
+    fn updated(&self, header: Header) -> Result<ClientState, ClientError> {
+        let timestamp = Timestamp::from_nanoseconds(header.timestamp)
+            .map_err(|e| client_error(format!("Invalid timestamp: {e}")))?;
+        let sequence = self.next_sequence()?;
+        Ok(ClientState::new(
+            sequence,
+            ConsensusState::new(
+                header.new_public_key,
+                header.new_diversifier,
+                timestamp,
+            ),
+        ))
+    }
+
+    fn next_sequence(&self) -> Result<u64, ClientError> {
+        self.sequence
+            .checked_add(1)
+            .ok_or_else(|| client_error("The sequence overflowed".to_string()))
+    }
+
+    /// Increment the sequence after the proofs of a message were verified so
+    /// that the signatures can't be replayed
+    pub fn increment_sequence<E>(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+    ) -> Result<(), ClientError>
+    where
+        E: ClientExecutionContext + ExtClientValidationContext,
+        E::ClientStateRef: From<ClientState>,
+        E::ConsensusStateRef: From<ConsensusState>,
+    {
+        let client_state = ClientState {
+            sequence: self.next_sequence()?,
+            ..self.clone()
+        };
+        client_state.store(ctx, client_id)
+    }
+
+    /// Verify the header signed by the current key
+    pub fn verify_header(&self, header: &Header) -> Result<(), ClientError> {
+        let data = raw::HeaderData {
+            new_pub_key: Some(encode_public_key(&header.new_public_key)),
+            new_diversifier: header.new_diversifier.clone(),
+        }
+        .encode_to_vec();
+        self.verify_signature(
+            self.sequence,
+            header.timestamp,
+            SENTINEL_HEADER_PATH.to_vec(),
+            data,
+            &header.signature,
+        )
+    }
+
+    /// Verify that the misbehaviour has two valid signatures of different
+    /// data for the same path at the current sequence
+    pub fn verify_misbehaviour(
+        &self,
+        misbehaviour: &raw::Misbehaviour,
+    ) -> Result<(), ClientError> {
+        if misbehaviour.sequence != self.sequence {
+            return Err(client_error(format!(
+                "The misbehaviour sequence {} isn't the current sequence {}",
+                misbehaviour.sequence, self.sequence
+            )));
+        }
+        let (Some(one), Some(two)) =
+            (&misbehaviour.signature_one, &misbehaviour.signature_two)
+        else {
+            return Err(client_error(
+                "The misbehaviour requires two signatures".to_string(),
+            ));
+        };
+        if one.path != two.path || one.data == two.data {
+            return Err(client_error(
+                "The signatures should be for different data of the same \
+                 path"
+                    .to_string(),
+            ));
+        }
+        for sig in [one, two] {
+            self.verify_signature(
+                misbehaviour.sequence,
+                sig.timestamp,
+                sig.path.clone(),
+                sig.data.clone(),
+                &sig.signature,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Store the client state and the current consensus state
+    fn store<E>(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+    ) -> Result<(), ClientError>
+    where
+        E: ClientExecutionContext + ExtClientValidationContext,
+        E::ClientStateRef: From<ClientState>,
+        E::ConsensusStateRef: From<ConsensusState>,
+    {
+        let height = Self::height(self.sequence);
+        ctx.store_client_state(
+            ClientStatePath(client_id.clone()),
+            self.clone().into(),
+        )
+        .map_err(context_error)?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath {
+                client_id: client_id.clone(),
+                revision_number: height.revision_number(),
+                revision_height: height.revision_height(),
+            },
+            self.consensus_state.clone().into(),
+        )
+        .map_err(context_error)?;
+        let host_timestamp = ctx.host_timestamp().map_err(context_error)?;
+        let host_height = ctx.host_height().map_err(context_error)?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            height,
+            host_timestamp,
+            host_height,
+        )
+        .map_err(context_error)
+    }
+}
+
+impl TryFrom<raw::ClientState> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: raw::ClientState) -> Result<Self, Self::Error> {
+        if raw.sequence == 0 {
+            return Err(client_error("The sequence should be positive".into()));
+        }
+        let consensus_state = raw
+            .consensus_state
+            .ok_or_else(|| client_error("No consensus state".to_string()))?
+            .try_into()?;
+        Ok(Self {
+            sequence: raw.sequence,
+            is_frozen: raw.is_frozen,
+            consensus_state,
+        })
+    }
+}
+
+impl From<ClientState> for raw::ClientState {
+    fn from(client_state: ClientState) -> Self {
+        Self {
+            sequence: client_state.sequence,
+            is_frozen: client_state.is_frozen,
+            consensus_state: Some(client_state.consensus_state.into()),
+        }
+    }
+}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        if any.type_url != SOLO_MACHINE_CLIENT_STATE_TYPE_URL {
+            return Err(client_error(format!(
+                "Unexpected type URL of the client state: {}",
+                any.type_url
+            )));
+        }
+        raw::ClientState::decode(&any.value[..])
+            .map_err(|e| client_error(e.to_string()))?
+            .try_into()
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: SOLO_MACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: raw::ClientState::from(client_state).encode_to_vec(),
+        }
+    }
+}
+
+/// Header of the solo machine client to rotate the key
+#[derive(Clone, Debug, PartialEq)]
+pub struct Header {
+    /// The timestamp in nanoseconds
+    pub timestamp: u64,
+    /// The signature of the current key over the header data
+    pub signature: Vec<u8>,
+    /// The new public key
+    pub new_public_key: common::PublicKey,
+    /// The new diversifier
+    pub new_diversifier: String,
+}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        if any.type_url != SOLO_MACHINE_HEADER_TYPE_URL {
+            return Err(client_error(format!(
+                "Unexpected type URL of the header: {}",
+                any.type_url
+            )));
+        }
+        let raw = raw::Header::decode(&any.value[..])
+            .map_err(|e| client_error(e.to_string()))?;
+        Ok(Self {
+            timestamp: raw.timestamp,
+            signature: raw.signature,
+            new_public_key: decode_public_key(raw.new_public_key)?,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        let raw = raw::Header {
+            timestamp: header.timestamp,
+            signature: header.signature,
+            new_public_key: Some(encode_public_key(&header.new_public_key)),
+            new_diversifier: header.new_diversifier,
+        };
+        Any {
+            type_url: SOLO_MACHINE_HEADER_TYPE_URL.to_string(),
+            value: raw.encode_to_vec(),
+        }
+    }
+}
+
+fn decode_misbehaviour(any: &Any) -> Result<raw::Misbehaviour, ClientError> {
+    raw::Misbehaviour::decode(&any.value[..])
+        .map_err(|e| client_error(e.to_string()))
+}
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        ConsensusState::try_from(consensus_state).map(|_| ())
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::from_str(SOLO_MACHINE_CLIENT_TYPE)
+            .expect("The client type should be valid")
+    }
+
+    fn latest_height(&self) -> Height {
+        Self::height(self.sequence)
+    }
+
+    fn validate_proof_height(
+        &self,
+        proof_height: Height,
+    ) -> Result<(), ClientError> {
+        if self.latest_height() < proof_height {
+            return Err(ClientError::InvalidProofHeight {
+                latest_height: self.latest_height(),
+                proof_height,
+            });
+        }
+        Ok(())
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(client_error(
+            "The solo machine client can't be upgraded".to_string(),
+        ))
+    }
+
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.verify_proof(prefix, proof, path, value)
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        // The absence is proved by signing the path with empty data
+        self.verify_proof(prefix, proof, path, vec![])
+    }
+}
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ClientValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        self.verify_message(&client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        // The misbehaviour has been verified by `verify_client_message`
+        Ok(client_message.type_url == SOLO_MACHINE_MISBEHAVIOUR_TYPE_URL)
+    }
+
+    fn status(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+    ) -> Result<Status, ClientError> {
+        Ok(if self.is_frozen {
+            Status::Frozen
+        } else {
+            Status::Active
+        })
+    }
+
+    fn check_substitute(
+        &self,
+        _ctx: &V,
+        substitute_client_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute = ClientState::try_from(substitute_client_state)?;
+        if substitute.is_frozen {
+            return Err(client_error(
+                "The substitute client is frozen".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ClientExecutionContext + ExtClientValidationContext,
+    E::ClientStateRef: From<ClientState>,
+    E::ConsensusStateRef: From<ConsensusState>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ConsensusState::try_from(consensus_state)?;
+        if consensus_state != self.consensus_state {
+            return Err(client_error(
+                "The consensus state should be the one of the client state"
+                    .to_string(),
+            ));
+        }
+        self.store(ctx, client_id)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let client_state = self.updated(Header::try_from(header)?)?;
+        client_state.store(ctx, client_id)?;
+        Ok(vec![client_state.latest_height()])
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        let client_state = ClientState {
+            is_frozen: true,
+            ..self.clone()
+        };
+        ctx.store_client_state(
+            ClientStatePath(client_id.clone()),
+            client_state.into(),
+        )
+        .map_err(context_error)
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(client_error(
+            "The solo machine client can't be upgraded".to_string(),
+        ))
+    }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        _substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute = ClientState::try_from(substitute_client_state)?;
+        let client_state = ClientState::new(
+            std::cmp::max(self.sequence, substitute.sequence),
+            substitute.consensus_state,
+        );
+        client_state.store(ctx, subject_client_id)
+    }
+}
+
+/// The solo machine client which can be wrapped by the wasm client. The
+/// states are the encoded protobuf messages and the client messages are the
+/// encoded `Any`s.
+pub struct SoloMachineLightClient;
+
+impl SoloMachineLightClient {
+    fn client_state(data: &[u8]) -> Result<ClientState, ClientError> {
+        raw::ClientState::decode(data)
+            .map_err(|e| client_error(e.to_string()))?
+            .try_into()
+    }
+
+    fn client_message(data: &[u8]) -> Result<Any, ClientError> {
+        Any::decode(data).map_err(|e| client_error(e.to_string()))
+    }
+
+    fn encode(client_state: ClientState) -> (Vec<u8>, Vec<u8>) {
+        let consensus_state =
+            raw::ConsensusState::from(client_state.consensus_state.clone());
+        (
+            raw::ClientState::from(client_state).encode_to_vec(),
+            consensus_state.encode_to_vec(),
+        )
+    }
+}
+
+impl LightClient for SoloMachineLightClient {
+    fn name(&self) -> &'static str {
+        SOLO_MACHINE_CLIENT_TYPE
+    }
+
+    fn latest_height(
+        &self,
+        client_state: &[u8],
+    ) -> Result<Height, ClientError> {
+        Ok(Self::client_state(client_state)?.latest_height())
+    }
+
+    fn status(&self, client_state: &[u8]) -> Result<Status, ClientError> {
+        Ok(if Self::client_state(client_state)?.is_frozen {
+            Status::Frozen
+        } else {
+            Status::Active
+        })
+    }
+
+    fn consensus_state_info(
+        &self,
+        consensus_state: &[u8],
+    ) -> Result<(Timestamp, CommitmentRoot), ClientError> {
+        let consensus_state: ConsensusState =
+            raw::ConsensusState::decode(consensus_state)
+                .map_err(|e| client_error(e.to_string()))?
+                .try_into()?;
+        Ok((consensus_state.timestamp, consensus_state.root))
+    }
+
+    fn verify_client_message(
+        &self,
+        client_state: &[u8],
+        client_message: &[u8],
+    ) -> Result<(), ClientError> {
+        Self::client_state(client_state)?
+            .verify_message(&Self::client_message(client_message)?)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        _client_state: &[u8],
+        client_message: &[u8],
+    ) -> Result<bool, ClientError> {
+        Ok(Self::client_message(client_message)?.type_url
+            == SOLO_MACHINE_MISBEHAVIOUR_TYPE_URL)
+    }
+
+    fn update_state(
+        &self,
+        client_state: &[u8],
+        header: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), ClientError> {
+        let header = Header::try_from(Self::client_message(header)?)?;
+        let client_state = Self::client_state(client_state)?.updated(header)?;
+        Ok(Self::encode(client_state))
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        client_state: &[u8],
+    ) -> Result<Vec<u8>, ClientError> {
+        let client_state = ClientState {
+            is_frozen: true,
+            ..Self::client_state(client_state)?
+        };
+        Ok(raw::ClientState::from(client_state).encode_to_vec())
+    }
+
+    fn verify_membership(
+        &self,
+        client_state: &[u8],
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        Self::client_state(client_state)?
+            .verify_proof(prefix, proof, path, value)
+    }
+
+    fn verify_non_membership(
+        &self,
+        client_state: &[u8],
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        Self::client_state(client_state)?.verify_proof(
+            prefix,
+            proof,
+            path,
+            vec![],
+        )
+    }
+
+    fn update_state_on_proofs(
+        &self,
+        client_state: &[u8],
+    ) -> Result<Option<(Vec<u8>, Vec<u8>)>, ClientError> {
+        let client_state = Self::client_state(client_state)?;
+        let client_state = ClientState {
+            sequence: client_state.next_sequence()?,
+            ..client_state
+        };
+        Ok(Some(Self::encode(client_state)))
+    }
+}
+
+/// Returns the path bytes signed for a proof
+fn prefixed_path(prefix: &CommitmentPrefix, path: &Path) -> Vec<u8> {
+    let mut bytes = prefix.as_bytes().to_vec();
+    bytes.push(b'/');
+    bytes.extend(path.to_string().as_bytes());
+    bytes
+}
+
+/// Encode the public key into `Any`
+pub fn encode_public_key(public_key: &common::PublicKey) -> Any {
+    Any {
+        type_url: PUBLIC_KEY_TYPE_URL.to_string(),
+        value: public_key.serialize_to_vec(),
+    }
+}
+
+fn decode_public_key(
+    any: Option<Any>,
+) -> Result<common::PublicKey, ClientError> {
+    let any = any.ok_or_else(|| client_error("No public key".to_string()))?;
+    if any.type_url != PUBLIC_KEY_TYPE_URL {
+        return Err(client_error(format!(
+            "Unsupported public key type: {}",
+            any.type_url
+        )));
+    }
+    common::PublicKey::try_from_slice(&any.value)
+        .map_err(|e| client_error(format!("Invalid public key: {e}")))
+}
+
+fn client_error(description: String) -> ClientError {
+    ClientError::ClientSpecific { description }
+}
+
+fn context_error(e: ContextError) -> ClientError {
+    ClientError::Other {
+        description: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::key::testing::{keypair_1, keypair_2};
+    use namada_core::key::RefTo;
+
+    use super::*;
+
+    fn client_state() -> ClientState {
+        let consensus_state = ConsensusState::new(
+            keypair_1().ref_to(),
+            "diversifier".to_string(),
+            Timestamp::from_nanoseconds(100).unwrap(),
+        );
+        ClientState::new(1, consensus_state)
+    }
+
+    fn sign(
+        sk: &common::SecretKey,
+        sequence: u64,
+        timestamp: u64,
+        path: &[u8],
+        data: &[u8],
+    ) -> Vec<u8> {
+        let sign_bytes = raw::SignBytes {
+            sequence,
+            timestamp,
+            diversifier: "diversifier".to_string(),
+            path: path.to_vec(),
+            data: data.to_vec(),
+        }
+        .encode_to_vec();
+        common::SigScheme::sign(sk, sign_bytes).serialize_to_vec()
+    }
+
+    #[test]
+    fn test_client_state_conversion() {
+        let client_state = client_state();
+        let any: Any = client_state.clone().into();
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+
+        let any: Any = client_state.consensus_state.clone().into();
+        assert_eq!(
+            ConsensusState::try_from(any.clone()).unwrap(),
+            client_state.consensus_state
+        );
+        // Not a client state
+        assert!(ClientState::try_from(any).is_err());
+    }
+
+    #[test]
+    fn test_verify_header() {
+        let client_state = client_state();
+        let new_public_key = keypair_2().ref_to();
+        let data = raw::HeaderData {
+            new_pub_key: Some(encode_public_key(&new_public_key)),
+            new_diversifier: "new".to_string(),
+        }
+        .encode_to_vec();
+        let mut header = Header {
+            timestamp: 200,
+            signature: sign(&keypair_1(), 1, 200, SENTINEL_HEADER_PATH, &data),
+            new_public_key,
+            new_diversifier: "new".to_string(),
+        };
+        client_state.verify_header(&header).unwrap();
+
+        // Signed by another key
+        header.signature =
+            sign(&keypair_2(), 1, 200, SENTINEL_HEADER_PATH, &data);
+        assert!(client_state.verify_header(&header).is_err());
+
+        // Older than the consensus state
+        header.timestamp = 50;
+        header.signature =
+            sign(&keypair_1(), 1, 50, SENTINEL_HEADER_PATH, &data);
+        assert!(client_state.verify_header(&header).is_err());
+    }
+
+    #[test]
+    fn test_verify_misbehaviour() {
+        let client_state = client_state();
+        let signature = |data: &[u8]| raw::SignatureAndData {
+            signature: sign(&keypair_1(), 1, 200, b"path", data),
+            path: b"path".to_vec(),
+            data: data.to_vec(),
+            timestamp: 200,
+        };
+        let misbehaviour = raw::Misbehaviour {
+            sequence: 1,
+            signature_one: Some(signature(b"one")),
+            signature_two: Some(signature(b"two")),
+        };
+        client_state.verify_misbehaviour(&misbehaviour).unwrap();
+
+        // The same data isn't misbehaviour
+        let misbehaviour = raw::Misbehaviour {
+            sequence: 1,
+            signature_one: Some(signature(b"one")),
+            signature_two: Some(signature(b"one")),
+        };
+        assert!(client_state.verify_misbehaviour(&misbehaviour).is_err());
+    }
+}
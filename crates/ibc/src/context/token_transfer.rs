@@ -3,6 +3,7 @@
 use std::cell::RefCell;
 use std::collections::BTreeSet;
 use std::rc::Rc;
+use std::str::FromStr;
 
 use ibc::apps::transfer::context::{
     TokenTransferExecutionContext, TokenTransferValidationContext,
@@ -17,6 +18,7 @@ use namada_core::token::Amount;
 use namada_core::uint::Uint;
 
 use super::common::IbcCommonContext;
+use crate::rate_limit::{self, FlowDirection};
 use crate::{trace, IBC_ESCROW_ADDRESS};
 
 /// Token transfer context to handle tokens
@@ -136,6 +138,25 @@ where
             .map_err(TokenTransferError::from)
     }
 
+    /// Add the amount to the flow of the token over the channel if the
+    /// channel has the rate limit for the token
+    fn update_channel_flow(
+        &self,
+        channel_id: &ChannelId,
+        token: &Address,
+        amount: Amount,
+        direction: FlowDirection,
+    ) -> Result<(), TokenTransferError> {
+        rate_limit::update_channel_flow(
+            self.inner.borrow_mut().storage_mut(),
+            channel_id,
+            token,
+            amount,
+            direction,
+        )
+        .map_err(|e| ContextError::from(e).into())
+    }
+
     fn maybe_store_ibc_denom(
         &self,
         owner: &Address,
@@ -236,13 +257,19 @@ where
         &mut self,
         from_account: &Self::AccountId,
         _port_id: &PortId,
-        _channel_id: &ChannelId,
+        channel_id: &ChannelId,
         coin: &PrefixedCoin,
         _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
         let (ibc_token, amount) = self.get_token_amount(coin)?;
 
         self.add_withdraw(&ibc_token, amount)?;
+        self.update_channel_flow(
+            channel_id,
+            &ibc_token,
+            amount,
+            FlowDirection::Outbound,
+        )?;
 
         // A transfer of NUT tokens must be verified by their VP
         if ibc_token.is_internal()
@@ -251,6 +278,10 @@ where
             self.insert_verifier(&ibc_token);
         }
 
+        // The token forwarded by the IBC escrow is already held by it
+        if *from_account == IBC_ESCROW_ADDRESS {
+            return Ok(());
+        }
         self.inner
             .borrow_mut()
             .transfer_token(
@@ -266,13 +297,23 @@ where
         &mut self,
         to_account: &Self::AccountId,
         _port_id: &PortId,
-        _channel_id: &ChannelId,
+        channel_id: &ChannelId,
         coin: &PrefixedCoin,
     ) -> Result<(), TokenTransferError> {
         let (ibc_token, amount) = self.get_token_amount(coin)?;
 
         self.add_deposit(&ibc_token, amount)?;
-
+        self.update_channel_flow(
+            channel_id,
+            &ibc_token,
+            amount,
+            FlowDirection::Inbound,
+        )?;
+
+        // The token received to be forwarded is held by the IBC escrow
+        if *to_account == IBC_ESCROW_ADDRESS {
+            return Ok(());
+        }
         self.inner
             .borrow_mut()
             .transfer_token(&IBC_ESCROW_ADDRESS, to_account, &ibc_token, amount)
@@ -289,6 +330,14 @@ where
 
         self.update_mint_amount(&ibc_token, amount, true)?;
         self.add_deposit(&ibc_token, amount)?;
+        if let Some(channel_id) = trace_channel(&coin.denom) {
+            self.update_channel_flow(
+                &channel_id,
+                &ibc_token,
+                amount,
+                FlowDirection::Inbound,
+            )?;
+        }
 
         // A transfer of NUT tokens must be verified by their VP
         if ibc_token.is_internal()
@@ -317,6 +366,14 @@ where
 
         self.update_mint_amount(&ibc_token, amount, false)?;
         self.add_withdraw(&ibc_token, amount)?;
+        if let Some(channel_id) = trace_channel(&coin.denom) {
+            self.update_channel_flow(
+                &channel_id,
+                &ibc_token,
+                amount,
+                FlowDirection::Outbound,
+            )?;
+        }
 
         // A transfer of NUT tokens must be verified by their VP
         if ibc_token.is_internal()
@@ -332,3 +389,13 @@ where
             .map_err(|e| ContextError::from(e).into())
     }
 }
+
+/// Get the channel on this chain from the first prefix of the trace path.
+/// The token minted or burned on this chain has the prefix with the port and
+/// the channel on this chain.
+fn trace_channel(denom: &PrefixedDenom) -> Option<ChannelId> {
+    let trace_path = denom.trace_path.to_string();
+    let mut segments = trace_path.split('/');
+    let _port_id = segments.next()?;
+    ChannelId::from_str(segments.next()?).ok()
+}
@@ -18,8 +18,11 @@ use ibc::apps::transfer::module::{
     on_timeout_packet_validate,
 };
 use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::packet::PacketData;
 use ibc::apps::transfer::types::MODULE_ID_STR;
-use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
 use ibc::core::channel::types::channel::{Counterparty, Order};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::packet::Packet;
@@ -32,6 +35,8 @@ use namada_core::address::Address;
 
 use super::common::IbcCommonContext;
 use super::token_transfer::TokenTransferContext;
+use crate::forward::{decode_forward_metadata, ForwardError};
+use crate::IBC_ESCROW_ADDRESS;
 
 /// IBC module wrapper for getting the reference of the module
 pub trait ModuleWrapper: Module {
@@ -277,7 +282,19 @@ where
         packet: &Packet,
         _relayer: &Signer,
     ) -> (ModuleExtras, Acknowledgement) {
-        on_recv_packet_execute(&mut self.ctx, packet)
+        // The IBC escrow receives the token to be forwarded to the next hop
+        match forward_packet(packet) {
+            Ok(Some(packet)) => on_recv_packet_execute(&mut self.ctx, &packet),
+            Ok(None) => on_recv_packet_execute(&mut self.ctx, packet),
+            Err(e) => (
+                ModuleExtras::empty(),
+                AcknowledgementStatus::error(
+                    StatusValue::new(e.to_string())
+                        .expect("The error message shouldn't be empty"),
+                )
+                .into(),
+            ),
+        }
     }
 
     fn on_acknowledgement_packet_validate(
@@ -330,6 +347,29 @@ where
     }
 }
 
+/// Returns the packet whose receiver is replaced with the IBC escrow if the
+/// received token should be forwarded to the next hop
+fn forward_packet(packet: &Packet) -> Result<Option<Packet>, ForwardError> {
+    let Ok(mut data) = serde_json::from_slice::<PacketData>(&packet.data)
+    else {
+        // The decoding error is handled by the transfer module
+        return Ok(None);
+    };
+    if decode_forward_metadata(data.memo.as_ref())?.is_none() {
+        return Ok(None);
+    }
+    data.receiver = IBC_ESCROW_ADDRESS.to_string().into();
+    let data = serde_json::to_vec(&data).map_err(|e| {
+        ForwardError::InvalidMetadata(format!(
+            "Encoding the packet data failed: {e}"
+        ))
+    })?;
+    Ok(Some(Packet {
+        data,
+        ..packet.clone()
+    }))
+}
+
 fn into_channel_error(error: TokenTransferError) -> ChannelError {
     ChannelError::AppModule {
         description: error.to_string(),
@@ -0,0 +1,727 @@
+//! Wasm light client wrapper (ICS-08)
+//!
+//! The client state, the consensus state and the client message of a wasm
+//! client wrap the opaque data of an underlying light client identified by
+//! the checksum of its code. The underlying light client is looked up by the
+//! checksum from the registry of light clients, and the checksum should be
+//! allowed by governance to create or update a client. Namada doesn't run
+//! light client contracts, so the registry has the native light clients
+//! whose checksum is the SHA-256 hash of their name.
+
+use std::str::FromStr;
+
+use data_encoding::HEXLOWER;
+use ibc::core::client::context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc::core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc::core::client::context::{
+    ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
+};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::{Height, Status};
+use ibc::core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ClientId, ClientType};
+use ibc::core::host::types::path::{
+    ClientConsensusStatePath, ClientStatePath, Path,
+};
+use ibc::primitives::proto::Any;
+use ibc::primitives::Timestamp;
+use prost::Message;
+use sha2::{Digest, Sha256};
+
+use super::solomachine::SoloMachineLightClient;
+
+/// The client type of the wasm client
+pub const WASM_CLIENT_TYPE: &str = "08-wasm";
+/// The type URL of the wasm client state
+pub const WASM_CLIENT_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.wasm.v1.ClientState";
+/// The type URL of the wasm consensus state
+pub const WASM_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.wasm.v1.ConsensusState";
+/// The type URL of the wasm client message
+pub const WASM_CLIENT_MESSAGE_TYPE_URL: &str =
+    "/ibc.lightclients.wasm.v1.ClientMessage";
+
+/// The light clients which can be wrapped by the wasm client
+static LIGHT_CLIENTS: &[&dyn LightClient] = &[&SoloMachineLightClient];
+
+/// The protobuf messages of the wasm client
+pub mod raw {
+    use prost::Message;
+
+    /// The client state
+    #[derive(Clone, PartialEq, Message)]
+    pub struct ClientState {
+        /// The client state of the underlying light client
+        #[prost(bytes = "vec", tag = "1")]
+        pub data: Vec<u8>,
+        /// The checksum of the underlying light client
+        #[prost(bytes = "vec", tag = "2")]
+        pub checksum: Vec<u8>,
+        /// The latest height of the underlying light client
+        #[prost(message, optional, tag = "3")]
+        pub latest_height: Option<Height>,
+    }
+
+    /// The consensus state
+    #[derive(Clone, PartialEq, Message)]
+    pub struct ConsensusState {
+        /// The consensus state of the underlying light client
+        #[prost(bytes = "vec", tag = "1")]
+        pub data: Vec<u8>,
+    }
+
+    /// The client message, i.e. a header or misbehaviour
+    #[derive(Clone, PartialEq, Message)]
+    pub struct ClientMessage {
+        /// The client message of the underlying light client
+        #[prost(bytes = "vec", tag = "1")]
+        pub data: Vec<u8>,
+    }
+
+    /// The height
+    #[derive(Clone, PartialEq, Message)]
+    pub struct Height {
+        /// The revision number
+        #[prost(uint64, tag = "1")]
+        pub revision_number: u64,
+        /// The revision height
+        #[prost(uint64, tag = "2")]
+        pub revision_height: u64,
+    }
+}
+
+/// A light client which can be wrapped by the wasm client. The states and
+/// the messages are the opaque data of the wasm client.
+pub trait LightClient: Sync {
+    /// The name of the light client from which the checksum is computed
+    fn name(&self) -> &'static str;
+
+    /// Get the latest height of the client state
+    fn latest_height(&self, client_state: &[u8])
+        -> Result<Height, ClientError>;
+
+    /// Get the status of the client state
+    fn status(&self, client_state: &[u8]) -> Result<Status, ClientError>;
+
+    /// Get the timestamp and the commitment root of the consensus state
+    fn consensus_state_info(
+        &self,
+        consensus_state: &[u8],
+    ) -> Result<(Timestamp, CommitmentRoot), ClientError>;
+
+    /// Verify the header or the misbehaviour
+    fn verify_client_message(
+        &self,
+        client_state: &[u8],
+        client_message: &[u8],
+    ) -> Result<(), ClientError>;
+
+    /// Check if the verified client message is misbehaviour
+    fn check_for_misbehaviour(
+        &self,
+        client_state: &[u8],
+        client_message: &[u8],
+    ) -> Result<bool, ClientError>;
+
+    /// Update the client state with the verified header. Returns the new
+    /// client state and the new consensus state.
+    fn update_state(
+        &self,
+        client_state: &[u8],
+        header: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), ClientError>;
+
+    /// Freeze the client state on the verified misbehaviour
+    fn update_state_on_misbehaviour(
+        &self,
+        client_state: &[u8],
+    ) -> Result<Vec<u8>, ClientError>;
+
+    /// Verify the membership of the value at the path
+    fn verify_membership(
+        &self,
+        client_state: &[u8],
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError>;
+
+    /// Verify the non-membership at the path
+    fn verify_non_membership(
+        &self,
+        client_state: &[u8],
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+    ) -> Result<(), ClientError>;
+
+    /// Update the client state after the proofs of a message were verified.
+    /// Returns the new client state and the new consensus state if the
+    /// client state is updated.
+    fn update_state_on_proofs(
+        &self,
+        _client_state: &[u8],
+    ) -> Result<Option<(Vec<u8>, Vec<u8>)>, ClientError> {
+        Ok(None)
+    }
+}
+
+/// Compute the checksum of the light client
+pub fn checksum(light_client: &dyn LightClient) -> Vec<u8> {
+    Sha256::digest(light_client.name().as_bytes()).to_vec()
+}
+
+/// Get the registered light client of the checksum
+pub fn light_client(
+    checksum_bytes: &[u8],
+) -> Result<&'static dyn LightClient, ClientError> {
+    LIGHT_CLIENTS
+        .iter()
+        .copied()
+        .find(|light_client| checksum(*light_client) == checksum_bytes)
+        .ok_or_else(|| {
+            client_error(format!(
+                "No light client is registered for the checksum {}",
+                HEXLOWER.encode(checksum_bytes)
+            ))
+        })
+}
+
+/// Consensus state of the wasm client
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsensusState {
+    /// The consensus state of the underlying light client
+    pub data: Vec<u8>,
+    /// The timestamp of the underlying consensus state
+    timestamp: Timestamp,
+    /// The commitment root of the underlying consensus state
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    /// Make a new consensus state of the underlying light client
+    pub fn new(
+        light_client: &dyn LightClient,
+        data: Vec<u8>,
+    ) -> Result<Self, ClientError> {
+        let (timestamp, root) = light_client.consensus_state_info(&data)?;
+        Ok(Self {
+            data,
+            timestamp,
+            root,
+        })
+    }
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        if any.type_url != WASM_CONSENSUS_STATE_TYPE_URL {
+            return Err(client_error(format!(
+                "Unexpected type URL of the consensus state: {}",
+                any.type_url
+            )));
+        }
+        let raw = raw::ConsensusState::decode(&any.value[..])
+            .map_err(|e| client_error(e.to_string()))?;
+        // The consensus state doesn't have the checksum. It's decoded by the
+        // first light client which can decode it.
+        LIGHT_CLIENTS
+            .iter()
+            .find_map(|light_client| {
+                Self::new(*light_client, raw.data.clone()).ok()
+            })
+            .ok_or_else(|| {
+                client_error(
+                    "No light client can decode the consensus state"
+                        .to_string(),
+                )
+            })
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: raw::ConsensusState {
+                data: consensus_state.data,
+            }
+            .encode_to_vec(),
+        }
+    }
+}
+
+/// Client state of the wasm client
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState {
+    /// The client state of the underlying light client
+    pub data: Vec<u8>,
+    /// The checksum of the underlying light client
+    pub checksum: Vec<u8>,
+    /// The latest height of the underlying light client
+    pub latest_height: Height,
+}
+
+impl ClientState {
+    /// Make a new client state of the underlying light client
+    pub fn new(
+        light_client: &dyn LightClient,
+        data: Vec<u8>,
+    ) -> Result<Self, ClientError> {
+        let latest_height = light_client.latest_height(&data)?;
+        Ok(Self {
+            data,
+            checksum: checksum(light_client),
+            latest_height,
+        })
+    }
+
+    /// Get the underlying light client
+    pub fn light_client(
+        &self,
+    ) -> Result<&'static dyn LightClient, ClientError> {
+        light_client(&self.checksum)
+    }
+
+    /// Update the client state after the proofs of a message were verified
+    pub fn update_state_on_proofs<E>(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+    ) -> Result<(), ClientError>
+    where
+        E: ClientExecutionContext + ExtClientValidationContext,
+        E::ClientStateRef: From<ClientState>,
+        E::ConsensusStateRef: From<ConsensusState>,
+    {
+        let light_client = self.light_client()?;
+        let Some((client_state, consensus_state)) =
+            light_client.update_state_on_proofs(&self.data)?
+        else {
+            return Ok(());
+        };
+        let client_state = Self::new(light_client, client_state)?;
+        let consensus_state =
+            ConsensusState::new(light_client, consensus_state)?;
+        client_state.store(ctx, client_id, consensus_state)
+    }
+
+    /// Store the client state and the consensus state at the latest height
+    fn store<E>(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: ConsensusState,
+    ) -> Result<(), ClientError>
+    where
+        E: ClientExecutionContext + ExtClientValidationContext,
+        E::ClientStateRef: From<ClientState>,
+        E::ConsensusStateRef: From<ConsensusState>,
+    {
+        let height = self.latest_height;
+        ctx.store_client_state(
+            ClientStatePath(client_id.clone()),
+            self.clone().into(),
+        )
+        .map_err(context_error)?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath {
+                client_id: client_id.clone(),
+                revision_number: height.revision_number(),
+                revision_height: height.revision_height(),
+            },
+            consensus_state.into(),
+        )
+        .map_err(context_error)?;
+        let host_timestamp = ctx.host_timestamp().map_err(context_error)?;
+        let host_height = ctx.host_height().map_err(context_error)?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            height,
+            host_timestamp,
+            host_height,
+        )
+        .map_err(context_error)
+    }
+}
+
+impl TryFrom<raw::ClientState> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: raw::ClientState) -> Result<Self, Self::Error> {
+        let height = raw
+            .latest_height
+            .ok_or_else(|| client_error("No latest height".to_string()))?;
+        let latest_height =
+            Height::new(height.revision_number, height.revision_height)?;
+        Ok(Self {
+            data: raw.data,
+            checksum: raw.checksum,
+            latest_height,
+        })
+    }
+}
+
+impl From<ClientState> for raw::ClientState {
+    fn from(client_state: ClientState) -> Self {
+        Self {
+            data: client_state.data,
+            checksum: client_state.checksum,
+            latest_height: Some(raw::Height {
+                revision_number: client_state.latest_height.revision_number(),
+                revision_height: client_state.latest_height.revision_height(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        if any.type_url != WASM_CLIENT_STATE_TYPE_URL {
+            return Err(client_error(format!(
+                "Unexpected type URL of the client state: {}",
+                any.type_url
+            )));
+        }
+        raw::ClientState::decode(&any.value[..])
+            .map_err(|e| client_error(e.to_string()))?
+            .try_into()
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: WASM_CLIENT_STATE_TYPE_URL.to_string(),
+            value: raw::ClientState::from(client_state).encode_to_vec(),
+        }
+    }
+}
+
+/// Decode the data of the underlying client message
+fn decode_client_message(any: &Any) -> Result<Vec<u8>, ClientError> {
+    if any.type_url != WASM_CLIENT_MESSAGE_TYPE_URL {
+        return Err(client_error(format!(
+            "Unexpected client message type: {}",
+            any.type_url
+        )));
+    }
+    raw::ClientMessage::decode(&any.value[..])
+        .map(|msg| msg.data)
+        .map_err(|e| client_error(e.to_string()))
+}
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ConsensusState::try_from(consensus_state)?;
+        self.light_client()?
+            .consensus_state_info(&consensus_state.data)
+            .map(|_| ())
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::from_str(WASM_CLIENT_TYPE)
+            .expect("The client type should be valid")
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn validate_proof_height(
+        &self,
+        proof_height: Height,
+    ) -> Result<(), ClientError> {
+        if self.latest_height < proof_height {
+            return Err(ClientError::InvalidProofHeight {
+                latest_height: self.latest_height,
+                proof_height,
+            });
+        }
+        Ok(())
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(client_error(
+            "The wasm client can't be upgraded".to_string(),
+        ))
+    }
+
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.light_client()?
+            .verify_membership(&self.data, root, prefix, proof, path, value)
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        self.light_client()?
+            .verify_non_membership(&self.data, root, prefix, proof, path)
+    }
+}
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ClientValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        let client_message = decode_client_message(&client_message)?;
+        self.light_client()?
+            .verify_client_message(&self.data, &client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        let client_message = decode_client_message(&client_message)?;
+        self.light_client()?
+            .check_for_misbehaviour(&self.data, &client_message)
+    }
+
+    fn status(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+    ) -> Result<Status, ClientError> {
+        self.light_client()?.status(&self.data)
+    }
+
+    fn check_substitute(
+        &self,
+        _ctx: &V,
+        substitute_client_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute = ClientState::try_from(substitute_client_state)?;
+        if substitute.checksum != self.checksum {
+            return Err(client_error(
+                "The substitute client should have the same checksum"
+                    .to_string(),
+            ));
+        }
+        if substitute.light_client()?.status(&substitute.data)?
+            != Status::Active
+        {
+            return Err(client_error(
+                "The substitute client isn't active".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ClientExecutionContext + ExtClientValidationContext,
+    E::ClientStateRef: From<ClientState>,
+    E::ConsensusStateRef: From<ConsensusState>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let light_client = self.light_client()?;
+        if light_client.latest_height(&self.data)? != self.latest_height {
+            return Err(client_error(
+                "The latest height should be the one of the underlying \
+                 client state"
+                    .to_string(),
+            ));
+        }
+        let consensus_state = ConsensusState::try_from(consensus_state)?;
+        let consensus_state =
+            ConsensusState::new(light_client, consensus_state.data)?;
+        self.store(ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let header = decode_client_message(&header)?;
+        let light_client = self.light_client()?;
+        let (client_state, consensus_state) =
+            light_client.update_state(&self.data, &header)?;
+        let client_state = Self::new(light_client, client_state)?;
+        let consensus_state =
+            ConsensusState::new(light_client, consensus_state)?;
+        client_state.store(ctx, client_id, consensus_state)?;
+        Ok(vec![client_state.latest_height])
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        let data = self
+            .light_client()?
+            .update_state_on_misbehaviour(&self.data)?;
+        let client_state = ClientState {
+            data,
+            ..self.clone()
+        };
+        ctx.store_client_state(
+            ClientStatePath(client_id.clone()),
+            client_state.into(),
+        )
+        .map_err(context_error)
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(client_error(
+            "The wasm client can't be upgraded".to_string(),
+        ))
+    }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute = ClientState::try_from(substitute_client_state)?;
+        let light_client = substitute.light_client()?;
+        let consensus_state =
+            ConsensusState::try_from(substitute_consensus_state)?;
+        let consensus_state =
+            ConsensusState::new(light_client, consensus_state.data)?;
+        substitute.store(ctx, subject_client_id, consensus_state)
+    }
+}
+
+fn client_error(description: String) -> ClientError {
+    ClientError::ClientSpecific { description }
+}
+
+fn context_error(e: ContextError) -> ClientError {
+    ClientError::Other {
+        description: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::key::testing::keypair_1;
+    use namada_core::key::RefTo;
+
+    use super::*;
+    use crate::context::solomachine::{
+        self, ConsensusState as SmConsensusState, SOLO_MACHINE_CLIENT_TYPE,
+    };
+
+    #[test]
+    fn test_wasm_client_state() {
+        let sm_client_state = solomachine::ClientState::new(
+            3,
+            SmConsensusState::new(
+                keypair_1().ref_to(),
+                "diversifier".to_string(),
+                Timestamp::from_nanoseconds(100).unwrap(),
+            ),
+        );
+        let data = solomachine::raw::ClientState::from(sm_client_state.clone())
+            .encode_to_vec();
+        let client_state =
+            ClientState::new(&SoloMachineLightClient, data).unwrap();
+        assert_eq!(client_state.latest_height, Height::new(0, 3).unwrap());
+        assert_eq!(
+            client_state.checksum,
+            Sha256::digest(SOLO_MACHINE_CLIENT_TYPE.as_bytes()).to_vec()
+        );
+        let light_client = client_state.light_client().unwrap();
+        assert_eq!(light_client.name(), SOLO_MACHINE_CLIENT_TYPE);
+
+        // The sequence is incremented after verifying proofs
+        let (data, _) = light_client
+            .update_state_on_proofs(&client_state.data)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            light_client.latest_height(&data).unwrap(),
+            Height::new(0, 4).unwrap()
+        );
+
+        let any: Any = client_state.clone().into();
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+
+        let data = solomachine::raw::ConsensusState::from(
+            sm_client_state.consensus_state.clone(),
+        )
+        .encode_to_vec();
+        let consensus_state =
+            ConsensusState::new(&SoloMachineLightClient, data).unwrap();
+        assert_eq!(
+            consensus_state.timestamp(),
+            sm_client_state.consensus_state.timestamp
+        );
+        let any: Any = consensus_state.clone().into();
+        assert_eq!(ConsensusState::try_from(any).unwrap(), consensus_state);
+
+        // Unknown checksum
+        assert!(light_client(&[0; 32]).is_err());
+    }
+}
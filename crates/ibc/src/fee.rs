@@ -0,0 +1,362 @@
+//! Relayer fee incentivisation (ICS-29)
+//!
+//! A channel is fee-enabled when the fee version wraps the application
+//! version in the channel handshake. Fees for the receipt, the
+//! acknowledgement and the timeout of a packet sent over a fee-enabled
+//! channel are escrowed when the packet is sent or later by anyone. When the
+//! packet is acknowledged, the receive fee is paid to the payee that the
+//! forward relayer registered on the counterparty chain and the ack fee is
+//! paid to the payee of the relayer of the acknowledgement. When the packet
+//! times out, the timeout fee is paid to the payee of the relayer of the
+//! timeout. The rest of the escrowed fee is refunded to the refund address.
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use data_encoding::BASE64;
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus,
+};
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::primitives::Signer;
+use namada_core::address::Address;
+use namada_core::storage::Key;
+use namada_core::token::Amount;
+use namada_state::{StorageRead, StorageResult, StorageWrite};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::storage::{
+    counterparty_payee_key, fee_enabled_key, fees_in_escrow_key,
+    fees_in_escrow_prefix, payee_key,
+};
+
+/// The ICS-29 version
+pub const FEE_VERSION: &str = "ics29-1";
+
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum FeeError {
+    #[error("Invalid version metadata: {0}")]
+    InvalidMetadata(String),
+    #[error("Invalid acknowledgement: {0}")]
+    InvalidAcknowledgement(String),
+    #[error("Invalid fee: {0}")]
+    InvalidFee(String),
+    #[error("The channel isn't fee-enabled: {0}")]
+    FeeNotEnabled(String),
+    #[error("Storage error: {0}")]
+    Storage(namada_state::StorageError),
+}
+
+/// The version metadata of a fee-enabled channel
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// ICS-29 version
+    pub fee_version: String,
+    /// The version of the wrapped application
+    pub app_version: String,
+}
+
+impl Metadata {
+    /// Make a new metadata wrapping the application version
+    pub fn new(app_version: &Version) -> Self {
+        Self {
+            fee_version: FEE_VERSION.to_string(),
+            app_version: app_version.to_string(),
+        }
+    }
+
+    /// Get the application version
+    pub fn app_version(&self) -> Version {
+        Version::new(self.app_version.clone())
+    }
+
+    /// Get the channel version
+    pub fn to_version(&self) -> Version {
+        Version::new(
+            serde_json::to_string(self)
+                .expect("Serializing the metadata shouldn't fail"),
+        )
+    }
+}
+
+/// Decode the fee version metadata from the channel version. It returns
+/// `None` if the version doesn't wrap the application version.
+pub fn decode_fee_version(
+    version: &Version,
+) -> Result<Option<Metadata>, FeeError> {
+    let Ok(serde_json::Value::Object(value)) =
+        serde_json::from_str::<serde_json::Value>(version.as_str())
+    else {
+        return Ok(None);
+    };
+    if !value.contains_key("fee_version") {
+        return Ok(None);
+    }
+    let metadata: Metadata =
+        serde_json::from_value(serde_json::Value::Object(value))
+            .map_err(|e| FeeError::InvalidMetadata(e.to_string()))?;
+    if metadata.fee_version != FEE_VERSION {
+        return Err(FeeError::InvalidMetadata(format!(
+            "Unsupported fee version {}",
+            metadata.fee_version
+        )));
+    }
+    Ok(Some(metadata))
+}
+
+/// The acknowledgement of a packet received over a fee-enabled channel
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IncentivizedAcknowledgement {
+    /// The base64-encoded acknowledgement of the application
+    pub app_acknowledgement: String,
+    /// The payee of the forward relayer registered on the receiving chain
+    pub forward_relayer_address: String,
+    /// Whether the application succeeded to receive the packet
+    pub underlying_app_success: bool,
+}
+
+impl IncentivizedAcknowledgement {
+    /// Wrap the acknowledgement of the application
+    pub fn new(
+        app_ack: &Acknowledgement,
+        forward_relayer_address: String,
+    ) -> Self {
+        let underlying_app_success =
+            serde_json::from_slice::<AcknowledgementStatus>(app_ack.as_ref())
+                .is_ok_and(|status| status.is_successful());
+        Self {
+            app_acknowledgement: BASE64.encode(app_ack.as_ref()),
+            forward_relayer_address,
+            underlying_app_success,
+        }
+    }
+
+    /// Decode the acknowledgement of a fee-enabled channel
+    pub fn decode(ack: &Acknowledgement) -> Result<Self, FeeError> {
+        serde_json::from_slice(ack.as_ref())
+            .map_err(|e| FeeError::InvalidAcknowledgement(e.to_string()))
+    }
+
+    /// Get the acknowledgement of the application
+    pub fn app_acknowledgement(&self) -> Result<Acknowledgement, FeeError> {
+        let bytes = BASE64
+            .decode(self.app_acknowledgement.as_bytes())
+            .map_err(|e| FeeError::InvalidAcknowledgement(e.to_string()))?;
+        Acknowledgement::try_from(bytes)
+            .map_err(|e| FeeError::InvalidAcknowledgement(e.to_string()))
+    }
+}
+
+impl From<IncentivizedAcknowledgement> for Acknowledgement {
+    fn from(ack: IncentivizedAcknowledgement) -> Self {
+        serde_json::to_vec(&ack)
+            .expect("Serializing the acknowledgement shouldn't fail")
+            .try_into()
+            .expect("The acknowledgement shouldn't be empty")
+    }
+}
+
+/// The fees for relaying a packet
+#[derive(
+    Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema,
+)]
+pub struct Fee {
+    /// The token of the fees
+    pub token: Address,
+    /// The fee paid for the receipt of the packet
+    pub recv_fee: Amount,
+    /// The fee paid for the acknowledgement of the packet
+    pub ack_fee: Amount,
+    /// The fee paid for the timeout of the packet
+    pub timeout_fee: Amount,
+}
+
+impl Fee {
+    /// The amount to be escrowed, i.e. the maximum of the receive fee plus
+    /// the ack fee and the timeout fee
+    pub fn total(&self) -> Result<Amount, FeeError> {
+        let recv_ack = self
+            .recv_fee
+            .checked_add(self.ack_fee)
+            .ok_or_else(|| FeeError::InvalidFee("Fee overflow".to_string()))?;
+        Ok(std::cmp::max(recv_ack, self.timeout_fee))
+    }
+
+    /// Check that any fee is set
+    pub fn validate(&self) -> Result<(), FeeError> {
+        if self.total()?.is_zero() {
+            return Err(FeeError::InvalidFee(
+                "All the fees are zero".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The fees escrowed for a packet with the address to refund them
+#[derive(
+    Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema,
+)]
+pub struct PacketFee {
+    /// The fees
+    pub fee: Fee,
+    /// The address paying the fees, to which the rest of them is refunded
+    pub refund_address: Address,
+}
+
+/// Check if the channel is fee-enabled
+pub fn is_fee_enabled<S: StorageRead>(
+    storage: &S,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> StorageResult<bool> {
+    storage.has_key(&fee_enabled_key(port_id, channel_id))
+}
+
+/// Set the channel fee-enabled
+pub fn set_fee_enabled<S: StorageWrite>(
+    storage: &mut S,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> StorageResult<()> {
+    storage.write(&fee_enabled_key(port_id, channel_id), true)
+}
+
+/// Read the fees escrowed for the packet
+pub fn read_packet_fees<S: StorageRead>(
+    storage: &S,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+) -> StorageResult<Vec<PacketFee>> {
+    let key = fees_in_escrow_key(port_id, channel_id, sequence);
+    Ok(storage.read(&key)?.unwrap_or_default())
+}
+
+/// Read all the fees escrowed for the packets sent over the channel
+pub fn read_channel_fees<S: StorageRead>(
+    storage: &S,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> StorageResult<Vec<(Key, Vec<PacketFee>)>> {
+    let prefix = fees_in_escrow_prefix(port_id, channel_id);
+    namada_storage::iter_prefix(storage, &prefix)?.collect()
+}
+
+/// Get the payee of the relayer on this chain. The relayer itself is the
+/// payee if it hasn't registered any payee.
+pub fn payee<S: StorageRead>(
+    storage: &S,
+    channel_id: &ChannelId,
+    relayer: &Address,
+) -> StorageResult<Address> {
+    let key = payee_key(channel_id, relayer);
+    Ok(storage.read(&key)?.unwrap_or_else(|| relayer.clone()))
+}
+
+/// Get the payee on the counterparty chain registered by the relayer. It
+/// returns an empty string if the relayer hasn't registered it.
+pub fn counterparty_payee<S: StorageRead>(
+    storage: &S,
+    channel_id: &ChannelId,
+    relayer: &Signer,
+) -> StorageResult<String> {
+    let Ok(relayer) = Address::decode(relayer.as_ref()) else {
+        return Ok(String::new());
+    };
+    let key = counterparty_payee_key(channel_id, &relayer);
+    Ok(storage.read(&key)?.unwrap_or_default())
+}
+
+/// Returns the acknowledgement written for the application acknowledgement
+/// of the packet relayed by the relayer. It's wrapped if the channel is
+/// fee-enabled.
+pub fn wrap_acknowledgement<S: StorageRead>(
+    storage: &S,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    relayer: &Signer,
+    app_ack: Acknowledgement,
+) -> StorageResult<Acknowledgement> {
+    if !is_fee_enabled(storage, port_id, channel_id)? {
+        return Ok(app_ack);
+    }
+    let forward_relayer = counterparty_payee(storage, channel_id, relayer)?;
+    Ok(IncentivizedAcknowledgement::new(&app_ack, forward_relayer).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::apps::transfer::types::ack_success_b64;
+    use ibc::core::channel::types::acknowledgement::StatusValue;
+    use namada_core::address::testing::nam;
+
+    use super::*;
+
+    #[test]
+    fn test_fee_version() {
+        let app_version = Version::new("ics20-1".to_string());
+        let version = Metadata::new(&app_version).to_version();
+        let metadata = decode_fee_version(&version).unwrap().unwrap();
+        assert_eq!(metadata.app_version(), app_version);
+
+        // Not wrapped
+        assert!(decode_fee_version(&app_version).unwrap().is_none());
+        let ica_version = Version::new(
+            r#"{"version":"ics27-1","encoding":"proto3"}"#.to_string(),
+        );
+        assert!(decode_fee_version(&ica_version).unwrap().is_none());
+
+        // Unsupported fee version
+        let version = Version::new(
+            r#"{"fee_version":"ics29-2","app_version":"ics20-1"}"#.to_string(),
+        );
+        assert!(decode_fee_version(&version).is_err());
+    }
+
+    #[test]
+    fn test_incentivized_ack() {
+        let app_ack: Acknowledgement =
+            AcknowledgementStatus::success(ack_success_b64()).into();
+        let ack: Acknowledgement =
+            IncentivizedAcknowledgement::new(&app_ack, "payee".to_string())
+                .into();
+        let decoded = IncentivizedAcknowledgement::decode(&ack).unwrap();
+        assert!(decoded.underlying_app_success);
+        assert_eq!(decoded.forward_relayer_address, "payee");
+        assert_eq!(decoded.app_acknowledgement().unwrap(), app_ack);
+
+        let app_ack: Acknowledgement =
+            AcknowledgementStatus::error(StatusValue::new("failure").unwrap())
+                .into();
+        let ack = IncentivizedAcknowledgement::new(&app_ack, String::new());
+        assert!(!ack.underlying_app_success);
+
+        // A plain acknowledgement isn't incentivized
+        assert!(IncentivizedAcknowledgement::decode(&app_ack).is_err());
+    }
+
+    #[test]
+    fn test_fee_total() {
+        let fee = Fee {
+            token: nam(),
+            recv_fee: Amount::from_u64(10),
+            ack_fee: Amount::from_u64(5),
+            timeout_fee: Amount::from_u64(20),
+        };
+        assert_eq!(fee.total().unwrap(), Amount::from_u64(20));
+        let fee = Fee {
+            timeout_fee: Amount::from_u64(1),
+            ..fee
+        };
+        assert_eq!(fee.total().unwrap(), Amount::from_u64(15));
+        let fee = Fee {
+            recv_fee: Amount::zero(),
+            ack_fee: Amount::zero(),
+            timeout_fee: Amount::zero(),
+            ..fee
+        };
+        assert!(fee.validate().is_err());
+    }
+}
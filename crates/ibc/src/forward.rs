@@ -0,0 +1,307 @@
+//! IBC packet forwarding
+//!
+//! A fungible token packet whose memo has the `forward` metadata of the
+//! packet-forward-middleware is received by the IBC escrow on Namada, which
+//! sends the tokens to the next hop. The next memo is set to the packet sent
+//! to the next hop so that the tokens can be routed over multiple hops. The
+//! forwarded packet is tracked until it's acknowledged and the received packet
+//! is acknowledged asynchronously with its result. When the tokens can't be
+//! forwarded, or the forwarded packet fails or times out without any retry
+//! left, receiving the tokens is reverted and the received packet is
+//! acknowledged with an error so that the tokens are refunded on the previous
+//! hop.
+
+use std::time::Duration;
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The default timeout of a forwarded packet
+pub const DEFAULT_FORWARD_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+/// The default number of retries when a forwarded packet times out
+pub const DEFAULT_FORWARD_RETRIES: u8 = 0;
+
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum ForwardError {
+    #[error("Invalid forward metadata: {0}")]
+    InvalidMetadata(String),
+    #[error("Invalid in-flight packet: {0}")]
+    InvalidInFlightPacket(String),
+    #[error("Storage error: {0}")]
+    Storage(namada_state::StorageError),
+}
+
+/// The forward metadata in the memo of a fungible token packet
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ForwardMetadata {
+    /// The receiver on the next hop
+    pub receiver: String,
+    /// The port ID to the next hop
+    pub port: String,
+    /// The channel ID to the next hop
+    pub channel: String,
+    /// The timeout of the packet to the next hop in nanoseconds or as a
+    /// duration string, e.g. "10m"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<serde_json::Value>,
+    /// The number of retries when the packet to the next hop times out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u8>,
+    /// The memo for the next hop
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<serde_json::Value>,
+}
+
+impl ForwardMetadata {
+    /// Get the port ID to the next hop
+    pub fn port_id(&self) -> Result<PortId, ForwardError> {
+        self.port.parse().map_err(|e| {
+            ForwardError::InvalidMetadata(format!(
+                "Invalid port ID {}: {e}",
+                self.port
+            ))
+        })
+    }
+
+    /// Get the channel ID to the next hop
+    pub fn channel_id(&self) -> Result<ChannelId, ForwardError> {
+        self.channel.parse().map_err(|e| {
+            ForwardError::InvalidMetadata(format!(
+                "Invalid channel ID {}: {e}",
+                self.channel
+            ))
+        })
+    }
+
+    /// Get the timeout of the packet to the next hop
+    pub fn timeout(&self) -> Result<Duration, ForwardError> {
+        let timeout = match &self.timeout {
+            None => return Ok(DEFAULT_FORWARD_TIMEOUT),
+            Some(serde_json::Value::Number(nanos)) => {
+                nanos.as_u64().map(Duration::from_nanos)
+            }
+            Some(serde_json::Value::String(duration)) => {
+                parse_duration(duration)
+            }
+            Some(_) => None,
+        };
+        timeout.filter(|t| !t.is_zero()).ok_or_else(|| {
+            ForwardError::InvalidMetadata(format!(
+                "Invalid timeout: {:?}",
+                self.timeout
+            ))
+        })
+    }
+
+    /// Get the number of retries
+    pub fn retries(&self) -> u8 {
+        self.retries.unwrap_or(DEFAULT_FORWARD_RETRIES)
+    }
+
+    /// Get the memo for the next hop
+    pub fn next_memo(&self) -> String {
+        match &self.next {
+            None => String::new(),
+            // The next memo could be given as an escaped JSON string
+            Some(serde_json::Value::String(memo)) => memo.clone(),
+            Some(next) => next.to_string(),
+        }
+    }
+
+    /// Check the metadata
+    pub fn validate(&self) -> Result<(), ForwardError> {
+        if self.receiver.is_empty() {
+            return Err(ForwardError::InvalidMetadata(
+                "The receiver is empty".to_string(),
+            ));
+        }
+        self.port_id()?;
+        self.channel_id()?;
+        self.timeout()?;
+        Ok(())
+    }
+}
+
+/// Decode the forward metadata from the memo of a fungible token packet. It
+/// returns `None` if the memo doesn't have any forward metadata.
+pub fn decode_forward_metadata(
+    memo: &str,
+) -> Result<Option<ForwardMetadata>, ForwardError> {
+    let Ok(serde_json::Value::Object(mut memo)) =
+        serde_json::from_str::<serde_json::Value>(memo)
+    else {
+        return Ok(None);
+    };
+    let Some(forward) = memo.remove("forward") else {
+        return Ok(None);
+    };
+    let metadata: ForwardMetadata = serde_json::from_value(forward)
+        .map_err(|e| ForwardError::InvalidMetadata(e.to_string()))?;
+    metadata.validate()?;
+    Ok(Some(metadata))
+}
+
+/// Parse a duration string like "1h30m", "10m", "30s" or "500ms"
+fn parse_duration(duration: &str) -> Option<Duration> {
+    let mut total = Duration::ZERO;
+    let mut rest = duration.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let value: u64 = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit = match &rest[..unit_len] {
+            "ns" => Duration::from_nanos(value),
+            "us" => Duration::from_micros(value),
+            "ms" => Duration::from_millis(value),
+            "s" => Duration::from_secs(value),
+            "m" => Duration::from_secs(value.checked_mul(60)?),
+            "h" => Duration::from_secs(value.checked_mul(60 * 60)?),
+            _ => return None,
+        };
+        total = total.checked_add(unit)?;
+        rest = &rest[unit_len..];
+    }
+    Some(total)
+}
+
+/// A packet forwarded to the next hop which hasn't been acknowledged yet
+#[derive(
+    Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema,
+)]
+pub struct InFlightPacket {
+    /// The received packet encoded in JSON
+    pub packet: String,
+    /// The relayer of the received packet
+    pub relayer: String,
+    /// The denom of the forwarded token on this chain
+    pub denom: String,
+    /// The forwarded amount
+    pub amount: String,
+    /// The receiver on the next hop
+    pub receiver: String,
+    /// The port ID to the next hop
+    pub port_id: String,
+    /// The channel ID to the next hop
+    pub channel_id: String,
+    /// The timeout of the packet to the next hop in nanoseconds
+    pub timeout: u64,
+    /// The number of retries left
+    pub retries_left: u8,
+    /// The memo for the next hop
+    pub next_memo: String,
+}
+
+impl InFlightPacket {
+    /// Get the port ID to the next hop
+    pub fn port_id(&self) -> Result<PortId, ForwardError> {
+        self.port_id.parse().map_err(|e| {
+            ForwardError::InvalidInFlightPacket(format!(
+                "Invalid port ID {}: {e}",
+                self.port_id
+            ))
+        })
+    }
+
+    /// Get the channel ID to the next hop
+    pub fn channel_id(&self) -> Result<ChannelId, ForwardError> {
+        self.channel_id.parse().map_err(|e| {
+            ForwardError::InvalidInFlightPacket(format!(
+                "Invalid channel ID {}: {e}",
+                self.channel_id
+            ))
+        })
+    }
+
+    /// Get the received packet
+    pub fn packet(&self) -> Result<Packet, ForwardError> {
+        serde_json::from_str(&self.packet).map_err(|e| {
+            ForwardError::InvalidInFlightPacket(format!(
+                "Invalid received packet: {e}"
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_forward_metadata() {
+        // Not a forward memo
+        assert!(decode_forward_metadata("").unwrap().is_none());
+        assert!(decode_forward_metadata("memo").unwrap().is_none());
+        assert!(
+            decode_forward_metadata(r#"{"wasm": {}}"#)
+                .unwrap()
+                .is_none()
+        );
+
+        let memo = r#"{
+            "forward": {
+                "receiver": "cosmos1receiver",
+                "port": "transfer",
+                "channel": "channel-1",
+                "timeout": "1h30m",
+                "retries": 2,
+                "next": {
+                    "forward": {
+                        "receiver": "osmo1receiver",
+                        "port": "transfer",
+                        "channel": "channel-7"
+                    }
+                }
+            }
+        }"#;
+        let metadata = decode_forward_metadata(memo).unwrap().unwrap();
+        assert_eq!(metadata.receiver, "cosmos1receiver");
+        assert_eq!(metadata.port_id().unwrap(), PortId::transfer());
+        assert_eq!(metadata.channel_id().unwrap(), ChannelId::new(1));
+        assert_eq!(metadata.timeout().unwrap(), Duration::from_secs(5400));
+        assert_eq!(metadata.retries(), 2);
+        let next = decode_forward_metadata(&metadata.next_memo())
+            .unwrap()
+            .unwrap();
+        assert_eq!(next.receiver, "osmo1receiver");
+        assert_eq!(next.timeout().unwrap(), DEFAULT_FORWARD_TIMEOUT);
+        assert_eq!(next.retries(), DEFAULT_FORWARD_RETRIES);
+        assert!(next.next_memo().is_empty());
+
+        // The timeout in nanoseconds
+        let memo = r#"{"forward": {"receiver": "a", "port": "transfer",
+            "channel": "channel-0", "timeout": 1000000000}}"#;
+        let metadata = decode_forward_metadata(memo).unwrap().unwrap();
+        assert_eq!(metadata.timeout().unwrap(), Duration::from_secs(1));
+
+        // Invalid metadata
+        let memo = r#"{"forward": {"receiver": "", "port": "transfer",
+            "channel": "channel-0"}}"#;
+        assert!(decode_forward_metadata(memo).is_err());
+        let memo = r#"{"forward": {"receiver": "a", "port": "transfer",
+            "channel": "channel-0", "timeout": "10x"}}"#;
+        assert!(decode_forward_metadata(memo).is_err());
+        let memo = r#"{"forward": {"receiver": "a"}}"#;
+        assert!(decode_forward_metadata(memo).is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("30s"), Some(Duration::from_secs(30)));
+        assert_eq!(parse_duration("10m"), Some(Duration::from_secs(600)));
+        assert_eq!(parse_duration("1h0m5s"), Some(Duration::from_secs(3605)));
+        assert_eq!(parse_duration("500ms"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("10"), None);
+        assert_eq!(parse_duration("m"), None);
+    }
+}
@@ -0,0 +1,454 @@
+//! Interchain accounts (ICS-27)
+//!
+//! A counterparty chain (the controller) can register an account on Namada
+//! (the host) and drive it by sending transactions over an ordered channel
+//! between the `icacontroller-<owner>` and the `icahost` ports. The host
+//! executes only the message types allowed by the host parameters. Namada
+//! accounts can also register and control accounts on counterparty chains.
+
+use std::str::FromStr;
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use data_encoding::BASE64;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ConnectionId, PortId};
+use ibc::primitives::proto::Any;
+use namada_core::address::{Address, InternalAddress, HASH_LEN, SHA_HASH_LEN};
+use namada_core::ibc::InterchainAccountHash;
+use namada_core::token::Amount;
+use namada_state::{StorageRead, StorageResult};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::storage::ica_host_params_key;
+
+/// The ICS-27 version
+pub const ICA_VERSION: &str = "ics27-1";
+/// The port ID of the host
+pub const HOST_PORT_ID_STR: &str = "icahost";
+/// The prefix of the port IDs of the controllers
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+/// The module ID of the host
+pub const HOST_MODULE_ID_STR: &str = "icahost";
+/// The module ID of the controller
+pub const CONTROLLER_MODULE_ID_STR: &str = "icacontroller";
+/// The supported encoding of the packet data
+pub const ENCODING_PROTO3: &str = "proto3";
+/// The supported transaction type
+pub const TX_TYPE_SDK_MULTI_MSG: &str = "sdk_multi_msg";
+/// The type URL of a bank send message
+pub const MSG_SEND_TYPE_URL: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum IcaError {
+    #[error("Invalid version metadata: {0}")]
+    InvalidMetadata(String),
+    #[error("Invalid channel: {0}")]
+    InvalidChannel(String),
+    #[error("Invalid packet data: {0}")]
+    InvalidPacketData(String),
+    #[error("The message isn't allowed by the host: {0}")]
+    UnauthorizedMessage(String),
+    #[error("The interchain account isn't active: {0}")]
+    InactiveAccount(String),
+    #[error("Insufficient balance of the interchain account: {0}")]
+    InsufficientBalance(String),
+    #[error("The host is disabled")]
+    HostDisabled,
+    #[error("IBC context error: {0}")]
+    Context(Box<ContextError>),
+    #[error("Storage error: {0}")]
+    Storage(namada_state::StorageError),
+}
+
+/// The version metadata negotiated in the channel handshake
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// ICS-27 version
+    pub version: String,
+    /// The connection ID on the controller chain
+    pub controller_connection_id: String,
+    /// The connection ID on the host chain
+    pub host_connection_id: String,
+    /// The interchain account address, set by the host
+    #[serde(default)]
+    pub address: String,
+    /// The encoding of the messages
+    pub encoding: String,
+    /// The transaction type
+    pub tx_type: String,
+}
+
+impl Metadata {
+    /// Make a new metadata to open a channel from the controller
+    pub fn new(
+        controller_connection_id: &ConnectionId,
+        host_connection_id: &ConnectionId,
+    ) -> Self {
+        Self {
+            version: ICA_VERSION.to_string(),
+            controller_connection_id: controller_connection_id.to_string(),
+            host_connection_id: host_connection_id.to_string(),
+            address: String::default(),
+            encoding: ENCODING_PROTO3.to_string(),
+            tx_type: TX_TYPE_SDK_MULTI_MSG.to_string(),
+        }
+    }
+
+    /// Check the version, the encoding and the transaction type
+    pub fn validate(&self) -> Result<(), IcaError> {
+        if self.version != ICA_VERSION {
+            return Err(IcaError::InvalidMetadata(format!(
+                "Unsupported version {}",
+                self.version
+            )));
+        }
+        if self.encoding != ENCODING_PROTO3 {
+            return Err(IcaError::InvalidMetadata(format!(
+                "Unsupported encoding {}",
+                self.encoding
+            )));
+        }
+        if self.tx_type != TX_TYPE_SDK_MULTI_MSG {
+            return Err(IcaError::InvalidMetadata(format!(
+                "Unsupported transaction type {}",
+                self.tx_type
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Metadata {
+    type Err = IcaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+            .map_err(|e| IcaError::InvalidMetadata(e.to_string()))
+    }
+}
+
+impl std::fmt::Display for Metadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
+        write!(f, "{json}")
+    }
+}
+
+/// The type of the interchain account packet
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PacketType {
+    /// Unspecified type
+    #[serde(rename = "TYPE_UNSPECIFIED")]
+    Unspecified,
+    /// Execute the messages on the host
+    #[serde(rename = "TYPE_EXECUTE_TX")]
+    ExecuteTx,
+}
+
+/// The packet data sent from the controller to the host
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterchainAccountPacketData {
+    /// Packet type
+    #[serde(rename = "type")]
+    pub packet_type: PacketType,
+    /// Base64-encoded `CosmosTx`
+    pub data: String,
+    /// Memo
+    #[serde(default)]
+    pub memo: String,
+}
+
+impl InterchainAccountPacketData {
+    /// Make the packet data to execute the given messages on the host
+    pub fn new(messages: Vec<Any>, memo: impl Into<String>) -> Self {
+        let tx = CosmosTx { messages };
+        Self {
+            packet_type: PacketType::ExecuteTx,
+            data: BASE64.encode(&tx.encode_to_vec()),
+            memo: memo.into(),
+        }
+    }
+
+    /// Decode the messages to be executed on the host
+    pub fn messages(&self) -> Result<Vec<Any>, IcaError> {
+        if self.packet_type != PacketType::ExecuteTx {
+            return Err(IcaError::InvalidPacketData(
+                "Only the execution of messages is supported".to_string(),
+            ));
+        }
+        let bytes = BASE64
+            .decode(self.data.as_bytes())
+            .map_err(|e| IcaError::InvalidPacketData(e.to_string()))?;
+        let tx = CosmosTx::decode(bytes.as_slice())
+            .map_err(|e| IcaError::InvalidPacketData(e.to_string()))?;
+        if tx.messages.is_empty() {
+            return Err(IcaError::InvalidPacketData(
+                "No message to be executed".to_string(),
+            ));
+        }
+        Ok(tx.messages)
+    }
+
+    /// Encode the packet data as the packet bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self)
+            .expect("Encoding the packet data shouldn't fail")
+    }
+
+    /// Decode the packet data from the packet bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, IcaError> {
+        serde_json::from_slice(bytes)
+            .map_err(|e| IcaError::InvalidPacketData(e.to_string()))
+    }
+}
+
+/// The messages executed by the interchain account
+#[derive(Clone, PartialEq, Message)]
+pub struct CosmosTx {
+    /// Messages
+    #[prost(message, repeated, tag = "1")]
+    pub messages: Vec<Any>,
+}
+
+/// A coin in the bank send message
+#[derive(Clone, PartialEq, Message)]
+pub struct Coin {
+    /// The denomination, i.e. a token address or an IBC denom
+    #[prost(string, tag = "1")]
+    pub denom: String,
+    /// The raw amount
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+/// A bank send message
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgSend {
+    /// The sender address
+    #[prost(string, tag = "1")]
+    pub from_address: String,
+    /// The receiver address
+    #[prost(string, tag = "2")]
+    pub to_address: String,
+    /// The amounts to be sent
+    #[prost(message, repeated, tag = "3")]
+    pub amount: Vec<Coin>,
+}
+
+impl MsgSend {
+    /// Wrap the message in `Any`
+    pub fn to_any(&self) -> Any {
+        Any {
+            type_url: MSG_SEND_TYPE_URL.to_string(),
+            value: self.encode_to_vec(),
+        }
+    }
+}
+
+/// A token transfer executed by an interchain account on the host
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HostTransfer {
+    /// The receiver
+    pub target: Address,
+    /// The token
+    pub token: Address,
+    /// The amount
+    pub amount: Amount,
+}
+
+/// Decode the given message into the host transfers from the interchain
+/// account
+pub fn decode_host_message(
+    message: &Any,
+    account: &Address,
+    params: &HostParams,
+) -> Result<Vec<HostTransfer>, IcaError> {
+    if !params.allow_messages.contains(&message.type_url) {
+        return Err(IcaError::UnauthorizedMessage(message.type_url.clone()));
+    }
+    match message.type_url.as_str() {
+        MSG_SEND_TYPE_URL => {
+            let msg = MsgSend::decode(message.value.as_slice())
+                .map_err(|e| IcaError::InvalidPacketData(e.to_string()))?;
+            if msg.from_address != account.to_string() {
+                return Err(IcaError::InvalidPacketData(format!(
+                    "The sender {} isn't the interchain account {account}",
+                    msg.from_address
+                )));
+            }
+            let target = Address::decode(&msg.to_address).map_err(|e| {
+                IcaError::InvalidPacketData(format!(
+                    "Invalid receiver {}: {e}",
+                    msg.to_address
+                ))
+            })?;
+            msg.amount
+                .iter()
+                .map(|coin| {
+                    let token =
+                        Address::decode(&coin.denom).unwrap_or_else(|_| {
+                            crate::trace::ibc_token(&coin.denom)
+                        });
+                    let amount =
+                        Amount::from_str(&coin.amount, 0u8).map_err(|e| {
+                            IcaError::InvalidPacketData(format!(
+                                "Invalid amount {}: {e}",
+                                coin.amount
+                            ))
+                        })?;
+                    Ok(HostTransfer {
+                        target: target.clone(),
+                        token,
+                        amount,
+                    })
+                })
+                .collect()
+        }
+        type_url => Err(IcaError::UnauthorizedMessage(type_url.to_string())),
+    }
+}
+
+/// Parameters of the interchain account host. The host is disabled and allows
+/// no messages by default, until governance enables it.
+#[derive(
+    Clone, Debug, Default, BorshSerialize, BorshDeserialize, BorshSchema,
+)]
+pub struct HostParams {
+    /// Whether the host accepts new interchain accounts and packets
+    pub enabled: bool,
+    /// Type URLs of the messages which interchain accounts can execute
+    pub allow_messages: Vec<String>,
+}
+
+/// Read the host parameters. The default parameters are returned if they
+/// haven't been set by governance.
+pub fn read_host_params<S: StorageRead>(
+    storage: &S,
+) -> StorageResult<HostParams> {
+    Ok(storage.read(&ica_host_params_key())?.unwrap_or_default())
+}
+
+/// An interchain account registered with an active channel
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct RegisteredAccount {
+    /// The active channel ID on this chain
+    pub channel_id: String,
+    /// The account address on the host chain
+    pub address: String,
+}
+
+/// Get the controller port ID of the owner
+pub fn controller_port_id(owner: &Address) -> PortId {
+    PortId::from_str(&format!("{CONTROLLER_PORT_PREFIX}{owner}"))
+        .expect("The controller port ID should be valid")
+}
+
+/// Get the owner of the controller port
+pub fn controller_port_owner(port_id: &PortId) -> Option<Address> {
+    let owner = port_id.as_str().strip_prefix(CONTROLLER_PORT_PREFIX)?;
+    Address::decode(owner).ok()
+}
+
+/// Check if the given port is a controller port
+pub fn is_controller_port(port_id: &PortId) -> bool {
+    port_id.as_str().starts_with(CONTROLLER_PORT_PREFIX)
+}
+
+/// Get the host port ID
+pub fn host_port_id() -> PortId {
+    PortId::from_str(HOST_PORT_ID_STR).expect("The host port ID is valid")
+}
+
+/// Derive the address of the interchain account on this chain from the host
+/// connection and the controller port
+pub fn interchain_account_address(
+    connection_id: &ConnectionId,
+    controller_port_id: &PortId,
+) -> Address {
+    let hash = {
+        let mut hasher = Sha256::new();
+        hasher.update(format!("{connection_id}/{controller_port_id}"));
+        hasher.finalize()
+    };
+
+    let input: &[u8; SHA_HASH_LEN] = hash.as_ref();
+    let mut output = [0; HASH_LEN];
+
+    output.copy_from_slice(&input[..HASH_LEN]);
+    Address::Internal(InternalAddress::InterchainAccount(
+        InterchainAccountHash(output),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::{established_address_1, nam};
+
+    use super::*;
+
+    #[test]
+    fn test_controller_port_owner() {
+        let owner = established_address_1();
+        let port_id = controller_port_id(&owner);
+        assert!(is_controller_port(&port_id));
+        assert_eq!(controller_port_owner(&port_id), Some(owner));
+        assert!(!is_controller_port(&host_port_id()));
+        assert_eq!(controller_port_owner(&host_port_id()), None);
+    }
+
+    #[test]
+    fn test_decode_host_message() {
+        let conn_id = ConnectionId::new(0);
+        let port_id = controller_port_id(&established_address_1());
+        let account = interchain_account_address(&conn_id, &port_id);
+        let target = established_address_1();
+        let msg = MsgSend {
+            from_address: account.to_string(),
+            to_address: target.to_string(),
+            amount: vec![Coin {
+                denom: nam().to_string(),
+                amount: "100".to_string(),
+            }],
+        };
+        let packet_data =
+            InterchainAccountPacketData::new(vec![msg.to_any()], "");
+        let decoded =
+            InterchainAccountPacketData::from_bytes(&packet_data.to_bytes())
+                .unwrap();
+        let messages = decoded.messages().unwrap();
+        assert_eq!(messages.len(), 1);
+
+        let params = HostParams {
+            enabled: true,
+            allow_messages: vec![MSG_SEND_TYPE_URL.to_string()],
+        };
+        let transfers =
+            decode_host_message(&messages[0], &account, &params).unwrap();
+        assert_eq!(
+            transfers,
+            vec![HostTransfer {
+                target: target.clone(),
+                token: nam(),
+                amount: Amount::from_u64(100),
+            }]
+        );
+
+        // The message type isn't allowed by default
+        assert!(matches!(
+            decode_host_message(&messages[0], &account, &HostParams::default()),
+            Err(IcaError::UnauthorizedMessage(_))
+        ));
+
+        // The sender isn't the interchain account
+        let other = interchain_account_address(&ConnectionId::new(1), &port_id);
+        assert_ne!(account, other);
+        assert!(matches!(
+            decode_host_message(&messages[0], &other, &params),
+            Err(IcaError::InvalidPacketData(_))
+        ));
+    }
+}
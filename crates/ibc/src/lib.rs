@@ -20,9 +20,13 @@
 mod actions;
 pub mod context;
 pub mod event;
+pub mod fee;
+pub mod forward;
+pub mod ica;
 mod msg;
 mod nft;
 pub mod parameters;
+pub mod rate_limit;
 pub mod storage;
 pub mod trace;
 pub mod vp;
@@ -32,12 +36,21 @@ use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::rc::Rc;
+use std::str::FromStr;
+use std::time::Duration;
 
 pub use actions::transfer_over_ibc;
 use apps::transfer::types::packet::PacketData;
 use apps::transfer::types::PORT_ID_STR;
 use borsh::BorshDeserialize;
+use context::client::{
+    is_client_type_allowed, is_wasm_checksum_allowed, AnyClientState,
+};
 pub use context::common::IbcCommonContext;
+pub use context::fee_mod::FeeModule;
+pub use context::ica_mod::{
+    InterchainAccountControllerModule, InterchainAccountHostModule,
+};
 pub use context::nft_transfer::NftTransferContext;
 pub use context::nft_transfer_mod::NftTransferModule;
 use context::router::IbcRouter;
@@ -54,27 +67,45 @@ use ibc::apps::nft_transfer::types::msgs::transfer::MsgTransfer as IbcMsgNftTran
 use ibc::apps::nft_transfer::types::{
     ack_success_b64, is_receiver_chain_source as is_nft_receiver_chain_source,
     PrefixedClassId, TokenId, TracePrefix as NftTracePrefix,
+    PORT_ID_STR as NFT_PORT_ID_STR,
 };
+use ibc::apps::transfer::context::TokenTransferExecutionContext;
 use ibc::apps::transfer::handler::{
     send_transfer_execute, send_transfer_validate,
 };
 use ibc::apps::transfer::types::error::TokenTransferError;
 use ibc::apps::transfer::types::msgs::transfer::MsgTransfer as IbcMsgTransfer;
-use ibc::apps::transfer::types::{is_receiver_chain_source, TracePrefix};
+use ibc::apps::transfer::types::{
+    is_receiver_chain_source, Memo, PrefixedCoin, TracePrefix,
+};
+use ibc::core::channel::handler::{send_packet_execute, send_packet_validate};
 use ibc::core::channel::types::acknowledgement::{
-    Acknowledgement, AcknowledgementStatus,
+    Acknowledgement, AcknowledgementStatus, StatusValue,
 };
+use ibc::core::channel::types::channel::Order;
 use ibc::core::channel::types::commitment::compute_ack_commitment;
+use ibc::core::channel::types::events::WriteAcknowledgement;
 use ibc::core::channel::types::msgs::{
-    MsgRecvPacket as IbcMsgRecvPacket, PacketMsg,
+    ChannelMsg, MsgChannelOpenInit, MsgRecvPacket as IbcMsgRecvPacket,
+    PacketMsg,
 };
+use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version;
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::types::msgs::ClientMsg;
+use ibc::core::connection::types::msgs::ConnectionMsg;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
-use ibc::core::handler::types::events::Error as RawIbcEventError;
+use ibc::core::handler::types::events::{
+    Error as RawIbcEventError, IbcEvent as RawIbcEvent, MessageEvent,
+};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::error::IdentifierError;
-use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::host::types::identifiers::{
+    ChannelId, ClientId, ConnectionId, PortId, Sequence,
+};
+use ibc::core::host::ExecutionContext;
 use ibc::core::router::types::error::RouterError;
 use ibc::primitives::proto::Any;
 pub use ibc::*;
@@ -98,14 +129,27 @@ use namada_state::{
 use namada_systems::ibc::ChangedBalances;
 use namada_systems::trans_token;
 pub use nft::*;
-use primitives::Timestamp;
+use primitives::{Signer, Timestamp};
 use prost::Message;
 use thiserror::Error;
 use trace::{convert_to_address, ibc_trace_for_nft, is_sender_chain_source};
 
+use crate::fee::{
+    is_fee_enabled, read_packet_fees, wrap_acknowledgement, FeeError,
+    IncentivizedAcknowledgement, PacketFee,
+};
+use crate::forward::{
+    decode_forward_metadata, ForwardError, ForwardMetadata, InFlightPacket,
+};
+use crate::ica::{
+    controller_port_id, host_port_id, IcaError, InterchainAccountPacketData,
+    Metadata, RegisteredAccount,
+};
 use crate::storage::{
-    channel_counter_key, client_counter_key, connection_counter_key,
-    deposit_prefix, withdraw_prefix,
+    channel_counter_key, client_counter_key, commitment_key,
+    connection_counter_key, counterparty_payee_key, deposit_prefix,
+    fees_in_escrow_key, forward_key, ica_controller_account_key, payee_key,
+    withdraw_prefix,
 };
 
 /// The event type defined in ibc-rs for receiving a token
@@ -136,6 +180,14 @@ pub enum Error {
     ChainId(IdentifierError),
     #[error("Verifier insertion error: {0}")]
     Verifier(namada_storage::Error),
+    #[error("Interchain account error: {0}")]
+    InterchainAccount(IcaError),
+    #[error("IBC packet forwarding error: {0}")]
+    Forward(ForwardError),
+    #[error("IBC relayer fee error: {0}")]
+    Fee(FeeError),
+    #[error("IBC client type error: {0}")]
+    ClientType(String),
 }
 
 struct IbcTransferInfo {
@@ -274,6 +326,10 @@ where
                     keys_changed,
                 )?;
             }
+            // No token is transferred over IBC
+            Some(IbcMessage::InterchainAccount(_)) => {}
+            // The relayer fees aren't transferred over IBC
+            Some(IbcMessage::Fee(_)) => {}
             // This event is emitted on the receiver
             Some(IbcMessage::Envelope(envelope)) => {
                 if let MsgEnvelope::Packet(PacketMsg::Recv(msg)) = *envelope {
@@ -299,7 +355,9 @@ where
                             amount,
                             keys_changed,
                         )?;
-                    } else {
+                    } else if msg.packet.port_id_on_b.as_str()
+                        == NFT_PORT_ID_STR
+                    {
                         let packet_data =
                             serde_json::from_slice::<NftPacketData>(
                                 &msg.packet.data,
@@ -470,15 +528,20 @@ fn is_receiving_success<S>(
     dst_port_id: &PortId,
     dst_channel_id: &ChannelId,
     sequence: Sequence,
+    relayer: &Signer,
 ) -> namada_storage::Result<bool>
 where
     S: StorageRead,
 {
     // Ensure that the event corresponds to the current changes to storage
     let ack_key = storage::ack_key(dst_port_id, dst_channel_id, sequence); // If the receive is a success, then the commitment is unique
-    let succ_ack_commitment = compute_ack_commitment(
-        &AcknowledgementStatus::success(ack_success_b64()).into(),
-    );
+    let succ_ack_commitment = compute_ack_commitment(&wrap_acknowledgement(
+        storage,
+        dst_port_id,
+        dst_channel_id,
+        relayer,
+        AcknowledgementStatus::success(ack_success_b64()).into(),
+    )?);
     Ok(match storage.read_bytes(&ack_key)? {
         // Success happens only if commitment equals the above
         Some(value) => {
@@ -510,6 +573,7 @@ where
         &msg.packet.port_id_on_b,
         &msg.packet.chan_id_on_b,
         msg.packet.seq_on_a,
+        &msg.signer,
     )? {
         for ibc_trace in ibc_traces {
             // Get the received token
@@ -573,8 +637,13 @@ where
         }
     }
 
-    /// Add a transfer module to the router
-    pub fn add_transfer_module(&mut self, module: impl ModuleWrapper + 'a) {
+    /// Add a transfer module to the router. The module is wrapped by the
+    /// relayer fee middleware.
+    pub fn add_transfer_module(&mut self, module: impl ModuleWrapper + 'a)
+    where
+        C: Debug + 'a,
+    {
+        let module = FeeModule::new(self.ctx.inner.clone(), module);
         self.router.add_transfer_module(module)
     }
 
@@ -596,34 +665,86 @@ where
                     self.verifiers.clone(),
                 );
                 self.insert_verifiers()?;
+                let port_id = msg.message.port_id_on_a.clone();
+                let channel_id = msg.message.chan_id_on_a.clone();
+                let sequence =
+                    self.next_sequence_send(&port_id, &channel_id)?;
                 send_transfer_execute(
                     &mut self.ctx,
                     &mut token_transfer_ctx,
                     msg.message,
                 )
                 .map_err(Error::TokenTransfer)?;
+                if let Some(packet_fee) = &msg.fee {
+                    self.escrow_packet_fee(
+                        &port_id,
+                        &channel_id,
+                        sequence,
+                        packet_fee,
+                    )?;
+                }
                 Ok((msg.transfer, None))
             }
             IbcMessage::NftTransfer(msg) => {
                 let mut nft_transfer_ctx =
                     NftTransferContext::<_, Token>::new(self.ctx.inner.clone());
+                let port_id = msg.message.port_id_on_a.clone();
+                let channel_id = msg.message.chan_id_on_a.clone();
+                let sequence =
+                    self.next_sequence_send(&port_id, &channel_id)?;
                 send_nft_transfer_execute(
                     &mut self.ctx,
                     &mut nft_transfer_ctx,
                     msg.message,
                 )
                 .map_err(Error::NftTransfer)?;
+                if let Some(packet_fee) = &msg.fee {
+                    self.escrow_packet_fee(
+                        &port_id,
+                        &channel_id,
+                        sequence,
+                        packet_fee,
+                    )?;
+                }
                 Ok((msg.transfer, None))
             }
+            IbcMessage::InterchainAccount(msg) => {
+                self.ctx
+                    .inner
+                    .borrow_mut()
+                    .insert_verifier(msg.owner())
+                    .map_err(Error::Verifier)?;
+                match msg {
+                    MsgInterchainAccount::Register(msg) => {
+                        let envelope = self.ica_channel_open_init(&msg)?;
+                        execute(&mut self.ctx, &mut self.router, envelope)
+                            .map_err(|e| Error::Context(Box::new(e)))?;
+                    }
+                    MsgInterchainAccount::SendTx(msg) => {
+                        let packet = self.ica_packet(&msg)?;
+                        send_packet_execute(&mut self.ctx, packet)
+                            .map_err(|e| Error::Context(Box::new(e)))?;
+                    }
+                }
+                Ok((None, None))
+            }
+            IbcMessage::Fee(msg) => {
+                self.execute_fee_msg(&msg)?;
+                Ok((None, None))
+            }
             IbcMessage::Envelope(envelope) => {
+                check_ica_channel_open_init(&envelope)?;
+                self.check_client_type(&envelope)?;
                 execute(&mut self.ctx, &mut self.router, *envelope.clone())
                     .map_err(|e| Error::Context(Box::new(e)))?;
+                self.update_client_on_proofs(&envelope)?;
                 // Extract MASP tx from the memo in the packet if needed
                 let masp_tx = match &*envelope {
                     MsgEnvelope::Packet(packet_msg) => {
                         match packet_msg {
                             PacketMsg::Recv(msg) => {
                                 if self.is_receiving_success(msg)? {
+                                    self.forward_received_packet(msg)?;
                                     extract_masp_tx_from_packet(
                                         &msg.packet,
                                         false,
@@ -633,7 +754,10 @@ where
                                 }
                             }
                             PacketMsg::Ack(msg) => {
-                                if is_ack_successful(&msg.acknowledgement)? {
+                                let is_success =
+                                    is_ack_successful(&msg.acknowledgement)?;
+                                self.complete_forward(&msg.packet, is_success)?;
+                                if is_success {
                                     // No refund
                                     None
                                 } else {
@@ -644,6 +768,7 @@ where
                                 }
                             }
                             PacketMsg::Timeout(msg) => {
+                                self.timeout_forward(&msg.packet)?;
                                 extract_masp_tx_from_packet(&msg.packet, true)
                             }
                             _ => None,
@@ -672,10 +797,15 @@ where
                 msg.packet.seq_on_a,
             )
             .map_err(|e| Error::Context(Box::new(e)))?;
-        let success_ack_commitment = compute_ack_commitment(
-            &AcknowledgementStatus::success(ack_success_b64()).into(),
-        );
-        Ok(packet_ack == success_ack_commitment)
+        let success_ack = wrap_acknowledgement(
+            self.ctx.inner.borrow().storage(),
+            &msg.packet.port_id_on_b,
+            &msg.packet.chan_id_on_b,
+            &msg.signer,
+            AcknowledgementStatus::success(ack_success_b64()).into(),
+        )
+        .map_err(|e| Error::Fee(FeeError::Storage(e)))?;
+        Ok(packet_ack == compute_ack_commitment(&success_ack))
     }
 
     /// Validate according to the message in IBC VP
@@ -695,6 +825,13 @@ where
                     verifiers.clone(),
                 );
                 self.insert_verifiers()?;
+                if let Some(packet_fee) = &msg.fee {
+                    self.check_packet_fee(
+                        &msg.message.port_id_on_a,
+                        &msg.message.chan_id_on_a,
+                        packet_fee,
+                    )?;
+                }
                 send_transfer_validate(
                     &self.ctx,
                     &token_transfer_ctx,
@@ -705,6 +842,13 @@ where
             IbcMessage::NftTransfer(msg) => {
                 let nft_transfer_ctx =
                     NftTransferContext::<_, Token>::new(self.ctx.inner.clone());
+                if let Some(packet_fee) = &msg.fee {
+                    self.check_packet_fee(
+                        &msg.message.port_id_on_a,
+                        &msg.message.chan_id_on_a,
+                        packet_fee,
+                    )?;
+                }
                 send_nft_transfer_validate(
                     &self.ctx,
                     &nft_transfer_ctx,
@@ -712,13 +856,738 @@ where
                 )
                 .map_err(Error::NftTransfer)
             }
+            IbcMessage::InterchainAccount(msg) => match msg {
+                MsgInterchainAccount::Register(msg) => {
+                    let envelope = self.ica_channel_open_init(&msg)?;
+                    validate(&self.ctx, &self.router, envelope)
+                        .map_err(|e| Error::Context(Box::new(e)))
+                }
+                MsgInterchainAccount::SendTx(msg) => {
+                    let packet = self.ica_packet(&msg)?;
+                    send_packet_validate(&self.ctx, &packet)
+                        .map_err(|e| Error::Context(Box::new(e)))
+                }
+            },
+            IbcMessage::Fee(msg) => self.check_fee_msg(&msg),
             IbcMessage::Envelope(envelope) => {
+                check_ica_channel_open_init(&envelope)?;
+                self.check_client_type(&envelope)?;
                 validate(&self.ctx, &self.router, *envelope)
                     .map_err(|e| Error::Context(Box::new(e)))
             }
         }
     }
 
+    /// A client should be of a type allowed by governance to be created,
+    /// updated and used for a new connection
+    fn check_client_type(&self, envelope: &MsgEnvelope) -> Result<(), Error> {
+        let client_state = match envelope {
+            MsgEnvelope::Client(ClientMsg::CreateClient(msg)) => {
+                AnyClientState::try_from(msg.client_state.clone())
+                    .map_err(|e| Error::ClientType(e.to_string()))?
+            }
+            MsgEnvelope::Client(ClientMsg::UpdateClient(msg)) => {
+                self.client_state(&msg.client_id)?
+            }
+            MsgEnvelope::Connection(ConnectionMsg::OpenInit(msg)) => {
+                self.client_state(&msg.client_id_on_a)?
+            }
+            MsgEnvelope::Connection(ConnectionMsg::OpenTry(msg)) => {
+                self.client_state(&msg.client_id_on_b)?
+            }
+            _ => return Ok(()),
+        };
+        let client_type = client_state.client_type();
+        let is_allowed = is_client_type_allowed(
+            self.ctx.inner.borrow().storage(),
+            &client_type,
+        )
+        .map_err(|e| Error::Context(Box::new(e.into())))?;
+        if !is_allowed {
+            return Err(Error::ClientType(format!(
+                "The client type {client_type} isn't allowed"
+            )));
+        }
+        if let AnyClientState::Wasm(client_state) = &client_state {
+            let is_allowed = is_wasm_checksum_allowed(
+                self.ctx.inner.borrow().storage(),
+                &client_state.checksum,
+            )
+            .map_err(|e| Error::Context(Box::new(e.into())))?;
+            if !is_allowed {
+                return Err(Error::ClientType(
+                    "The light client wrapped by the wasm client isn't \
+                     allowed"
+                        .to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Update the client which verified the proofs of the executed message,
+    /// e.g. increment the sequence of a solo machine client so that the
+    /// signatures can't be replayed
+    fn update_client_on_proofs(
+        &mut self,
+        envelope: &MsgEnvelope,
+    ) -> Result<(), Error> {
+        let Some(client_id) = self.proof_client_id(envelope)? else {
+            return Ok(());
+        };
+        match self.client_state(&client_id)? {
+            AnyClientState::SoloMachine(client_state) => {
+                client_state.increment_sequence(&mut self.ctx, &client_id)
+            }
+            AnyClientState::Wasm(client_state) => {
+                client_state.update_state_on_proofs(&mut self.ctx, &client_id)
+            }
+            _ => Ok(()),
+        }
+        .map_err(|e| Error::Context(Box::new(e.into())))
+    }
+
+    /// Get the client which verifies the proofs of the message
+    fn proof_client_id(
+        &self,
+        envelope: &MsgEnvelope,
+    ) -> Result<Option<ClientId>, Error> {
+        let (port_id, channel_id) = match envelope {
+            MsgEnvelope::Connection(ConnectionMsg::OpenTry(msg)) => {
+                return Ok(Some(msg.client_id_on_b.clone()));
+            }
+            MsgEnvelope::Connection(ConnectionMsg::OpenAck(msg)) => {
+                return self.connection_client_id(&msg.conn_id_on_a).map(Some);
+            }
+            MsgEnvelope::Connection(ConnectionMsg::OpenConfirm(msg)) => {
+                return self.connection_client_id(&msg.conn_id_on_b).map(Some);
+            }
+            MsgEnvelope::Channel(ChannelMsg::OpenTry(msg)) => {
+                return match msg.connection_hops_on_b.first() {
+                    Some(conn_id) => {
+                        self.connection_client_id(conn_id).map(Some)
+                    }
+                    None => Ok(None),
+                };
+            }
+            MsgEnvelope::Channel(ChannelMsg::OpenAck(msg)) => {
+                (&msg.port_id_on_a, &msg.chan_id_on_a)
+            }
+            MsgEnvelope::Channel(ChannelMsg::OpenConfirm(msg)) => {
+                (&msg.port_id_on_b, &msg.chan_id_on_b)
+            }
+            MsgEnvelope::Channel(ChannelMsg::CloseConfirm(msg)) => {
+                (&msg.port_id_on_b, &msg.chan_id_on_b)
+            }
+            MsgEnvelope::Packet(PacketMsg::Recv(msg)) => {
+                (&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b)
+            }
+            MsgEnvelope::Packet(PacketMsg::Ack(msg)) => {
+                (&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a)
+            }
+            MsgEnvelope::Packet(PacketMsg::Timeout(msg)) => {
+                (&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a)
+            }
+            MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(msg)) => {
+                (&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a)
+            }
+            _ => return Ok(None),
+        };
+        let channel = self
+            .ctx
+            .inner
+            .borrow()
+            .channel_end(port_id, channel_id)
+            .map_err(|e| Error::Context(Box::new(e)))?;
+        match channel.connection_hops().first() {
+            Some(conn_id) => self.connection_client_id(conn_id).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn connection_client_id(
+        &self,
+        conn_id: &ConnectionId,
+    ) -> Result<ClientId, Error> {
+        let connection = self
+            .ctx
+            .inner
+            .borrow()
+            .connection_end(conn_id)
+            .map_err(|e| Error::Context(Box::new(e)))?;
+        Ok(connection.client_id().clone())
+    }
+
+    fn client_state(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<AnyClientState, Error> {
+        self.ctx
+            .inner
+            .borrow()
+            .client_state(client_id)
+            .map_err(|e| Error::Context(Box::new(e)))
+    }
+
+    /// Make the message to open a channel for a new interchain account of
+    /// the owner
+    fn ica_channel_open_init(
+        &self,
+        msg: &MsgRegisterInterchainAccount,
+    ) -> Result<MsgEnvelope, Error> {
+        let conn_id = ConnectionId::from_str(&msg.connection_id)
+            .map_err(|e| {
+                IcaError::InvalidChannel(format!(
+                    "Invalid connection ID {}: {e}",
+                    msg.connection_id
+                ))
+            })
+            .map_err(Error::InterchainAccount)?;
+        let conn = self
+            .ctx
+            .inner
+            .borrow()
+            .connection_end(&conn_id)
+            .map_err(|e| Error::Context(Box::new(e)))?;
+        let host_conn_id =
+            conn.counterparty().connection_id().ok_or_else(|| {
+                Error::InterchainAccount(IcaError::InvalidChannel(format!(
+                    "The counterparty connection of {conn_id} isn't set"
+                )))
+            })?;
+        let metadata = Metadata::new(&conn_id, host_conn_id);
+        let msg = MsgChannelOpenInit {
+            port_id_on_a: controller_port_id(&msg.owner),
+            connection_hops_on_a: vec![conn_id],
+            port_id_on_b: host_port_id(),
+            ordering: Order::Ordered,
+            signer: msg.owner.to_string().into(),
+            version_proposal: Version::new(metadata.to_string()),
+        };
+        Ok(MsgEnvelope::Channel(ChannelMsg::OpenInit(msg)))
+    }
+
+    /// Make the packet to send the transaction to the interchain account of
+    /// the owner
+    fn ica_packet(&self, msg: &MsgSendTx) -> Result<Packet, Error> {
+        let conn_id = ConnectionId::from_str(&msg.connection_id)
+            .map_err(|e| {
+                IcaError::InvalidChannel(format!(
+                    "Invalid connection ID {}: {e}",
+                    msg.connection_id
+                ))
+            })
+            .map_err(Error::InterchainAccount)?;
+        // Check the messages before sending them
+        InterchainAccountPacketData::from_bytes(&msg.packet_data)
+            .and_then(|data| data.messages())
+            .map_err(Error::InterchainAccount)?;
+        if msg.relative_timeout == 0 {
+            return Err(Error::InterchainAccount(IcaError::InvalidPacketData(
+                "The relative timeout should be positive".to_string(),
+            )));
+        }
+
+        let port_id = controller_port_id(&msg.owner);
+        let ctx = self.ctx.inner.borrow();
+        let key = ica_controller_account_key(&conn_id, &port_id);
+        let account: RegisteredAccount = ctx
+            .storage()
+            .read(&key)
+            .map_err(|e| Error::InterchainAccount(IcaError::Storage(e)))?
+            .ok_or_else(|| {
+                Error::InterchainAccount(IcaError::InactiveAccount(format!(
+                    "Owner {}, connection ID {conn_id}",
+                    msg.owner
+                )))
+            })?;
+        let chan_id_on_a = ChannelId::from_str(&account.channel_id)
+            .map_err(Error::ChainId)?;
+        let channel = ctx
+            .channel_end(&port_id, &chan_id_on_a)
+            .map_err(|e| Error::Context(Box::new(e)))?;
+        let chan_id_on_b =
+            channel.counterparty().channel_id().cloned().ok_or_else(|| {
+                Error::InterchainAccount(IcaError::InvalidChannel(format!(
+                    "The counterparty channel of {chan_id_on_a} isn't set"
+                )))
+            })?;
+        let seq_on_a = ctx
+            .get_next_sequence_send(&port_id, &chan_id_on_a)
+            .map_err(|e| Error::Context(Box::new(e)))?;
+        let timeout_timestamp_on_b = (ctx
+            .host_timestamp()
+            .map_err(|e| Error::Context(Box::new(e)))?
+            + Duration::from_nanos(msg.relative_timeout))
+        .map_err(|e| {
+            Error::InterchainAccount(IcaError::InvalidPacketData(e.to_string()))
+        })?;
+
+        Ok(Packet {
+            seq_on_a,
+            port_id_on_a: port_id,
+            chan_id_on_a,
+            port_id_on_b: channel.counterparty().port_id().clone(),
+            chan_id_on_b,
+            data: msg.packet_data.clone(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b,
+        })
+    }
+
+    fn next_sequence_send(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Sequence, Error> {
+        self.ctx
+            .inner
+            .borrow()
+            .get_next_sequence_send(port_id, channel_id)
+            .map_err(|e| Error::Context(Box::new(e)))
+    }
+
+    /// Check the fees paid for a packet sent over the channel
+    fn check_packet_fee(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        packet_fee: &PacketFee,
+    ) -> Result<(), Error> {
+        self.check_fee_enabled(port_id, channel_id)?;
+        packet_fee.fee.validate().map_err(Error::Fee)
+    }
+
+    /// Escrow the fees paid by the refund address for the packet
+    fn escrow_packet_fee(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        packet_fee: &PacketFee,
+    ) -> Result<(), Error> {
+        self.check_packet_fee(port_id, channel_id, packet_fee)?;
+        let amount = packet_fee.fee.total().map_err(Error::Fee)?;
+        let mut ctx = self.ctx.inner.borrow_mut();
+        ctx.insert_verifier(&packet_fee.refund_address)
+            .map_err(Error::Verifier)?;
+        ctx.transfer_token(
+            &packet_fee.refund_address,
+            &IBC_ESCROW_ADDRESS,
+            &packet_fee.fee.token,
+            amount,
+        )
+        .map_err(|e| Error::Fee(FeeError::Storage(e)))?;
+        let mut packet_fees =
+            read_packet_fees(ctx.storage(), port_id, channel_id, sequence)
+                .map_err(|e| Error::Fee(FeeError::Storage(e)))?;
+        packet_fees.push(packet_fee.clone());
+        let key = fees_in_escrow_key(port_id, channel_id, sequence);
+        ctx.storage_mut()
+            .write(&key, packet_fees)
+            .map_err(|e| Error::Fee(FeeError::Storage(e)))
+    }
+
+    /// Check the relayer fee message
+    fn check_fee_msg(&self, msg: &MsgFee) -> Result<(), Error> {
+        let (port_id, channel_id) =
+            parse_channel(msg.port_id(), msg.channel_id())?;
+        match msg {
+            MsgFee::RegisterPayee(_) => {
+                self.check_fee_enabled(&port_id, &channel_id)
+            }
+            MsgFee::RegisterCounterpartyPayee(msg) => {
+                if msg.counterparty_payee.trim().is_empty() {
+                    return Err(Error::Fee(FeeError::InvalidFee(
+                        "The counterparty payee is empty".to_string(),
+                    )));
+                }
+                self.check_fee_enabled(&port_id, &channel_id)
+            }
+            MsgFee::PayPacketFeeAsync(msg) => {
+                self.check_packet_fee(&port_id, &channel_id, &msg.packet_fee)?;
+                // The packet should be in flight
+                let sequence = msg.sequence.into();
+                let key = commitment_key(&port_id, &channel_id, sequence);
+                let ctx = self.ctx.inner.borrow();
+                if ctx
+                    .storage()
+                    .has_key(&key)
+                    .map_err(|e| Error::Fee(FeeError::Storage(e)))?
+                {
+                    Ok(())
+                } else {
+                    Err(Error::Fee(FeeError::InvalidFee(format!(
+                        "No packet in flight: Port ID {port_id}, Channel ID \
+                         {channel_id}, Sequence {sequence}"
+                    ))))
+                }
+            }
+        }
+    }
+
+    fn check_fee_enabled(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), Error> {
+        let ctx = self.ctx.inner.borrow();
+        if is_fee_enabled(ctx.storage(), port_id, channel_id)
+            .map_err(|e| Error::Fee(FeeError::Storage(e)))?
+        {
+            Ok(())
+        } else {
+            Err(Error::Fee(FeeError::FeeNotEnabled(format!(
+                "Port ID {port_id}, Channel ID {channel_id}"
+            ))))
+        }
+    }
+
+    /// Execute the relayer fee message
+    fn execute_fee_msg(&mut self, msg: &MsgFee) -> Result<(), Error> {
+        self.check_fee_msg(msg)?;
+        let (port_id, channel_id) =
+            parse_channel(msg.port_id(), msg.channel_id())?;
+        let mut ctx = self.ctx.inner.borrow_mut();
+        let result = match msg {
+            MsgFee::RegisterPayee(msg) => {
+                ctx.insert_verifier(&msg.relayer).map_err(Error::Verifier)?;
+                let key = payee_key(&channel_id, &msg.relayer);
+                ctx.storage_mut().write(&key, msg.payee.clone())
+            }
+            MsgFee::RegisterCounterpartyPayee(msg) => {
+                ctx.insert_verifier(&msg.relayer).map_err(Error::Verifier)?;
+                let key = counterparty_payee_key(&channel_id, &msg.relayer);
+                ctx.storage_mut()
+                    .write(&key, msg.counterparty_payee.clone())
+            }
+            MsgFee::PayPacketFeeAsync(msg) => {
+                drop(ctx);
+                return self.escrow_packet_fee(
+                    &port_id,
+                    &channel_id,
+                    msg.sequence.into(),
+                    &msg.packet_fee,
+                );
+            }
+        };
+        result.map_err(|e| Error::Fee(FeeError::Storage(e)))
+    }
+
+    /// Send the token received by the IBC escrow to the next hop if the
+    /// packet has the forward metadata. The received packet is acknowledged
+    /// when the forwarded packet is acknowledged or timed out. If the token
+    /// can't be forwarded, receiving it is reverted and the received packet
+    /// is acknowledged with the error.
+    fn forward_received_packet(
+        &mut self,
+        msg: &IbcMsgRecvPacket,
+    ) -> Result<(), Error> {
+        let packet = &msg.packet;
+        if packet.port_id_on_b != PortId::transfer() {
+            return Ok(());
+        }
+        let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data)
+        else {
+            return Ok(());
+        };
+        let Some(metadata) = decode_forward_metadata(data.memo.as_ref())
+            .map_err(Error::Forward)?
+        else {
+            return Ok(());
+        };
+        let denom = received_ibc_trace(
+            data.token.denom.to_string(),
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+        )?;
+        let amount = data.token.amount.to_string();
+        let result = new_in_flight_packet(msg, &metadata, &denom, &amount)
+            .and_then(|in_flight| self.send_forward_packet(in_flight));
+        match result {
+            // The acknowledgement written by receiving the packet is removed
+            // to acknowledge it asynchronously
+            Ok(()) => self
+                .ctx
+                .inner
+                .borrow_mut()
+                .delete_packet_ack(
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    packet.seq_on_a,
+                )
+                .map_err(|e| Error::Context(Box::new(e))),
+            Err(e) => self.fail_forward(
+                packet,
+                &msg.signer,
+                &denom,
+                &amount,
+                format!("Forwarding the packet failed: {e}"),
+            ),
+        }
+    }
+
+    /// Send the packet to the next hop and track it until it's acknowledged
+    fn send_forward_packet(
+        &mut self,
+        in_flight: InFlightPacket,
+    ) -> Result<(), Error> {
+        let port_id = in_flight.port_id().map_err(Error::Forward)?;
+        let channel_id = in_flight.channel_id().map_err(Error::Forward)?;
+        let sequence = self
+            .ctx
+            .inner
+            .borrow()
+            .get_next_sequence_send(&port_id, &channel_id)
+            .map_err(|e| Error::Context(Box::new(e)))?;
+        self.send_transfer_from_escrow(
+            port_id.clone(),
+            channel_id.clone(),
+            &in_flight.denom,
+            &in_flight.amount,
+            &in_flight.receiver,
+            &in_flight.next_memo,
+            Duration::from_nanos(in_flight.timeout),
+        )?;
+        let key = forward_key(&port_id, &channel_id, sequence);
+        self.ctx
+            .inner
+            .borrow_mut()
+            .storage_mut()
+            .write(&key, in_flight)
+            .map_err(|e| Error::Forward(ForwardError::Storage(e)))
+    }
+
+    /// Complete the forwarded packet when it's acknowledged. The received
+    /// packet is acknowledged with the result of the forwarded packet. The
+    /// tokens refunded to the IBC escrow are reverted if the forwarded packet
+    /// failed.
+    fn complete_forward(
+        &mut self,
+        packet: &Packet,
+        is_success: bool,
+    ) -> Result<(), Error> {
+        let Some(in_flight) = self.take_in_flight_packet(packet)? else {
+            return Ok(());
+        };
+        let received_packet = in_flight.packet().map_err(Error::Forward)?;
+        let relayer = Signer::from(in_flight.relayer.clone());
+        if is_success {
+            let ack = AcknowledgementStatus::success(ack_success_b64()).into();
+            self.write_forward_ack(&received_packet, &relayer, ack)
+        } else {
+            self.fail_forward(
+                &received_packet,
+                &relayer,
+                &in_flight.denom,
+                &in_flight.amount,
+                "The forwarded packet failed on the next hop".to_string(),
+            )
+        }
+    }
+
+    /// Retry the forwarded packet which timed out. The tokens refunded to the
+    /// IBC escrow are reverted if there is no retry left.
+    fn timeout_forward(&mut self, packet: &Packet) -> Result<(), Error> {
+        let Some(mut in_flight) = self.take_in_flight_packet(packet)? else {
+            return Ok(());
+        };
+        let received_packet = in_flight.packet().map_err(Error::Forward)?;
+        let relayer = Signer::from(in_flight.relayer.clone());
+        let denom = in_flight.denom.clone();
+        let amount = in_flight.amount.clone();
+        let error = match in_flight.retries_left.checked_sub(1) {
+            Some(retries_left) => {
+                in_flight.retries_left = retries_left;
+                match self.send_forward_packet(in_flight) {
+                    Ok(()) => return Ok(()),
+                    Err(e) => {
+                        format!("Retrying the forwarded packet failed: {e}")
+                    }
+                }
+            }
+            None => "The forwarded packet timed out".to_string(),
+        };
+        self.fail_forward(&received_packet, &relayer, &denom, &amount, error)
+    }
+
+    /// Read and delete the in-flight packet of the given forwarded packet
+    fn take_in_flight_packet(
+        &mut self,
+        packet: &Packet,
+    ) -> Result<Option<InFlightPacket>, Error> {
+        let key = forward_key(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        );
+        let mut ctx = self.ctx.inner.borrow_mut();
+        let in_flight: Option<InFlightPacket> = ctx
+            .storage()
+            .read(&key)
+            .map_err(|e| Error::Forward(ForwardError::Storage(e)))?;
+        if in_flight.is_some() {
+            ctx.storage_mut()
+                .delete(&key)
+                .map_err(|e| Error::Forward(ForwardError::Storage(e)))?;
+        }
+        Ok(in_flight)
+    }
+
+    /// Revert receiving the token held by the IBC escrow and acknowledge the
+    /// received packet with the error
+    fn fail_forward(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+        denom: &str,
+        amount: &str,
+        error: String,
+    ) -> Result<(), Error> {
+        let coin = PrefixedCoin {
+            denom: denom.parse().map_err(Error::TokenTransfer)?,
+            amount: amount.parse().map_err(Error::TokenTransfer)?,
+        };
+        let memo = Memo::from(String::new());
+        let mut token_transfer_ctx = TokenTransferContext::new(
+            self.ctx.inner.clone(),
+            self.verifiers.clone(),
+        );
+        if is_sender_chain_source(
+            denom,
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+        ) {
+            // The unescrowed token is escrowed again
+            token_transfer_ctx.escrow_coins_execute(
+                &IBC_ESCROW_ADDRESS,
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                &coin,
+                &memo,
+            )
+        } else {
+            // The minted token is burned
+            token_transfer_ctx.burn_coins_execute(
+                &IBC_ESCROW_ADDRESS,
+                &coin,
+                &memo,
+            )
+        }
+        .map_err(Error::TokenTransfer)?;
+        self.insert_verifiers()?;
+
+        let ack = AcknowledgementStatus::error(
+            StatusValue::new(error)
+                .expect("The error message shouldn't be empty"),
+        )
+        .into();
+        self.write_forward_ack(packet, relayer, ack)
+    }
+
+    /// Write the acknowledgement of the received packet which was forwarded
+    fn write_forward_ack(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+        app_ack: Acknowledgement,
+    ) -> Result<(), Error> {
+        let ack = wrap_acknowledgement(
+            self.ctx.inner.borrow().storage(),
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            relayer,
+            app_ack,
+        )
+        .map_err(|e| Error::Fee(FeeError::Storage(e)))?;
+        let channel_end = self
+            .ctx
+            .inner
+            .borrow()
+            .channel_end(&packet.port_id_on_b, &packet.chan_id_on_b)
+            .map_err(|e| Error::Context(Box::new(e)))?;
+        let Some(conn_id) = channel_end.connection_hops().first().cloned()
+        else {
+            return Err(Error::Forward(ForwardError::InvalidInFlightPacket(
+                "The channel has no connection hop".to_string(),
+            )));
+        };
+        self.ctx
+            .inner
+            .borrow_mut()
+            .store_packet_ack(
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                packet.seq_on_a,
+                compute_ack_commitment(&ack),
+            )
+            .map_err(|e| Error::Context(Box::new(e)))?;
+        let event = RawIbcEvent::WriteAcknowledgement(
+            WriteAcknowledgement::new(packet.clone(), ack, conn_id),
+        );
+        self.ctx
+            .emit_ibc_event(RawIbcEvent::Message(MessageEvent::Channel))
+            .map_err(|e| Error::Context(Box::new(e)))?;
+        self.ctx
+            .emit_ibc_event(event)
+            .map_err(|e| Error::Context(Box::new(e)))
+    }
+
+    /// Send the token held by the IBC escrow
+    #[allow(clippy::too_many_arguments)]
+    fn send_transfer_from_escrow(
+        &mut self,
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        denom: &str,
+        amount: &str,
+        receiver: &str,
+        memo: &str,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let timeout_timestamp_on_b = (self
+            .ctx
+            .inner
+            .borrow()
+            .host_timestamp()
+            .map_err(|e| Error::Context(Box::new(e)))?
+            + timeout)
+            .map_err(|e| {
+                Error::Forward(ForwardError::InvalidInFlightPacket(
+                    e.to_string(),
+                ))
+            })?;
+        let token = PrefixedCoin {
+            denom: denom.parse().map_err(Error::TokenTransfer)?,
+            amount: amount.parse().map_err(Error::TokenTransfer)?,
+        };
+        let msg = IbcMsgTransfer {
+            port_id_on_a,
+            chan_id_on_a,
+            packet_data: PacketData {
+                token,
+                sender: IBC_ESCROW_ADDRESS.to_string().into(),
+                receiver: receiver.to_string().into(),
+                memo: memo.to_string().into(),
+            },
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b,
+        };
+        let mut token_transfer_ctx = TokenTransferContext::new(
+            self.ctx.inner.clone(),
+            self.verifiers.clone(),
+        );
+        // Validate before any state change so that a failure can be
+        // acknowledged with an error
+        send_transfer_validate(&self.ctx, &token_transfer_ctx, msg.clone())
+            .map_err(Error::TokenTransfer)?;
+        send_transfer_execute(&mut self.ctx, &mut token_transfer_ctx, msg)
+            .map_err(Error::TokenTransfer)?;
+        self.insert_verifiers()
+    }
+
     fn insert_verifiers(&self) -> Result<(), Error> {
         let mut ctx = self.ctx.inner.borrow_mut();
         for verifier in self.verifiers.borrow().iter() {
@@ -728,7 +1597,70 @@ where
     }
 }
 
+fn parse_channel(
+    port_id: &str,
+    channel_id: &str,
+) -> Result<(PortId, ChannelId), Error> {
+    let port_id = PortId::from_str(port_id).map_err(|e| {
+        Error::Fee(FeeError::InvalidFee(format!("Invalid port ID: {e}")))
+    })?;
+    let channel_id = ChannelId::from_str(channel_id).map_err(|e| {
+        Error::Fee(FeeError::InvalidFee(format!("Invalid channel ID: {e}")))
+    })?;
+    Ok((port_id, channel_id))
+}
+
+/// An interchain account should be registered by its owner, not by a channel
+/// opening message from anyone
+fn check_ica_channel_open_init(envelope: &MsgEnvelope) -> Result<(), Error> {
+    match envelope {
+        MsgEnvelope::Channel(ChannelMsg::OpenInit(msg))
+            if ica::is_controller_port(&msg.port_id_on_a) =>
+        {
+            Err(Error::InterchainAccount(IcaError::InvalidChannel(
+                "An interchain account should be registered by its owner"
+                    .to_string(),
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Make the in-flight packet to forward the received token to the next hop
+fn new_in_flight_packet(
+    msg: &IbcMsgRecvPacket,
+    metadata: &ForwardMetadata,
+    denom: &str,
+    amount: &str,
+) -> Result<InFlightPacket, Error> {
+    let packet = serde_json::to_string(&msg.packet).map_err(|e| {
+        Error::Forward(ForwardError::InvalidInFlightPacket(e.to_string()))
+    })?;
+    let timeout = metadata.timeout().map_err(Error::Forward)?;
+    let timeout = u64::try_from(timeout.as_nanos()).map_err(|_| {
+        Error::Forward(ForwardError::InvalidMetadata(format!(
+            "The timeout is too long: {timeout:?}"
+        )))
+    })?;
+    Ok(InFlightPacket {
+        packet,
+        relayer: msg.signer.to_string(),
+        denom: denom.to_string(),
+        amount: amount.to_string(),
+        receiver: metadata.receiver.clone(),
+        port_id: metadata.port.clone(),
+        channel_id: metadata.channel.clone(),
+        timeout,
+        retries_left: metadata.retries(),
+        next_memo: metadata.next_memo(),
+    })
+}
+
 fn is_ack_successful(ack: &Acknowledgement) -> Result<bool, Error> {
+    // The acknowledgement of a fee-enabled channel
+    if let Ok(ack) = IncentivizedAcknowledgement::decode(ack) {
+        return Ok(ack.underlying_app_success);
+    }
     let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(
         ack.as_ref(),
     )
@@ -753,6 +1685,7 @@ pub fn decode_message<Transfer: BorshDeserialize>(
             let msg = MsgTransfer {
                 message,
                 transfer: None,
+                fee: None,
             };
             return Ok(IbcMessage::Transfer(Box::new(msg)));
         }
@@ -760,6 +1693,7 @@ pub fn decode_message<Transfer: BorshDeserialize>(
             let msg = MsgNftTransfer {
                 message,
                 transfer: None,
+                fee: None,
             };
             return Ok(IbcMessage::NftTransfer(msg));
         }
@@ -775,6 +1709,16 @@ pub fn decode_message<Transfer: BorshDeserialize>(
         return Ok(IbcMessage::NftTransfer(msg));
     }
 
+    // Interchain account message
+    if let Ok(msg) = MsgInterchainAccount::try_from_slice(tx_data) {
+        return Ok(IbcMessage::InterchainAccount(msg));
+    }
+
+    // Relayer fee message
+    if let Ok(msg) = MsgFee::try_from_slice(tx_data) {
+        return Ok(IbcMessage::Fee(msg));
+    }
+
     Err(Error::DecodingData)
 }
 
@@ -15,8 +15,11 @@ use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::PortId;
 use ibc::primitives::proto::Protobuf;
 use masp_primitives::transaction::Transaction as MaspTransaction;
+use namada_core::address::Address;
 use namada_core::borsh::BorshSerializeExt;
 
+use crate::fee::PacketFee;
+
 /// The different variants of an Ibc message
 #[derive(Debug, Clone)]
 pub enum IbcMessage<Transfer> {
@@ -26,8 +29,15 @@ pub enum IbcMessage<Transfer> {
     Transfer(Box<MsgTransfer<Transfer>>),
     /// NFT transfer
     NftTransfer(MsgNftTransfer<Transfer>),
+    /// Interchain account controlled by its owner on this chain
+    InterchainAccount(MsgInterchainAccount),
+    /// Relayer fee incentivisation
+    Fee(MsgFee),
 }
 
+/// The encoded IBC message, the shielded transfer and the relayer fees
+type EncodedTransfer<Transfer> = (Vec<u8>, Option<Transfer>, Option<PacketFee>);
+
 /// IBC transfer message with `Transfer`
 #[derive(Debug, Clone)]
 pub struct MsgTransfer<Transfer> {
@@ -35,6 +45,8 @@ pub struct MsgTransfer<Transfer> {
     pub message: IbcMsgTransfer,
     /// Shieleded transfer for MASP transaction
     pub transfer: Option<Transfer>,
+    /// Fees for relaying the packet
+    pub fee: Option<PacketFee>,
 }
 
 impl<Transfer: BorshSerialize> BorshSerialize for MsgTransfer<Transfer> {
@@ -43,7 +55,7 @@ impl<Transfer: BorshSerialize> BorshSerialize for MsgTransfer<Transfer> {
         writer: &mut W,
     ) -> std::io::Result<()> {
         let encoded_msg = self.message.clone().encode_vec();
-        let members = (encoded_msg, &self.transfer);
+        let members = (encoded_msg, &self.transfer, &self.fee);
         BorshSerialize::serialize(&members, writer)
     }
 }
@@ -53,11 +65,15 @@ impl<Transfer: BorshDeserialize> BorshDeserialize for MsgTransfer<Transfer> {
         reader: &mut R,
     ) -> std::io::Result<Self> {
         use std::io::{Error, ErrorKind};
-        let (msg, transfer): (Vec<u8>, Option<Transfer>) =
+        let (msg, transfer, fee): EncodedTransfer<Transfer> =
             BorshDeserialize::deserialize_reader(reader)?;
         let message = IbcMsgTransfer::decode_vec(&msg)
             .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
-        Ok(Self { message, transfer })
+        Ok(Self {
+            message,
+            transfer,
+            fee,
+        })
     }
 }
 
@@ -65,9 +81,9 @@ impl<Transfer: BorshSchema> BorshSchema for MsgTransfer<Transfer> {
     fn add_definitions_recursively(
         definitions: &mut BTreeMap<Declaration, Definition>,
     ) {
-        <(Vec<u8>, Option<Transfer>)>::add_definitions_recursively(definitions);
+        <EncodedTransfer<Transfer>>::add_definitions_recursively(definitions);
         let fields = Fields::UnnamedFields(vec![
-            <(Vec<u8>, Option<Transfer>)>::declaration(),
+            <EncodedTransfer<Transfer>>::declaration(),
         ]);
         definitions.insert(Self::declaration(), Definition::Struct { fields });
     }
@@ -84,6 +100,8 @@ pub struct MsgNftTransfer<Transfer> {
     pub message: IbcMsgNftTransfer,
     /// Shieleded transfer for MASP transaction
     pub transfer: Option<Transfer>,
+    /// Fees for relaying the packet
+    pub fee: Option<PacketFee>,
 }
 
 impl<Transfer: BorshSerialize> BorshSerialize for MsgNftTransfer<Transfer> {
@@ -92,7 +110,7 @@ impl<Transfer: BorshSerialize> BorshSerialize for MsgNftTransfer<Transfer> {
         writer: &mut W,
     ) -> std::io::Result<()> {
         let encoded_msg = self.message.clone().encode_vec();
-        let members = (encoded_msg, &self.transfer);
+        let members = (encoded_msg, &self.transfer, &self.fee);
         BorshSerialize::serialize(&members, writer)
     }
 }
@@ -102,11 +120,15 @@ impl<Transfer: BorshDeserialize> BorshDeserialize for MsgNftTransfer<Transfer> {
         reader: &mut R,
     ) -> std::io::Result<Self> {
         use std::io::{Error, ErrorKind};
-        let (msg, transfer): (Vec<u8>, Option<Transfer>) =
+        let (msg, transfer, fee): EncodedTransfer<Transfer> =
             BorshDeserialize::deserialize_reader(reader)?;
         let message = IbcMsgNftTransfer::decode_vec(&msg)
             .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
-        Ok(Self { message, transfer })
+        Ok(Self {
+            message,
+            transfer,
+            fee,
+        })
     }
 }
 
@@ -114,9 +136,9 @@ impl<Transfer: BorshSchema> BorshSchema for MsgNftTransfer<Transfer> {
     fn add_definitions_recursively(
         definitions: &mut BTreeMap<Declaration, Definition>,
     ) {
-        <(Vec<u8>, Option<Transfer>)>::add_definitions_recursively(definitions);
+        <EncodedTransfer<Transfer>>::add_definitions_recursively(definitions);
         let fields = Fields::UnnamedFields(vec![
-            <(Vec<u8>, Option<Transfer>)>::declaration(),
+            <EncodedTransfer<Transfer>>::declaration(),
         ]);
         definitions.insert(Self::declaration(), Definition::Struct { fields });
     }
@@ -126,6 +148,127 @@ impl<Transfer: BorshSchema> BorshSchema for MsgNftTransfer<Transfer> {
     }
 }
 
+/// Interchain account message sent by the owner of the controller port
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub enum MsgInterchainAccount {
+    /// Register a new interchain account on the host chain
+    Register(MsgRegisterInterchainAccount),
+    /// Send messages to be executed by the interchain account
+    SendTx(MsgSendTx),
+}
+
+impl MsgInterchainAccount {
+    /// Get the owner of the interchain account
+    pub fn owner(&self) -> &Address {
+        match self {
+            Self::Register(msg) => &msg.owner,
+            Self::SendTx(msg) => &msg.owner,
+        }
+    }
+}
+
+/// Message to open a channel for a new interchain account
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct MsgRegisterInterchainAccount {
+    /// The owner of the interchain account
+    pub owner: Address,
+    /// The connection ID to the host chain
+    pub connection_id: String,
+}
+
+/// Message to send a transaction to be executed by the interchain account
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct MsgSendTx {
+    /// The owner of the interchain account
+    pub owner: Address,
+    /// The connection ID to the host chain
+    pub connection_id: String,
+    /// The encoded `InterchainAccountPacketData`
+    pub packet_data: Vec<u8>,
+    /// Timeout relative to the current block timestamp in nanoseconds
+    pub relative_timeout: u64,
+}
+
+/// Relayer fee message
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub enum MsgFee {
+    /// Register the payee of the relayer on this chain
+    RegisterPayee(MsgRegisterPayee),
+    /// Register the payee of the relayer on the counterparty chain
+    RegisterCounterpartyPayee(MsgRegisterCounterpartyPayee),
+    /// Escrow the fees for a packet which has been already sent
+    PayPacketFeeAsync(MsgPayPacketFeeAsync),
+}
+
+impl MsgFee {
+    /// Get the port ID
+    pub fn port_id(&self) -> &str {
+        match self {
+            Self::RegisterPayee(msg) => &msg.port_id,
+            Self::RegisterCounterpartyPayee(msg) => &msg.port_id,
+            Self::PayPacketFeeAsync(msg) => &msg.port_id,
+        }
+    }
+
+    /// Get the channel ID
+    pub fn channel_id(&self) -> &str {
+        match self {
+            Self::RegisterPayee(msg) => &msg.channel_id,
+            Self::RegisterCounterpartyPayee(msg) => &msg.channel_id,
+            Self::PayPacketFeeAsync(msg) => &msg.channel_id,
+        }
+    }
+
+    /// Get the relayer registering the payee
+    pub fn relayer(&self) -> Option<&Address> {
+        match self {
+            Self::RegisterPayee(msg) => Some(&msg.relayer),
+            Self::RegisterCounterpartyPayee(msg) => Some(&msg.relayer),
+            Self::PayPacketFeeAsync(_) => None,
+        }
+    }
+}
+
+/// Message to register the payee of the relayer on this chain
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct MsgRegisterPayee {
+    /// The port ID of the channel
+    pub port_id: String,
+    /// The channel ID
+    pub channel_id: String,
+    /// The relayer address
+    pub relayer: Address,
+    /// The payee address to which the fees are paid
+    pub payee: Address,
+}
+
+/// Message to register the payee of the relayer on the counterparty chain
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct MsgRegisterCounterpartyPayee {
+    /// The port ID of the channel
+    pub port_id: String,
+    /// The channel ID
+    pub channel_id: String,
+    /// The relayer address
+    pub relayer: Address,
+    /// The payee address on the counterparty chain to which the receive
+    /// fees are paid
+    pub counterparty_payee: String,
+}
+
+/// Message to escrow the fees for a packet which has been already sent
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct MsgPayPacketFeeAsync {
+    /// The port ID of the packet
+    pub port_id: String,
+    /// The channel ID of the packet
+    pub channel_id: String,
+    /// The sequence of the packet
+    pub sequence: u64,
+    /// The fees paid by the refund address
+    pub packet_fee: PacketFee,
+}
+
 /// Shielding data in IBC packet memo
 #[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
 pub struct IbcShieldingData {
@@ -0,0 +1,271 @@
+//! Per-channel rate limits and the deny list
+//!
+//! A rate limit can be set for a token over a channel with separate quotas
+//! for the inbound and the outbound flows. The flows are accumulated over a
+//! window of epochs and they are reset when the window has passed. A channel
+//! or a denom in the deny list can't be used for any transfer. The rate
+//! limits and the deny list are managed by governance.
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use ibc::core::host::types::identifiers::ChannelId;
+use namada_core::address::Address;
+use namada_core::storage::Epoch;
+use namada_core::token::Amount;
+use namada_state::{StorageError, StorageRead, StorageResult, StorageWrite};
+
+use crate::storage::{
+    channel_flow_key, denied_channel_key, denied_denom_key, rate_limit_key,
+};
+
+/// The rate limit of a token over a channel
+#[derive(
+    Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema,
+)]
+pub struct ChannelRateLimit {
+    /// The maximum amount received over the channel in a window
+    pub inbound_quota: Amount,
+    /// The maximum amount sent over the channel in a window
+    pub outbound_quota: Amount,
+    /// The number of epochs of a window
+    pub window_epochs: u64,
+}
+
+impl ChannelRateLimit {
+    /// Check if the window started at the given epoch has passed. A window
+    /// is at least one epoch.
+    pub fn is_window_passed(&self, window_start: Epoch, epoch: Epoch) -> bool {
+        let window_len = std::cmp::max(self.window_epochs, 1);
+        epoch.0.saturating_sub(window_start.0) >= window_len
+    }
+
+    /// Check the flow against the quotas
+    pub fn check(&self, flow: &ChannelFlow) -> Result<(), String> {
+        if self.inbound_quota < flow.inbound {
+            return Err(format!(
+                "Inbound quota {}, inbound flow {}",
+                self.inbound_quota, flow.inbound
+            ));
+        }
+        if self.outbound_quota < flow.outbound {
+            return Err(format!(
+                "Outbound quota {}, outbound flow {}",
+                self.outbound_quota, flow.outbound
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The direction of a flow over a channel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlowDirection {
+    /// Received over the channel
+    Inbound,
+    /// Sent over the channel
+    Outbound,
+}
+
+/// The flows of a token over a channel in the current window
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshSchema,
+)]
+pub struct ChannelFlow {
+    /// The epoch when the current window started
+    pub window_start: Epoch,
+    /// The amount received over the channel
+    pub inbound: Amount,
+    /// The amount sent over the channel
+    pub outbound: Amount,
+}
+
+impl ChannelFlow {
+    /// Make a new flow of a window starting at the epoch
+    pub fn new(window_start: Epoch) -> Self {
+        Self {
+            window_start,
+            ..Default::default()
+        }
+    }
+
+    /// Add the amount to the flow of the direction
+    pub fn add(
+        &mut self,
+        amount: Amount,
+        direction: FlowDirection,
+    ) -> Option<()> {
+        let flow = match direction {
+            FlowDirection::Inbound => &mut self.inbound,
+            FlowDirection::Outbound => &mut self.outbound,
+        };
+        *flow = flow.checked_add(amount)?;
+        Some(())
+    }
+}
+
+/// Read the rate limit of the token over the channel
+pub fn read_rate_limit<S: StorageRead>(
+    storage: &S,
+    channel_id: &ChannelId,
+    token: &Address,
+) -> StorageResult<Option<ChannelRateLimit>> {
+    storage.read(&rate_limit_key(channel_id, token))
+}
+
+/// Read the flow of the token over the channel in the current window
+pub fn read_channel_flow<S: StorageRead>(
+    storage: &S,
+    channel_id: &ChannelId,
+    token: &Address,
+) -> StorageResult<Option<ChannelFlow>> {
+    storage.read(&channel_flow_key(channel_id, token))
+}
+
+/// Add the amount to the flow of the token over the channel. The flow is
+/// recorded only when the channel has a rate limit for the token, and it is
+/// reset when the window has passed.
+pub fn update_channel_flow<S: StorageRead + StorageWrite>(
+    storage: &mut S,
+    channel_id: &ChannelId,
+    token: &Address,
+    amount: Amount,
+    direction: FlowDirection,
+) -> StorageResult<()> {
+    let Some(rate_limit) = read_rate_limit(storage, channel_id, token)? else {
+        return Ok(());
+    };
+    let epoch = storage.get_block_epoch()?;
+    let mut flow = read_channel_flow(storage, channel_id, token)?
+        .filter(|flow| !rate_limit.is_window_passed(flow.window_start, epoch))
+        .unwrap_or_else(|| ChannelFlow::new(epoch));
+    flow.add(amount, direction).ok_or_else(|| {
+        StorageError::new_const("The channel flow overflowed")
+    })?;
+    storage.write(&channel_flow_key(channel_id, token), flow)
+}
+
+/// Check if the channel is in the deny list
+pub fn is_channel_denied<S: StorageRead>(
+    storage: &S,
+    channel_id: &ChannelId,
+) -> StorageResult<bool> {
+    storage.has_key(&denied_channel_key(channel_id))
+}
+
+/// Check if the denom is in the deny list
+pub fn is_denom_denied<S: StorageRead>(
+    storage: &S,
+    denom: impl AsRef<str>,
+) -> StorageResult<bool> {
+    storage.has_key(&denied_denom_key(denom))
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::nam;
+    use namada_state::testing::TestState;
+
+    use super::*;
+
+    #[test]
+    fn test_channel_flow_window() {
+        let mut state = TestState::default();
+        let channel_id = ChannelId::new(0);
+        let token = nam();
+        let amount = Amount::from_u64(100);
+
+        // No flow is recorded without the rate limit
+        update_channel_flow(
+            &mut state,
+            &channel_id,
+            &token,
+            amount,
+            FlowDirection::Outbound,
+        )
+        .unwrap();
+        assert!(
+            read_channel_flow(&state, &channel_id, &token)
+                .unwrap()
+                .is_none()
+        );
+
+        let rate_limit = ChannelRateLimit {
+            inbound_quota: Amount::from_u64(150),
+            outbound_quota: Amount::from_u64(150),
+            window_epochs: 2,
+        };
+        state
+            .write(&rate_limit_key(&channel_id, &token), rate_limit.clone())
+            .unwrap();
+        for _ in 0..2 {
+            update_channel_flow(
+                &mut state,
+                &channel_id,
+                &token,
+                amount,
+                FlowDirection::Outbound,
+            )
+            .unwrap();
+        }
+        update_channel_flow(
+            &mut state,
+            &channel_id,
+            &token,
+            amount,
+            FlowDirection::Inbound,
+        )
+        .unwrap();
+        let flow = read_channel_flow(&state, &channel_id, &token)
+            .unwrap()
+            .unwrap();
+        assert_eq!(flow.inbound, amount);
+        assert_eq!(flow.outbound, Amount::from_u64(200));
+        assert!(rate_limit.check(&flow).is_err());
+
+        // Still in the window
+        state.in_mem_mut().block.epoch = Epoch(1);
+        assert!(!rate_limit.is_window_passed(flow.window_start, Epoch(1)));
+
+        // The flow is reset in the next window
+        state.in_mem_mut().block.epoch = Epoch(2);
+        update_channel_flow(
+            &mut state,
+            &channel_id,
+            &token,
+            amount,
+            FlowDirection::Outbound,
+        )
+        .unwrap();
+        let flow = read_channel_flow(&state, &channel_id, &token)
+            .unwrap()
+            .unwrap();
+        assert_eq!(flow.window_start, Epoch(2));
+        assert_eq!(flow.inbound, Amount::zero());
+        assert_eq!(flow.outbound, amount);
+        assert!(rate_limit.check(&flow).is_ok());
+    }
+
+    #[test]
+    fn test_deny_list() {
+        let mut state = TestState::default();
+        let channel_id = ChannelId::new(0);
+        let denom = "transfer/channel-0/uatom";
+        assert!(!is_channel_denied(&state, &channel_id).unwrap());
+        assert!(!is_denom_denied(&state, denom).unwrap());
+
+        state.write(&denied_channel_key(&channel_id), true).unwrap();
+        state
+            .write(&denied_denom_key(denom), denom.to_string())
+            .unwrap();
+        assert!(is_channel_denied(&state, &channel_id).unwrap());
+        assert!(is_denom_denied(&state, denom).unwrap());
+        assert!(!is_channel_denied(&state, &ChannelId::new(1)).unwrap());
+        assert!(!is_denom_denied(&state, "uatom").unwrap());
+    }
+}
@@ -2,10 +2,11 @@
 
 use std::str::FromStr;
 
+use data_encoding::HEXLOWER;
 use ibc::apps::nft_transfer::types::{PrefixedClassId, TokenId};
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::{
-    ChannelId, ClientId, ConnectionId, PortId, Sequence,
+    ChannelId, ClientId, ClientType, ConnectionId, PortId, Sequence,
 };
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath,
@@ -22,7 +23,7 @@ use thiserror::Error;
 
 use crate::event::TOKEN_EVENT_DESCRIPTOR;
 use crate::parameters::IbcParameters;
-use crate::trace::{ibc_token, ibc_token_for_nft};
+use crate::trace::{calc_hash, ibc_token, ibc_token_for_nft};
 
 const CLIENTS_COUNTER_PREFIX: &str = "clients";
 const CONNECTIONS_COUNTER_PREFIX: &str = "connections";
@@ -37,6 +38,21 @@ const MINT: &str = "mint";
 const THROUGHPUT_LIMIT: &str = "throughput_limit";
 const DEPOSIT: &str = "deposit";
 const WITHDRAW: &str = "withdraw";
+const ICA_HOST: &str = "ica_host";
+const ICA_CONTROLLER: &str = "ica_controller";
+const ICA_HOST_PARAMS: &str = "ica_host_params";
+const FORWARD: &str = "forward";
+const FEE_ENABLED: &str = "fee_enabled";
+const FEES_IN_ESCROW: &str = "fees_in_escrow";
+const PAYEE: &str = "payee";
+const COUNTERPARTY_PAYEE: &str = "counterparty_payee";
+const RATE_LIMIT: &str = "rate_limit";
+const CHANNEL_FLOW: &str = "channel_flow";
+const DENY_LIST: &str = "deny_list";
+const DENIED_CHANNEL: &str = "channel";
+const DENIED_DENOM: &str = "denom";
+const ALLOWED_CLIENT_TYPE: &str = "allowed_client_type";
+const ALLOWED_WASM_CHECKSUM: &str = "allowed_wasm_checksum";
 
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
@@ -500,6 +516,14 @@ pub fn is_ibc_key(key: &Key) -> bool {
              DbKeySeg::AddressSeg(addr) if *addr == Address::Internal(InternalAddress::Ibc))
 }
 
+/// Checks if the key is owned by an interchain account on this chain, e.g. its
+/// token balance key
+pub fn is_ica_owned_key(key: &Key) -> bool {
+    key.iter_addresses().any(|addr| {
+        matches!(addr, Address::Internal(InternalAddress::InterchainAccount(_)))
+    })
+}
+
 /// Checks if the key is an IBC commitment key
 pub fn is_ibc_commitment_key(key: &Key) -> Option<CommitmentPath> {
     let addr = Address::Internal(InternalAddress::Ibc);
@@ -637,3 +661,230 @@ pub fn withdraw_key(token: &Address) -> Key {
         .push(&token.to_string().to_db_key())
         .expect("Cannot obtain a storage key")
 }
+
+/// Returns a key of the interchain account hosted on this chain for the
+/// controller port on the counterparty chain
+pub fn ica_host_account_key(
+    connection_id: &ConnectionId,
+    controller_port_id: &PortId,
+) -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&ICA_HOST.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&connection_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&controller_port_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the interchain account on the counterparty chain
+/// controlled by the controller port on this chain
+pub fn ica_controller_account_key(
+    connection_id: &ConnectionId,
+    controller_port_id: &PortId,
+) -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&ICA_CONTROLLER.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&connection_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&controller_port_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the interchain account host parameters
+pub fn ica_host_params_key() -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&ICA_HOST_PARAMS.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the in-flight packet forwarded to the next hop
+pub fn forward_key(
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+) -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&FORWARD.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&port_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&channel_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&sequence.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the flag that the channel is fee-enabled
+pub fn fee_enabled_key(port_id: &PortId, channel_id: &ChannelId) -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&FEE_ENABLED.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&port_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&channel_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a prefix of the fees escrowed for packets sent over the channel
+pub fn fees_in_escrow_prefix(port_id: &PortId, channel_id: &ChannelId) -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&FEES_IN_ESCROW.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&port_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&channel_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the fees escrowed for the packet
+pub fn fees_in_escrow_key(
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+) -> Key {
+    fees_in_escrow_prefix(port_id, channel_id)
+        .push(&sequence.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the payee registered by the relayer on this chain
+pub fn payee_key(channel_id: &ChannelId, relayer: &Address) -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&PAYEE.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&channel_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        // Set as String to avoid triggering the relayer's VP
+        .push(&relayer.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the payee on the counterparty chain registered by the
+/// relayer
+pub fn counterparty_payee_key(
+    channel_id: &ChannelId,
+    relayer: &Address,
+) -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&COUNTERPARTY_PAYEE.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&channel_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        // Set as String to avoid triggering the relayer's VP
+        .push(&relayer.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the rate limit for the token over the channel
+pub fn rate_limit_key(channel_id: &ChannelId, token: &Address) -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&RATE_LIMIT.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&channel_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        // Set as String to avoid checking the token address
+        .push(&token.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the flow of the token over the channel in the current
+/// rate limit window
+pub fn channel_flow_key(channel_id: &ChannelId, token: &Address) -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&CHANNEL_FLOW.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&channel_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        // Set as String to avoid checking the token address
+        .push(&token.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns the channel ID and the token if the given key is a channel flow
+/// key
+pub fn is_channel_flow_key(key: &Key) -> Option<(ChannelId, Address)> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(channel_id),
+            DbKeySeg::StringSeg(token),
+        ] => {
+            if addr == &Address::Internal(InternalAddress::Ibc)
+                && prefix == CHANNEL_FLOW
+            {
+                let channel_id = ChannelId::from_str(channel_id).ok()?;
+                let token = Address::decode(token).ok()?;
+                Some((channel_id, token))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns a prefix of the denied channels
+pub fn denied_channel_prefix() -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&DENY_LIST.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&DENIED_CHANNEL.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the flag that the channel is denied
+pub fn denied_channel_key(channel_id: &ChannelId) -> Key {
+    denied_channel_prefix()
+        .push(&channel_id.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a prefix of the denied denoms
+pub fn denied_denom_prefix() -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&DENY_LIST.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&DENIED_DENOM.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the denied denom. The denom is hashed because it can
+/// include `/`, and the value of the key is the denom itself.
+pub fn denied_denom_key(denom: impl AsRef<str>) -> Key {
+    denied_denom_prefix()
+        .push(&calc_hash(denom).to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a prefix of the client types allowed by governance
+pub fn allowed_client_type_prefix() -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&ALLOWED_CLIENT_TYPE.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the flag that the client type is allowed
+pub fn allowed_client_type_key(client_type: &ClientType) -> Key {
+    allowed_client_type_prefix()
+        .push(&client_type.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a prefix of the checksums of the light clients wrapped by the wasm
+/// client allowed by governance
+pub fn allowed_wasm_checksum_prefix() -> Key {
+    Key::from(Address::Internal(InternalAddress::Ibc).to_db_key())
+        .push(&ALLOWED_WASM_CHECKSUM.to_string().to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Returns a key of the flag that the checksum of the light client wrapped by
+/// the wasm client is allowed
+pub fn allowed_wasm_checksum_key(checksum: &[u8]) -> Key {
+    allowed_wasm_checksum_prefix()
+        .push(&HEXLOWER.encode(checksum).to_db_key())
+        .expect("Cannot obtain a storage key")
+}
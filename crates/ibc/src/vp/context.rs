@@ -19,7 +19,7 @@ use namada_vp::native_vp::{CtxPreStorageRead, VpEvaluator};
 use namada_vp::VpEnv;
 
 use crate::event::IbcEvent;
-use crate::storage::{self, is_ibc_key};
+use crate::storage::{self, is_ibc_key, is_ica_owned_key};
 use crate::{IbcCommonContext, IbcStorageContext};
 
 /// Pseudo execution environment context for ibc native vp
@@ -71,7 +71,7 @@ where
         self.storage
             .store
             .keys()
-            .filter(|k| is_ibc_key(k))
+            .filter(|k| is_ibc_key(k) || is_ica_owned_key(k))
             .collect()
     }
 
@@ -22,6 +22,7 @@ use namada_state::write_log::StorageModification;
 use namada_state::StateRead;
 use namada_systems::trans_token::{self as token, Amount};
 use namada_systems::{governance, parameters, proof_of_stake};
+use namada_tx::action::{Action, IbcAction, Read};
 use namada_tx::BatchedTxRef;
 use namada_vp::native_vp::{
     self, Ctx, CtxPreStorageRead, NativeVp, VpEvaluator,
@@ -29,16 +30,22 @@ use namada_vp::native_vp::{
 use namada_vp::VpEnv;
 use thiserror::Error;
 
-use crate::core::host::types::identifiers::ChainId as IbcChainId;
+use crate::apps::nft_transfer::types::packet::PacketData as NftPacketData;
+use crate::apps::transfer::types::packet::PacketData;
+use crate::core::channel::types::msgs::PacketMsg;
+use crate::core::handler::types::msgs::MsgEnvelope;
+use crate::core::host::types::identifiers::{ChainId as IbcChainId, ChannelId};
 use crate::event::IbcEvent;
+use crate::rate_limit::{self, ChannelFlow};
 use crate::storage::{
-    deposit_key, get_limits, is_ibc_key, is_ibc_trace_key, mint_amount_key,
-    withdraw_key,
+    channel_flow_key, deposit_key, get_limits, is_channel_flow_key, is_ibc_key,
+    is_ibc_trace_key, is_ica_owned_key, mint_amount_key, withdraw_key,
 };
 use crate::trace::calc_hash;
 use crate::{
-    Error as ActionError, IbcActions, NftTransferModule, TransferModule,
-    ValidationParams,
+    decode_message, Error as ActionError, IbcActions, IbcMessage,
+    InterchainAccountControllerModule, InterchainAccountHostModule,
+    NftTransferModule, TransferModule, ValidationParams,
 };
 
 #[allow(missing_docs)]
@@ -60,6 +67,8 @@ pub enum Error {
     IbcEvent(String),
     #[error("IBC rate limit: {0}")]
     RateLimit(String),
+    #[error("IBC VP error: Denied transfer: {0}")]
+    DeniedTransfer(String),
     #[error("Arithmetic {0}")]
     Arith(#[from] arith::Error),
 }
@@ -146,7 +155,7 @@ where
         &'view self,
         batched_tx: &BatchedTxRef<'_>,
         keys_changed: &BTreeSet<Key>,
-        _verifiers: &BTreeSet<Address>,
+        verifiers: &BTreeSet<Address>,
     ) -> VpResult<()> {
         // Is VP triggered by a governance proposal?
         if Gov::is_proposal_accepted(
@@ -171,12 +180,24 @@ where
         // Validate the state according to the given IBC message
         self.validate_with_msg(&tx_data)?;
 
+        // Validate the channel and the denom against the deny list
+        self.validate_deny_list(&tx_data)?;
+
+        // Validate the owner of an interchain account controller
+        self.validate_ica_owner(&tx_data, verifiers)?;
+
+        // Validate the relayer registering its payee
+        self.validate_fee_relayer(&tx_data, verifiers)?;
+
         // Validate the denom store if a denom key has been changed
         self.validate_trace(keys_changed)?;
 
         // Check the limits
         self.check_limits(keys_changed)?;
 
+        // Check the per-channel rate limits
+        self.check_channel_limits(keys_changed)?;
+
         Ok(())
     }
 }
@@ -253,14 +274,20 @@ where
         actions.add_transfer_module(module);
         let module = NftTransferModule::<_, Token>::new(ctx.clone());
         actions.add_transfer_module(module);
+        let module = InterchainAccountHostModule::<_, Token>::new(ctx.clone());
+        actions.add_transfer_module(module);
+        let module = InterchainAccountControllerModule::new(ctx.clone());
+        actions.add_transfer_module(module);
         // Charge gas for the expensive execution
         self.ctx
             .charge_gas(IBC_ACTION_EXECUTE_GAS)
             .map_err(Error::NativeVpError)?;
         actions.execute::<Transfer>(tx_data)?;
 
-        let changed_ibc_keys: HashSet<&Key> =
-            keys_changed.iter().filter(|k| is_ibc_key(k)).collect();
+        let changed_ibc_keys: HashSet<&Key> = keys_changed
+            .iter()
+            .filter(|k| is_ibc_key(k) || is_ica_owned_key(k))
+            .collect();
         if changed_ibc_keys.len() != ctx.borrow().get_changed_keys().len() {
             return Err(Error::StateChange(format!(
                 "The changed keys mismatched: Actual {:?}, Expected {:?}",
@@ -309,7 +336,11 @@ where
 
         let module = TransferModule::new(ctx.clone(), verifiers);
         actions.add_transfer_module(module);
-        let module = NftTransferModule::<_, Token>::new(ctx);
+        let module = NftTransferModule::<_, Token>::new(ctx.clone());
+        actions.add_transfer_module(module);
+        let module = InterchainAccountHostModule::<_, Token>::new(ctx.clone());
+        actions.add_transfer_module(module);
+        let module = InterchainAccountControllerModule::new(ctx);
         actions.add_transfer_module(module);
         // Charge gas for the expensive validation
         self.ctx
@@ -320,6 +351,138 @@ where
             .map_err(Error::IbcAction)
     }
 
+    /// The owner of an interchain account controller port should authorize
+    /// the registration and the transactions sent to its account
+    fn validate_ica_owner(
+        &'view self,
+        tx_data: &[u8],
+        verifiers: &BTreeSet<Address>,
+    ) -> VpResult<()> {
+        let IbcMessage::InterchainAccount(msg) =
+            decode_message::<Transfer>(tx_data)?
+        else {
+            return Ok(());
+        };
+        let owner = msg.owner();
+        let is_authorized =
+            self.ctx.read_actions()?.into_iter().any(|action| {
+                matches!(
+                    action,
+                    Action::Ibc(IbcAction::ControlInterchainAccount(addr))
+                        if &addr == owner
+                )
+            });
+        if !is_authorized || !verifiers.contains(owner) {
+            return Err(Error::StateChange(format!(
+                "The interchain account of {owner} isn't authorized by the \
+                 owner"
+            )));
+        }
+        Ok(())
+    }
+
+    /// A relayer should authorize the registration of its payee
+    fn validate_fee_relayer(
+        &'view self,
+        tx_data: &[u8],
+        verifiers: &BTreeSet<Address>,
+    ) -> VpResult<()> {
+        let IbcMessage::Fee(msg) = decode_message::<Transfer>(tx_data)? else {
+            return Ok(());
+        };
+        let Some(relayer) = msg.relayer() else {
+            return Ok(());
+        };
+        let is_authorized =
+            self.ctx.read_actions()?.into_iter().any(|action| {
+                matches!(
+                    action,
+                    Action::Ibc(IbcAction::RegisterPayee(addr))
+                        if &addr == relayer
+                )
+            });
+        if !is_authorized || !verifiers.contains(relayer) {
+            return Err(Error::StateChange(format!(
+                "The payee registration isn't authorized by the relayer \
+                 {relayer}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Transfers over a denied channel or of a denied denom aren't allowed.
+    /// The acknowledgement and the timeout of a packet sent before the
+    /// denial are still allowed to refund the token.
+    fn validate_deny_list(&'view self, tx_data: &[u8]) -> VpResult<()> {
+        let (channel_id, denoms) = match decode_message::<Transfer>(tx_data)? {
+            IbcMessage::Transfer(msg) => {
+                let denom = &msg.message.packet_data.token.denom;
+                (
+                    msg.message.chan_id_on_a.clone(),
+                    vec![denom.to_string(), denom.base_denom.to_string()],
+                )
+            }
+            IbcMessage::NftTransfer(msg) => {
+                let class_id = &msg.message.packet_data.class_id;
+                (
+                    msg.message.chan_id_on_a.clone(),
+                    vec![
+                        class_id.to_string(),
+                        class_id.base_class_id.to_string(),
+                    ],
+                )
+            }
+            IbcMessage::Envelope(envelope) => {
+                let MsgEnvelope::Packet(PacketMsg::Recv(msg)) = *envelope
+                else {
+                    return Ok(());
+                };
+                let packet = msg.packet;
+                // The packet data is validated by the module
+                let denoms = if let Ok(data) =
+                    serde_json::from_slice::<PacketData>(&packet.data)
+                {
+                    let denom = data.token.denom;
+                    vec![denom.to_string(), denom.base_denom.to_string()]
+                } else if let Ok(data) =
+                    serde_json::from_slice::<NftPacketData>(&packet.data)
+                {
+                    let class_id = data.class_id;
+                    vec![
+                        class_id.to_string(),
+                        class_id.base_class_id.to_string(),
+                    ]
+                } else {
+                    vec![]
+                };
+                (packet.chan_id_on_b, denoms)
+            }
+            _ => return Ok(()),
+        };
+        self.check_denied_channel(&channel_id)?;
+        for denom in denoms {
+            if rate_limit::is_denom_denied(&self.ctx.pre(), &denom)
+                .map_err(Error::NativeVpError)?
+            {
+                return Err(Error::DeniedTransfer(format!(
+                    "The denom {denom} is in the deny list"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_denied_channel(&self, channel_id: &ChannelId) -> VpResult<()> {
+        if rate_limit::is_channel_denied(&self.ctx.pre(), channel_id)
+            .map_err(Error::NativeVpError)?
+        {
+            return Err(Error::DeniedTransfer(format!(
+                "The channel {channel_id} is in the deny list"
+            )));
+        }
+        Ok(())
+    }
+
     /// Retrieve the validation params
     pub fn validation_params(&'view self) -> VpResult<ValidationParams> {
         use std::str::FromStr;
@@ -414,6 +577,40 @@ where
         Ok(true)
     }
 
+    fn check_channel_limits(
+        &self,
+        keys_changed: &BTreeSet<Key>,
+    ) -> VpResult<()> {
+        for (channel_id, token) in
+            keys_changed.iter().filter_map(is_channel_flow_key)
+        {
+            let Some(rate_limit) = rate_limit::read_rate_limit(
+                &self.ctx.pre(),
+                &channel_id,
+                &token,
+            )
+            .map_err(Error::NativeVpError)?
+            else {
+                return Err(Error::RateLimit(format!(
+                    "The flow of {token} over {channel_id} was updated \
+                     without the rate limit"
+                )));
+            };
+            let flow: ChannelFlow = self
+                .ctx
+                .read_post(&channel_flow_key(&channel_id, &token))
+                .map_err(Error::NativeVpError)?
+                .unwrap_or_default();
+            rate_limit.check(&flow).map_err(|e| {
+                Error::RateLimit(format!(
+                    "Transfer of {token} over {channel_id} exceeding the \
+                     quota is not allowed: {e}"
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
     fn calc_throughput(&self, token: &Address) -> VpResult<Amount> {
         let deposit_key = deposit_key(token);
         let deposit: Amount = self
@@ -814,7 +814,6 @@ impl Client for BenchShell {
         } else {
             let ctx = RequestCtx {
                 state: &self.state,
-                event_log: self.event_log(),
                 vp_wasm_cache: self.vp_wasm_cache.read_only(),
                 tx_wasm_cache: self.tx_wasm_cache.read_only(),
                 storage_read_past_height_limit: None,
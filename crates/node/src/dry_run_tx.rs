@@ -138,7 +138,6 @@ where
 mod test {
     use borsh::BorshDeserialize;
     use borsh_ext::BorshSerializeExt;
-    use namada_sdk::events::log::EventLog;
     use namada_sdk::hash::Hash;
     use namada_sdk::queries::{
         Client, EncodedResponseQuery, RequestCtx, RequestQuery, Router, RPC,
@@ -166,8 +165,6 @@ mod test {
         pub rpc: RPC,
         /// state
         pub state: TestState,
-        /// event log
-        pub event_log: EventLog,
         /// VP wasm compilation cache
         pub vp_wasm_cache: VpCache<WasmCacheRoAccess>,
         /// tx wasm compilation cache
@@ -204,7 +201,6 @@ mod test {
                 .db_write(&gas_scale_key, 100_000_000_u64.serialize_to_vec())
                 .expect("Gas scale parameter must be initialized in storage");
 
-            let event_log = EventLog::default();
             let (vp_wasm_cache, vp_cache_dir) =
                 wasm::compilation_cache::common::testing::cache();
             let (tx_wasm_cache, tx_cache_dir) =
@@ -212,7 +208,6 @@ mod test {
             Self {
                 rpc,
                 state,
-                event_log,
                 vp_wasm_cache: vp_wasm_cache.read_only(),
                 tx_wasm_cache: tx_wasm_cache.read_only(),
                 vp_cache_dir,
@@ -263,7 +258,6 @@ mod test {
             } else {
                 let ctx = RequestCtx {
                     state: self.state.read_only(),
-                    event_log: &self.event_log,
                     vp_wasm_cache: self.vp_wasm_cache.clone(),
                     tx_wasm_cache: self.tx_wasm_cache.clone(),
                     storage_read_past_height_limit: None,
@@ -183,15 +183,15 @@ impl Shell {
             Request::ListSnapshots => {
                 self.list_snapshots().map(Response::ListSnapshots)
             }
-            Request::OfferSnapshot(_) => {
-                Ok(Response::OfferSnapshot(Default::default()))
+            Request::OfferSnapshot(req) => {
+                Ok(Response::OfferSnapshot(self.offer_snapshot(req)))
             }
             Request::LoadSnapshotChunk(req) => self
                 .load_snapshot_chunk(req)
                 .map(Response::LoadSnapshotChunk),
-            Request::ApplySnapshotChunk(_) => {
-                Ok(Response::ApplySnapshotChunk(Default::default()))
-            }
+            Request::ApplySnapshotChunk(req) => self
+                .apply_snapshot_chunk(req)
+                .map(Response::ApplySnapshotChunk),
         }
     }
 
@@ -384,6 +384,134 @@ pub fn query_db(
     );
 }
 
+/// List the column families of Namada ledger node's DB with their estimated
+/// number of keys and size
+pub fn db_column_families(config: config::Ledger) -> Result<(), shell::Error> {
+    let db = open_db_read_only(&config)?;
+    let stats = db.column_family_stats().map_err(db_error)?;
+    println!(
+        "{:<20} {:>15} {:>15}",
+        "Column family", "Keys (est.)", "Size"
+    );
+    for storage::ColumnFamilyStats {
+        name,
+        num_keys,
+        size,
+    } in stats
+    {
+        let size = Byte::from_bytes(u128::from(size))
+            .get_appropriate_unit(true)
+            .to_string();
+        println!("{name:<20} {num_keys:>15} {size:>15}");
+    }
+    Ok(())
+}
+
+/// Read the value of a key from Namada ledger node's DB
+pub fn db_get(
+    config: config::Ledger,
+    args::LedgerDbGet {
+        key,
+        height,
+        cf,
+        type_hash,
+    }: args::LedgerDbGet,
+) -> Result<(), shell::Error> {
+    let db = open_db_read_only(&config)?;
+    match db
+        .read_value_at_height(&cf, &key, height)
+        .map_err(db_error)?
+    {
+        Some(bytes) => {
+            println!("{key} = {}", format_db_value(&bytes, type_hash.as_ref()))
+        }
+        None => println!("No value found under key {key}"),
+    }
+    Ok(())
+}
+
+/// Read the key-vals under a key prefix from Namada ledger node's DB
+pub fn db_scan(
+    config: config::Ledger,
+    args::LedgerDbScan {
+        prefix,
+        height,
+        cf,
+        type_hash,
+    }: args::LedgerDbScan,
+) -> Result<(), shell::Error> {
+    let db = open_db_read_only(&config)?;
+    let key_vals = db
+        .read_prefix_at_height(&cf, prefix.as_ref(), height)
+        .map_err(db_error)?;
+    for (key, bytes) in &key_vals {
+        println!("{key} = {}", format_db_value(bytes, type_hash.as_ref()));
+    }
+    println!("Found {} keys", key_vals.len());
+    Ok(())
+}
+
+/// Verify that the merkle tree stores and diffs in Namada ledger node's DB
+/// reproduce the committed merkle root
+pub fn db_verify_merkle(
+    config: config::Ledger,
+    args::LedgerDbVerifyMerkle { height }: args::LedgerDbVerifyMerkle,
+) -> Result<(), shell::Error> {
+    let (height, root) = shell::verify_merkle_tree(config, height)?;
+    println!("The merkle tree at height {height} is valid with root {root}");
+    Ok(())
+}
+
+/// Compact the column families of Namada ledger node's DB
+pub fn db_compact(
+    config: config::Ledger,
+    args::LedgerDbCompact { cf }: args::LedgerDbCompact,
+) -> Result<(), shell::Error> {
+    let db_path = config.shell.db_dir(&config.chain_id);
+    let db = storage::open(db_path, false, None).map_err(db_error)?;
+    tracing::info!("Compacting the DB...");
+    db.compact(cf.as_ref()).map_err(db_error)?;
+    tracing::info!("Done compacting the DB");
+    Ok(())
+}
+
+/// Open Namada ledger node's DB in read-only mode
+fn open_db_read_only(
+    config: &config::Ledger,
+) -> Result<storage::PersistentDB, shell::Error> {
+    let db_path = config.shell.db_dir(&config.chain_id);
+    storage::open(db_path, true, None).map_err(db_error)
+}
+
+fn db_error(error: namada_sdk::state::DbError) -> shell::Error {
+    shell::Error::Storage(namada_sdk::state::StorageError::new(error))
+}
+
+/// Format a value read from the DB, decoding it with the deserializer of the
+/// given type hash, if any
+fn format_db_value(bytes: &[u8], type_hash: Option<&[u8; 32]>) -> String {
+    let hex_bytes = HEXUPPER.encode(bytes);
+    #[cfg(feature = "migrations")]
+    if let Some(type_hash) = type_hash {
+        return match namada_migrations::get_deserializer(type_hash) {
+            Some(deserializer) => {
+                deserializer(bytes.to_vec()).unwrap_or_else(|| {
+                    format!("{hex_bytes} (not decodable with the given type)")
+                })
+            }
+            None => format!("{hex_bytes} (unknown type hash)"),
+        };
+    }
+    #[cfg(not(feature = "migrations"))]
+    if type_hash.is_some() {
+        tracing::warn!(
+            "Values can only be decoded if built with the \"migrations\" \
+             feature"
+        );
+    }
+    hex_bytes
+}
+
 /// Change the funds of an account in-place. Use with
 /// caution, as this modifies state in storage without
 /// going through the consensus protocol.
@@ -548,11 +548,14 @@ where
                 shell_params.state,
             )
             .map_err(Error::StorageError)?;
+            let sponsor = tx.fee_sponsor();
+            let fee_payer =
+                get_fee_payer(shell_params.state, tx, wrapper, fees)?;
 
             let balance = token::read_balance(
                 shell_params.state,
                 &wrapper.fee.token,
-                &wrapper.fee_payer(),
+                &fee_payer,
             )
             .map_err(Error::StorageError)?;
 
@@ -562,13 +565,21 @@ where
                 fee_token_transfer(
                     shell_params.state,
                     &wrapper.fee.token,
-                    &wrapper.fee_payer(),
+                    &fee_payer,
                     block_proposer,
                     fees,
                 )?;
 
                 (post_bal, None)
             } else {
+                if sponsor.is_some() {
+                    // A MASP fee payment would credit the wrapper signer, not
+                    // the sponsor
+                    return Err(Error::FeeError(format!(
+                        "The fee sponsor {fee_payer} has insufficient balance \
+                         for fee payment"
+                    )));
+                }
                 // See if the first inner transaction of the batch pays the fees
                 // with a masp unshield
                 if let Ok(Some(valid_batched_tx_result)) =
@@ -577,7 +588,7 @@ where
                     let balance = token::read_balance(
                         shell_params.state,
                         &wrapper.fee.token,
-                        &wrapper.fee_payer(),
+                        &fee_payer,
                     )
                     .expect("Could not read balance key from storage");
 
@@ -588,7 +599,7 @@ where
                             fee_token_transfer(
                                 shell_params.state,
                                 &wrapper.fee.token,
-                                &wrapper.fee_payer(),
+                                &fee_payer,
                                 block_proposer,
                                 fees,
                             )?;
@@ -625,6 +636,18 @@ where
                 }
             };
 
+            if let Some(sponsor) = sponsor {
+                token::spend_fee_grant(
+                    &mut shell_params.state.with_tx_writes(),
+                    &wrapper.fee.token,
+                    sponsor,
+                    &wrapper.fee_payer(),
+                    fees,
+                    &inner_tx_code_hashes(tx),
+                )
+                .map_err(Error::StorageError)?;
+            }
+
             let target_post_balance = Some(
                 token::read_balance(
                     shell_params.state,
@@ -647,7 +670,7 @@ where
                     descriptor: FEE_PAYMENT_DESCRIPTOR,
                     level: EventLevel::Tx,
                     operation: TokenOperation::transfer(
-                        UserAccount::Internal(wrapper.fee_payer()),
+                        UserAccount::Internal(fee_payer),
                         UserAccount::Internal(block_proposer.clone()),
                         wrapper.fee.token.clone(),
                         fees.into(),
@@ -799,6 +822,47 @@ where
     Ok(valid_batched_tx_result)
 }
 
+/// Get the account paying the fees of the wrapper. This is the sponsor of the
+/// fees, if the tx has one whose fee grant to the wrapper signer covers them,
+/// and otherwise the wrapper signer.
+fn get_fee_payer<S>(
+    state: &S,
+    tx: &Tx,
+    wrapper: &WrapperTx,
+    fees: Amount,
+) -> Result<Address>
+where
+    S: StorageRead,
+{
+    match tx.fee_sponsor() {
+        Some(sponsor) => {
+            token::check_fee_grant(
+                state,
+                &wrapper.fee.token,
+                sponsor,
+                &wrapper.fee_payer(),
+                fees,
+                &inner_tx_code_hashes(tx),
+            )
+            .map_err(|err| Error::FeeError(err.to_string()))?;
+            Ok(sponsor.clone())
+        }
+        None => Ok(wrapper.fee_payer()),
+    }
+}
+
+/// Get the code hashes of the inner txs of the batch
+fn inner_tx_code_hashes(tx: &Tx) -> Vec<Hash> {
+    tx.commitments()
+        .iter()
+        .filter_map(|cmt| {
+            tx.get_section(cmt.code_sechash())
+                .and_then(|section| section.code_sec())
+                .map(|code_sec| code_sec.code.hash())
+        })
+        .collect()
+}
+
 // Manage the token transfer for the fee payment. If an error is detected the
 // write log is dropped to prevent committing an inconsistent state. Propagates
 // the result to the caller
@@ -812,6 +876,17 @@ fn fee_token_transfer<WLS>(
 where
     WLS: State + StorageRead + TxWrites,
 {
+    // The tokens still locked by a vesting schedule cannot pay for fees
+    let spendable_balance = token::read_spendable_balance(&*state, token, src)
+        .map_err(Error::StorageError)?;
+    if spendable_balance < amount {
+        state.write_log_mut().drop_tx();
+
+        return Err(Error::FeeError(format!(
+            "{src} has insufficient unlocked balance for fee payment"
+        )));
+    }
+
     token::transfer(&mut state.with_tx_writes(), token, src, dest, amount)
         .map_err(|err| {
             state.write_log_mut().drop_tx();
@@ -844,16 +919,26 @@ where
                 shell_params.state,
             )
             .map_err(Error::StorageError)?;
+            let fee_payer =
+                get_fee_payer(shell_params.state, tx, wrapper, fees)?;
 
-            let balance = token::read_balance(
+            let balance = token::read_spendable_balance(
                 shell_params.state,
                 &wrapper.fee.token,
-                &wrapper.fee_payer(),
+                &fee_payer,
             )
             .map_err(Error::StorageError)?;
 
             checked!(balance - fees).map_or_else(
                 |_| {
+                    if tx.fee_sponsor().is_some() {
+                        // A MASP fee payment would credit the wrapper signer,
+                        // not the sponsor
+                        return Err(Error::FeeError(format!(
+                            "The fee sponsor {fee_payer} has insufficient \
+                             balance for fee payment"
+                        )));
+                    }
                     // See if the first inner transaction of the batch pays
                     // the fees with a masp unshield
                     if let Ok(valid_batched_tx_result @ Some(_)) =
@@ -863,10 +948,10 @@ where
                             &TxIndex::default(),
                         )
                     {
-                        let balance = token::read_balance(
+                        let balance = token::read_spendable_balance(
                             shell_params.state,
                             &wrapper.fee.token,
-                            &wrapper.fee_payer(),
+                            &fee_payer,
                         )
                         .map_err(Error::StorageError)?;
 
@@ -1277,7 +1362,8 @@ where
                                 .map_err(Error::NutNativeVpError)
                         }
                         internal_addr @ (InternalAddress::IbcToken(_)
-                        | InternalAddress::Erc20(_)) => {
+                        | InternalAddress::Erc20(_)
+                        | InternalAddress::FactoryToken(_)) => {
                             // The address should be a part of a multitoken
                             // key
                             verifiers
@@ -1290,6 +1376,21 @@ where
                                     )
                                 })
                         }
+                        internal_addr @ InternalAddress::InterchainAccount(
+                            _,
+                        ) => {
+                            // The account can only be driven by the IBC host
+                            // module, which is checked by the IBC VP
+                            verifiers
+                                .contains(&Address::Internal(
+                                    InternalAddress::Ibc,
+                                ))
+                                .ok_or_else(|| {
+                                    Error::AccessForbidden(
+                                        internal_addr.clone(),
+                                    )
+                                })
+                        }
                         InternalAddress::TempStorage => Err(
                             // Temp storage changes must never be committed
                             Error::AccessForbidden((*internal_addr).clone()),
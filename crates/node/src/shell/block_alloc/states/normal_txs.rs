@@ -15,8 +15,16 @@ impl TryAlloc for BlockAllocator<BuildingNormalTxBatch> {
         &mut self,
         resource_required: Self::Resources<'_>,
     ) -> Result<(), AllocFailure> {
+        let occupied_space = self.normal_txs.space.occupied;
         self.normal_txs.space.try_dump(resource_required.tx)?;
-        self.normal_txs.gas.try_dump(resource_required.gas)
+        self.normal_txs
+            .gas
+            .try_dump(resource_required.gas)
+            .map_err(|err| {
+                // Release the space of the tx, as it won't be included
+                self.normal_txs.space.occupied = occupied_space;
+                err
+            })
     }
 }
 
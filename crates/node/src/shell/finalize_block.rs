@@ -152,6 +152,14 @@ where
                 height,
             },
         );
+        // The gas limits of the wrappers fill the block gas, as in the
+        // `BlockAllocator`
+        let block_gas = successful_wrappers
+            .iter()
+            .filter_map(|wrapper| wrapper.tx.header().wrapper())
+            .fold(0_u64, |acc, wrapper| {
+                acc.saturating_add(u64::from(wrapper.gas_limit))
+            });
 
         // Execute inner transactions
         self.execute_tx_batches(
@@ -193,6 +201,9 @@ where
             self.state.write(&anchor_key, ())?;
         }
 
+        // Adjust the base fees for the next block to the gas used
+        parameters::update_base_fees(&mut self.state, block_gas)?;
+
         if update_for_tendermint {
             self.update_epoch(&mut response);
             // send the latest oracle configs. These may have changed due to
@@ -205,7 +216,8 @@ where
             native_block_proposer_address,
         )?;
 
-        self.event_log_mut().emit_many(response.events.clone());
+        // the events are written to the log with the block on commit
+        self.block_events.clone_from(&response.events);
         tracing::debug!("End finalize_block {height} of epoch {current_epoch}");
 
         Ok(response)
@@ -261,9 +273,10 @@ where
     }
 
     /// Calculate the new inflation rate, mint the new tokens to the PoS
-    /// account, then update the reward products of the validators. This is
-    /// executed while finalizing the first block of a new epoch and is applied
-    /// with respect to the previous epoch.
+    /// account, then update the reward products of the validators and restake
+    /// the rewards of the bonds that opted into it. This is executed while
+    /// finalizing the first block of a new epoch and is applied with respect
+    /// to the previous epoch.
     fn apply_inflation(
         &mut self,
         current_epoch: Epoch,
@@ -299,6 +312,15 @@ where
             token::Store<_>,
         >(&mut self.state, last_epoch, num_blocks_in_last_epoch)?;
 
+        // Restake the rewards of the bonds that opted into it. This has to be
+        // done after the PoS inflation so that the rewards of the last epoch
+        // are included
+        proof_of_stake::rewards::restake_rewards::<
+            _,
+            governance::Store<_>,
+            token::Store<_>,
+        >(&mut self.state, events, current_epoch)?;
+
         // Pgf inflation
         pgf_apply_inflation(self.state.restrict_writes_to_write_log())?;
 
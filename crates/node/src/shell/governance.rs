@@ -1,31 +1,40 @@
-use namada_sdk::collections::HashMap;
+use namada_sdk::arith::checked;
+use namada_sdk::collections::{HashMap, HashSet};
 use namada_sdk::events::extend::{ComposeEvent, Height, UserAccount};
 use namada_sdk::events::{EmitEvents, EventLevel};
 use namada_sdk::governance::event::GovernanceEvent;
+use namada_sdk::governance::parameters::GovernanceParameters;
+use namada_sdk::governance::pgf::parameters::PgfParameters;
 use namada_sdk::governance::pgf::storage::keys as pgf_storage;
 use namada_sdk::governance::pgf::storage::steward::StewardDetail;
 use namada_sdk::governance::pgf::{storage as pgf, ADDRESS};
 use namada_sdk::governance::storage::proposal::{
-    AddRemove, PGFAction, PGFTarget, ProposalType, StoragePgfFunding,
+    AddRemove, PGFAction, PGFTarget, ParameterChange, ParameterChangeError,
+    ParameterModule, ProposalType, StoragePgfFunding,
 };
 use namada_sdk::governance::storage::{keys as gov_storage, load_proposals};
 use namada_sdk::governance::utils::{
-    compute_proposal_result, ProposalVotes, TallyResult, TallyType, VotePower,
+    compute_proposal_result, conviction_voting_power, ProposalVotes,
+    TallyResult, TallyType, TallyWeighting, VotePower,
 };
 pub use namada_sdk::governance::Store;
 use namada_sdk::governance::{
     storage as gov_api, ProposalVote, ADDRESS as gov_address,
 };
 use namada_sdk::proof_of_stake::bond_amount;
-use namada_sdk::proof_of_stake::parameters::PosParams;
+use namada_sdk::proof_of_stake::parameters::{OwnedPosParams, PosParams};
+use namada_sdk::proof_of_stake::queries::bonds_and_unbonds;
 use namada_sdk::proof_of_stake::storage::{
+    read_below_capacity_validator_set_addresses,
+    read_consensus_validator_set_addresses, read_owned_pos_params,
     read_total_active_stake, read_validator_stake, validator_state_handle,
+    write_pos_params,
 };
 use namada_sdk::proof_of_stake::types::{BondId, ValidatorState};
 use namada_sdk::state::StorageWrite;
 use namada_sdk::storage::Epoch;
 use namada_sdk::token::event::{TokenEvent, TokenOperation};
-use namada_sdk::token::read_balance;
+use namada_sdk::token::{read_balance, read_denom, ShieldedParams};
 use namada_sdk::tx::{Code, Data};
 use namada_sdk::{encode, ibc, parameters};
 
@@ -82,6 +91,9 @@ where
 {
     let mut proposals_result = ProposalsResult::default();
     let params = read_pos_params::<_, Store<_>>(&shell.state)?;
+    let conviction_epochs = gov_api::get_conviction_epochs(&shell.state)?;
+    let mut conviction_voting_powers: HashMap<Epoch, ConvictionVotingPowers> =
+        HashMap::default();
 
     for id in proposal_ids {
         let proposal_funds_key = gov_storage::get_funds_key(id);
@@ -99,9 +111,33 @@ where
             force_read(&shell.state, &proposal_author_key)?;
 
         let is_steward = pgf::is_steward(&shell.state, &proposal_author)?;
-
-        let total_active_voting_power =
-            read_total_active_stake(&shell.state, &params, proposal_end_epoch)?;
+        let tally_weighting =
+            gov_api::get_proposal_tally_weighting(&shell.state, id)?;
+
+        // The weighted voting powers are shared by all the proposals tallied
+        // in the same epoch
+        let conviction_powers = match tally_weighting {
+            TallyWeighting::Stake => None,
+            TallyWeighting::Conviction => {
+                if !conviction_voting_powers.contains_key(&proposal_end_epoch) {
+                    let powers = compute_conviction_voting_powers(
+                        &shell.state,
+                        proposal_end_epoch,
+                        conviction_epochs,
+                    )?;
+                    conviction_voting_powers.insert(proposal_end_epoch, powers);
+                }
+                conviction_voting_powers.get(&proposal_end_epoch)
+            }
+        };
+        let total_voting_power = match conviction_powers {
+            Some(powers) => powers.total,
+            None => read_total_active_stake(
+                &shell.state,
+                &params,
+                proposal_end_epoch,
+            )?,
+        };
 
         let tally_type = TallyType::from(proposal_type.clone(), is_steward);
         let votes = compute_proposal_votes(
@@ -109,13 +145,12 @@ where
             &params,
             id,
             proposal_end_epoch,
+            tally_weighting,
+            conviction_powers,
         )?;
-        let proposal_result = compute_proposal_result(
-            votes,
-            total_active_voting_power,
-            tally_type,
-        )
-        .expect("Proposal result calculation must not over/underflow");
+        let proposal_result =
+            compute_proposal_result(votes, total_voting_power, tally_type)
+                .expect("Proposal result calculation must not over/underflow");
         gov_api::write_proposal_result(&mut shell.state, id, proposal_result)?;
 
         let transfer_address = match proposal_result.result {
@@ -189,6 +224,25 @@ where
                             id
                         );
 
+                        GovernanceEvent::passed_proposal(id, false, false)
+                    }
+                    ProposalType::ParameterChange(changes) => {
+                        let result = execute_parameter_change_proposal(
+                            &mut shell.state,
+                            changes,
+                        )?;
+                        tracing::info!(
+                            "Governance proposal #{} for parameter changes \
+                             has passed and been executed. {}.",
+                            id,
+                            if result {
+                                "The parameters have been updated successfully"
+                            } else {
+                                "FAILURE trying to apply the parameter changes \
+                                 - no parameter was updated"
+                            }
+                        );
+
                         GovernanceEvent::passed_proposal(id, false, false)
                     }
                 };
@@ -308,6 +362,8 @@ fn compute_proposal_votes<S>(
     params: &PosParams,
     proposal_id: u64,
     epoch: Epoch,
+    tally_weighting: TallyWeighting,
+    conviction_powers: Option<&ConvictionVotingPowers>,
 ) -> namada_sdk::state::StorageResult<ProposalVotes>
 where
     S: StorageRead,
@@ -351,9 +407,17 @@ where
         if vote.is_validator() {
             let vote_data = vote.data.clone();
 
-            let validator_stake =
-                read_validator_stake(storage, params, &validator, epoch)
-                    .unwrap_or_default();
+            let validator_stake = match conviction_powers {
+                Some(powers) => powers
+                    .validators
+                    .get(&validator)
+                    .copied()
+                    .unwrap_or_default(),
+                None => {
+                    read_validator_stake(storage, params, &validator, epoch)
+                        .unwrap_or_default()
+                }
+            };
 
             validators_vote.insert(validator.clone(), vote_data);
             validator_voting_power.insert(validator, validator_stake);
@@ -369,6 +433,12 @@ where
                 bond_amount::<_, Store<_>>(storage, &bond_id, epoch);
 
             if let Ok(stake) = delegator_stake {
+                let stake = match conviction_powers {
+                    Some(powers) => {
+                        powers.bonds.get(&bond_id).copied().unwrap_or_default()
+                    }
+                    None => stake,
+                };
                 delegators_vote.insert(delegator.clone(), vote_data);
                 delegator_voting_power
                     .entry(delegator)
@@ -385,9 +455,65 @@ where
         validator_voting_power,
         delegators_vote,
         delegator_voting_power,
+        tally_weighting,
     })
 }
 
+/// The voting powers weighted by the number of epochs for which each bond has
+/// contributed to the stake.
+#[derive(Default)]
+struct ConvictionVotingPowers {
+    /// The weighted voting power of every bond
+    bonds: HashMap<BondId, VotePower>,
+    /// The weighted voting power of every validator, from all of its bonds
+    validators: HashMap<Address, VotePower>,
+    /// The weighted voting power of the active validators
+    total: VotePower,
+}
+
+/// Compute the conviction weighted voting powers of all the bonds, of their
+/// validators and of the active validator set in the given epoch.
+fn compute_conviction_voting_powers<S>(
+    storage: &S,
+    epoch: Epoch,
+    conviction_epochs: u64,
+) -> namada_sdk::state::StorageResult<ConvictionVotingPowers>
+where
+    S: StorageRead,
+{
+    let active_validators: HashSet<Address> =
+        read_consensus_validator_set_addresses(storage, epoch)?
+            .into_iter()
+            .chain(read_below_capacity_validator_set_addresses(storage, epoch)?)
+            .collect();
+
+    let mut powers = ConvictionVotingPowers::default();
+    for (bond_id, details) in
+        bonds_and_unbonds::<_, Store<_>>(storage, None, None)?
+    {
+        let bonds = details.bonds.into_iter().map(|bond| {
+            let amount = bond
+                .amount
+                .checked_sub(bond.slashed_amount.unwrap_or_default())
+                .unwrap_or_default();
+            (bond.start, amount)
+        });
+        let voting_power =
+            conviction_voting_power(bonds, epoch, conviction_epochs)?;
+
+        if active_validators.contains(&bond_id.validator) {
+            checked!(powers.total += voting_power)?;
+        }
+        let validator_power = powers
+            .validators
+            .entry(bond_id.validator.clone())
+            .or_default();
+        checked!(*validator_power += voting_power)?;
+        powers.bonds.insert(bond_id, voting_power);
+    }
+    Ok(powers)
+}
+
 fn execute_default_proposal<D, H>(
     shell: &mut Shell<D, H>,
     id: u64,
@@ -458,6 +584,135 @@ where
     }
 }
 
+/// The parameters of a module updated by a parameter change proposal
+enum ParameterUpdate {
+    Pos(OwnedPosParams),
+    Governance(GovernanceParameters),
+    Pgf(PgfParameters),
+    Masp(Address, token::Denomination, ShieldedParams),
+}
+
+/// Apply the changes of a parameter change proposal. The parameters are only
+/// updated if the new parameters of every changed module are valid. Returns
+/// whether the parameters have been updated.
+fn execute_parameter_change_proposal<S>(
+    storage: &mut S,
+    changes: BTreeSet<ParameterChange>,
+) -> Result<bool>
+where
+    S: StorageRead + StorageWrite,
+{
+    let modules = changes
+        .iter()
+        .map(|change| &change.module)
+        .collect::<BTreeSet<_>>();
+
+    let mut updates = Vec::with_capacity(modules.len());
+    for module in modules {
+        match compute_parameter_update(storage, module, &changes)? {
+            Ok(update) => updates.push(update),
+            Err(err) => {
+                tracing::info!(
+                    "Invalid parameter change of module {module}: {err}"
+                );
+                return Ok(false);
+            }
+        }
+    }
+
+    for update in updates {
+        match update {
+            ParameterUpdate::Pos(params) => write_pos_params(storage, &params)?,
+            ParameterUpdate::Governance(params) => {
+                params.write_storage(storage)?
+            }
+            ParameterUpdate::Pgf(params) => params.init_storage(storage)?,
+            ParameterUpdate::Masp(token, denom, params) => {
+                token::update_params::<_, token::Store<()>>(
+                    &params, storage, &token, &denom,
+                )?
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+/// Compute the new parameters of a module from its current parameters and the
+/// given changes. The inner error describes invalid changes.
+fn compute_parameter_update<S>(
+    storage: &S,
+    module: &ParameterModule,
+    changes: &BTreeSet<ParameterChange>,
+) -> Result<std::result::Result<ParameterUpdate, String>>
+where
+    S: StorageRead,
+{
+    fn validate_params<T, E: std::fmt::Display>(
+        params: std::result::Result<T, ParameterChangeError>,
+        validate: impl FnOnce(&T) -> Vec<E>,
+    ) -> std::result::Result<T, String> {
+        let params = params.map_err(|err| err.to_string())?;
+        let errors = validate(&params);
+        if errors.is_empty() {
+            Ok(params)
+        } else {
+            Err(errors
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "))
+        }
+    }
+
+    let update = match module {
+        ParameterModule::Pos => {
+            let params = read_owned_pos_params(storage)?;
+            validate_params(
+                ParameterChange::apply(module, &params, changes),
+                OwnedPosParams::validate,
+            )
+            .map(ParameterUpdate::Pos)
+        }
+        ParameterModule::Governance => {
+            let params = gov_api::get_parameters(storage)?;
+            validate_params(
+                ParameterChange::apply(module, &params, changes),
+                GovernanceParameters::validate,
+            )
+            .map(ParameterUpdate::Governance)
+        }
+        ParameterModule::Pgf => {
+            let params = pgf::get_parameters(storage)?;
+            validate_params(
+                ParameterChange::apply(module, &params, changes),
+                PgfParameters::validate,
+            )
+            .map(ParameterUpdate::Pgf)
+        }
+        ParameterModule::Masp(token) => {
+            let Some(denom) = read_denom(storage, token)? else {
+                return Ok(Err(format!("Unknown token {token}")));
+            };
+            let Some(params) = token::read_params::<_, token::Store<()>>(
+                storage, token, &denom,
+            )?
+            else {
+                return Ok(Err(format!(
+                    "Token {token} has no shielded rewards parameters"
+                )));
+            };
+            validate_params(
+                ParameterChange::apply(module, &params, changes),
+                ShieldedParams::validate,
+            )
+            .map(|params| ParameterUpdate::Masp(token.clone(), denom, params))
+        }
+    };
+
+    Ok(update)
+}
+
 fn execute_pgf_steward_proposal<S>(
     storage: &mut S,
     stewards: BTreeSet<AddRemove<Address>>,
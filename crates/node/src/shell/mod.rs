@@ -43,7 +43,7 @@ use namada_sdk::eth_bridge::protocol::validation::ethereum_events::validate_eth_
 use namada_sdk::eth_bridge::protocol::validation::validator_set_update::validate_valset_upd_vext;
 use namada_sdk::eth_bridge::{EthBridgeQueries, EthereumOracleConfig};
 use namada_sdk::ethereum_events::EthereumEvent;
-use namada_sdk::events::log::EventLog;
+use namada_sdk::events::{log, Event};
 use namada_sdk::gas::{Gas, TxGasMeter};
 use namada_sdk::key::*;
 use namada_sdk::migrations::ScheduledMigration;
@@ -73,7 +73,9 @@ use thiserror::Error;
 use tokio::sync::mpsc::{Receiver, UnboundedSender};
 
 use super::ethereum_oracle::{self as oracle, last_processed_block};
-use crate::config::{self, genesis, TendermintMode, ValidatorLocalConfig};
+use crate::config::{
+    self, genesis, PruningPolicy, TendermintMode, ValidatorLocalConfig,
+};
 use crate::facade::tendermint::v0_37::abci::{request, response};
 use crate::facade::tendermint::{self, validator};
 use crate::facade::tendermint_proto::v0_37::crypto::public_key;
@@ -174,6 +176,44 @@ pub fn rollback(config: config::Ledger) -> Result<()> {
         .map_err(|e| Error::Storage(namada_sdk::state::StorageError::new(e)))
 }
 
+/// Check that the merkle tree stores and diffs in the DB reproduce the
+/// merkle tree committed at the given height, or at the last committed
+/// height if none is given. Returns the verified height and merkle root.
+pub fn verify_merkle_tree(
+    config: config::Ledger,
+    height: Option<BlockHeight>,
+) -> Result<(BlockHeight, namada_sdk::hash::Hash)> {
+    let chain_dir = config.chain_dir();
+    let native_token = genesis::chain::Finalized::read_toml_files(&chain_dir)
+        .map_err(|e| {
+            Error::Storage(namada_sdk::state::StorageError::new_alloc(format!(
+                "Failed to read the genesis files: {e}"
+            )))
+        })?
+        .get_native_token()
+        .clone();
+    let db_path = config.shell.db_dir(&config.chain_id);
+    let db = storage::open(db_path, true, None)
+        .map_err(|e| Error::Storage(namada_sdk::state::StorageError::new(e)))?;
+    let mut state = FullAccessState::<_, Sha256Hasher>::from_db(
+        db,
+        config.chain_id,
+        native_token,
+        None,
+        is_key_diff_storable,
+    );
+    // Loading the last state already verifies its merkle tree
+    state
+        .reload_last_state()
+        .map_err(|e| Error::Storage(namada_sdk::state::StorageError::new(e)))?;
+    let last_height = state.in_mem().get_last_block_height();
+    let height = height.unwrap_or(last_height);
+    let root = state
+        .verify_merkle_tree(height)
+        .map_err(|e| Error::Storage(namada_sdk::state::StorageError::new(e)))?;
+    Ok((height, root))
+}
+
 #[derive(Debug)]
 #[allow(dead_code, clippy::large_enum_variant)]
 pub(super) enum ShellMode {
@@ -366,13 +406,21 @@ where
     /// limit the how many block heights in the past can the storage be
     /// queried for reading values.
     storage_read_past_height_limit: Option<u64>,
-    /// Log of events emitted by `FinalizeBlock` ABCI calls.
-    event_log: EventLog,
+    /// The parameters of the log of events emitted by `FinalizeBlock` ABCI
+    /// calls
+    event_log_params: log::Params,
+    /// The events emitted by the last `FinalizeBlock` ABCI call, written to
+    /// the event log when the block is committed
+    block_events: Vec<Event>,
     /// A migration that can be scheduled at a given block height
     pub scheduled_migration: Option<ScheduledMigration<D::Migrator>>,
     /// When set, indicates after how many blocks a new snapshot
     /// will be taken (counting from the first block)
     pub blocks_between_snapshots: Option<NonZeroU64>,
+    /// The policy for pruning the historical state from the DB
+    pruning: PruningPolicy,
+    /// The progress of restoring the state from a snapshot, if any
+    snapshot_sync: Option<snapshots::SnapshotSync>,
 }
 
 /// Storage key filter to store the diffs into the storage. Return `false` for
@@ -604,27 +652,19 @@ where
                 ),
             ),
             storage_read_past_height_limit,
-            // TODO(namada#3237): config event log params
-            event_log: EventLog::default(),
+            event_log_params: log::Params {
+                retention_blocks: config.shell.event_log_retention_blocks,
+            },
+            block_events: vec![],
             scheduled_migration,
             blocks_between_snapshots: config.shell.blocks_between_snapshots,
+            pruning: config.shell.pruning,
+            snapshot_sync: None,
         };
         shell.update_eth_oracle(&Default::default());
         shell
     }
 
-    /// Return a reference to the [`EventLog`].
-    #[inline]
-    pub fn event_log(&self) -> &EventLog {
-        &self.event_log
-    }
-
-    /// Return a mutable reference to the [`EventLog`].
-    #[inline]
-    pub fn event_log_mut(&mut self) -> &mut EventLog {
-        &mut self.event_log
-    }
-
     /// Load the Merkle root hash and the height of the last committed block, if
     /// any. This is returned when ABCI sends an `info` request.
     pub fn last_state(&self) -> response::Info {
@@ -722,8 +762,16 @@ where
     pub fn commit(&mut self) -> shim::Response {
         self.bump_last_processed_eth_block();
 
+        let mut batch = D::batch();
+        log::write_events(
+            self.state.db(),
+            &mut batch,
+            self.state.in_mem().block.height,
+            &std::mem::take(&mut self.block_events),
+        )
+        .expect("Encountered a storage error while writing the block events");
         self.state
-            .commit_block()
+            .commit_block_with_batch(batch)
             .expect("Encountered a storage error while committing a block");
         let committed_height = self.state.in_mem().get_last_block_height();
         migrations::commit(
@@ -731,6 +779,8 @@ where
             committed_height,
             &mut self.scheduled_migration,
         );
+        self.prune_history();
+        self.prune_events();
         let merkle_root = self.state.in_mem().merkle_root();
 
         tracing::info!(
@@ -752,6 +802,65 @@ where
         )
     }
 
+    /// Prune the historical state from the DB according to the configured
+    /// pruning policy. The DB removes the pruned data in the background.
+    fn prune_history(&self) {
+        let Some(oldest_height) = self.oldest_height_to_keep() else {
+            return;
+        };
+        if let Err(e) = self.state.db().prune_history(oldest_height) {
+            tracing::error!(
+                "Failed to prune the history below height {oldest_height}: {e}"
+            );
+        }
+    }
+
+    /// Prune the events of the old blocks from the event log according to
+    /// the configured retention.
+    fn prune_events(&self) {
+        let last_height = self.state.in_mem().get_last_block_height();
+        let Some(oldest_height) =
+            self.event_log_params.oldest_height_to_keep(last_height)
+        else {
+            return;
+        };
+        if let Err(e) = log::prune_events(self.state.db(), oldest_height) {
+            tracing::error!(
+                "Failed to prune the events below height {oldest_height}: {e}"
+            );
+        }
+    }
+
+    /// Get the oldest height whose historical state must be kept under the
+    /// pruning policy, if any of the history may be pruned.
+    fn oldest_height_to_keep(&self) -> Option<BlockHeight> {
+        let in_mem = self.state.in_mem();
+        let last_height = in_mem.get_last_block_height();
+        let pred_epochs = &in_mem.block.pred_epochs;
+        let height = match self.pruning {
+            PruningPolicy::Archive => return None,
+            PruningPolicy::KeepLastBlocks { blocks } => {
+                BlockHeight(last_height.0.checked_sub(blocks.get())?)
+            }
+            PruningPolicy::KeepEveryNthEpoch { epochs } => {
+                let (current_epoch, _) = in_mem.get_last_epoch();
+                let epochs = epochs.get();
+                let last_pruning_epoch = current_epoch
+                    .0
+                    .checked_sub(current_epoch.0.checked_rem(epochs)?)?;
+                let oldest_epoch = namada_sdk::storage::Epoch(
+                    last_pruning_epoch.checked_sub(epochs)?,
+                );
+                pred_epochs.get_start_height_of_epoch(oldest_epoch)?
+            }
+        };
+        // The merkle tree at a given height is restored from the stores at
+        // the start of its epoch, so the whole epoch must be kept
+        let epoch_start = pred_epochs.get_epoch_start_height(height)?;
+        // The previous block must always be kept for rollback
+        Some(std::cmp::min(epoch_start, last_height.prev_height()?))
+    }
+
     /// Check if we have reached a block height at which we should take a
     /// snapshot
     fn check_snapshot_required(&self) -> TakeSnapshot {
@@ -1393,8 +1502,8 @@ where
     CA: 'static + WasmCacheAccess + Sync,
 {
     let minimum_gas_price =
-        parameters::read_gas_cost(shell_params.state, &wrapper.fee.token)
-            .expect("Must be able to read gas cost parameter")
+        parameters::read_base_fee(shell_params.state, &wrapper.fee.token)
+            .expect("Must be able to read the base fee")
             .ok_or(Error::TxApply(protocol::Error::FeeError(format!(
                 "The provided {} token is not allowed for fee payment",
                 wrapper.fee.token
@@ -1994,7 +2103,7 @@ mod test_utils {
 mod shell_tests {
     use eth_bridge::storage::eth_bridge_queries::is_bridge_comptime_enabled;
     use namada_sdk::address;
-    use namada_sdk::storage::Epoch;
+    use namada_sdk::storage::{Epoch, Epochs};
     use namada_sdk::token::read_denom;
     use namada_sdk::tx::data::protocol::{ProtocolTx, ProtocolTxType};
     use namada_sdk::tx::data::Fee;
@@ -2103,6 +2212,60 @@ mod shell_tests {
         );
     }
 
+    /// Test that the history is pruned up to the start of the epoch of the
+    /// oldest height kept under the pruning policy.
+    #[test]
+    fn test_oldest_height_to_keep() {
+        let (mut shell, _, _, _) = test_utils::setup_at_height(35);
+        shell.state.in_mem_mut().block.pred_epochs = Epochs {
+            first_block_heights: vec![
+                BlockHeight(1),
+                BlockHeight(11),
+                BlockHeight(21),
+                BlockHeight(31),
+            ],
+        };
+        shell.state.in_mem_mut().last_epoch = Epoch(3);
+
+        let cases = [
+            (PruningPolicy::Archive, None),
+            (
+                PruningPolicy::KeepLastBlocks {
+                    blocks: NonZeroU64::new(10).unwrap(),
+                },
+                Some(BlockHeight(21)),
+            ),
+            (
+                PruningPolicy::KeepLastBlocks {
+                    blocks: NonZeroU64::new(40).unwrap(),
+                },
+                None,
+            ),
+            (
+                PruningPolicy::KeepEveryNthEpoch {
+                    epochs: NonZeroU64::new(1).unwrap(),
+                },
+                Some(BlockHeight(21)),
+            ),
+            (
+                PruningPolicy::KeepEveryNthEpoch {
+                    epochs: NonZeroU64::new(2).unwrap(),
+                },
+                Some(BlockHeight(1)),
+            ),
+            (
+                PruningPolicy::KeepEveryNthEpoch {
+                    epochs: NonZeroU64::new(4).unwrap(),
+                },
+                None,
+            ),
+        ];
+        for (policy, expected) in cases {
+            shell.pruning = policy;
+            assert_eq!(shell.oldest_height_to_keep(), expected, "{policy:?}");
+        }
+    }
+
     /// Test that Ethereum events with outdated nonces are
     /// not validated by `CheckTx`.
     #[test]
@@ -1,16 +1,21 @@
 //! Implementation of the [`RequestPrepareProposal`] ABCI++ method for the Shell
 
 use std::cell::RefCell;
+use std::cmp::Reverse;
 
 use namada_sdk::address::Address;
+use namada_sdk::collections::HashMap;
 use namada_sdk::gas::TxGasMeter;
 use namada_sdk::key::tm_raw_hash_to_string;
 use namada_sdk::parameters::get_gas_scale;
 use namada_sdk::proof_of_stake::storage::find_validator_by_raw_hash;
-use namada_sdk::state::{DBIter, StorageHasher, TempWlState, TxIndex, DB};
+use namada_sdk::state::{
+    DBIter, StorageHasher, StorageRead, TempWlState, TxIndex, DB,
+};
 use namada_sdk::token::{Amount, DenominatedAmount};
 use namada_sdk::tx::data::WrapperTx;
 use namada_sdk::tx::Tx;
+use namada_sdk::uint::{Uint, MAX_VALUE};
 use namada_vm::wasm::{TxCache, VpCache};
 use namada_vm::WasmCacheAccess;
 
@@ -20,13 +25,17 @@ use super::block_alloc::states::{
     WithNormalTxs, WithoutNormalTxs,
 };
 use super::block_alloc::{AllocFailure, BlockAllocator, BlockResources};
-use crate::config::ValidatorLocalConfig;
+use crate::config::{TxOrdering, ValidatorLocalConfig};
 use crate::facade::tendermint_proto::google::protobuf::Timestamp;
 use crate::facade::tendermint_proto::v0_37::abci::RequestPrepareProposal;
 use crate::protocol::{self, ShellParams};
 use crate::shell::ShellMode;
 use crate::shims::abcipp_shim_types::shim::{response, TxBytes};
 
+/// The scale of the gas prices relative to the base fees used to order the
+/// txs of a proposal
+const GAS_PRICE_RATIO_SCALE: u64 = 1_000_000_000_000;
+
 impl<D, H> Shell<D, H>
 where
     D: DB + for<'iter> DBIter<'iter> + Sync + 'static,
@@ -126,66 +135,97 @@ where
         let mut vp_wasm_cache = self.vp_wasm_cache.clone();
         let mut tx_wasm_cache = self.tx_wasm_cache.clone();
 
-        let txs = txs
-            .iter()
-            .enumerate()
-            .filter_map(|(tx_index, tx_bytes)| {
-                let result = validate_wrapper_bytes(
-                    tx_bytes,
-                    &TxIndex::must_from_usize(tx_index),
-                    block_time,
-                    block_proposer,
-                    proposer_local_config,
-                    &mut temp_state,
-                    &mut vp_wasm_cache,
-                    &mut tx_wasm_cache
-                );
-                match result {
-                    Ok(gas) => {
-                        temp_state.write_log_mut().commit_batch();
-                        Some((tx_bytes.to_owned(), gas))
-                    },
-                    Err(()) => {
-                        temp_state.write_log_mut().drop_batch();
-                        None
-                    }
+        let tx_ordering = proposer_local_config
+            .map(|config| config.tx_ordering)
+            .unwrap_or_default();
+        let mut normal_txs = vec![];
+        let txs = self.order_normal_txs(txs, tx_ordering);
+        for (tx_index, tx_bytes) in txs.into_iter().enumerate() {
+            let result = validate_wrapper_bytes(
+                tx_bytes,
+                &TxIndex::must_from_usize(tx_index),
+                block_time,
+                block_proposer,
+                proposer_local_config,
+                &mut temp_state,
+                &mut vp_wasm_cache,
+                &mut tx_wasm_cache,
+            );
+            let Ok(tx_gas) = result else {
+                temp_state.write_log_mut().drop_batch();
+                continue;
+            };
+            // A tx that doesn't fit is dropped and the next ones are still
+            // considered, so that smaller txs can fill the remaining space
+            match alloc.try_alloc(BlockResources::new(&tx_bytes[..], tx_gas)) {
+                Ok(()) => {
+                    temp_state.write_log_mut().commit_batch();
+                    normal_txs.push(tx_bytes.to_owned());
                 }
-            })
-            .take_while(|(tx_bytes, tx_gas)| {
-                alloc.try_alloc(BlockResources::new(&tx_bytes[..], tx_gas.to_owned()))
-                    .map_or_else(
-                        |status| match status {
-                            AllocFailure::Rejected { bin_resource_left} => {
-                                tracing::debug!(
-                                    ?tx_bytes,
-                                    bin_resource_left,
-                                    proposal_height =
-                                        ?self.get_current_decision_height(),
-                                    "Dropping encrypted tx from the current proposal",
-                                );
-                                false
-                            }
-                            AllocFailure::OverflowsBin { bin_resource} => {
-                                // TODO(namada#3250): handle tx whose size is greater
-                                // than bin size
-                                tracing::warn!(
-                                    ?tx_bytes,
-                                    bin_resource,
-                                    proposal_height =
-                                        ?self.get_current_decision_height(),
-                                    "Dropping large encrypted tx from the current proposal",
-                                );
-                                true
-                            }
-                        },
-                        |()| true,
-                    )
-            })
-            .map(|(tx, _)| tx)
-            .collect();
+                Err(AllocFailure::Rejected { bin_resource_left }) => {
+                    tracing::debug!(
+                        ?tx_bytes,
+                        bin_resource_left,
+                        proposal_height = ?self.get_current_decision_height(),
+                        "Dropping encrypted tx from the current proposal",
+                    );
+                    temp_state.write_log_mut().drop_batch();
+                }
+                Err(AllocFailure::OverflowsBin { bin_resource }) => {
+                    // TODO(namada#3250): handle tx whose size is greater
+                    // than bin size
+                    tracing::warn!(
+                        ?tx_bytes,
+                        bin_resource,
+                        proposal_height = ?self.get_current_decision_height(),
+                        "Dropping large encrypted tx from the current proposal",
+                    );
+                    temp_state.write_log_mut().drop_batch();
+                }
+            }
+        }
         let alloc = alloc.next_state();
 
-        (txs, alloc)
+        (normal_txs, alloc)
+    }
+
+    /// Order the txs from the mempool according to the proposer's policy.
+    fn order_normal_txs<'tx>(
+        &self,
+        txs: &'tx [TxBytes],
+        tx_ordering: TxOrdering,
+    ) -> Vec<&'tx TxBytes> {
+        let txs = txs.iter();
+        match tx_ordering {
+            TxOrdering::Fifo => txs.collect(),
+            TxOrdering::FeePriority => {
+                // The round of a tx is the number of txs with the same fee
+                // payer that precede it in the mempool
+                let mut fee_payer_txs: HashMap<Address, usize> = HashMap::new();
+                let mut txs: Vec<_> = txs
+                    .map(|tx_bytes| {
+                        let priority = fee_priority(&self.state, tx_bytes).map(
+                            |(fee_payer, gas_price_ratio)| {
+                                let round =
+                                    fee_payer_txs.entry(fee_payer).or_default();
+                                let priority =
+                                    (*round, Reverse(gas_price_ratio));
+                                *round = round.saturating_add(1);
+                                priority
+                            },
+                        );
+                        // Undecodable txs go last, they will be rejected
+                        let priority = priority
+                            .unwrap_or((usize::MAX, Reverse(Uint::zero())));
+                        (priority, tx_bytes)
+                    })
+                    .collect();
+                // The sort is stable, so the txs with the same priority keep
+                // the order of the mempool
+                txs.sort_by(|(priority, _), (other, _)| priority.cmp(other));
+                txs.into_iter().map(|(_, tx)| tx).collect()
+            }
+        }
     }
 
     /// Allocate an initial set of protocol txs and advance to the
@@ -272,6 +312,37 @@ where
     }
 }
 
+/// Get the fee payer of a wrapper tx and its gas price relative to the base
+/// fee of the fee token, scaled by [`GAS_PRICE_RATIO_SCALE`]
+fn fee_priority<S>(storage: &S, tx_bytes: &[u8]) -> Option<(Address, Uint)>
+where
+    S: StorageRead,
+{
+    let tx = Tx::try_from(tx_bytes).ok()?;
+    let wrapper = tx.header.wrapper()?;
+    let fee_payer = tx
+        .fee_sponsor()
+        .cloned()
+        .unwrap_or_else(|| wrapper.fee_payer());
+    let base_fee =
+        namada_sdk::parameters::read_base_fee(storage, &wrapper.fee.token)
+            .ok()??;
+    let gas_price = namada_sdk::token::denom_to_amount(
+        wrapper.fee.amount_per_gas_unit,
+        &wrapper.fee.token,
+        storage,
+    )
+    .ok()?;
+    let gas_price_ratio = gas_price
+        .raw_amount()
+        .checked_mul_div(
+            Uint::from(GAS_PRICE_RATIO_SCALE),
+            base_fee.raw_amount(),
+        )
+        .map_or(MAX_VALUE, |(ratio, _)| ratio);
+    Some((fee_payer, gas_price_ratio))
+}
+
 // Validity checks on a wrapper tx
 #[allow(clippy::too_many_arguments)]
 fn validate_wrapper_bytes<D, H, CA>(
@@ -367,8 +438,8 @@ where
     H: StorageHasher + Sync + 'static,
 {
     let consensus_min_gas_price =
-        namada_sdk::parameters::read_gas_cost(temp_state, fee_token)
-            .expect("Must be able to read gas cost parameter")
+        namada_sdk::parameters::read_base_fee(temp_state, fee_token)
+            .expect("Must be able to read the base fee")
             .ok_or_else(|| {
                 Error::TxApply(protocol::Error::FeeError(format!(
                     "The provided {fee_token} token is not allowed for fee \
@@ -420,7 +491,7 @@ mod test_prepare_proposal {
     use namada_apps_lib::wallet;
     use namada_replay_protection as replay_protection;
     use namada_sdk::ethereum_events::EthereumEvent;
-    use namada_sdk::key::RefTo;
+    use namada_sdk::key::{common, RefTo};
     use namada_sdk::proof_of_stake::storage::{
         consensus_validator_set_handle,
         read_consensus_validator_set_addresses_with_stake, read_pos_params,
@@ -929,6 +1000,76 @@ mod test_prepare_proposal {
         assert_eq!(received_txs.len(), 2);
     }
 
+    /// Test that the txs are ordered by gas price, taking one tx per fee
+    /// payer in each round, unless the proposer keeps the mempool order
+    #[test]
+    fn test_fee_priority_ordering() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+
+        let new_wrapper = |keypair: common::SecretKey, gas_price: u64| {
+            let mut wrapper =
+                Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                    Fee {
+                        amount_per_gas_unit: DenominatedAmount::native(
+                            gas_price.into(),
+                        ),
+                        token: shell.state.in_mem().native_token.clone(),
+                    },
+                    keypair.ref_to(),
+                    GAS_LIMIT_MULTIPLIER.into(),
+                ))));
+            wrapper.header.chain_id = shell.chain_id.clone();
+            wrapper
+                .set_code(Code::new("wasm_code".as_bytes().to_owned(), None));
+            wrapper.set_data(Data::new(gas_price.to_le_bytes().to_vec()));
+            wrapper.add_section(Section::Authorization(Authorization::new(
+                wrapper.sechashes(),
+                [(0, keypair)].into_iter().collect(),
+                None,
+            )));
+            TxBytes::from(wrapper.to_bytes())
+        };
+        let low_fee =
+            new_wrapper(namada_apps_lib::wallet::defaults::daewon_keypair(), 1);
+        let high_fee = new_wrapper(
+            namada_apps_lib::wallet::defaults::albert_keypair(),
+            10,
+        );
+        let higher_fee = new_wrapper(
+            namada_apps_lib::wallet::defaults::albert_keypair(),
+            20,
+        );
+        let txs = vec![low_fee.clone(), high_fee.clone(), higher_fee.clone()];
+        let accepted_gas_tokens = namada_sdk::collections::HashMap::from([(
+            address::testing::nam(),
+            Amount::from(1),
+        )]);
+
+        for (tx_ordering, expected_txs) in [
+            // The second tx of the same fee payer comes after the txs of the
+            // other fee payers
+            (TxOrdering::FeePriority, vec![high_fee, low_fee, higher_fee]),
+            (TxOrdering::Fifo, txs.clone()),
+        ] {
+            if let ShellMode::Validator {
+                validator_local_config,
+                ..
+            } = &mut shell.mode
+            {
+                *validator_local_config = Some(ValidatorLocalConfig {
+                    accepted_gas_tokens: accepted_gas_tokens.clone(),
+                    tx_ordering,
+                });
+            }
+            let req = RequestPrepareProposal {
+                txs: txs.clone(),
+                ..Default::default()
+            };
+            let received_txs = shell.prepare_proposal(req).txs;
+            assert_eq!(received_txs, expected_txs);
+        }
+    }
+
     /// Test that expired wrapper transactions are not included in the block
     #[test]
     fn test_expired_wrapper_tx() {
@@ -1066,6 +1207,7 @@ mod test_prepare_proposal {
                 accepted_gas_tokens: namada_sdk::collections::HashMap::from([
                     (namada_sdk::address::testing::nam(), Amount::from(1)),
                 ]),
+                tx_ordering: Default::default(),
             });
         }
 
@@ -1171,6 +1313,7 @@ mod test_prepare_proposal {
                 accepted_gas_tokens: namada_sdk::collections::HashMap::from([
                     (namada_sdk::address::testing::nam(), Amount::from(100)),
                 ]),
+                tx_ordering: Default::default(),
             });
         }
 
@@ -1460,6 +1603,7 @@ mod test_prepare_proposal {
                 );
                 m
             },
+            tx_ordering: Default::default(),
         };
         let computed_min_gas_price = compute_min_gas_price(
             &shell.state.in_mem().native_token,
@@ -551,8 +551,8 @@ where
     CA: 'static + WasmCacheAccess + Sync,
 {
     let minimum_gas_price =
-        parameters::read_gas_cost(shell_params.state, &wrapper.fee.token)
-            .expect("Must be able to read gas cost parameter")
+        parameters::read_base_fee(shell_params.state, &wrapper.fee.token)
+            .expect("Must be able to read the base fee")
             .ok_or(Error::TxApply(protocol::Error::FeeError(format!(
                 "The provided {} token is not allowed for fee payment",
                 wrapper.fee.token
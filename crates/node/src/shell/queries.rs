@@ -31,7 +31,6 @@ where
         } else {
             let ctx = RequestCtx {
                 state: self.state.read_only(),
-                event_log: self.event_log(),
                 vp_wasm_cache: self.vp_wasm_cache.read_only(),
                 tx_wasm_cache: self.tx_wasm_cache.read_only(),
                 storage_read_past_height_limit: self
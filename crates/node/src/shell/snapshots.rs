@@ -1,17 +1,43 @@
-use borsh_ext::BorshSerializeExt;
-use namada_sdk::hash::{Hash, Sha256Hasher};
-use namada_sdk::state::BlockHeight;
+use namada_sdk::arith::checked;
+use namada_sdk::hash::Hash;
+use namada_sdk::state::{BlockHeight, InMemory, StorageError, StorageHasher};
+use namada_sdk::storage::DbColFam;
 
 use super::{Error, Result};
 use crate::facade::tendermint::abci::types::Snapshot;
 use crate::facade::tendermint::v0_37::abci::{
     request as tm_request, response as tm_response,
 };
+use crate::facade::tendermint::AppHash;
 use crate::shell::Shell;
-use crate::storage;
-use crate::storage::{DbSnapshot, SnapshotMetadata};
+use crate::storage::{
+    Chunk, DbSnapshot, LedgerDB, SnapshotFormat, SnapshotManifest,
+    SnapshotMetadata,
+};
+
+/// The progress of restoring the state from a snapshot offered
+/// to this node by CometBFT during state sync.
+#[derive(Debug)]
+pub struct SnapshotSync {
+    /// The height of the snapshot being restored
+    height: BlockHeight,
+    /// The trusted app hash which the restored state must commit to
+    app_hash: AppHash,
+    /// The format of the snapshot
+    format: SnapshotFormat,
+    /// The column families listed in the manifest of the snapshot
+    column_families: Vec<String>,
+    /// The expected hashes of the chunks of the snapshot
+    chunks: Vec<Chunk>,
+    /// The index of the next chunk to be applied
+    next_chunk: usize,
+}
 
-impl Shell<storage::PersistentDB, Sha256Hasher> {
+impl<D, H> Shell<D, H>
+where
+    D: LedgerDB,
+    H: StorageHasher + Sync + 'static,
+{
     /// List the snapshot files held locally. Furthermore, the number
     /// of chunks, as hash of each chunk, and a hash of the chunk
     /// metadata are provided so that syncing nodes can verify can verify
@@ -23,17 +49,25 @@ impl Shell<storage::PersistentDB, Sha256Hasher> {
             let snapshots = DbSnapshot::files(&self.base_dir)
                 .map_err(Error::Snapshot)?
                 .into_iter()
-                .map(|SnapshotMetadata { height, chunks, .. }| {
-                    let hash = Hash::sha256(chunks.serialize_to_vec()).0;
-                    Snapshot {
-                        height: u32::try_from(height.0).unwrap().into(),
-                        format: 0,
-                        #[allow(clippy::cast_possible_truncation)]
-                        chunks: chunks.len() as u32,
-                        hash: hash.into_iter().collect(),
-                        metadata: Default::default(),
-                    }
-                })
+                .map(
+                    |SnapshotMetadata {
+                         height,
+                         format,
+                         manifest,
+                         ..
+                     }| {
+                        let metadata = manifest.encode(format);
+                        let hash = Hash::sha256(&metadata).0;
+                        Snapshot {
+                            height: u32::try_from(height.0).unwrap().into(),
+                            format: format.version(),
+                            #[allow(clippy::cast_possible_truncation)]
+                            chunks: manifest.chunks.len() as u32,
+                            hash: hash.into_iter().collect(),
+                            metadata: metadata.into_iter().collect(),
+                        }
+                    },
+                )
                 .collect();
 
             Ok(tm_response::ListSnapshots { snapshots })
@@ -56,4 +90,234 @@ impl Shell<storage::PersistentDB, Sha256Hasher> {
             chunk: chunk.into_iter().collect(),
         })
     }
+
+    /// Decide whether to accept a snapshot offered by CometBFT to
+    /// restore the state of this node from. The snapshot must be in a
+    /// known format and its metadata must contain the hashes of its
+    /// chunks and hash to the snapshot's advertised hash.
+    pub fn offer_snapshot(
+        &mut self,
+        req: tm_request::OfferSnapshot,
+    ) -> tm_response::OfferSnapshot {
+        // We can only restore a snapshot onto an empty database
+        if self.state.in_mem().last_block.is_some() {
+            tracing::warn!(
+                "Rejecting an offered snapshot, as this node already has \
+                 some state"
+            );
+            return tm_response::OfferSnapshot::Abort;
+        }
+        let Snapshot {
+            height,
+            format,
+            chunks,
+            hash,
+            metadata,
+        } = req.snapshot;
+        let Ok(format) = SnapshotFormat::try_from(format) else {
+            return tm_response::OfferSnapshot::RejectFormat;
+        };
+        if Hash::sha256(&metadata).0[..] != hash[..] {
+            tracing::info!(
+                "Rejecting snapshot at height {height} whose metadata does \
+                 not match its hash"
+            );
+            return tm_response::OfferSnapshot::Reject;
+        }
+        let Ok(SnapshotManifest {
+            column_families,
+            chunks: chunk_hashes,
+        }) = SnapshotManifest::decode(format, &metadata)
+        else {
+            tracing::info!(
+                "Rejecting snapshot at height {height} with undecodable \
+                 metadata"
+            );
+            return tm_response::OfferSnapshot::Reject;
+        };
+        if let Some(cf) = column_families
+            .iter()
+            .find(|cf| !DbColFam::all().contains(&cf.as_str()))
+        {
+            tracing::info!(
+                "Rejecting snapshot at height {height} with unknown column \
+                 family {cf}"
+            );
+            return tm_response::OfferSnapshot::Reject;
+        }
+        if chunk_hashes.is_empty()
+            || u32::try_from(chunk_hashes.len()).ok() != Some(chunks)
+        {
+            tracing::info!(
+                "Rejecting snapshot at height {height} with an inconsistent \
+                 number of chunks"
+            );
+            return tm_response::OfferSnapshot::Reject;
+        }
+
+        tracing::info!(
+            "Accepted snapshot at height {height} with {chunks} chunks"
+        );
+        self.snapshot_sync = Some(SnapshotSync {
+            height: BlockHeight(height.value()),
+            app_hash: req.app_hash,
+            format,
+            column_families,
+            chunks: chunk_hashes,
+            next_chunk: 0,
+        });
+        tm_response::OfferSnapshot::Accept
+    }
+
+    /// Verify a chunk of the snapshot being restored against its
+    /// expected hash and write its contents to the database. Once the
+    /// last chunk has been applied, the state is loaded from the
+    /// database and its merkle root checked against the trusted app
+    /// hash.
+    pub fn apply_snapshot_chunk(
+        &mut self,
+        req: tm_request::ApplySnapshotChunk,
+    ) -> Result<tm_response::ApplySnapshotChunk> {
+        let Some(sync) = self.snapshot_sync.as_mut() else {
+            tracing::error!(
+                "Received a snapshot chunk without having accepted a snapshot"
+            );
+            return Ok(tm_response::ApplySnapshotChunk {
+                result: tm_response::ApplySnapshotChunkResult::Abort,
+                ..Default::default()
+            });
+        };
+        let index = usize::try_from(req.index).unwrap();
+        if index != sync.next_chunk {
+            // Chunks must be applied in order. Ask for the one we need.
+            return Ok(tm_response::ApplySnapshotChunk {
+                result: tm_response::ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![u32::try_from(sync.next_chunk).unwrap()],
+                ..Default::default()
+            });
+        }
+        if Hash::sha256(&req.chunk) != sync.chunks[index].hash {
+            tracing::info!(
+                "Received snapshot chunk {index} with an invalid hash from \
+                 {}",
+                req.sender
+            );
+            return Ok(tm_response::ApplySnapshotChunk {
+                result: tm_response::ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![req.index],
+                reject_senders: vec![req.sender],
+            });
+        }
+
+        // The chunk matches the metadata, so if it cannot be decoded or it
+        // holds entries of column families missing from the manifest, the
+        // snapshot itself is faulty
+        let lines = DbSnapshot::decode_chunk(sync.format, &req.chunk).and_then(
+            |lines| {
+                DbSnapshot::check_chunk_column_families(
+                    &lines,
+                    &sync.column_families,
+                )?;
+                Ok(lines)
+            },
+        );
+        let lines = match lines {
+            Ok(lines) => lines,
+            Err(e) => {
+                tracing::error!(
+                    "Failed to decode chunk {index} of the snapshot at \
+                     height {}: {e}",
+                    sync.height
+                );
+                self.snapshot_sync = None;
+                self.discard_restored_state()?;
+                return Ok(tm_response::ApplySnapshotChunk {
+                    result:
+                        tm_response::ApplySnapshotChunkResult::RejectSnapshot,
+                    ..Default::default()
+                });
+            }
+        };
+        self.state
+            .db()
+            .apply_snapshot_chunk(sync.format, &lines)
+            .map_err(|e| Error::Storage(StorageError::new(e)))?;
+        sync.next_chunk = checked!(sync.next_chunk + 1).unwrap();
+        tracing::debug!("Applied snapshot chunk {index}");
+
+        if sync.next_chunk < sync.chunks.len() {
+            return Ok(tm_response::ApplySnapshotChunk {
+                result: tm_response::ApplySnapshotChunkResult::Accept,
+                ..Default::default()
+            });
+        }
+
+        // All the chunks have been applied, verify the restored state
+        let sync = self.snapshot_sync.take().unwrap();
+        if let Err(e) = self.verify_restored_state(&sync) {
+            tracing::error!(
+                "Failed to restore the state from the snapshot at height {}: \
+                 {e}",
+                sync.height
+            );
+            self.discard_restored_state()?;
+            return Ok(tm_response::ApplySnapshotChunk {
+                result: tm_response::ApplySnapshotChunkResult::RejectSnapshot,
+                ..Default::default()
+            });
+        }
+        tracing::info!(
+            "Restored the state from the snapshot at height {}",
+            sync.height
+        );
+        Ok(tm_response::ApplySnapshotChunk {
+            result: tm_response::ApplySnapshotChunkResult::Accept,
+            ..Default::default()
+        })
+    }
+
+    /// Load the state written to the database by the snapshot chunks and
+    /// check that it matches the height and app hash of the snapshot.
+    fn verify_restored_state(
+        &mut self,
+        sync: &SnapshotSync,
+    ) -> std::result::Result<(), String> {
+        self.state
+            .reload_last_state()
+            .map_err(|e| format!("Could not load the restored state: {e}"))?;
+        let Some((root, height)) = self.state.in_mem().get_state() else {
+            return Err("No block was restored".to_string());
+        };
+        if height != sync.height {
+            return Err(format!(
+                "Restored state is at height {height}, expected {}",
+                sync.height
+            ));
+        }
+        if root.0[..] != *sync.app_hash.as_bytes() {
+            return Err(format!(
+                "Restored merkle root {root} does not match the trusted app \
+                 hash {}",
+                sync.app_hash
+            ));
+        }
+        Ok(())
+    }
+
+    /// Wipe any state restored from a rejected snapshot, such
+    /// that another one can be restored from scratch.
+    fn discard_restored_state(&mut self) -> Result<()> {
+        self.state
+            .db()
+            .clear()
+            .map_err(|e| Error::Storage(StorageError::new(e)))?;
+        let in_mem = self.state.in_mem();
+        let fresh = InMemory::new(
+            in_mem.chain_id.clone(),
+            in_mem.native_token.clone(),
+            self.storage_read_past_height_limit,
+        );
+        *self.state.in_mem_mut() = fresh;
+        Ok(())
+    }
 }
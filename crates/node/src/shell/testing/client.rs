@@ -10,9 +10,10 @@ use namada_sdk::io::Io;
 
 use super::node::MockNode;
 use crate::shell::testing::utils::{Bin, TestingIo};
+use crate::storage::LedgerDB;
 
-pub fn run(
-    node: &MockNode,
+pub fn run<D: LedgerDB>(
+    node: &MockNode<D>,
     who: Bin,
     mut args: Vec<&str>,
 ) -> Result<(), Report> {
@@ -93,7 +94,7 @@ pub fn run(
 }
 
 #[async_trait::async_trait(?Send)]
-impl<'a> CliClient for &'a MockNode {
+impl<'a, D: LedgerDB> CliClient for &'a MockNode<D> {
     fn from_tendermint_address(_: &crate::facade::tendermint_rpc::Url) -> Self {
         unreachable!("MockNode should always be instantiated at test start.")
     }
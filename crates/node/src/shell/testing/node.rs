@@ -15,8 +15,8 @@ use namada_sdk::control_flow::time::Duration;
 use namada_sdk::eth_bridge::oracle::config::Config as OracleConfig;
 use namada_sdk::ethereum_events::EthereumEvent;
 use namada_sdk::events::extend::Height as HeightAttr;
-use namada_sdk::events::log::dumb_queries;
-use namada_sdk::events::Event;
+use namada_sdk::events::log::{self, dumb_queries};
+use namada_sdk::events::{Event, EventType};
 use namada_sdk::hash::Hash;
 use namada_sdk::key::tm_consensus_key_raw_hash;
 use namada_sdk::proof_of_stake::storage::{
@@ -60,6 +60,7 @@ use crate::shims::abcipp_shim_types::shim::request::{
     FinalizeBlock, ProcessedTx,
 };
 use crate::shims::abcipp_shim_types::shim::response::TxResult;
+use crate::storage::LedgerDB;
 use crate::{dry_run_tx, storage};
 
 /// Mock Ethereum oracle used for testing purposes.
@@ -246,8 +247,11 @@ pub enum NodeResults {
     Failed(ResultCode),
 }
 
-pub struct MockNode {
-    pub shell: Arc<Mutex<Shell<storage::PersistentDB, Sha256Hasher>>>,
+pub struct MockNode<D = storage::PersistentDB>
+where
+    D: LedgerDB,
+{
+    pub shell: Arc<Mutex<Shell<D, Sha256Hasher>>>,
     pub test_dir: ManuallyDrop<TestDir>,
     pub keep_temp: bool,
     pub results: Arc<Mutex<Vec<NodeResults>>>,
@@ -256,7 +260,7 @@ pub struct MockNode {
     pub auto_drive_services: bool,
 }
 
-impl Debug for MockNode {
+impl<D: LedgerDB> Debug for MockNode<D> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MockNode")
             .field("shell", &self.shell)
@@ -264,7 +268,7 @@ impl Debug for MockNode {
     }
 }
 
-impl Drop for MockNode {
+impl<D: LedgerDB> Drop for MockNode<D> {
     fn drop(&mut self) {
         unsafe {
             if !self.keep_temp {
@@ -280,7 +284,7 @@ impl Drop for MockNode {
     }
 }
 
-impl MockNode {
+impl<D: LedgerDB> MockNode<D> {
     pub async fn handle_service_action(&self, action: MockServiceAction) {
         match action {
             MockServiceAction::BroadcastTxs(txs) => {
@@ -709,7 +713,7 @@ impl MockNode {
 }
 
 #[async_trait::async_trait(?Send)]
-impl<'a> Client for &'a MockNode {
+impl<'a, D: LedgerDB> Client for &'a MockNode<D> {
     type Error = Report;
 
     async fn request(
@@ -758,7 +762,6 @@ impl<'a> Client for &'a MockNode {
         } else {
             let ctx = RequestCtx {
                 state: &borrowed.state,
-                event_log: borrowed.event_log(),
                 vp_wasm_cache: borrowed.vp_wasm_cache.read_only(),
                 tx_wasm_cache: borrowed.tx_wasm_cache.read_only(),
                 storage_read_past_height_limit: None,
@@ -842,15 +845,16 @@ impl<'a> Client for &'a MockNode {
 
         // we store a hash of some event in the log as a block
         // height in the response of the query... VERY NAISSSE
-        let matching_events = borrowed.event_log().iter().flat_map(|event| {
-            if matcher.matches(event) {
-                Some(EncodedEvent::encode(event))
-            } else {
-                None
-            }
-        });
+        let matching_events = log::query_events(
+            borrowed.state.db(),
+            &log::EventQuery::new(matcher),
+        )
+        .map_err(|e| RpcError::server(e.to_string()))?;
         let blocks = matching_events
-            .map(block_search_response)
+            .iter()
+            .map(|(_height, event)| {
+                block_search_response(EncodedEvent::encode(event))
+            })
             .collect::<Vec<_>>();
 
         Ok(
@@ -874,10 +878,18 @@ impl<'a> Client for &'a MockNode {
             RpcError::parse("Failed to cast block height".to_string())
         })?;
         let locked = self.shell.lock().unwrap();
-        let events: Vec<_> = locked
-            .event_log()
+        let all_events = log::query_events(
+            locked.state.db(),
+            &log::EventQuery::new(dumb_queries::QueryMatcher::with_prefix(
+                EventType::new(""),
+            ))
+            .from_height(BlockHeight(height.value()))
+            .to_height(BlockHeight(height.value())),
+        )
+        .map_err(|e| RpcError::server(e.to_string()))?;
+        let events: Vec<_> = all_events
             .iter()
-            .flat_map(|event| {
+            .flat_map(|(_height, event)| {
                 let same_block_height = event
                     .read_attribute::<HeightAttr>()
                     .map(|event_height| {
@@ -28,7 +28,7 @@ use crate::facade::tendermint::v0_37::abci::{
 };
 use crate::facade::tower_abci::BoxError;
 use crate::shell::{EthereumOracleChannels, Shell};
-use crate::storage::DbSnapshot;
+use crate::storage::{DbSnapshot, SnapshotFormat};
 
 /// The shim wraps the shell, which implements ABCI++.
 /// The shim makes a crude translation between the ABCI interface currently used
@@ -224,7 +224,12 @@ impl AbcippShim {
                 .expect("Last block should exists")
                 .height;
             let cfs = db.column_families();
-            snapshot.write_to_file(cfs, base_dir.clone(), last_height)?;
+            snapshot.write_to_file(
+                cfs,
+                base_dir.clone(),
+                last_height,
+                SnapshotFormat::LATEST,
+            )?;
             DbSnapshot::cleanup(last_height, &base_dir)
         });
 
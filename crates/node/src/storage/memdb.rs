@@ -0,0 +1,1543 @@
+//! An in-memory DB with the same column families and storage tree as the
+//! persistent storage in RocksDB (see the docs of the `rocksdb` module). It
+//! supports everything that's needed to run the full ledger shell, including
+//! the diffs to read past heights, rollback and snapshots, without the cost
+//! of starting up RocksDB. The writes of a batch are applied atomically.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use itertools::Either;
+use namada_replay_protection as replay_protection;
+use namada_sdk::arith::checked;
+use namada_sdk::collections::{HashMap, HashSet};
+use namada_sdk::eth_bridge::storage::bridge_pool;
+use namada_sdk::eth_bridge::storage::proof::BridgePoolRootProof;
+use namada_sdk::events::log;
+use namada_sdk::migrations::{DBUpdateVisitor, DbUpdateType};
+use namada_sdk::state::merkle_tree::{
+    tree_key_prefix_with_epoch, tree_key_prefix_with_height,
+};
+use namada_sdk::state::{
+    BlockStateRead, BlockStateWrite, DBIter, DBWriteBatch, DbError as Error,
+    DbResult as Result, MerkleTreeStoresRead, StoreType, DB,
+};
+use namada_sdk::storage::{
+    BlockHeight, DbColFam, Epoch, Header, Key, KeySeg, BLOCK_CF, DIFFS_CF,
+    EVENTS_CF, REPLAY_PROTECTION_CF, ROLLBACK_CF, STATE_CF, SUBSPACE_CF,
+};
+use namada_sdk::{decode, encode, ethereum_events};
+use regex::Regex;
+
+use super::rocksdb::{
+    block_keys_at_height, decode_snapshot_entries, old_and_new_diff_key,
+    write_snapshot_file, ADDRESS_GEN_KEY_SEGMENT, BLOCK_HEADER_KEY_SEGMENT,
+    BLOCK_HEIGHT_KEY, BLOCK_TIME_KEY_SEGMENT, COMMIT_ONLY_DATA_KEY,
+    CONVERSION_STATE_KEY, EPOCH_KEY_SEGMENT, ETHEREUM_HEIGHT_KEY,
+    ETH_EVENTS_QUEUE_KEY, MERKLE_TREE_ROOT_KEY_SEGMENT,
+    MERKLE_TREE_STORE_KEY_SEGMENT, NEW_DIFF_PREFIX,
+    NEXT_EPOCH_MIN_START_HEIGHT_KEY, NEXT_EPOCH_MIN_START_TIME_KEY,
+    OLDEST_HEIGHT_KEY, OLD_DIFF_PREFIX, PRED_EPOCHS_KEY_SEGMENT,
+    PRED_KEY_PREFIX, RESULTS_KEY_PREFIX, UPDATE_EPOCH_BLOCKS_DELAY_KEY,
+};
+use super::SnapshotFormat;
+
+/// The key-vals of every column family, ordered by their keys
+type ColumnFamilies = HashMap<&'static str, BTreeMap<String, Vec<u8>>>;
+
+/// In-memory DB handle
+#[derive(Debug)]
+pub struct MemDB {
+    cfs: RwLock<ColumnFamilies>,
+}
+
+/// A batch of writes that are applied atomically on
+/// [`DB::exec_batch`]
+#[derive(Debug, Default)]
+pub struct MemDBWriteBatch(Vec<BatchOp>);
+
+#[derive(Debug)]
+enum BatchOp {
+    Put {
+        cf: &'static str,
+        key: String,
+        value: Vec<u8>,
+    },
+    Delete {
+        cf: &'static str,
+        key: String,
+    },
+}
+
+impl MemDBWriteBatch {
+    fn put(
+        &mut self,
+        cf: &'static str,
+        key: impl Into<String>,
+        value: impl Into<Vec<u8>>,
+    ) {
+        self.0.push(BatchOp::Put {
+            cf,
+            key: key.into(),
+            value: value.into(),
+        })
+    }
+
+    fn delete(&mut self, cf: &'static str, key: impl Into<String>) {
+        self.0.push(BatchOp::Delete {
+            cf,
+            key: key.into(),
+        })
+    }
+}
+
+impl Default for MemDB {
+    fn default() -> Self {
+        let cfs = DbColFam::all()
+            .into_iter()
+            .map(|cf_name| (cf_name, BTreeMap::new()))
+            .collect();
+        Self {
+            cfs: RwLock::new(cfs),
+        }
+    }
+}
+
+impl MemDB {
+    fn read_cfs(&self) -> RwLockReadGuard<'_, ColumnFamilies> {
+        self.cfs.read().expect("The DB lock shouldn't be poisoned")
+    }
+
+    fn write_cfs(&self) -> RwLockWriteGuard<'_, ColumnFamilies> {
+        self.cfs.write().expect("The DB lock shouldn't be poisoned")
+    }
+
+    fn read_value<T>(
+        &self,
+        cf_name: &str,
+        key: impl AsRef<str>,
+    ) -> Result<Option<T>>
+    where
+        T: BorshDeserialize,
+    {
+        self.read_value_bytes(cf_name, key)?
+            .map(|bytes| decode(bytes).map_err(Error::CodingError))
+            .transpose()
+    }
+
+    fn read_value_bytes(
+        &self,
+        cf_name: &str,
+        key: impl AsRef<str>,
+    ) -> Result<Option<Vec<u8>>> {
+        let cfs = self.read_cfs();
+        let cf = cfs.get(cf_name).ok_or_else(|| {
+            Error::DBError(format!("No {cf_name} column family"))
+        })?;
+        Ok(cf.get(key.as_ref()).cloned())
+    }
+
+    fn add_state_value_to_batch<T>(
+        &self,
+        key: &str,
+        value: &T,
+        batch: &mut MemDBWriteBatch,
+    ) -> Result<()>
+    where
+        T: BorshSerialize,
+    {
+        if let Some(current_value) = self.read_value_bytes(STATE_CF, key)? {
+            batch.put(
+                STATE_CF,
+                format!("{PRED_KEY_PREFIX}/{key}"),
+                current_value,
+            );
+        }
+        batch.put(STATE_CF, key, encode(value));
+        Ok(())
+    }
+
+    /// Persist the diff of an account subspace key-val under the height where
+    /// it was changed in a batch write.
+    fn batch_write_subspace_diff(
+        batch: &mut MemDBWriteBatch,
+        height: BlockHeight,
+        key: &Key,
+        old_value: Option<&[u8]>,
+        new_value: Option<&[u8]>,
+        persist_diffs: bool,
+    ) -> Result<()> {
+        let cf = if persist_diffs { DIFFS_CF } else { ROLLBACK_CF };
+        let (old_val_key, new_val_key) = old_and_new_diff_key(key, height)?;
+
+        if let Some(old_value) = old_value {
+            batch.put(cf, old_val_key, old_value);
+        }
+
+        if let Some(new_value) = new_value {
+            batch.put(cf, new_val_key, new_value);
+        }
+        Ok(())
+    }
+
+    /// Iterate the key-vals of the given column family matching the given
+    /// prefix(es). If any, the `stripped_prefix` is matched first and will be
+    /// removed from the matched keys. If any, the second `prefix` is matched
+    /// against the stripped keys and remains in the matched keys.
+    fn iter_cf_prefix(
+        &self,
+        cf_name: &str,
+        stripped_prefix: Option<&Key>,
+        prefix: Option<&Key>,
+    ) -> MemDBPrefixIterator {
+        let stripped_prefix = match stripped_prefix {
+            Some(p) if !p.is_empty() => format!("{p}/"),
+            _ => "".to_owned(),
+        };
+        let prefix = match prefix {
+            Some(p) if !p.is_empty() => {
+                format!("{stripped_prefix}{p}/")
+            }
+            _ => stripped_prefix.clone(),
+        };
+        let cfs = self.read_cfs();
+        let key_vals: Vec<_> = cfs
+            .get(cf_name)
+            .into_iter()
+            .flat_map(|cf| cf.range(prefix.clone()..))
+            .take_while(|(key, _val)| key.starts_with(&prefix))
+            .filter_map(|(key, val)| {
+                let key = key.strip_prefix(&stripped_prefix)?;
+                Some((key.to_owned(), val.clone()))
+            })
+            .collect();
+        MemDBPrefixIterator(key_vals.into_iter())
+    }
+
+    fn iter_diffs_prefix(
+        &self,
+        cf_name: &str,
+        height: BlockHeight,
+        prefix: Option<&Key>,
+        is_old: bool,
+    ) -> MemDBPrefixIterator {
+        let kind = if is_old {
+            OLD_DIFF_PREFIX
+        } else {
+            NEW_DIFF_PREFIX
+        };
+        let stripped_prefix = Some(
+            Key::from(height.to_db_key())
+                .push(&kind.to_string())
+                .unwrap(),
+        );
+        // get keys without the `stripped_prefix`
+        self.iter_cf_prefix(cf_name, stripped_prefix.as_ref(), prefix)
+    }
+
+    /// Rollback to previous block. As with the persistent DB, calling
+    /// rollback more than once results in a single rollback.
+    pub fn rollback(
+        &mut self,
+        tendermint_block_height: BlockHeight,
+    ) -> Result<()> {
+        let last_block = self.read_last_block()?.ok_or(Error::DBError(
+            "Missing last block in storage".to_string(),
+        ))?;
+        if tendermint_block_height == last_block.height {
+            return Ok(());
+        }
+        let last_height = last_block.height;
+        let previous_height = last_height.prev_height().ok_or_else(|| {
+            Error::DBError("Cannot rollback the first block".to_string())
+        })?;
+
+        let mut batch = MemDBWriteBatch::default();
+        // Revert the non-height-prepended metadata keys which get updated
+        // with every block
+        batch.put(STATE_CF, BLOCK_HEIGHT_KEY, encode(&previous_height));
+        for metadata_key in [
+            NEXT_EPOCH_MIN_START_HEIGHT_KEY,
+            NEXT_EPOCH_MIN_START_TIME_KEY,
+            COMMIT_ONLY_DATA_KEY,
+            UPDATE_EPOCH_BLOCKS_DELAY_KEY,
+        ] {
+            let previous_key = format!("{PRED_KEY_PREFIX}/{metadata_key}");
+            let previous_value = self
+                .read_value_bytes(STATE_CF, &previous_key)?
+                .ok_or(Error::UnknownKey { key: previous_key })?;
+            batch.put(STATE_CF, metadata_key, previous_value);
+        }
+
+        // Revert conversion state if the epoch had been changed
+        if last_block.pred_epochs.get_epoch(previous_height)
+            != Some(last_block.epoch)
+        {
+            let previous_key =
+                format!("{PRED_KEY_PREFIX}/{CONVERSION_STATE_KEY}");
+            let previous_value = self
+                .read_value_bytes(STATE_CF, &previous_key)?
+                .ok_or(Error::UnknownKey { key: previous_key })?;
+            batch.put(STATE_CF, CONVERSION_STATE_KEY, previous_value);
+        }
+
+        // Delete block results for the last block
+        batch.delete(
+            BLOCK_CF,
+            format!("{RESULTS_KEY_PREFIX}/{}", last_height.raw()),
+        );
+
+        // Remove the "current" tx hashes of replay protection
+        for (current_key, _, _) in self.iter_current_replay_protection() {
+            batch.delete(REPLAY_PROTECTION_CF, current_key);
+        }
+
+        // Delete the events of the last block from the event log
+        log::delete_events(self, &mut batch, last_height)
+            .map_err(|e| Error::DBError(e.to_string()))?;
+
+        // Restore the subspace keys with diffs to their previous value
+        for (key, _val, _gas) in self.iter_prefix(None) {
+            match self.read_subspace_val_with_height(
+                &Key::from(key.to_db_key()),
+                previous_height,
+                last_height,
+            )? {
+                Some(previous_value) => {
+                    batch.put(SUBSPACE_CF, key, previous_value)
+                }
+                None => batch.delete(SUBSPACE_CF, key),
+            }
+        }
+        // Restore the keys that were deleted in the last block
+        for (key, val, _gas) in
+            self.iter_diffs_prefix(DIFFS_CF, last_height, None, true)
+        {
+            batch.put(SUBSPACE_CF, key, val);
+        }
+
+        // Look for non-persisted diffs for rollback
+        let mut keys_with_old_value = HashSet::<String>::new();
+        for (key, val, _gas) in
+            self.iter_diffs_prefix(ROLLBACK_CF, last_height, None, true)
+        {
+            keys_with_old_value.insert(key.clone());
+            batch.put(SUBSPACE_CF, key, val);
+        }
+        for (key, _val, _gas) in
+            self.iter_diffs_prefix(ROLLBACK_CF, last_height, None, false)
+        {
+            if !keys_with_old_value.contains(&key) {
+                // The key was newly written in the last block
+                batch.delete(SUBSPACE_CF, key);
+            }
+        }
+
+        // Delete the diffs and the block data of the last height
+        let height_prefix = Key::from(last_height.to_db_key());
+        for cf_name in [DIFFS_CF, ROLLBACK_CF] {
+            for (key, _val, _gas) in
+                self.iter_cf_prefix(cf_name, None, Some(&height_prefix))
+            {
+                batch.delete(cf_name, key);
+            }
+        }
+        for key in block_keys_at_height(last_height) {
+            batch.delete(BLOCK_CF, key);
+        }
+
+        self.exec_batch(batch)
+    }
+
+    /// Take a copy of the current state of the DB
+    pub fn snapshot(&self) -> MemDBSnapshot {
+        MemDBSnapshot(self.read_cfs().clone())
+    }
+
+    /// Write the key-vals contained in a chunk of a snapshot in the given
+    /// format into the database.
+    pub fn apply_snapshot_chunk(
+        &self,
+        format: SnapshotFormat,
+        chunk: &[u8],
+    ) -> Result<()> {
+        let mut batch = MemDBWriteBatch::default();
+        for (cf_name, key, val) in decode_snapshot_entries(format, chunk)? {
+            batch.put(column_family(cf_name)?, key, val);
+        }
+        self.exec_batch(batch)
+    }
+
+    /// Delete all the data held in every column family
+    pub fn clear(&self) -> Result<()> {
+        for cf in self.write_cfs().values_mut() {
+            cf.clear();
+        }
+        Ok(())
+    }
+}
+
+/// Get the name of a column family with a static lifetime
+fn column_family(cf_name: &str) -> Result<&'static str> {
+    DbColFam::all()
+        .into_iter()
+        .find(|name| *name == cf_name)
+        .ok_or_else(|| Error::DBError(format!("No {cf_name} column family")))
+}
+
+/// A copy of the state of a [`MemDB`] at some point in time
+#[derive(Debug, Clone)]
+pub struct MemDBSnapshot(ColumnFamilies);
+
+impl MemDBSnapshot {
+    /// Write the snapshot out to file in the given format, in the same way
+    /// as the snapshots of the persistent DB.
+    pub fn write_to_file(
+        &self,
+        base_dir: PathBuf,
+        height: BlockHeight,
+        format: SnapshotFormat,
+    ) -> std::io::Result<()> {
+        let cf_names = DbColFam::all();
+        let column_families =
+            cf_names.iter().map(|cf_name| cf_name.to_string()).collect();
+        write_snapshot_file(column_families, base_dir, height, format, |f| {
+            for cf_name in cf_names {
+                for (key, val) in self.0.get(cf_name).into_iter().flatten() {
+                    f(cf_name, key, val)?;
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+impl DB for MemDB {
+    /// There is no cache for the in-memory DB
+    type Cache = ();
+    type Migrator = DbUpdateType;
+    type WriteBatch = MemDBWriteBatch;
+
+    fn open(
+        _db_path: impl AsRef<std::path::Path>,
+        _cache: Option<&Self::Cache>,
+    ) -> Self {
+        Self::default()
+    }
+
+    fn flush(&self, _wait: bool) -> Result<()> {
+        Ok(())
+    }
+
+    fn read_last_block(&self) -> Result<Option<BlockStateRead>> {
+        // Block height
+        let height: BlockHeight =
+            match self.read_value(STATE_CF, BLOCK_HEIGHT_KEY)? {
+                Some(h) => h,
+                None => return Ok(None),
+            };
+
+        // Epoch start height and time
+        let next_epoch_min_start_height =
+            match self.read_value(STATE_CF, NEXT_EPOCH_MIN_START_HEIGHT_KEY)? {
+                Some(h) => h,
+                None => return Ok(None),
+            };
+
+        let next_epoch_min_start_time =
+            match self.read_value(STATE_CF, NEXT_EPOCH_MIN_START_TIME_KEY)? {
+                Some(t) => t,
+                None => return Ok(None),
+            };
+
+        let update_epoch_blocks_delay =
+            match self.read_value(STATE_CF, UPDATE_EPOCH_BLOCKS_DELAY_KEY)? {
+                Some(d) => d,
+                None => return Ok(None),
+            };
+
+        let commit_only_data =
+            match self.read_value(STATE_CF, COMMIT_ONLY_DATA_KEY)? {
+                Some(d) => d,
+                None => return Ok(None),
+            };
+
+        let conversion_state =
+            match self.read_value(STATE_CF, CONVERSION_STATE_KEY)? {
+                Some(c) => c,
+                None => return Ok(None),
+            };
+
+        let ethereum_height =
+            match self.read_value(STATE_CF, ETHEREUM_HEIGHT_KEY)? {
+                Some(h) => h,
+                None => return Ok(None),
+            };
+
+        let eth_events_queue =
+            match self.read_value(STATE_CF, ETH_EVENTS_QUEUE_KEY)? {
+                Some(q) => q,
+                None => return Ok(None),
+            };
+
+        // Block results
+        let results_key = format!("{RESULTS_KEY_PREFIX}/{}", height.raw());
+        let results = match self.read_value(BLOCK_CF, results_key)? {
+            Some(r) => r,
+            None => return Ok(None),
+        };
+
+        let prefix = height.raw();
+
+        let time_key = format!("{prefix}/{BLOCK_TIME_KEY_SEGMENT}");
+        let time = match self.read_value(BLOCK_CF, time_key)? {
+            Some(t) => t,
+            None => return Ok(None),
+        };
+
+        let epoch_key = format!("{prefix}/{EPOCH_KEY_SEGMENT}");
+        let epoch = match self.read_value(BLOCK_CF, epoch_key)? {
+            Some(e) => e,
+            None => return Ok(None),
+        };
+
+        let pred_epochs_key = format!("{prefix}/{PRED_EPOCHS_KEY_SEGMENT}");
+        let pred_epochs = match self.read_value(BLOCK_CF, pred_epochs_key)? {
+            Some(e) => e,
+            None => return Ok(None),
+        };
+
+        let address_gen_key = format!("{prefix}/{ADDRESS_GEN_KEY_SEGMENT}");
+        let address_gen = match self.read_value(BLOCK_CF, address_gen_key)? {
+            Some(a) => a,
+            None => return Ok(None),
+        };
+
+        Ok(Some(BlockStateRead {
+            height,
+            time,
+            epoch,
+            pred_epochs,
+            results,
+            conversion_state,
+            next_epoch_min_start_height,
+            next_epoch_min_start_time,
+            update_epoch_blocks_delay,
+            address_gen,
+            ethereum_height,
+            eth_events_queue,
+            commit_only_data,
+        }))
+    }
+
+    fn add_block_to_batch(
+        &self,
+        state: BlockStateWrite<'_>,
+        batch: &mut Self::WriteBatch,
+        is_full_commit: bool,
+    ) -> Result<()> {
+        let BlockStateWrite {
+            merkle_tree_stores,
+            header,
+            height,
+            time,
+            epoch,
+            pred_epochs,
+            next_epoch_min_start_height,
+            next_epoch_min_start_time,
+            update_epoch_blocks_delay,
+            address_gen,
+            results,
+            conversion_state,
+            ethereum_height,
+            eth_events_queue,
+            commit_only_data,
+        }: BlockStateWrite<'_> = state;
+
+        // Epoch start height and time
+        self.add_state_value_to_batch(
+            NEXT_EPOCH_MIN_START_HEIGHT_KEY,
+            &next_epoch_min_start_height,
+            batch,
+        )?;
+        self.add_state_value_to_batch(
+            NEXT_EPOCH_MIN_START_TIME_KEY,
+            &next_epoch_min_start_time,
+            batch,
+        )?;
+        self.add_state_value_to_batch(
+            UPDATE_EPOCH_BLOCKS_DELAY_KEY,
+            &update_epoch_blocks_delay,
+            batch,
+        )?;
+        self.add_state_value_to_batch(
+            COMMIT_ONLY_DATA_KEY,
+            &commit_only_data,
+            batch,
+        )?;
+
+        // Save the conversion state when the epoch is updated
+        if is_full_commit {
+            self.add_state_value_to_batch(
+                CONVERSION_STATE_KEY,
+                &conversion_state,
+                batch,
+            )?;
+        }
+
+        batch.put(STATE_CF, ETHEREUM_HEIGHT_KEY, encode(&ethereum_height));
+        batch.put(STATE_CF, ETH_EVENTS_QUEUE_KEY, encode(&eth_events_queue));
+
+        let prefix = height.raw();
+
+        // Merkle tree
+        for st in StoreType::iter() {
+            if st.is_stored_every_block() || is_full_commit {
+                let key_prefix = if st.is_stored_every_block() {
+                    tree_key_prefix_with_height(st, height)
+                } else {
+                    tree_key_prefix_with_epoch(st, epoch)
+                };
+                let root_key =
+                    format!("{key_prefix}/{MERKLE_TREE_ROOT_KEY_SEGMENT}");
+                batch.put(
+                    BLOCK_CF,
+                    root_key,
+                    encode(merkle_tree_stores.root(st)),
+                );
+                let store_key =
+                    format!("{key_prefix}/{MERKLE_TREE_STORE_KEY_SEGMENT}");
+                batch.put(
+                    BLOCK_CF,
+                    store_key,
+                    merkle_tree_stores.store(st).encode(),
+                );
+            }
+        }
+
+        // Block header
+        if let Some(h) = header {
+            let header_key = format!("{prefix}/{BLOCK_HEADER_KEY_SEGMENT}");
+            batch.put(BLOCK_CF, header_key, encode(&h));
+        }
+        // Block time
+        let time_key = format!("{prefix}/{BLOCK_TIME_KEY_SEGMENT}");
+        batch.put(BLOCK_CF, time_key, encode(&time));
+        // Block epoch
+        let epoch_key = format!("{prefix}/{EPOCH_KEY_SEGMENT}");
+        batch.put(BLOCK_CF, epoch_key, encode(&epoch));
+        // Block results
+        let results_key = format!("{RESULTS_KEY_PREFIX}/{}", height.raw());
+        batch.put(BLOCK_CF, results_key, encode(&results));
+        // Predecessor block epochs
+        let pred_epochs_key = format!("{prefix}/{PRED_EPOCHS_KEY_SEGMENT}");
+        batch.put(BLOCK_CF, pred_epochs_key, encode(&pred_epochs));
+        // Address gen
+        let address_gen_key = format!("{prefix}/{ADDRESS_GEN_KEY_SEGMENT}");
+        batch.put(BLOCK_CF, address_gen_key, encode(&address_gen));
+
+        // Block height
+        batch.put(STATE_CF, BLOCK_HEIGHT_KEY, encode(&height));
+
+        Ok(())
+    }
+
+    fn read_block_header(&self, height: BlockHeight) -> Result<Option<Header>> {
+        let header_key = format!("{}/{BLOCK_HEADER_KEY_SEGMENT}", height.raw());
+        self.read_value(BLOCK_CF, header_key)
+    }
+
+    fn read_merkle_tree_stores(
+        &self,
+        epoch: Epoch,
+        base_height: BlockHeight,
+        store_type: Option<StoreType>,
+    ) -> Result<Option<MerkleTreeStoresRead>> {
+        let mut merkle_tree_stores = MerkleTreeStoresRead::default();
+        let store_types = store_type
+            .as_ref()
+            .map(|st| Either::Left(std::iter::once(st)))
+            .unwrap_or_else(|| Either::Right(StoreType::iter()));
+        for st in store_types {
+            let key_prefix = if st.is_stored_every_block() {
+                tree_key_prefix_with_height(st, base_height)
+            } else {
+                tree_key_prefix_with_epoch(st, epoch)
+            };
+            let root_key =
+                format!("{key_prefix}/{MERKLE_TREE_ROOT_KEY_SEGMENT}");
+            match self.read_value(BLOCK_CF, root_key)? {
+                Some(root) => merkle_tree_stores.set_root(st, root),
+                None => return Ok(None),
+            }
+
+            let store_key =
+                format!("{key_prefix}/{MERKLE_TREE_STORE_KEY_SEGMENT}");
+            match self.read_value_bytes(BLOCK_CF, store_key)? {
+                Some(bytes) => {
+                    merkle_tree_stores.set_store(st.decode_store(bytes)?)
+                }
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(merkle_tree_stores))
+    }
+
+    fn has_replay_protection_entry(
+        &self,
+        hash: &namada_sdk::hash::Hash,
+    ) -> Result<bool> {
+        for key in [
+            replay_protection::current_key(hash),
+            replay_protection::key(hash),
+        ] {
+            if self
+                .read_value_bytes(REPLAY_PROTECTION_CF, key.to_string())?
+                .is_some()
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn read_diffs_val(
+        &self,
+        key: &Key,
+        height: BlockHeight,
+        is_old: bool,
+    ) -> Result<Option<Vec<u8>>> {
+        let key = if is_old {
+            old_and_new_diff_key(key, height)?.0
+        } else {
+            old_and_new_diff_key(key, height)?.1
+        };
+        self.read_value_bytes(DIFFS_CF, key)
+    }
+
+    fn read_subspace_val(&self, key: &Key) -> Result<Option<Vec<u8>>> {
+        self.read_value_bytes(SUBSPACE_CF, key.to_string())
+    }
+
+    fn read_subspace_val_with_height(
+        &self,
+        key: &Key,
+        height: BlockHeight,
+        last_height: BlockHeight,
+    ) -> Result<Option<Vec<u8>>> {
+        // Check if the value changed at this height
+        let (old_val_key, new_val_key) = old_and_new_diff_key(key, height)?;
+
+        // If it has a "new" val, it was written at this height
+        if let Some(new_val) = self.read_value_bytes(DIFFS_CF, new_val_key)? {
+            return Ok(Some(new_val));
+        }
+        // If it has an "old" val, it was deleted at this height
+        if self.read_value_bytes(DIFFS_CF, old_val_key)?.is_some() {
+            return Ok(None);
+        }
+
+        // If the value didn't change at the given height, we try to look for
+        // it at successor heights, up to the `last_height`
+        let mut raw_height = checked!(height.0 + 1)?;
+        while raw_height <= last_height.0 {
+            let (old_val_key, new_val_key) =
+                old_and_new_diff_key(key, BlockHeight(raw_height))?;
+            // If it has an "old" val, it's the one we're looking for
+            if let Some(old_val) =
+                self.read_value_bytes(DIFFS_CF, old_val_key)?
+            {
+                return Ok(Some(old_val));
+            }
+            // Check if the value was created at this height instead, which
+            // would mean that it wasn't present before
+            if self.read_value_bytes(DIFFS_CF, new_val_key)?.is_some() {
+                return Ok(None);
+            }
+            checked!(raw_height += 1)?;
+        }
+        // Read from latest height
+        self.read_subspace_val(key)
+    }
+
+    fn write_subspace_val(
+        &mut self,
+        height: BlockHeight,
+        key: &Key,
+        value: impl AsRef<[u8]>,
+        persist_diffs: bool,
+    ) -> Result<i64> {
+        let mut batch = MemDB::batch();
+        let size_diff = self.batch_write_subspace_val(
+            &mut batch,
+            height,
+            key,
+            value,
+            persist_diffs,
+        )?;
+        self.exec_batch(batch)?;
+        Ok(size_diff)
+    }
+
+    fn delete_subspace_val(
+        &mut self,
+        height: BlockHeight,
+        key: &Key,
+        persist_diffs: bool,
+    ) -> Result<i64> {
+        let mut batch = MemDB::batch();
+        let prev_len = self.batch_delete_subspace_val(
+            &mut batch,
+            height,
+            key,
+            persist_diffs,
+        )?;
+        self.exec_batch(batch)?;
+        Ok(prev_len)
+    }
+
+    fn batch() -> Self::WriteBatch {
+        MemDBWriteBatch::default()
+    }
+
+    fn exec_batch(&self, batch: Self::WriteBatch) -> Result<()> {
+        let mut cfs = self.write_cfs();
+        for op in batch.0 {
+            match op {
+                BatchOp::Put { cf, key, value } => {
+                    cfs.entry(cf).or_default().insert(key, value);
+                }
+                BatchOp::Delete { cf, key } => {
+                    if let Some(cf) = cfs.get_mut(cf) {
+                        cf.remove(&key);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn batch_write_subspace_val(
+        &self,
+        batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+        key: &Key,
+        value: impl AsRef<[u8]>,
+        persist_diffs: bool,
+    ) -> Result<i64> {
+        let value = value.as_ref();
+        let size_diff = match self.read_subspace_val(key)? {
+            Some(old_value) => {
+                let len = i64::try_from(value.len())?;
+                let old_len = i64::try_from(old_value.len())?;
+                let size_diff = checked!(len - old_len)?;
+                // Persist the previous value
+                Self::batch_write_subspace_diff(
+                    batch,
+                    height,
+                    key,
+                    Some(&old_value),
+                    Some(value),
+                    persist_diffs,
+                )?;
+                size_diff
+            }
+            None => {
+                Self::batch_write_subspace_diff(
+                    batch,
+                    height,
+                    key,
+                    None,
+                    Some(value),
+                    persist_diffs,
+                )?;
+                i64::try_from(value.len())?
+            }
+        };
+
+        // Write the new key-val
+        batch.put(SUBSPACE_CF, key.to_string(), value);
+
+        Ok(size_diff)
+    }
+
+    fn batch_delete_subspace_val(
+        &self,
+        batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+        key: &Key,
+        persist_diffs: bool,
+    ) -> Result<i64> {
+        // Check the length of previous value, if any
+        let prev_len = match self.read_subspace_val(key)? {
+            Some(prev_value) => {
+                let prev_len = i64::try_from(prev_value.len())?;
+                // Persist the previous value
+                Self::batch_write_subspace_diff(
+                    batch,
+                    height,
+                    key,
+                    Some(&prev_value),
+                    None,
+                    persist_diffs,
+                )?;
+                prev_len
+            }
+            None => 0,
+        };
+
+        // Delete the key-val
+        batch.delete(SUBSPACE_CF, key.to_string());
+
+        Ok(prev_len)
+    }
+
+    fn prune_merkle_tree_store(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+        store_type: &StoreType,
+        epoch: Epoch,
+    ) -> Result<()> {
+        let key_prefix = tree_key_prefix_with_epoch(store_type, epoch);
+        let root_key = format!("{key_prefix}/{MERKLE_TREE_ROOT_KEY_SEGMENT}");
+        batch.delete(BLOCK_CF, root_key);
+        let store_key = format!("{key_prefix}/{MERKLE_TREE_STORE_KEY_SEGMENT}");
+        batch.delete(BLOCK_CF, store_key);
+        Ok(())
+    }
+
+    fn read_bridge_pool_signed_nonce(
+        &self,
+        height: BlockHeight,
+        last_height: BlockHeight,
+    ) -> Result<Option<ethereum_events::Uint>> {
+        let nonce_key = bridge_pool::get_signed_root_key();
+        let bytes = if height == BlockHeight(0) || height >= last_height {
+            self.read_subspace_val(&nonce_key)?
+        } else {
+            self.read_subspace_val_with_height(&nonce_key, height, last_height)?
+        };
+        match bytes {
+            Some(bytes) => {
+                let bp_root_proof = BridgePoolRootProof::try_from_slice(&bytes)
+                    .map_err(Error::BorshCodingError)?;
+                Ok(Some(bp_root_proof.data.1))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn write_replay_protection_entry(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+        key: &Key,
+    ) -> Result<()> {
+        batch.put(REPLAY_PROTECTION_CF, key.to_string(), vec![]);
+        Ok(())
+    }
+
+    fn move_current_replay_protection_entries(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+    ) -> Result<()> {
+        let stripped_prefix = Some(replay_protection::current_prefix());
+
+        for (ref hash_str, _, _) in self.iter_cf_prefix(
+            REPLAY_PROTECTION_CF,
+            stripped_prefix.as_ref(),
+            None,
+        ) {
+            let hash = namada_sdk::hash::Hash::from_str(hash_str)
+                .expect("Failed hash conversion");
+            let current_key = replay_protection::current_key(&hash);
+            let key = replay_protection::key(&hash);
+
+            // Delete the current key and move it to the general bucket
+            batch.delete(REPLAY_PROTECTION_CF, current_key.to_string());
+            batch.put(REPLAY_PROTECTION_CF, key.to_string(), vec![]);
+        }
+
+        Ok(())
+    }
+
+    fn prune_non_persisted_diffs(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+    ) -> Result<()> {
+        let height_prefix = Key::from(height.to_db_key());
+        for (key, _val, _gas) in
+            self.iter_cf_prefix(ROLLBACK_CF, None, Some(&height_prefix))
+        {
+            batch.delete(ROLLBACK_CF, key);
+        }
+        Ok(())
+    }
+
+    fn prune_history(&self, oldest_height: BlockHeight) -> Result<()> {
+        let pruned_height = self.read_oldest_height()?.unwrap_or_default();
+        if oldest_height <= pruned_height {
+            return Ok(());
+        }
+
+        let mut batch = MemDB::batch();
+        batch.put(STATE_CF, OLDEST_HEIGHT_KEY, encode(&oldest_height));
+        let mut height = pruned_height;
+        while height < oldest_height {
+            let height_prefix = Key::from(height.to_db_key());
+            for cf_name in [DIFFS_CF, ROLLBACK_CF] {
+                for (key, _val, _gas) in
+                    self.iter_cf_prefix(cf_name, None, Some(&height_prefix))
+                {
+                    batch.delete(cf_name, key);
+                }
+            }
+            for key in block_keys_at_height(height) {
+                batch.delete(BLOCK_CF, key);
+            }
+            height = height.next_height();
+        }
+        self.exec_batch(batch)
+    }
+
+    fn read_oldest_height(&self) -> Result<Option<BlockHeight>> {
+        self.read_value(STATE_CF, OLDEST_HEIGHT_KEY)
+    }
+
+    fn read_events_index(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let cfs = self.read_cfs();
+        Ok(cfs.get(EVENTS_CF).and_then(|cf| cf.get(key)).cloned())
+    }
+
+    fn batch_write_events_index(
+        &self,
+        batch: &mut Self::WriteBatch,
+        entries: impl IntoIterator<Item = (String, Vec<u8>)>,
+    ) -> Result<()> {
+        for (key, value) in entries {
+            batch.put(EVENTS_CF, key, value);
+        }
+        Ok(())
+    }
+
+    fn batch_delete_events_index(
+        &self,
+        batch: &mut Self::WriteBatch,
+        keys: impl IntoIterator<Item = String>,
+    ) -> Result<()> {
+        for key in keys {
+            batch.delete(EVENTS_CF, key);
+        }
+        Ok(())
+    }
+
+    fn overwrite_entry(
+        &self,
+        batch: &mut Self::WriteBatch,
+        height: Option<BlockHeight>,
+        cf: &DbColFam,
+        key: &Key,
+        new_value: impl AsRef<[u8]>,
+    ) -> Result<()> {
+        let last_height: BlockHeight = self
+            .read_value(STATE_CF, BLOCK_HEIGHT_KEY)?
+            .ok_or_else(|| {
+                Error::DBError("No block height found".to_string())
+            })?;
+        if height.is_some_and(|height| height != last_height) {
+            return Err(Error::DBError(
+                "Overwriting values at heights different than the last \
+                 committed height is not supported"
+                    .to_string(),
+            ));
+        }
+        let val = new_value.as_ref();
+
+        // Write the new key-val in the Db column family
+        batch.put(column_family(cf.to_str())?, key.to_string(), val);
+
+        // If the CF is subspace, additionally update the diffs
+        if cf == &DbColFam::SUBSPACE {
+            let (_old_val_key, new_val_key) =
+                old_and_new_diff_key(key, last_height)?;
+            batch.put(DIFFS_CF, new_val_key, val);
+        }
+
+        Ok(())
+    }
+
+    fn apply_migration_to_batch(
+        &self,
+        updates: impl IntoIterator<Item = DbUpdateType>,
+    ) -> Result<MemDBWriteBatch> {
+        let mut db_visitor = MemDBUpdateVisitor {
+            db: self,
+            batch: MemDB::batch(),
+        };
+        for change in updates.into_iter() {
+            match change.update(&mut db_visitor) {
+                Ok(status) => {
+                    tracing::info!("{}", status);
+                }
+                Err(e) => {
+                    let error = format!(
+                        "Attempt to write to key/pattern <{}> failed:\n{}.",
+                        change.pattern(),
+                        e
+                    );
+                    tracing::error!(error);
+                    return Err(Error::DBError(error));
+                }
+            }
+        }
+        Ok(db_visitor.batch)
+    }
+}
+
+/// A struct that can visit a set of updates, registering them all in the
+/// batch
+struct MemDBUpdateVisitor<'db> {
+    db: &'db MemDB,
+    batch: MemDBWriteBatch,
+}
+
+impl<'db> DBUpdateVisitor for MemDBUpdateVisitor<'db> {
+    fn read(&self, key: &Key, cf: &DbColFam) -> Option<Vec<u8>> {
+        self.db
+            .read_value_bytes(cf.to_str(), key.to_string())
+            .expect("Failed to read from storage")
+    }
+
+    fn write(&mut self, key: &Key, cf: &DbColFam, value: impl AsRef<[u8]>) {
+        self.db
+            .overwrite_entry(&mut self.batch, None, cf, key, value)
+            .expect("Failed to overwrite a key in storage")
+    }
+
+    fn delete(&mut self, key: &Key, cf: &DbColFam) {
+        let last_height: BlockHeight = self
+            .db
+            .read_value(STATE_CF, BLOCK_HEIGHT_KEY)
+            .unwrap()
+            .unwrap();
+        match cf {
+            DbColFam::SUBSPACE => {
+                self.db
+                    .batch_delete_subspace_val(
+                        &mut self.batch,
+                        last_height,
+                        key,
+                        true,
+                    )
+                    .expect("Failed to delete key from storage");
+            }
+            _ => {
+                let cf_name = column_family(cf.to_str())
+                    .expect("Failed to read column family from storage");
+                self.batch.delete(cf_name, key.to_string());
+            }
+        };
+    }
+
+    fn get_pattern(&self, pattern: Regex) -> Vec<(String, Vec<u8>)> {
+        self.db
+            .iter_pattern(None, pattern)
+            .map(|(k, v, _)| (k, v))
+            .collect()
+    }
+}
+
+impl<'iter> DBIter<'iter> for MemDB {
+    type PatternIter = MemDBPatternIterator;
+    type PrefixIter = MemDBPrefixIterator;
+
+    fn iter_prefix(&'iter self, prefix: Option<&Key>) -> MemDBPrefixIterator {
+        self.iter_cf_prefix(SUBSPACE_CF, None, prefix)
+    }
+
+    fn iter_pattern(
+        &'iter self,
+        prefix: Option<&Key>,
+        pattern: Regex,
+    ) -> MemDBPatternIterator {
+        MemDBPatternIterator {
+            iter: self.iter_prefix(prefix),
+            pattern,
+        }
+    }
+
+    fn iter_results(&'iter self) -> MemDBPrefixIterator {
+        let prefix = Key::from(RESULTS_KEY_PREFIX.to_string().to_db_key());
+        self.iter_cf_prefix(BLOCK_CF, Some(&prefix), None)
+    }
+
+    fn iter_old_diffs(
+        &'iter self,
+        height: BlockHeight,
+        prefix: Option<&'iter Key>,
+    ) -> MemDBPrefixIterator {
+        self.iter_diffs_prefix(DIFFS_CF, height, prefix, true)
+    }
+
+    fn iter_new_diffs(
+        &'iter self,
+        height: BlockHeight,
+        prefix: Option<&'iter Key>,
+    ) -> MemDBPrefixIterator {
+        self.iter_diffs_prefix(DIFFS_CF, height, prefix, false)
+    }
+
+    fn iter_current_replay_protection(&'iter self) -> MemDBPrefixIterator {
+        let prefix = Some(replay_protection::current_prefix());
+        self.iter_cf_prefix(REPLAY_PROTECTION_CF, None, prefix.as_ref())
+    }
+
+    fn iter_events_index(
+        &'iter self,
+        prefix: &str,
+        start: Option<&str>,
+    ) -> Result<MemDBPrefixIterator> {
+        let start = start.map_or(prefix, |start| start.max(prefix));
+        let cfs = self.read_cfs();
+        let key_vals: Vec<_> = cfs
+            .get(EVENTS_CF)
+            .into_iter()
+            .flat_map(|cf| cf.range(start.to_owned()..))
+            .take_while(|(key, _val)| key.starts_with(prefix))
+            .map(|(key, val)| (key.clone(), val.clone()))
+            .collect();
+        Ok(MemDBPrefixIterator(key_vals.into_iter()))
+    }
+}
+
+/// An iterator over the key-vals read from a [`MemDB`] under some prefix.
+/// The key-vals are read eagerly, so that the DB is not locked while
+/// iterating.
+#[derive(Debug)]
+pub struct MemDBPrefixIterator(std::vec::IntoIter<(String, Vec<u8>)>);
+
+impl Iterator for MemDBPrefixIterator {
+    type Item = (String, Vec<u8>, u64);
+
+    /// Returns the next pair and the gas cost
+    fn next(&mut self) -> Option<(String, Vec<u8>, u64)> {
+        let (key, val) = self.0.next()?;
+        let gas = u64::try_from(key.len().checked_add(val.len())?).ok()?;
+        Some((key, val, gas))
+    }
+}
+
+/// An iterator over the key-vals read from a [`MemDB`] whose keys match a
+/// pattern
+#[derive(Debug)]
+pub struct MemDBPatternIterator {
+    iter: MemDBPrefixIterator,
+    pattern: Regex,
+}
+
+impl Iterator for MemDBPatternIterator {
+    type Item = (String, Vec<u8>, u64);
+
+    /// Returns the next pair and the gas cost
+    fn next(&mut self) -> Option<(String, Vec<u8>, u64)> {
+        loop {
+            let next_result = self.iter.next()?;
+            if self.pattern.is_match(&next_result.0) {
+                return Some(next_result);
+            }
+        }
+    }
+}
+
+impl DBWriteBatch for MemDBWriteBatch {}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[cfg(test)]
+mod test {
+    use namada_sdk::address::EstablishedAddressGen;
+    use namada_sdk::hash::Hash;
+    use namada_sdk::state::{MerkleTree, Sha256Hasher};
+    use namada_sdk::storage::conversion_state::ConversionState;
+    use namada_sdk::storage::types::CommitOnlyData;
+    use namada_sdk::storage::{BlockResults, Epochs, EthEventsQueue};
+    use namada_sdk::time::DateTimeUtc;
+    use test_log::test;
+
+    use super::*;
+    use crate::storage::DbSnapshot;
+
+    /// Test that the values at past heights are read back from the diffs
+    #[test]
+    fn test_read_subspace_val_with_height() {
+        let mut db = MemDB::default();
+        let key = Key::parse("key").unwrap();
+
+        db.write_subspace_val(BlockHeight(1), &key, [1_u8], true)
+            .unwrap();
+        db.write_subspace_val(BlockHeight(3), &key, [3_u8], true)
+            .unwrap();
+        db.delete_subspace_val(BlockHeight(5), &key, true).unwrap();
+        db.write_subspace_val(BlockHeight(6), &key, [6_u8], true)
+            .unwrap();
+
+        let last_height = BlockHeight(7);
+        let expected = [
+            None,
+            Some(vec![1_u8]),
+            Some(vec![1_u8]),
+            Some(vec![3_u8]),
+            Some(vec![3_u8]),
+            None,
+            Some(vec![6_u8]),
+            Some(vec![6_u8]),
+        ];
+        for (height, expected) in (0..).zip(expected) {
+            let val = db
+                .read_subspace_val_with_height(
+                    &key,
+                    BlockHeight(height),
+                    last_height,
+                )
+                .unwrap();
+            assert_eq!(val, expected, "Unexpected value at height {height}");
+        }
+
+        // Non-persisted diffs cannot be read at past heights
+        let non_persisted = Key::parse("non_persisted").unwrap();
+        db.write_subspace_val(BlockHeight(1), &non_persisted, [1_u8], false)
+            .unwrap();
+        db.write_subspace_val(BlockHeight(3), &non_persisted, [3_u8], false)
+            .unwrap();
+        let val = db
+            .read_subspace_val_with_height(
+                &non_persisted,
+                BlockHeight(1),
+                last_height,
+            )
+            .unwrap();
+        assert_eq!(val, Some(vec![3_u8]));
+    }
+
+    /// Test that a rollback restores the state of the previous block
+    #[test]
+    fn test_rollback() {
+        for persist_diffs in [true, false] {
+            println!("Running with persist_diffs: {persist_diffs}");
+
+            let mut db = MemDB::default();
+
+            // A key that's gonna be added on a second block
+            let add_key = Key::parse("add").unwrap();
+            // A key that's gonna be deleted on a second block
+            let delete_key = Key::parse("delete").unwrap();
+            // A key that's gonna be overwritten on a second block
+            let overwrite_key = Key::parse("overwrite").unwrap();
+
+            // Write first block
+            let mut batch = MemDB::batch();
+            let height_0 = BlockHeight(100);
+            let mut pred_epochs = Epochs::default();
+            pred_epochs.new_epoch(height_0);
+            let to_delete_val = vec![1_u8, 1, 0, 0];
+            let to_overwrite_val = vec![1_u8, 1, 1, 0];
+            db.batch_write_subspace_val(
+                &mut batch,
+                height_0,
+                &delete_key,
+                &to_delete_val,
+                persist_diffs,
+            )
+            .unwrap();
+            db.batch_write_subspace_val(
+                &mut batch,
+                height_0,
+                &overwrite_key,
+                &to_overwrite_val,
+                persist_diffs,
+            )
+            .unwrap();
+            db.write_replay_protection_entry(
+                &mut batch,
+                &replay_protection::current_key(&Hash::sha256(b"tx1")),
+            )
+            .unwrap();
+            add_block_to_batch(
+                &db,
+                &mut batch,
+                height_0,
+                Epoch(1),
+                pred_epochs.clone(),
+            )
+            .unwrap();
+            db.exec_batch(batch).unwrap();
+
+            // Write second block
+            let mut batch = MemDB::batch();
+            let height_1 = BlockHeight(101);
+            pred_epochs.new_epoch(height_1);
+            let add_val = vec![1_u8, 0, 0, 0];
+            let overwrite_val = vec![1_u8, 1, 1, 1];
+            db.batch_write_subspace_val(
+                &mut batch,
+                height_1,
+                &add_key,
+                &add_val,
+                persist_diffs,
+            )
+            .unwrap();
+            db.batch_write_subspace_val(
+                &mut batch,
+                height_1,
+                &overwrite_key,
+                &overwrite_val,
+                persist_diffs,
+            )
+            .unwrap();
+            db.batch_delete_subspace_val(
+                &mut batch,
+                height_1,
+                &delete_key,
+                persist_diffs,
+            )
+            .unwrap();
+            db.move_current_replay_protection_entries(&mut batch)
+                .unwrap();
+            db.write_replay_protection_entry(
+                &mut batch,
+                &replay_protection::current_key(&Hash::sha256(b"tx2")),
+            )
+            .unwrap();
+            add_block_to_batch(
+                &db,
+                &mut batch,
+                height_1,
+                Epoch(2),
+                pred_epochs,
+            )
+            .unwrap();
+            db.exec_batch(batch).unwrap();
+
+            // Rollback to the first block height
+            db.rollback(height_0).unwrap();
+
+            // Check that the values are back to the state at the first block
+            let last_block = db.read_last_block().unwrap().unwrap();
+            assert_eq!(last_block.height, height_0);
+            assert_eq!(last_block.epoch, Epoch(1));
+            let added = db.read_subspace_val(&add_key).unwrap();
+            assert_eq!(added, None);
+            let overwritten = db.read_subspace_val(&overwrite_key).unwrap();
+            assert_eq!(overwritten, Some(to_overwrite_val));
+            let deleted = db.read_subspace_val(&delete_key).unwrap();
+            assert_eq!(deleted, Some(to_delete_val));
+            let tx1 = Hash::sha256(b"tx1");
+            assert!(db.has_replay_protection_entry(&tx1).unwrap());
+            let tx2 = Hash::sha256(b"tx2");
+            assert!(!db.has_replay_protection_entry(&tx2).unwrap());
+            assert_eq!(db.iter_new_diffs(height_1, None).count(), 0);
+            assert_eq!(db.iter_old_diffs(height_1, None).count(), 0);
+        }
+    }
+
+    /// Test that a snapshot of the in-memory DB can be restored from the
+    /// files written out in the same format as the persistent DB snapshots
+    #[test]
+    fn test_snapshot_round_trip() {
+        let mut db = MemDB::default();
+        let key = Key::parse("bing/bong").unwrap();
+        db.write_subspace_val(BlockHeight(1), &key, [1_u8; 64], true)
+            .unwrap();
+        let snapshot = db.snapshot();
+        // Writes after the snapshot was taken are not included in it
+        db.write_subspace_val(BlockHeight(2), &key, [2_u8; 64], true)
+            .unwrap();
+
+        let snap_dir = tempfile::tempdir().unwrap();
+        snapshot
+            .write_to_file(
+                snap_dir.path().to_path_buf(),
+                BlockHeight(1),
+                SnapshotFormat::LATEST,
+            )
+            .unwrap();
+        let mut files = DbSnapshot::files(snap_dir.path()).unwrap();
+        assert_eq!(files.len(), 1);
+        let metadata = files.pop().unwrap();
+
+        let restored = MemDB::default();
+        for (ix, _chunk) in (0u64..).zip(&metadata.manifest.chunks) {
+            let bytes =
+                DbSnapshot::load_chunk(BlockHeight(1), ix, snap_dir.path())
+                    .unwrap();
+            let lines =
+                DbSnapshot::decode_chunk(metadata.format, &bytes).unwrap();
+            restored
+                .apply_snapshot_chunk(metadata.format, &lines)
+                .unwrap();
+        }
+        assert_eq!(
+            restored.read_subspace_val(&key).unwrap(),
+            Some(vec![1_u8; 64])
+        );
+        assert_eq!(
+            restored
+                .read_diffs_val(&key, BlockHeight(1), false)
+                .unwrap(),
+            Some(vec![1_u8; 64])
+        );
+        assert_eq!(
+            restored
+                .read_diffs_val(&key, BlockHeight(2), false)
+                .unwrap(),
+            None
+        );
+
+        restored.clear().unwrap();
+        assert_eq!(restored.read_subspace_val(&key).unwrap(), None);
+    }
+
+    /// A test helper to write a block
+    fn add_block_to_batch(
+        db: &MemDB,
+        batch: &mut MemDBWriteBatch,
+        height: BlockHeight,
+        epoch: Epoch,
+        pred_epochs: Epochs,
+    ) -> Result<()> {
+        let merkle_tree = MerkleTree::<Sha256Hasher>::default();
+        let merkle_tree_stores = merkle_tree.stores();
+        #[allow(clippy::disallowed_methods)]
+        let time = DateTimeUtc::now();
+        let next_epoch_min_start_height = BlockHeight::default();
+        #[allow(clippy::disallowed_methods)]
+        let next_epoch_min_start_time = DateTimeUtc::now();
+        let update_epoch_blocks_delay = None;
+        let address_gen = EstablishedAddressGen::new("whatever");
+        let results = BlockResults::default();
+        let conversion_state = ConversionState::default();
+        let eth_events_queue = EthEventsQueue::default();
+        let commit_only_data = CommitOnlyData::default();
+        let block = BlockStateWrite {
+            merkle_tree_stores,
+            header: None,
+            height,
+            time,
+            epoch,
+            results: &results,
+            conversion_state: &conversion_state,
+            pred_epochs: &pred_epochs,
+            next_epoch_min_start_height,
+            next_epoch_min_start_time,
+            update_epoch_blocks_delay,
+            address_gen: &address_gen,
+            ethereum_height: None,
+            eth_events_queue: &eth_events_queue,
+            commit_only_data: &commit_only_data,
+        };
+
+        db.add_block_to_batch(block, batch, true)
+    }
+}
@@ -1,6 +1,7 @@
 //! The storage module handles both the current state in-memory and the stored
 //! state in DB.
 
+mod memdb;
 mod rocksdb;
 
 use std::fmt;
@@ -9,8 +10,16 @@ use arse_merkle_tree::blake2b::Blake2bHasher;
 use arse_merkle_tree::traits::Hasher;
 use arse_merkle_tree::H256;
 use blake2b_rs::{Blake2b, Blake2bBuilder};
-use namada_sdk::state::{FullAccessState, StorageHasher};
-pub use rocksdb::{open, DbSnapshot, RocksDBUpdateVisitor, SnapshotMetadata};
+pub use memdb::{
+    MemDB, MemDBPatternIterator, MemDBPrefixIterator, MemDBSnapshot,
+    MemDBWriteBatch,
+};
+use namada_sdk::state::{DBIter, DbResult, FullAccessState, StorageHasher, DB};
+use namada_sdk::storage::BlockHeight;
+pub use rocksdb::{
+    open, Chunk, ColumnFamilyStats, DbSnapshot, RocksDBUpdateVisitor,
+    SnapshotFormat, SnapshotManifest, SnapshotMetadata,
+};
 
 #[derive(Default)]
 pub struct PersistentStorageHasher(Blake2bHasher);
@@ -20,6 +29,72 @@ pub type PersistentDB = rocksdb::RocksDB;
 pub type PersistentState =
     FullAccessState<PersistentDB, PersistentStorageHasher>;
 
+/// A DB backend that can run the full ledger shell, including the state sync
+/// and rollback that are not covered by the [`DB`] trait.
+pub trait LedgerDB:
+    DB + for<'iter> DBIter<'iter> + Send + Sync + 'static
+{
+    /// Rollback to the previous block. Calling it more than once results in
+    /// a single rollback.
+    fn rollback(
+        &mut self,
+        tendermint_block_height: BlockHeight,
+    ) -> DbResult<()>;
+
+    /// Write the key-vals contained in a chunk of a snapshot in the given
+    /// format into the DB.
+    fn apply_snapshot_chunk(
+        &self,
+        format: SnapshotFormat,
+        chunk: &[u8],
+    ) -> DbResult<()>;
+
+    /// Delete all the data held in the DB.
+    fn clear(&self) -> DbResult<()>;
+}
+
+impl LedgerDB for PersistentDB {
+    fn rollback(
+        &mut self,
+        tendermint_block_height: BlockHeight,
+    ) -> DbResult<()> {
+        PersistentDB::rollback(self, tendermint_block_height)
+    }
+
+    fn apply_snapshot_chunk(
+        &self,
+        format: SnapshotFormat,
+        chunk: &[u8],
+    ) -> DbResult<()> {
+        PersistentDB::apply_snapshot_chunk(self, format, chunk)
+    }
+
+    fn clear(&self) -> DbResult<()> {
+        PersistentDB::clear(self)
+    }
+}
+
+impl LedgerDB for MemDB {
+    fn rollback(
+        &mut self,
+        tendermint_block_height: BlockHeight,
+    ) -> DbResult<()> {
+        MemDB::rollback(self, tendermint_block_height)
+    }
+
+    fn apply_snapshot_chunk(
+        &self,
+        format: SnapshotFormat,
+        chunk: &[u8],
+    ) -> DbResult<()> {
+        MemDB::apply_snapshot_chunk(self, format, chunk)
+    }
+
+    fn clear(&self) -> DbResult<()> {
+        MemDB::clear(self)
+    }
+}
+
 impl Hasher for PersistentStorageHasher {
     fn write_bytes(&mut self, h: &[u8]) {
         self.0.write_bytes(h)
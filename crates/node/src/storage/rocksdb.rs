@@ -19,6 +19,8 @@
 //!     - `commit_only_data_commitment`
 //!     - `update_epoch_blocks_delay`
 //!   - `conversion_state`: MASP conversion state
+//!   - `oldest_height`: the oldest height whose diffs and block data have not
+//!     been pruned, if any history has been pruned
 //! - `subspace`: accounts sub-spaces
 //!   - `{address}/{dyn}`: any byte data associated with accounts
 //! - `diffs`: diffs in account subspaces' key-vals modified with `persist_diff
@@ -44,12 +46,17 @@
 //!     - `current/{hash}`: a hash included in the current block
 //!     - `{hash}`: a hash included in previous blocks
 
+use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::ffi::OsStr;
 use std::fs::File;
-use std::io::{BufRead, BufReader, BufWriter, ErrorKind, Write};
+use std::io::{
+    BufRead, BufReader, BufWriter, ErrorKind, Read, Seek, SeekFrom, Write,
+};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
@@ -60,6 +67,7 @@ use namada_sdk::arith::checked;
 use namada_sdk::collections::{HashMap, HashSet};
 use namada_sdk::eth_bridge::storage::bridge_pool;
 use namada_sdk::eth_bridge::storage::proof::BridgePoolRootProof;
+use namada_sdk::events::log;
 use namada_sdk::hash::Hash;
 use namada_sdk::migrations::{DBUpdateVisitor, DbUpdateType};
 use namada_sdk::state::merkle_tree::{
@@ -72,7 +80,7 @@ use namada_sdk::state::{
 };
 use namada_sdk::storage::{
     BlockHeight, DbColFam, Epoch, Header, Key, KeySeg, BLOCK_CF, DIFFS_CF,
-    REPLAY_PROTECTION_CF, ROLLBACK_CF, STATE_CF, SUBSPACE_CF,
+    EVENTS_CF, REPLAY_PROTECTION_CF, ROLLBACK_CF, STATE_CF, SUBSPACE_CF,
 };
 use namada_sdk::{decode, encode, ethereum_events};
 use rayon::prelude::*;
@@ -93,36 +101,62 @@ use crate::storage;
 const ENV_VAR_ROCKSDB_COMPACTION_THREADS: &str =
     "NAMADA_ROCKSDB_COMPACTION_THREADS";
 
-const BLOCK_HEIGHT_KEY: &str = "height";
-const NEXT_EPOCH_MIN_START_HEIGHT_KEY: &str = "next_epoch_min_start_height";
-const NEXT_EPOCH_MIN_START_TIME_KEY: &str = "next_epoch_min_start_time";
-const UPDATE_EPOCH_BLOCKS_DELAY_KEY: &str = "update_epoch_blocks_delay";
-const COMMIT_ONLY_DATA_KEY: &str = "commit_only_data_commitment";
-const CONVERSION_STATE_KEY: &str = "conversion_state";
-const ETHEREUM_HEIGHT_KEY: &str = "ethereum_height";
-const ETH_EVENTS_QUEUE_KEY: &str = "eth_events_queue";
-const RESULTS_KEY_PREFIX: &str = "results";
-const PRED_KEY_PREFIX: &str = "pred";
-
-const MERKLE_TREE_ROOT_KEY_SEGMENT: &str = "root";
-const MERKLE_TREE_STORE_KEY_SEGMENT: &str = "store";
-const BLOCK_HEADER_KEY_SEGMENT: &str = "header";
-const BLOCK_TIME_KEY_SEGMENT: &str = "time";
-const EPOCH_KEY_SEGMENT: &str = "epoch";
-const PRED_EPOCHS_KEY_SEGMENT: &str = "pred_epochs";
-const ADDRESS_GEN_KEY_SEGMENT: &str = "address_gen";
-
-const OLD_DIFF_PREFIX: &str = "old";
-const NEW_DIFF_PREFIX: &str = "new";
+pub(super) const BLOCK_HEIGHT_KEY: &str = "height";
+pub(super) const NEXT_EPOCH_MIN_START_HEIGHT_KEY: &str =
+    "next_epoch_min_start_height";
+pub(super) const NEXT_EPOCH_MIN_START_TIME_KEY: &str =
+    "next_epoch_min_start_time";
+pub(super) const UPDATE_EPOCH_BLOCKS_DELAY_KEY: &str =
+    "update_epoch_blocks_delay";
+pub(super) const COMMIT_ONLY_DATA_KEY: &str = "commit_only_data_commitment";
+pub(super) const CONVERSION_STATE_KEY: &str = "conversion_state";
+pub(super) const ETHEREUM_HEIGHT_KEY: &str = "ethereum_height";
+pub(super) const ETH_EVENTS_QUEUE_KEY: &str = "eth_events_queue";
+pub(super) const RESULTS_KEY_PREFIX: &str = "results";
+pub(super) const PRED_KEY_PREFIX: &str = "pred";
+pub(super) const OLDEST_HEIGHT_KEY: &str = "oldest_height";
+
+pub(super) const MERKLE_TREE_ROOT_KEY_SEGMENT: &str = "root";
+pub(super) const MERKLE_TREE_STORE_KEY_SEGMENT: &str = "store";
+pub(super) const BLOCK_HEADER_KEY_SEGMENT: &str = "header";
+pub(super) const BLOCK_TIME_KEY_SEGMENT: &str = "time";
+pub(super) const EPOCH_KEY_SEGMENT: &str = "epoch";
+pub(super) const PRED_EPOCHS_KEY_SEGMENT: &str = "pred_epochs";
+pub(super) const ADDRESS_GEN_KEY_SEGMENT: &str = "address_gen";
+
+pub(super) const OLD_DIFF_PREFIX: &str = "old";
+pub(super) const NEW_DIFF_PREFIX: &str = "new";
 const MAX_CHUNK_SIZE: usize = 10_000_000;
+/// Upper bound on the size of a decompressed snapshot chunk. Chunks are
+/// at most [`MAX_CHUNK_SIZE`] large, unless they consist of a single
+/// larger key-val.
+const MAX_DECOMPRESSED_CHUNK_SIZE: usize = 100_000_000;
+/// The zstd compression level of snapshot chunks
+const SNAPSHOT_COMPRESSION_LEVEL: i32 = 3;
+/// The number of heights whose block data is removed in a single write
+/// batch when pruning the history
+const PRUNING_BATCH_HEIGHTS: u64 = 10_000;
 
 /// RocksDB handle
 #[derive(Debug)]
 pub struct RocksDB {
     /// Handle to the db
-    inner: rocksdb::DB,
+    inner: Arc<rocksdb::DB>,
     /// Indicates if read only
     read_only: bool,
+    /// The background task pruning the history, if one was started
+    pruning_task: Mutex<Option<JoinHandle<()>>>,
+}
+
+/// The estimated number of keys and size of a column family
+#[derive(Debug, Clone)]
+pub struct ColumnFamilyStats {
+    /// The name of the column family
+    pub name: &'static str,
+    /// The estimated number of keys
+    pub num_keys: u64,
+    /// The size of the column family's files and memtables in bytes
+    pub size: u64,
 }
 
 /// DB Handle for batch writes.
@@ -228,25 +262,57 @@ pub fn open(
         REPLAY_PROTECTION_CF,
         replay_protection_cf_opts,
     ));
+
+    // for the events index (insert-intensive)
+    let mut events_cf_opts = Options::default();
+    events_cf_opts.set_compression_type(DBCompressionType::Zstd);
+    events_cf_opts.set_compression_options(0, 0, 0, 1024 * 1024);
+    events_cf_opts.set_compaction_style(DBCompactionStyle::Universal);
+    events_cf_opts.set_block_based_table_factory(&table_opts);
+    // a DB created before the events index was added doesn't have its column
+    // family, which can only be created when the DB isn't read-only
+    let has_events_cf = !read_only
+        || rocksdb::DB::list_cf(&db_opts, &path)
+            .is_ok_and(|cf_names| cf_names.iter().any(|cf| cf == EVENTS_CF));
+    if has_events_cf {
+        cfs.push(ColumnFamilyDescriptor::new(EVENTS_CF, events_cf_opts));
+    }
     Ok(if read_only {
         RocksDB {
-            inner: rocksdb::DB::open_cf_descriptors_read_only(
-                &db_opts, path, cfs, false,
-            )
-            .map_err(|e| Error::DBError(e.into_string()))?,
+            inner: Arc::new(
+                rocksdb::DB::open_cf_descriptors_read_only(
+                    &db_opts, path, cfs, false,
+                )
+                .map_err(|e| Error::DBError(e.into_string()))?,
+            ),
             read_only: true,
+            pruning_task: Mutex::new(None),
         }
     } else {
         RocksDB {
-            inner: rocksdb::DB::open_cf_descriptors(&db_opts, path, cfs)
-                .map_err(|e| Error::DBError(e.into_string()))?,
+            inner: Arc::new(
+                rocksdb::DB::open_cf_descriptors(&db_opts, path, cfs)
+                    .map_err(|e| Error::DBError(e.into_string()))?,
+            ),
             read_only: false,
+            pruning_task: Mutex::new(None),
         }
     })
 }
 
 impl Drop for RocksDB {
     fn drop(&mut self) {
+        // Let an ongoing pruning finish before closing the DB
+        if let Some(task) = self
+            .pruning_task
+            .get_mut()
+            .ok()
+            .and_then(|task| task.take())
+        {
+            if task.join().is_err() {
+                tracing::error!("The history pruning task panicked");
+            }
+        }
         if !self.read_only {
             self.flush(true).expect("flush failed");
         }
@@ -599,6 +665,11 @@ impl RocksDB {
             batch.0.delete_cf(reprot_cf, current_key);
         }
 
+        // Delete the events of the last block from the event log
+        tracing::info!("Removing last block events");
+        log::delete_events(self, &mut batch, last_block.height)
+            .map_err(|e| Error::DBError(e.to_string()))?;
+
         // Execute next step in parallel
         let batch = Mutex::new(batch);
 
@@ -717,6 +788,196 @@ impl RocksDB {
             .unwrap()
     }
 
+    /// Read the estimated number of keys and the size of every column
+    /// family
+    pub fn column_family_stats(&self) -> Result<Vec<ColumnFamilyStats>> {
+        self.column_families()
+            .into_iter()
+            .map(|(name, cf)| {
+                let read_property = |property| {
+                    self.inner
+                        .property_int_value_cf(cf, property)
+                        .map(Option::unwrap_or_default)
+                        .map_err(|e| Error::DBError(e.into_string()))
+                };
+                let num_keys =
+                    read_property(rocksdb::properties::ESTIMATE_NUM_KEYS)?;
+                let sst_files_size =
+                    read_property(rocksdb::properties::TOTAL_SST_FILES_SIZE)?;
+                let mem_tables_size =
+                    read_property(rocksdb::properties::SIZE_ALL_MEM_TABLES)?;
+                Ok(ColumnFamilyStats {
+                    name,
+                    num_keys,
+                    size: checked!(sst_files_size + mem_tables_size)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Read the value of a key in the given column family. When a height
+    /// is given, the value of a subspace key is read as it was at that
+    /// height. The other column families can only be read at the last
+    /// height.
+    pub fn read_value_at_height(
+        &self,
+        cf: &DbColFam,
+        key: &Key,
+        height: Option<BlockHeight>,
+    ) -> Result<Option<Vec<u8>>> {
+        match self.past_height(cf, height)? {
+            Some((height, last_height)) => {
+                self.read_subspace_val_with_height(key, height, last_height)
+            }
+            None => {
+                let cf = self.get_column_family(cf.to_str())?;
+                self.read_value_bytes(cf, key.to_string())
+            }
+        }
+    }
+
+    /// Read the key-vals under the given key prefix in the given column
+    /// family, ordered by keys. When a height is given, the subspace is read
+    /// as it was at that height. The other column families can only be read
+    /// at the last height.
+    pub fn read_prefix_at_height(
+        &self,
+        cf: &DbColFam,
+        prefix: Option<&Key>,
+        height: Option<BlockHeight>,
+    ) -> Result<Vec<(String, Vec<u8>)>> {
+        let Some((height, last_height)) = self.past_height(cf, height)? else {
+            let cf = self.get_column_family(cf.to_str())?;
+            return Ok(iter_prefix(self, cf, None, prefix)
+                .map(|(key, val, _gas)| (key, val))
+                .collect());
+        };
+
+        // The keys present at the given height are either still present
+        // or were deleted at a later height
+        let diffs_cf = self.get_column_family(DIFFS_CF)?;
+        let mut keys: BTreeSet<String> = self
+            .iter_prefix(prefix)
+            .map(|(key, _val, _gas)| key)
+            .collect();
+        let mut diff_height = height.next_height();
+        while diff_height <= last_height {
+            keys.extend(
+                iter_diffs_prefix(self, diffs_cf, diff_height, prefix, true)
+                    .map(|(key, _val, _gas)| key),
+            );
+            diff_height = diff_height.next_height();
+        }
+
+        let mut key_vals = vec![];
+        for key in keys {
+            if let Some(val) = self.read_subspace_val_with_height(
+                &Key::from(key.to_db_key()),
+                height,
+                last_height,
+            )? {
+                key_vals.push((key, val));
+            }
+        }
+        Ok(key_vals)
+    }
+
+    /// Check that the given height can be read from the given column
+    /// family. Returns the height with the last committed height if it's a
+    /// past height.
+    fn past_height(
+        &self,
+        cf: &DbColFam,
+        height: Option<BlockHeight>,
+    ) -> Result<Option<(BlockHeight, BlockHeight)>> {
+        let Some(height) = height else {
+            return Ok(None);
+        };
+        let state_cf = self.get_column_family(STATE_CF)?;
+        let last_height: BlockHeight = self
+            .read_value(state_cf, BLOCK_HEIGHT_KEY)?
+            .ok_or_else(|| Error::DBError("No block height found".into()))?;
+        if height > last_height {
+            return Err(Error::DBError(format!(
+                "The height {height} is above the last committed height \
+                 {last_height}"
+            )));
+        }
+        if height == last_height {
+            return Ok(None);
+        }
+        if *cf != DbColFam::SUBSPACE {
+            return Err(Error::DBError(format!(
+                "Only the subspace can be read at a past height, not the {} \
+                 column family",
+                cf.to_str()
+            )));
+        }
+        if let Some(oldest_height) = self.read_oldest_height()? {
+            if height < oldest_height {
+                return Err(Error::DBError(format!(
+                    "The state at height {height} has been pruned. The \
+                     oldest readable height is {oldest_height}"
+                )));
+            }
+        }
+        Ok(Some((height, last_height)))
+    }
+
+    /// Compact the given column family, or all of them if none is given
+    pub fn compact(&self, cf: Option<&DbColFam>) -> Result<()> {
+        let cfs = match cf {
+            Some(cf) => vec![self.get_column_family(cf.to_str())?],
+            None => self
+                .column_families()
+                .into_iter()
+                .map(|(_name, cf)| cf)
+                .collect(),
+        };
+        for cf in cfs {
+            self.inner
+                .compact_range_cf(cf, None::<&[u8]>, None::<&[u8]>);
+        }
+        Ok(())
+    }
+
+    /// Write the key-vals contained in a chunk of a snapshot in the given
+    /// format into the database, as written by
+    /// [`DbSnapshot::write_to_file`].
+    pub fn apply_snapshot_chunk(
+        &self,
+        format: SnapshotFormat,
+        chunk: &[u8],
+    ) -> Result<()> {
+        let mut batch = RocksDBWriteBatch::default();
+        for (cf_name, key, val) in decode_snapshot_entries(format, chunk)? {
+            let cf = self.get_column_family(cf_name)?;
+            batch.0.put_cf(cf, key, val);
+        }
+        self.exec_batch(batch)
+    }
+
+    /// Delete all the data held in every column family. This is used to
+    /// discard the state of a snapshot that failed to be restored.
+    pub fn clear(&self) -> Result<()> {
+        let mut batch = RocksDBWriteBatch::default();
+        for (_, cf) in self.column_families() {
+            // Delete the range up to the last key and the last key itself,
+            // such that the batch doesn't grow with the size of the state
+            let last_key = self
+                .inner
+                .iterator_cf(cf, IteratorMode::End)
+                .next()
+                .transpose()
+                .map_err(|e| Error::DBError(e.into_string()))?;
+            if let Some((last_key, _)) = last_key {
+                batch.0.delete_range_cf(cf, [], &last_key);
+                batch.0.delete_cf(cf, last_key);
+            }
+        }
+        self.exec_batch(batch)
+    }
+
     /// Read diffs of non-persisted key-vals that are only kept for rollback of
     /// one block height.
     #[cfg(test)]
@@ -739,6 +1000,123 @@ impl RocksDB {
     }
 }
 
+/// The formats in which snapshots are written to disk and served to
+/// syncing nodes. The format is advertised to peers in the `format`
+/// field of ABCI snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapshotFormat {
+    /// Plain text lines of `{cf_name}:{key}={base64 value}`. Chunks are
+    /// delimited by line numbers and the metadata only holds the hashes
+    /// of the chunks.
+    Lines,
+    /// Lines of `{cf_name}:{hex key}={base64 value}`, where every chunk is
+    /// compressed independently with zstd. Chunks are delimited by byte
+    /// offsets in the file and the metadata also lists the column
+    /// families included in the snapshot.
+    ZstdLines,
+}
+
+impl SnapshotFormat {
+    /// The format in which new snapshots are written
+    pub const LATEST: Self = Self::ZstdLines;
+
+    /// The version of the format, as used in ABCI snapshots
+    pub const fn version(&self) -> u32 {
+        match self {
+            Self::Lines => 0,
+            Self::ZstdLines => 1,
+        }
+    }
+}
+
+impl TryFrom<u32> for SnapshotFormat {
+    type Error = std::io::Error;
+
+    fn try_from(version: u32) -> std::io::Result<Self> {
+        match version {
+            0 => Ok(Self::Lines),
+            1 => Ok(Self::ZstdLines),
+            _ => Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                format!("Unknown snapshot format {version}"),
+            )),
+        }
+    }
+}
+
+/// The metadata of a snapshot that is shared with syncing nodes,
+/// such that they can verify the chunks they receive.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SnapshotManifest {
+    /// The names of the column families included in the snapshot
+    pub column_families: Vec<String>,
+    /// Data about the chunks that the snapshot is
+    /// partitioned into
+    pub chunks: Vec<Chunk>,
+}
+
+impl SnapshotManifest {
+    /// Encode the manifest as the metadata of a snapshot in the given
+    /// format.
+    pub fn encode(&self, format: SnapshotFormat) -> Vec<u8> {
+        match format {
+            // The first format only carried the chunks
+            SnapshotFormat::Lines => self.chunks.serialize_to_vec(),
+            SnapshotFormat::ZstdLines => self.serialize_to_vec(),
+        }
+    }
+
+    /// Decode the metadata of a snapshot in the given format.
+    pub fn decode(
+        format: SnapshotFormat,
+        bytes: &[u8],
+    ) -> std::io::Result<Self> {
+        match format {
+            SnapshotFormat::Lines => Ok(Self {
+                column_families: DbColFam::all()
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+                chunks: BorshDeserialize::try_from_slice(bytes)?,
+            }),
+            SnapshotFormat::ZstdLines => {
+                BorshDeserialize::try_from_slice(bytes)
+            }
+        }
+    }
+
+    /// Write the manifest to a metadata file. Snapshots in the
+    /// [`SnapshotFormat::Lines`] format contain only the hex encoded
+    /// metadata, while the later formats prefix it with their version.
+    fn to_file_contents(&self, format: SnapshotFormat) -> String {
+        let metadata = HEXLOWER.encode(&self.encode(format));
+        match format {
+            SnapshotFormat::Lines => metadata,
+            _ => format!("{}:{metadata}", format.version()),
+        }
+    }
+
+    /// Parse the contents of a metadata file written with
+    /// [`SnapshotManifest::to_file_contents`].
+    fn from_file_contents(
+        contents: &str,
+    ) -> std::io::Result<(SnapshotFormat, Self)> {
+        let (format, metadata) = match contents.split_once(':') {
+            Some((version, metadata)) => {
+                let version = u32::from_str(version).map_err(|e| {
+                    std::io::Error::new(ErrorKind::InvalidData, e)
+                })?;
+                (SnapshotFormat::try_from(version)?, metadata)
+            }
+            None => (SnapshotFormat::Lines, contents),
+        };
+        let metadata_bytes = HEXLOWER
+            .decode(metadata.as_bytes())
+            .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+        Ok((format, Self::decode(format, &metadata_bytes)?))
+    }
+}
+
 /// Information about a particular snapshot
 /// owned by a node
 pub struct SnapshotMetadata {
@@ -747,48 +1125,171 @@ pub struct SnapshotMetadata {
     /// The name of the paths to the file and metadata
     /// holding the snapshot minus extensions
     pub path_stem: String,
-    /// Data about the chunks that the snapshot is
-    /// partitioned into
-    pub chunks: Vec<Chunk>,
+    /// The format the snapshot was written in
+    pub format: SnapshotFormat,
+    /// The column families and chunks of the snapshot
+    pub manifest: SnapshotManifest,
+}
+
+/// Write a snapshot of the given column families out to file in the given
+/// format, along with the metadata about how to break the file into chunks.
+/// The given function must call its argument on the column family name, the
+/// key and the value of every key-val of the snapshot.
+pub(super) fn write_snapshot_file<F>(
+    column_families: Vec<String>,
+    base_dir: PathBuf,
+    height: BlockHeight,
+    format: SnapshotFormat,
+    for_each_entry: F,
+) -> std::io::Result<()>
+where
+    F: FnOnce(
+        &mut dyn FnMut(&str, &str, &[u8]) -> std::io::Result<()>,
+    ) -> std::io::Result<()>,
+{
+    let [snap_file, metadata_file] = DbSnapshot::paths(height, base_dir);
+    let file = File::create(snap_file)?;
+    let chunks = match format {
+        SnapshotFormat::Lines => {
+            let mut buf = BufWriter::new(file);
+            let mut chunker = Chunker::new(MAX_CHUNK_SIZE);
+            for_each_entry(&mut |cf_name, key, val| {
+                let line = encode_snapshot_entry(format, cf_name, key, val)?;
+                chunker.add_line(&line);
+                buf.write_all(line.as_bytes())
+            })?;
+            buf.flush()?;
+            chunker.finalize()
+        }
+        SnapshotFormat::ZstdLines => {
+            let mut chunker =
+                ZstdChunker::new(BufWriter::new(file), MAX_CHUNK_SIZE);
+            for_each_entry(&mut |cf_name, key, val| {
+                let line = encode_snapshot_entry(format, cf_name, key, val)?;
+                chunker.add_line(&line)
+            })?;
+            chunker.finalize()?
+        }
+    };
+    let manifest = SnapshotManifest {
+        column_families,
+        chunks,
+    };
+    std::fs::write(metadata_file, manifest.to_file_contents(format))?;
+    Ok(())
+}
+
+/// Encode a key-val of a column family as a line of a snapshot in the given
+/// format. The lines are of the form `{cf_name}:{key}={base64 value}`. The
+/// keys are hex encoded in the formats after [`SnapshotFormat::Lines`], which
+/// cannot hold the keys containing a `=`.
+pub(super) fn encode_snapshot_entry(
+    format: SnapshotFormat,
+    cf_name: &str,
+    key: &str,
+    val: &[u8],
+) -> std::io::Result<String> {
+    let val = base64::encode(val);
+    match format {
+        SnapshotFormat::Lines => {
+            if key.contains('=') {
+                return Err(std::io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "The key {key} cannot be written in the {format:?} \
+                         snapshot format"
+                    ),
+                ));
+            }
+            Ok(format!("{cf_name}:{key}={val}\n"))
+        }
+        SnapshotFormat::ZstdLines => {
+            let key = HEXLOWER.encode(key.as_bytes());
+            Ok(format!("{cf_name}:{key}={val}\n"))
+        }
+    }
+}
+
+/// Decode the key-vals contained in a chunk of a snapshot in the given
+/// format, as encoded by [`encode_snapshot_entry`].
+pub(super) fn decode_snapshot_entries(
+    format: SnapshotFormat,
+    chunk: &[u8],
+) -> Result<Vec<(&str, String, Vec<u8>)>> {
+    let chunk = std::str::from_utf8(chunk)
+        .map_err(|e| Error::DBError(e.to_string()))?;
+    chunk
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let malformed = || {
+                Error::DBError(format!("Malformed snapshot entry: {line}"))
+            };
+            let (cf_name, entry) =
+                line.split_once(':').ok_or_else(malformed)?;
+            // NB: neither the keys nor the base64 values may contain a `=`,
+            // other than the padding at the end of the values, so we split
+            // on the first occurrence
+            let (key, val) = entry.split_once('=').ok_or_else(malformed)?;
+            let key = match format {
+                SnapshotFormat::Lines => key.to_owned(),
+                SnapshotFormat::ZstdLines => HEXLOWER
+                    .decode(key.as_bytes())
+                    .ok()
+                    .and_then(|key| String::from_utf8(key).ok())
+                    .ok_or_else(malformed)?,
+            };
+            let val = base64::decode(val)
+                .map_err(|e| Error::DBError(e.to_string()))?;
+            Ok((cf_name, key, val))
+        })
+        .collect()
 }
 
 pub struct DbSnapshot<'a>(pub rocksdb::Snapshot<'a>);
 
 impl<'a> DbSnapshot<'a> {
-    /// Write a snapshot of the database out to file. The last line
-    /// of the file contains metadata about how to break the file into
-    /// chunks.
+    /// Write a snapshot of the database out to file in the given
+    /// format. The metadata about how to break the file into chunks
+    /// is written to a separate file.
     pub fn write_to_file(
         &self,
         cfs: [(&'static str, &'a ColumnFamily); 6],
         base_dir: PathBuf,
         height: BlockHeight,
+        format: SnapshotFormat,
+    ) -> std::io::Result<()> {
+        let column_families =
+            cfs.iter().map(|(cf_name, _)| cf_name.to_string()).collect();
+        write_snapshot_file(
+            column_families,
+            base_dir,
+            height,
+            format,
+            |f| self.for_each_entry(&cfs, f),
+        )
+    }
+
+    /// Call the given function on every key-val of the given column families
+    /// in the snapshot.
+    fn for_each_entry(
+        &self,
+        cfs: &[(&'static str, &'a ColumnFamily)],
+        mut f: impl FnMut(&str, &str, &[u8]) -> std::io::Result<()>,
     ) -> std::io::Result<()> {
-        let [snap_file, metadata_file] = Self::paths(height, base_dir);
-        let file = File::create(snap_file)?;
-        let mut buf = BufWriter::new(file);
-        let mut chunker = Chunker::new(MAX_CHUNK_SIZE);
         for (cf_name, cf) in cfs {
             let read_opts = make_iter_read_opts(None);
             let iter =
-                self.0.iterator_cf_opt(cf, read_opts, IteratorMode::Start);
+                self.0.iterator_cf_opt(*cf, read_opts, IteratorMode::Start);
 
             for (key, raw_val, _gas) in PersistentPrefixIterator(
                 PrefixIterator::new(iter, String::default()),
                 // Empty string to prevent prefix stripping, the prefix is
                 // already in the enclosed iterator
             ) {
-                let val = base64::encode(raw_val);
-                let bytes = format!("{cf_name}:{key}={val}\n");
-                chunker.add_line(&bytes);
-                buf.write_all(bytes.as_bytes())?;
+                f(cf_name, &key, &raw_val)?;
             }
-            buf.flush()?;
         }
-        buf.flush()?;
-        let chunks = chunker.finalize();
-        let metadata = base64::encode(chunks.serialize_to_vec());
-        std::fs::write(metadata_file, metadata.as_bytes())?;
         Ok(())
     }
 
@@ -811,12 +1312,14 @@ impl<'a> DbSnapshot<'a> {
     }
 
     /// List all snapshot files along with the block height at which
-    /// they were created and their chunks.
+    /// they were created, their format and their chunks.
     pub fn files(base_dir: &Path) -> std::io::Result<Vec<SnapshotMetadata>> {
         let snap = OsStr::new("snap");
         let meta = OsStr::new("meta");
-        let mut files =
-            HashMap::<BlockHeight, (Option<String>, Option<Vec<Chunk>>)>::new();
+        let mut files = HashMap::<
+            BlockHeight,
+            (Option<String>, Option<(SnapshotFormat, SnapshotManifest)>),
+        >::new();
         for entry in std::fs::read_dir(base_dir)? {
             let entry = entry?;
             let entry_path = entry.path();
@@ -841,16 +1344,9 @@ impl<'a> DbSnapshot<'a> {
                     // for a given block height
                     if entry_ext == Some(meta) {
                         let metadata = std::fs::read_to_string(entry_path)?;
-                        let metadata_bytes = HEXLOWER
-                            .decode(metadata.as_bytes())
-                            .map_err(|e| {
-                                std::io::Error::new(ErrorKind::InvalidData, e)
-                            })?;
-                        let chunks: Vec<Chunk> =
-                            BorshDeserialize::try_from_slice(
-                                &metadata_bytes[..],
-                            )?;
-                        files.entry(height).or_default().1 = Some(chunks);
+                        files.entry(height).or_default().1 = Some(
+                            SnapshotManifest::from_file_contents(&metadata)?,
+                        );
                     } else {
                         files.entry(height).or_default().0 = Some(
                             base_dir
@@ -863,13 +1359,14 @@ impl<'a> DbSnapshot<'a> {
             }
         }
         let mut res = Vec::with_capacity(files.len());
-        for (height, (path, chunks)) in files {
+        for (height, (path, metadata)) in files {
             // only include snapshots which have both a .snap and .meta file.
-            if let Some((path_stem, chunks)) = path.zip(chunks) {
+            if let Some((path_stem, (format, manifest))) = path.zip(metadata) {
                 res.push(SnapshotMetadata {
                     height,
                     path_stem,
-                    chunks,
+                    format,
+                    manifest,
                 });
             }
         }
@@ -883,7 +1380,11 @@ impl<'a> DbSnapshot<'a> {
         [snap_file, metadata_file]
     }
 
-    /// Load the specified chunk of a snapshot at the given block height
+    /// Load the specified chunk of a snapshot at the given block height.
+    /// The bytes are returned as they are sent to syncing nodes, such
+    /// that they hash to the value recorded in the snapshot's metadata.
+    /// For [`SnapshotFormat::Lines`], this means the lines of the chunk
+    /// newline terminated, for later formats the compressed chunk.
     pub fn load_chunk(
         height: BlockHeight,
         chunk: u64,
@@ -901,14 +1402,8 @@ impl<'a> DbSnapshot<'a> {
                 ),
             ));
         };
-        let chunk_start = if chunk == 0 {
-            0usize
-        } else {
-            let prev = checked!(usize::try_from(chunk).unwrap() - 1).unwrap();
-            usize::try_from(metadata.chunks[prev].boundary).unwrap()
-        };
-        let chunk_end = metadata
-            .chunks
+        let chunks = &metadata.manifest.chunks;
+        let chunk_end = chunks
             .get(usize::try_from(chunk).unwrap())
             .ok_or_else(|| {
                 std::io::Error::new(
@@ -917,31 +1412,110 @@ impl<'a> DbSnapshot<'a> {
                 )
             })?
             .boundary;
-        let chunk_end = usize::try_from(chunk_end).unwrap();
+        let chunk_start = if chunk == 0 {
+            0u64
+        } else {
+            let prev = checked!(usize::try_from(chunk).unwrap() - 1).unwrap();
+            chunks[prev].boundary
+        };
+        let chunk_len = checked!(chunk_end - chunk_start).map_err(|_| {
+            std::io::Error::new(
+                ErrorKind::InvalidData,
+                format!("Chunk {} has invalid boundaries", chunk),
+            )
+        })?;
+        let chunk_len = usize::try_from(chunk_len).unwrap();
 
-        let file = File::open(
+        let mut file = File::open(
             PathBuf::from(metadata.path_stem).with_extension("snap"),
         )?;
-        let reader = BufReader::new(file);
-        let mut bytes: Vec<u8> = vec![];
-        for line in reader
-            .lines()
-            .skip(chunk_start)
-            .take(checked!(chunk_end - chunk_start).unwrap())
-        {
-            bytes.extend(line?.as_bytes());
+        match metadata.format {
+            SnapshotFormat::Lines => {
+                let reader = BufReader::new(file);
+                let mut bytes: Vec<u8> = vec![];
+                for line in reader
+                    .lines()
+                    .skip(usize::try_from(chunk_start).unwrap())
+                    .take(chunk_len)
+                {
+                    bytes.extend(line?.as_bytes());
+                    bytes.push(b'\n');
+                }
+                Ok(bytes)
+            }
+            SnapshotFormat::ZstdLines => {
+                file.seek(SeekFrom::Start(chunk_start))?;
+                let mut bytes = vec![0u8; chunk_len];
+                file.read_exact(&mut bytes)?;
+                Ok(bytes)
+            }
+        }
+    }
+
+    /// Turn the bytes of a chunk of a snapshot in the given format, as
+    /// returned by [`DbSnapshot::load_chunk`], back into the lines of
+    /// key-vals to be written to the database.
+    pub fn decode_chunk(
+        format: SnapshotFormat,
+        bytes: &[u8],
+    ) -> std::io::Result<Cow<'_, [u8]>> {
+        match format {
+            SnapshotFormat::Lines => Ok(Cow::Borrowed(bytes)),
+            SnapshotFormat::ZstdLines => {
+                let decoder = zstd::stream::read::Decoder::new(bytes)?;
+                let mut lines = vec![];
+                // Bound the size of the output to guard against chunks
+                // crafted to decompress to excessive sizes
+                let limit = u64::try_from(MAX_DECOMPRESSED_CHUNK_SIZE).unwrap();
+                decoder
+                    .take(checked!(limit + 1).unwrap())
+                    .read_to_end(&mut lines)?;
+                if lines.len() > MAX_DECOMPRESSED_CHUNK_SIZE {
+                    return Err(std::io::Error::new(
+                        ErrorKind::InvalidData,
+                        "Decompressed snapshot chunk is too large",
+                    ));
+                }
+                Ok(Cow::Owned(lines))
+            }
+        }
+    }
+
+    /// Check that all the key-vals in the decoded lines of a chunk of a
+    /// snapshot belong to the column families listed in the manifest of the
+    /// snapshot.
+    pub fn check_chunk_column_families(
+        lines: &[u8],
+        column_families: &[String],
+    ) -> std::io::Result<()> {
+        let lines = std::str::from_utf8(lines)
+            .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+        for line in lines.lines().filter(|line| !line.is_empty()) {
+            let cf_name = line.split_once(':').map(|(cf_name, _)| cf_name);
+            if !cf_name.is_some_and(|cf_name| {
+                column_families.iter().any(|cf| cf == cf_name)
+            }) {
+                return Err(std::io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "Snapshot entry of a column family missing from the \
+                         manifest: {line}"
+                    ),
+                ));
+            }
         }
-        Ok(bytes)
+        Ok(())
     }
 }
 
-/// A chunk of a snapshot. Includes the last line number in the file
-/// for this chunk and a hash of the chunk contents.
+/// A chunk of a snapshot. Includes the end of the chunk in the file
+/// (a line number for [`SnapshotFormat::Lines`], a byte offset for
+/// later formats) and a hash of the chunk contents.
 #[derive(
     Debug, Clone, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize, Hash,
 )]
 pub struct Chunk {
-    /// The line number ending the chunk
+    /// The line number or byte offset ending the chunk
     pub boundary: u64,
     /// Sha256 hash of the chunk
     pub hash: Hash,
@@ -998,6 +1572,62 @@ impl Chunker {
     }
 }
 
+/// Builds a set of chunks from a stream of lines, compressing each
+/// chunk with zstd and writing it out as soon as it is complete.
+struct ZstdChunker<W: Write> {
+    writer: W,
+    chunks: Vec<Chunk>,
+    max_size: usize,
+    buffer: Vec<u8>,
+    offset: u64,
+}
+
+impl<W: Write> ZstdChunker<W> {
+    fn new(writer: W, max_size: usize) -> Self {
+        Self {
+            writer,
+            chunks: vec![],
+            max_size,
+            buffer: vec![],
+            offset: 0,
+        }
+    }
+
+    fn add_line(&mut self, line: &str) -> std::io::Result<()> {
+        if checked!(self.buffer.len() + line.as_bytes().len()).unwrap()
+            > self.max_size
+            && !self.buffer.is_empty()
+        {
+            self.write_chunk()?;
+        }
+        self.buffer.extend_from_slice(line.as_bytes());
+        Ok(())
+    }
+
+    /// Compress the buffered lines and write them out as a new chunk
+    fn write_chunk(&mut self) -> std::io::Result<()> {
+        let compressed =
+            zstd::bulk::compress(&self.buffer, SNAPSHOT_COMPRESSION_LEVEL)?;
+        self.writer.write_all(&compressed)?;
+        let len = u64::try_from(compressed.len()).unwrap();
+        self.offset = checked!(self.offset + len).unwrap();
+        self.chunks.push(Chunk {
+            boundary: self.offset,
+            hash: Hash::sha256(&compressed),
+        });
+        self.buffer.clear();
+        Ok(())
+    }
+
+    fn finalize(mut self) -> std::io::Result<Vec<Chunk>> {
+        if !self.buffer.is_empty() || self.chunks.is_empty() {
+            self.write_chunk()?;
+        }
+        self.writer.flush()?;
+        Ok(self.chunks)
+    }
+}
+
 impl DB for RocksDB {
     type Cache = rocksdb::Cache;
     type Migrator = DbUpdateType;
@@ -1657,6 +2287,86 @@ impl DB for RocksDB {
         Ok(())
     }
 
+    fn prune_history(&self, oldest_height: BlockHeight) -> Result<()> {
+        if self.read_only {
+            return Err(Error::DBError(
+                "Cannot prune the history of a read-only DB".to_string(),
+            ));
+        }
+        let mut pruning_task = self
+            .pruning_task
+            .lock()
+            .map_err(|_| Error::DBError("Poisoned pruning task".to_string()))?;
+        if pruning_task
+            .as_ref()
+            .is_some_and(|task| !task.is_finished())
+        {
+            // The history will be pruned further once the ongoing task is
+            // done
+            return Ok(());
+        }
+        let pruned_height = self.read_oldest_height()?.unwrap_or_default();
+        if oldest_height <= pruned_height {
+            return Ok(());
+        }
+
+        // Record the new oldest height before removing anything, so that
+        // the heights being pruned are no longer queried
+        let state_cf = self.get_column_family(STATE_CF)?;
+        self.inner
+            .put_cf(state_cf, OLDEST_HEIGHT_KEY, encode(&oldest_height))
+            .map_err(|e| Error::DBError(e.into_string()))?;
+
+        let db = Arc::clone(&self.inner);
+        *pruning_task = Some(std::thread::spawn(move || {
+            match prune_history_range(&db, pruned_height, oldest_height) {
+                Ok(()) => tracing::info!(
+                    "Pruned the history from height {pruned_height} to \
+                     {oldest_height}"
+                ),
+                Err(e) => tracing::error!(
+                    "Failed to prune the history from height \
+                     {pruned_height} to {oldest_height}: {e}"
+                ),
+            }
+        }));
+        Ok(())
+    }
+
+    fn read_oldest_height(&self) -> Result<Option<BlockHeight>> {
+        let state_cf = self.get_column_family(STATE_CF)?;
+        self.read_value(state_cf, OLDEST_HEIGHT_KEY)
+    }
+
+    fn read_events_index(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        self.read_value_bytes(events_cf, key)
+    }
+
+    fn batch_write_events_index(
+        &self,
+        batch: &mut Self::WriteBatch,
+        entries: impl IntoIterator<Item = (String, Vec<u8>)>,
+    ) -> Result<()> {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        for (key, value) in entries {
+            self.add_value_bytes_to_batch(events_cf, key, value, batch);
+        }
+        Ok(())
+    }
+
+    fn batch_delete_events_index(
+        &self,
+        batch: &mut Self::WriteBatch,
+        keys: impl IntoIterator<Item = String>,
+    ) -> Result<()> {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        for key in keys {
+            batch.0.delete_cf(events_cf, key);
+        }
+        Ok(())
+    }
+
     #[inline]
     fn overwrite_entry(
         &self,
@@ -1887,6 +2597,25 @@ impl<'iter> DBIter<'iter> for RocksDB {
         let prefix = Some(replay_protection::current_prefix());
         iter_prefix(self, replay_protection_cf, None, prefix.as_ref())
     }
+
+    fn iter_events_index(
+        &'iter self,
+        prefix: &str,
+        start: Option<&str>,
+    ) -> Result<Self::PrefixIter> {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        let start = start.map_or(prefix, |start| start.max(prefix));
+        let read_opts = make_iter_read_opts(Some(prefix.to_owned()));
+        let iter = self.inner.iterator_cf_opt(
+            events_cf,
+            read_opts,
+            IteratorMode::From(start.as_bytes(), Direction::Forward),
+        );
+        Ok(PersistentPrefixIterator(PrefixIterator::new(
+            iter,
+            String::new(),
+        )))
+    }
 }
 
 fn iter_subspace_prefix<'iter>(
@@ -2051,7 +2780,72 @@ fn make_iter_read_opts(prefix: Option<String>) -> ReadOptions {
 
 impl DBWriteBatch for RocksDBWriteBatch {}
 
-fn old_and_new_diff_key(
+/// Remove the diffs and the per-height block data of the heights in the
+/// range `[from, to)`. The block results and the merkle tree stores keyed by
+/// epoch are left untouched.
+fn prune_history_range(
+    db: &rocksdb::DB,
+    from: BlockHeight,
+    to: BlockHeight,
+) -> Result<()> {
+    let get_cf = |cf_name: &str| {
+        db.cf_handle(cf_name)
+            .ok_or(Error::DBError(format!("No {cf_name} column family")))
+    };
+    let diffs_cf = get_cf(DIFFS_CF)?;
+    let rollback_cf = get_cf(ROLLBACK_CF)?;
+    let block_cf = get_cf(BLOCK_CF)?;
+
+    // The diffs are prefixed with their zero-padded height, so they can be
+    // removed by range
+    let mut batch = WriteBatch::default();
+    batch.delete_range_cf(diffs_cf, from.raw(), to.raw());
+    batch.delete_range_cf(rollback_cf, from.raw(), to.raw());
+    db.write(batch)
+        .map_err(|e| Error::DBError(e.into_string()))?;
+
+    // The height prefixed block data is interleaved with the merkle tree
+    // stores prefixed by epoch, so it is removed key by key
+    let mut batch_start = from.0;
+    while batch_start < to.0 {
+        let batch_end =
+            std::cmp::min(checked!(batch_start + PRUNING_BATCH_HEIGHTS)?, to.0);
+        let mut batch = WriteBatch::default();
+        for height in batch_start..batch_end {
+            for key in block_keys_at_height(BlockHeight(height)) {
+                batch.delete_cf(block_cf, key);
+            }
+        }
+        db.write(batch)
+            .map_err(|e| Error::DBError(e.into_string()))?;
+        batch_start = batch_end;
+    }
+    Ok(())
+}
+
+/// The keys of the block data written at the given height in the block
+/// column family, without the block results which are kept forever
+pub(super) fn block_keys_at_height(height: BlockHeight) -> Vec<String> {
+    let prefix = height.raw();
+    let mut keys: Vec<String> = [
+        BLOCK_HEADER_KEY_SEGMENT,
+        BLOCK_TIME_KEY_SEGMENT,
+        EPOCH_KEY_SEGMENT,
+        PRED_EPOCHS_KEY_SEGMENT,
+        ADDRESS_GEN_KEY_SEGMENT,
+    ]
+    .iter()
+    .map(|segment| format!("{prefix}/{segment}"))
+    .collect();
+    for st in StoreType::iter().filter(|st| st.is_stored_every_block()) {
+        let key_prefix = tree_key_prefix_with_height(st, height);
+        keys.push(format!("{key_prefix}/{MERKLE_TREE_ROOT_KEY_SEGMENT}"));
+        keys.push(format!("{key_prefix}/{MERKLE_TREE_STORE_KEY_SEGMENT}"));
+    }
+    keys
+}
+
+pub(super) fn old_and_new_diff_key(
     key: &Key,
     height: BlockHeight,
 ) -> Result<(String, String)> {
@@ -2129,6 +2923,8 @@ mod imp {
 mod test {
     use namada_sdk::address::EstablishedAddressGen;
     use namada_sdk::collections::HashMap;
+    use namada_sdk::events::log::dumb_queries::QueryMatcher;
+    use namada_sdk::events::{Event, EventLevel, EventType};
     use namada_sdk::hash::Hash;
     use namada_sdk::state::{MerkleTree, Sha256Hasher};
     use namada_sdk::storage::conversion_state::ConversionState;
@@ -2381,6 +3177,14 @@ mod test {
                 .unwrap();
             }
 
+            log::write_events(
+                &db,
+                &mut batch,
+                height_0,
+                &[Event::new(EventType::new("test"), EventLevel::Block)],
+            )
+            .unwrap();
+
             add_block_to_batch(
                 &db,
                 &mut batch,
@@ -2434,6 +3238,14 @@ mod test {
                 .unwrap();
             }
 
+            log::write_events(
+                &db,
+                &mut batch,
+                height_1,
+                &[Event::new(EventType::new("test"), EventLevel::Block)],
+            )
+            .unwrap();
+
             add_block_to_batch(
                 &db,
                 &mut batch,
@@ -2488,6 +3300,17 @@ mod test {
                     !db.has_replay_protection_entry(&Hash::sha256(tx)).unwrap()
                 );
             }
+            // Check that the events of the second block were removed
+            let events = log::query_events(
+                &db,
+                &log::EventQuery::new(QueryMatcher::with_prefix(
+                    EventType::new(""),
+                )),
+            )
+            .unwrap();
+            let heights: Vec<_> =
+                events.into_iter().map(|(height, _)| height).collect();
+            assert_eq!(heights, vec![height_0]);
         }
     }
 
@@ -2647,6 +3470,168 @@ mod test {
         }
     }
 
+    /// Test that pruning the history removes the diffs and block data of
+    /// the pruned heights only
+    #[test]
+    fn test_prune_history() {
+        let dir = tempdir().unwrap();
+        let mut db = RocksDB::open(dir.path(), None);
+        let key = Key::parse("with_diffs").unwrap();
+
+        for height in 1..=5_u64 {
+            let height = BlockHeight(height);
+            let mut batch = RocksDB::batch();
+            db.batch_write_subspace_val(
+                &mut batch,
+                height,
+                &key,
+                height.0.to_le_bytes(),
+                true,
+            )
+            .unwrap();
+            add_block_to_batch(
+                &db,
+                &mut batch,
+                height,
+                Epoch::default(),
+                Epochs::default(),
+                &ConversionState::default(),
+            )
+            .unwrap();
+            db.exec_batch(batch).unwrap();
+        }
+        assert_eq!(db.read_oldest_height().unwrap(), None);
+
+        let oldest_height = BlockHeight(4);
+        db.prune_history(oldest_height).unwrap();
+        assert_eq!(db.read_oldest_height().unwrap(), Some(oldest_height));
+        let task = db.pruning_task.get_mut().unwrap().take().unwrap();
+        task.join().unwrap();
+
+        // Pruning up to an older height is a no-op
+        db.prune_history(BlockHeight(2)).unwrap();
+        assert_eq!(db.read_oldest_height().unwrap(), Some(oldest_height));
+
+        let diffs_cf = db.get_column_family(DIFFS_CF).unwrap();
+        let block_cf = db.get_column_family(BLOCK_CF).unwrap();
+        for height in 1..=5_u64 {
+            let height = BlockHeight(height);
+            let is_pruned = height < oldest_height;
+            let (_old, new) = old_and_new_diff_key(&key, height).unwrap();
+            assert_eq!(
+                db.inner.get_cf(diffs_cf, new).unwrap().is_none(),
+                is_pruned
+            );
+            let time_key = format!("{}/{BLOCK_TIME_KEY_SEGMENT}", height.raw());
+            assert_eq!(
+                db.inner.get_cf(block_cf, time_key).unwrap().is_none(),
+                is_pruned
+            );
+            let results_key = format!("{RESULTS_KEY_PREFIX}/{}", height.raw());
+            assert!(db.inner.get_cf(block_cf, results_key).unwrap().is_some());
+        }
+        // The epoch keyed merkle tree stores are kept
+        let key_prefix =
+            tree_key_prefix_with_epoch(&StoreType::PoS, Epoch::default());
+        let root_key = format!("{key_prefix}/{MERKLE_TREE_ROOT_KEY_SEGMENT}");
+        assert!(db.inner.get_cf(block_cf, root_key).unwrap().is_some());
+
+        // The state at the retained heights can still be read
+        let val = db
+            .read_subspace_val_with_height(&key, oldest_height, BlockHeight(5))
+            .unwrap();
+        assert_eq!(val, Some(4_u64.to_le_bytes().to_vec()));
+    }
+
+    /// Test reading the subspace under a prefix as it was at past heights
+    #[test]
+    fn test_read_prefix_at_height() {
+        let dir = tempdir().unwrap();
+        let db = RocksDB::open(dir.path(), None);
+        let prefix = Key::parse("prefix").unwrap();
+        let key_a = prefix.push(&"a".to_string()).unwrap();
+        let key_b = prefix.push(&"b".to_string()).unwrap();
+
+        // Write `key_a` at height 1, `key_b` at height 2 and delete
+        // `key_a` at height 3
+        for height in 1..=3_u64 {
+            let height = BlockHeight(height);
+            let mut batch = RocksDB::batch();
+            match height.0 {
+                1 => {
+                    db.batch_write_subspace_val(
+                        &mut batch,
+                        height,
+                        &key_a,
+                        [1_u8],
+                        true,
+                    )
+                    .unwrap();
+                }
+                2 => {
+                    db.batch_write_subspace_val(
+                        &mut batch,
+                        height,
+                        &key_b,
+                        [2_u8],
+                        true,
+                    )
+                    .unwrap();
+                }
+                _ => {
+                    db.batch_delete_subspace_val(
+                        &mut batch, height, &key_a, true,
+                    )
+                    .unwrap();
+                }
+            }
+            add_block_to_batch(
+                &db,
+                &mut batch,
+                height,
+                Epoch::default(),
+                Epochs::default(),
+                &ConversionState::default(),
+            )
+            .unwrap();
+            db.exec_batch(batch).unwrap();
+        }
+
+        let subspace = DbColFam::SUBSPACE;
+        let read = |height: Option<u64>| {
+            db.read_prefix_at_height(
+                &subspace,
+                Some(&prefix),
+                height.map(BlockHeight),
+            )
+            .unwrap()
+        };
+        assert_eq!(read(None), vec![(key_b.to_string(), vec![2])]);
+        assert_eq!(read(Some(3)), vec![(key_b.to_string(), vec![2])]);
+        assert_eq!(
+            read(Some(2)),
+            vec![(key_a.to_string(), vec![1]), (key_b.to_string(), vec![2])]
+        );
+        assert_eq!(read(Some(1)), vec![(key_a.to_string(), vec![1])]);
+
+        assert_eq!(
+            db.read_value_at_height(&subspace, &key_a, Some(BlockHeight(2)))
+                .unwrap(),
+            Some(vec![1])
+        );
+        // Heights above the last one and past heights of the other column
+        // families cannot be read
+        let above_last =
+            db.read_value_at_height(&subspace, &key_a, Some(BlockHeight(4)));
+        assert!(above_last.is_err());
+        let past_state = db.read_value_at_height(
+            &DbColFam::STATE,
+            &key_a,
+            Some(BlockHeight(2)),
+        );
+        assert!(past_state.is_err());
+    }
+
     /// A test helper to write a block
     fn add_block_to_batch(
         db: &RocksDB,
@@ -2930,7 +3915,12 @@ mod test {
         let path = temp.path().to_path_buf();
 
         snapshot
-            .write_to_file(db.column_families(), path.clone(), 0.into())
+            .write_to_file(
+                db.column_families(),
+                path.clone(),
+                0.into(),
+                SnapshotFormat::Lines,
+            )
             .expect("Test failed");
         let snapshot =
             std::fs::read_to_string(path.clone().join("snapshot_0.snap"))
@@ -2943,7 +3933,7 @@ mod test {
                     .as_bytes(),
             ),
         }];
-        let chunk_val = base64::encode(chunks.serialize_to_vec());
+        let chunk_val = HEXLOWER.encode(&chunks.serialize_to_vec());
         let expected = [
             "subspace:bing/fucking/bong=AQ==".to_string(),
             "rollback:0000000000002/new/bing/fucking/bong=AQ==".to_string(),
@@ -2962,8 +3952,9 @@ mod test {
     #[test]
     fn test_load_chunks() {
         let temp = tempfile::tempdir().expect("Test failed");
-        let mut chunker = Chunker::new(10);
-        let lines = vec!["fffffggggghh", "aaaa", "bbbbb", "cc", "dddddddd"];
+        let mut chunker = Chunker::new(12);
+        let lines =
+            vec!["fffffggggghh\n", "aaaa\n", "bbbbb\n", "cc\n", "dddddddd\n"];
         for l in lines {
             chunker.add_line(l);
         }
@@ -2971,15 +3962,15 @@ mod test {
         let expected = vec![
             Chunk {
                 boundary: 1,
-                hash: Hash::sha256("fffffggggghh"),
+                hash: Hash::sha256("fffffggggghh\n"),
             },
             Chunk {
                 boundary: 3,
-                hash: Hash::sha256("aaaabbbbb".as_bytes()),
+                hash: Hash::sha256("aaaa\nbbbbb\n".as_bytes()),
             },
             Chunk {
                 boundary: 5,
-                hash: Hash::sha256("ccdddddddd".as_bytes()),
+                hash: Hash::sha256("cc\ndddddddd\n".as_bytes()),
             },
         ];
         assert_eq!(chunks, expected);
@@ -2992,21 +3983,202 @@ mod test {
         .expect("Test failed");
         std::fs::write(meta_file, HEXLOWER.encode(&chunks.serialize_to_vec()))
             .expect("Test failed");
-        let chunks: Vec<_> = (0..3)
+        let loaded: Vec<_> = (0..3)
             .filter_map(|i| {
                 DbSnapshot::load_chunk(1.into(), i, temp.path()).ok()
             })
             .collect();
         let expected = vec![
-            "fffffggggghh".as_bytes().to_vec(),
-            "aaaabbbbb".as_bytes().to_vec(),
-            "ccdddddddd".as_bytes().to_vec(),
+            "fffffggggghh\n".as_bytes().to_vec(),
+            "aaaa\nbbbbb\n".as_bytes().to_vec(),
+            "cc\ndddddddd\n".as_bytes().to_vec(),
         ];
-        assert_eq!(chunks, expected);
+        assert_eq!(loaded, expected);
+        // the loaded bytes must match the hashes in the metadata
+        for (bytes, chunk) in loaded.iter().zip(&chunks) {
+            assert_eq!(Hash::sha256(bytes), chunk.hash);
+        }
 
         assert!(DbSnapshot::load_chunk(0.into(), 0, temp.path()).is_err());
         assert!(DbSnapshot::load_chunk(0.into(), 4, temp.path()).is_err());
         std::fs::remove_file(snap_file).unwrap();
         assert!(DbSnapshot::load_chunk(0.into(), 0, temp.path()).is_err());
     }
+
+    /// Test that the chunks of a snapshot can be applied
+    /// to a fresh database to restore its contents
+    #[test]
+    fn test_apply_snapshot_chunks() {
+        let temp = tempfile::tempdir().expect("Test failed");
+        let mut db = open(&temp, false, None).expect("Test failed");
+        db.write_subspace_val(
+            1.into(),
+            &Key::parse("bing/fucking/bong").expect("Test failed"),
+            [1u8; 64],
+            true,
+        )
+        .expect("Test failed");
+        // a key that the legacy format can't hold
+        db.write_subspace_val(
+            1.into(),
+            &Key::parse("bing/a=b").expect("Test failed"),
+            [2u8; 8],
+            true,
+        )
+        .expect("Test failed");
+        drop(db);
+        let db = open(&temp, true, None).expect("Test failed");
+        let snapshot = db.snapshot();
+        let legacy_dir = tempfile::tempdir().expect("Test failed");
+        assert!(
+            snapshot
+                .write_to_file(
+                    db.column_families(),
+                    legacy_dir.path().to_path_buf(),
+                    1.into(),
+                    SnapshotFormat::Lines,
+                )
+                .is_err()
+        );
+        let snap_dir = tempfile::tempdir().expect("Test failed");
+        snapshot
+            .write_to_file(
+                db.column_families(),
+                snap_dir.path().to_path_buf(),
+                1.into(),
+                SnapshotFormat::LATEST,
+            )
+            .expect("Test failed");
+
+        let mut files =
+            DbSnapshot::files(snap_dir.path()).expect("Test failed");
+        assert_eq!(files.len(), 1);
+        let metadata = files.pop().expect("Test failed");
+        assert_eq!(metadata.format, SnapshotFormat::LATEST);
+        assert_eq!(
+            metadata.manifest.column_families,
+            DbColFam::all().map(String::from).to_vec()
+        );
+        let restored_dir = tempfile::tempdir().expect("Test failed");
+        let restored = open(&restored_dir, false, None).expect("Test failed");
+        for (ix, chunk) in (0u64..).zip(&metadata.manifest.chunks) {
+            let bytes = DbSnapshot::load_chunk(1.into(), ix, snap_dir.path())
+                .expect("Test failed");
+            assert_eq!(Hash::sha256(&bytes), chunk.hash);
+            let lines = DbSnapshot::decode_chunk(metadata.format, &bytes)
+                .expect("Test failed");
+            DbSnapshot::check_chunk_column_families(
+                &lines,
+                &metadata.manifest.column_families,
+            )
+            .expect("Test failed");
+            restored
+                .apply_snapshot_chunk(metadata.format, &lines)
+                .expect("Test failed");
+        }
+
+        let key = Key::parse("bing/fucking/bong").expect("Test failed");
+        assert_eq!(
+            restored.read_subspace_val(&key).expect("Test failed"),
+            Some(vec![1u8; 64])
+        );
+        // entries of column families missing from the manifest are rejected
+        let line = format!("{SUBSPACE_CF}:00=AA==\n");
+        assert!(
+            DbSnapshot::check_chunk_column_families(
+                line.as_bytes(),
+                &[STATE_CF.to_string()],
+            )
+            .is_err()
+        );
+        assert_eq!(
+            restored
+                .read_subspace_val(
+                    &Key::parse("bing/a=b").expect("Test failed")
+                )
+                .expect("Test failed"),
+            Some(vec![2u8; 8])
+        );
+        assert_eq!(
+            restored
+                .read_diffs_val(&key, 1.into(), false)
+                .expect("Test failed"),
+            Some(vec![1u8; 64])
+        );
+
+        restored.clear().expect("Test failed");
+        assert_eq!(
+            restored.read_subspace_val(&key).expect("Test failed"),
+            None
+        );
+    }
+
+    /// Test that the compressed chunks of a snapshot are
+    /// split at byte offsets and can be decompressed back
+    /// into the lines they were built from
+    #[test]
+    fn test_zstd_chunker() {
+        let mut file = vec![];
+        let mut chunker = ZstdChunker::new(&mut file, 10);
+        let lines = ["fffffggggghh\n", "aaaa\n", "bbb\n", "cc\n"];
+        for l in lines {
+            chunker.add_line(l).expect("Test failed");
+        }
+        let chunks = chunker.finalize().expect("Test failed");
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            chunks.last().expect("Test failed").boundary,
+            file.len() as u64
+        );
+
+        let mut start = 0;
+        let mut decoded = vec![];
+        for Chunk { boundary, hash } in chunks {
+            let end = usize::try_from(boundary).expect("Test failed");
+            let bytes = &file[start..end];
+            assert_eq!(Hash::sha256(bytes), hash);
+            decoded.push(
+                DbSnapshot::decode_chunk(SnapshotFormat::ZstdLines, bytes)
+                    .expect("Test failed")
+                    .into_owned(),
+            );
+            start = end;
+        }
+        let expected = vec![
+            "fffffggggghh\n".as_bytes().to_vec(),
+            "aaaa\nbbb\n".as_bytes().to_vec(),
+            "cc\n".as_bytes().to_vec(),
+        ];
+        assert_eq!(decoded, expected);
+    }
+
+    /// Test that the metadata of snapshots written in the
+    /// legacy format is still understood
+    #[test]
+    fn test_snapshot_metadata_formats() {
+        let manifest = SnapshotManifest {
+            column_families: DbColFam::all().map(String::from).to_vec(),
+            chunks: vec![Chunk {
+                boundary: 1,
+                hash: Hash::sha256("bing"),
+            }],
+        };
+        for format in [SnapshotFormat::Lines, SnapshotFormat::ZstdLines] {
+            let contents = manifest.to_file_contents(format);
+            let (parsed_format, parsed) =
+                SnapshotManifest::from_file_contents(&contents)
+                    .expect("Test failed");
+            assert_eq!(parsed_format, format);
+            assert_eq!(parsed, manifest);
+            let decoded =
+                SnapshotManifest::decode(format, &manifest.encode(format))
+                    .expect("Test failed");
+            assert_eq!(decoded, manifest);
+        }
+        let legacy = HEXLOWER.encode(&manifest.chunks.serialize_to_vec());
+        let (format, _) =
+            SnapshotManifest::from_file_contents(&legacy).expect("Test failed");
+        assert_eq!(format, SnapshotFormat::Lines);
+        assert!(SnapshotManifest::from_file_contents("7:00").is_err());
+    }
 }
@@ -137,6 +137,7 @@ where
         masp_fee_payment_gas_limit,
         gas_scale,
         is_native_token_transferable,
+        base_fee_params,
     } = parameters;
 
     // write max tx bytes parameter
@@ -202,9 +203,27 @@ where
     storage
         .write(&native_token_transferable_key, is_native_token_transferable)?;
 
+    update_base_fee_params(storage, base_fee_params.as_ref())?;
+
     Ok(())
 }
 
+/// Update the base fee market parameters in storage. The base fee market is
+/// disabled without them.
+pub fn update_base_fee_params<S>(
+    storage: &mut S,
+    value: Option<&BaseFeeParams>,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage::get_base_fee_params_key();
+    match value {
+        Some(base_fee_params) => storage.write(&key, base_fee_params),
+        None => storage.delete(&key),
+    }
+}
+
 /// Update the vp allowlist parameter in storage. Returns the parameters and gas
 /// cost.
 pub fn update_vp_allowlist_parameter<S>(
@@ -342,6 +361,84 @@ where
     Ok(gas_cost_table.get(token).map(|amount| amount.to_owned()))
 }
 
+/// Read the base fee market parameters, if the base fee market is enabled
+pub fn read_base_fee_params<S>(
+    storage: &S,
+) -> namada_storage::Result<Option<BaseFeeParams>>
+where
+    S: StorageRead,
+{
+    storage.read(&storage::get_base_fee_params_key())
+}
+
+/// Read the base fee per unit of gas for the provided token. It is the
+/// minimum gas price of the token if the base fee market is disabled, and it
+/// is never lower than it. Returns `None` if the token is not allowed for fee
+/// payment.
+pub fn read_base_fee<S>(
+    storage: &S,
+    token: &Address,
+) -> namada_storage::Result<Option<token::Amount>>
+where
+    S: StorageRead,
+{
+    let Some(minimum_gas_price) = read_gas_cost(storage, token)? else {
+        return Ok(None);
+    };
+    let base_fees: BTreeMap<Address, token::Amount> = storage
+        .read(&storage::get_base_fee_key())?
+        .unwrap_or_default();
+    Ok(Some(
+        base_fees.get(token).map_or(minimum_gas_price, |base_fee| {
+            std::cmp::max(*base_fee, minimum_gas_price)
+        }),
+    ))
+}
+
+/// Update the base fee of every fee token with the gas used by the last
+/// block. The base fees are cleared if the base fee market is disabled.
+pub fn update_base_fees<S>(
+    storage: &mut S,
+    block_gas: u64,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let base_fee_key = storage::get_base_fee_key();
+    let Some(base_fee_params) = read_base_fee_params(storage)? else {
+        if storage.has_key(&base_fee_key)? {
+            storage.delete(&base_fee_key)?;
+        }
+        return Ok(());
+    };
+    let max_block_gas = get_max_block_gas(storage)?;
+    let gas_cost_table: BTreeMap<Address, token::Amount> = storage
+        .read(&storage::get_gas_cost_key())?
+        .ok_or(ReadError::ParametersMissing)
+        .into_storage_result()?;
+    let base_fees: BTreeMap<Address, token::Amount> =
+        storage.read(&base_fee_key)?.unwrap_or_default();
+
+    // Tokens removed from the gas cost table are dropped
+    let next_base_fees: BTreeMap<Address, token::Amount> = gas_cost_table
+        .into_iter()
+        .map(|(token, minimum_gas_price)| {
+            let base_fee =
+                base_fees.get(&token).copied().unwrap_or(minimum_gas_price);
+            let next_base_fee = base_fee_params
+                .next_base_fee(
+                    base_fee,
+                    minimum_gas_price,
+                    block_gas,
+                    max_block_gas,
+                )
+                .unwrap_or(base_fee);
+            (token, next_base_fee)
+        })
+        .collect();
+    storage.write(&base_fee_key, next_base_fees)
+}
+
 /// Read the number of epochs per year parameter
 pub fn read_epochs_per_year<S>(storage: &S) -> namada_storage::Result<u64>
 where
@@ -461,6 +558,9 @@ where
         .ok_or(ReadError::ParametersMissing)
         .into_storage_result()?;
 
+    // read base fee market parameters
+    let base_fee_params = read_base_fee_params(storage)?;
+
     Ok(Parameters {
         max_tx_bytes,
         epoch_duration,
@@ -475,6 +575,7 @@ where
         masp_fee_payment_gas_limit,
         gas_scale,
         is_native_token_transferable,
+        base_fee_params,
     })
 }
 
@@ -520,6 +621,7 @@ where
         gas_scale: 10_000_000,
         minimum_gas_price: Default::default(),
         is_native_token_transferable: true,
+        base_fee_params: None,
     };
     init_storage(&params, storage)
 }
@@ -845,4 +947,56 @@ mod tests {
 
         assert_eq!(max_block_time, Some(BLOCK_TIME_ESTIMATE_UPPER_BOUND));
     }
+
+    #[test]
+    fn test_base_fee_market() {
+        use namada_core::address::testing::{btc, nam};
+
+        let mut storage = TestStorage::default();
+        init_test_storage(&mut storage).unwrap();
+        let minimum_gas_price = token::Amount::from_u64(1000);
+        storage
+            .write(
+                &storage::get_gas_cost_key(),
+                BTreeMap::from([(nam(), minimum_gas_price)]),
+            )
+            .unwrap();
+        let max_block_gas = get_max_block_gas(&storage).unwrap();
+        let base_fee =
+            |storage: &TestStorage| read_base_fee(storage, &nam()).unwrap();
+
+        // The base fee is the minimum gas price while the market is disabled
+        update_base_fees(&mut storage, max_block_gas).unwrap();
+        assert_eq!(base_fee(&storage), Some(minimum_gas_price));
+        assert!(read_base_fee(&storage, &btc()).unwrap().is_none());
+
+        update_base_fee_params(&mut storage, Some(&BaseFeeParams::default()))
+            .unwrap();
+
+        // Full blocks raise the base fee by 1/8
+        update_base_fees(&mut storage, max_block_gas).unwrap();
+        assert_eq!(base_fee(&storage), Some(token::Amount::from_u64(1125)));
+        update_base_fees(&mut storage, max_block_gas).unwrap();
+        assert_eq!(base_fee(&storage), Some(token::Amount::from_u64(1265)));
+
+        // A block at the target keeps the base fee
+        let target_block_gas = checked!(max_block_gas / 2).unwrap();
+        update_base_fees(&mut storage, target_block_gas).unwrap();
+        assert_eq!(base_fee(&storage), Some(token::Amount::from_u64(1265)));
+
+        // Empty blocks lower the base fee down to the minimum gas price
+        update_base_fees(&mut storage, 0).unwrap();
+        assert_eq!(base_fee(&storage), Some(token::Amount::from_u64(1107)));
+        for _ in 0..5 {
+            update_base_fees(&mut storage, 0).unwrap();
+        }
+        assert_eq!(base_fee(&storage), Some(minimum_gas_price));
+
+        // Disabling the market resets the base fee
+        update_base_fees(&mut storage, max_block_gas).unwrap();
+        assert!(base_fee(&storage) > Some(minimum_gas_price));
+        update_base_fee_params(&mut storage, None).unwrap();
+        update_base_fees(&mut storage, max_block_gas).unwrap();
+        assert_eq!(base_fee(&storage), Some(minimum_gas_price));
+    }
 }
@@ -38,6 +38,8 @@ struct Keys {
     max_tx_bytes: &'static str,
     max_block_gas: &'static str,
     minimum_gas_price: &'static str,
+    base_fee_params: &'static str,
+    base_fee: &'static str,
     masp_fee_payment_gas_limit: &'static str,
     gas_scale: &'static str,
     native_token_transferable: &'static str,
@@ -156,6 +158,16 @@ pub fn get_gas_cost_key() -> Key {
     get_minimum_gas_price_key_at_addr(ADDRESS)
 }
 
+/// Storage key used for the base fee market parameters
+pub fn get_base_fee_params_key() -> Key {
+    get_base_fee_params_key_at_addr(ADDRESS)
+}
+
+/// Storage key used for the base fee of every fee token
+pub fn get_base_fee_key() -> Key {
+    get_base_fee_key_at_addr(ADDRESS)
+}
+
 /// Helper function to retrieve the `max_block_gas` protocol parameter from
 /// storage
 pub fn get_max_block_gas(
@@ -46,6 +46,8 @@ pub enum BondError {
     InactiveValidator(Address),
     #[error("Voting power overflow: {0}")]
     VotingPowerOverflow(TryFromIntError),
+    #[error("No bond could be found for {0} to restake its rewards")]
+    NoBondToRestake(BondId),
 }
 
 #[allow(missing_docs)]
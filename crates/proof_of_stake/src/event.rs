@@ -15,6 +15,10 @@ pub mod types {
 
     /// Slash event.
     pub const SLASH: EventType = event_type!(PosEvent, "slash");
+
+    /// Rewards restaking event.
+    pub const REWARDS_RESTAKED: EventType =
+        event_type!(PosEvent, "rewards-restaked");
 }
 
 /// Proof of Stake event.
@@ -27,6 +31,15 @@ pub enum PosEvent {
         /// Amount of tokens that have been slashed.
         amount: token::Amount,
     },
+    /// Rewards restaking event.
+    RewardsRestaked {
+        /// The source address of the bond.
+        source: Address,
+        /// The validator address of the bond.
+        validator: Address,
+        /// Amount of reward tokens that have been bonded.
+        amount: token::Amount,
+    },
 }
 
 impl EventToEmit for PosEvent {
@@ -42,6 +55,15 @@ impl From<PosEvent> for Event {
                     .with(SlashedAmount(&amount.into()))
                     .into()
             }
+            PosEvent::RewardsRestaked {
+                source,
+                validator,
+                amount,
+            } => Event::new(types::REWARDS_RESTAKED, EventLevel::Block)
+                .with(RestakedSource(source))
+                .with(RestakedValidator(validator))
+                .with(RestakedAmount(&amount.into()))
+                .into(),
         }
     }
 }
@@ -73,3 +95,45 @@ impl<'amt> EventAttributeEntry<'amt> for SlashedAmount<'amt> {
         self.0
     }
 }
+
+/// Extend an [`Event`] with the source address of a restaked bond.
+pub struct RestakedSource(pub Address);
+
+impl EventAttributeEntry<'static> for RestakedSource {
+    type Value = Address;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "restaked-source";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with the validator address of a restaked bond.
+pub struct RestakedValidator(pub Address);
+
+impl EventAttributeEntry<'static> for RestakedValidator {
+    type Value = Address;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "restaked-validator";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with restaked amount data.
+pub struct RestakedAmount<'amt>(pub &'amt Uint);
+
+impl<'amt> EventAttributeEntry<'amt> for RestakedAmount<'amt> {
+    type Value = &'amt Uint;
+    type ValueOwned = Uint;
+
+    const KEY: &'static str = "restaked-amount";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
@@ -87,8 +87,8 @@ use crate::storage::{
     validator_slashes_handle, validator_state_handle,
     validator_total_redelegated_bonded_handle,
     validator_total_redelegated_unbonded_handle,
-    write_last_pos_inflation_amount, write_last_reward_claim_epoch,
-    write_last_staked_ratio, write_pos_params,
+    write_auto_restake_epoch, write_last_pos_inflation_amount,
+    write_last_reward_claim_epoch, write_last_staked_ratio, write_pos_params,
     write_validator_address_raw_hash, write_validator_avatar,
     write_validator_description, write_validator_discord_handle,
     write_validator_email, write_validator_max_commission_rate_change,
@@ -1582,6 +1582,15 @@ where
         withdrawable_amount,
     )?;
 
+    // Stop restaking the rewards of a bond once it's fully withdrawn
+    if bond_handle(source, validator)
+        .get_data_handler()
+        .is_empty(storage)?
+        && unbond_handle.is_empty(storage)?
+    {
+        write_auto_restake_epoch(storage, source, validator, None)?;
+    }
+
     // TODO: Transfer the slashed tokens from the PoS address to the Slash Pool
     // address
     // Token::transfer(
@@ -2795,6 +2804,50 @@ where
     Ok(res)
 }
 
+/// Enable or disable the automatic restaking of a bond's rewards at the start
+/// of every new epoch. It may only be enabled for a bond with some tokens at
+/// the pipeline offset.
+pub fn set_auto_restake<S, Gov>(
+    storage: &mut S,
+    source: Option<&Address>,
+    validator: &Address,
+    enabled: bool,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+    Gov: governance::Read<S>,
+{
+    if !is_validator(storage, validator)? {
+        return Err(BondError::NotAValidator(validator.clone()).into());
+    }
+    let source = source.unwrap_or(validator);
+    if enabled {
+        let params = read_pos_params::<S, Gov>(storage)?;
+        let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+        let bonded = bond_handle(source, validator)
+            .get_sum(storage, pipeline_epoch, &params)?
+            .unwrap_or_default();
+        if bonded.is_zero() {
+            return Err(BondError::NoBondToRestake(BondId {
+                source: source.clone(),
+                validator: validator.clone(),
+            })
+            .into());
+        }
+    }
+    tracing::debug!(
+        "Setting auto-restake of bond {source} --> {validator} to {enabled} in \
+         epoch {current_epoch}"
+    );
+    write_auto_restake_epoch(
+        storage,
+        source,
+        validator,
+        enabled.then_some(current_epoch),
+    )
+}
+
 /// Jail a validator by removing it from and updating the validator sets and
 /// changing a its state to `Jailed`. Validators are jailed for liveness and for
 /// misbehaving.
@@ -3213,6 +3266,7 @@ pub mod test_utils {
             gas_scale: 100_000_000,
             minimum_gas_price: BTreeMap::new(),
             is_native_token_transferable: true,
+            base_fee_params: None,
         };
         Params::write(storage, &chain_parameters).unwrap();
         init_genesis_helper::<S, Gov, Token>(
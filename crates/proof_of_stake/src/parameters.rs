@@ -13,6 +13,7 @@ use namada_governance::parameters::GovernanceParameters;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Proof-of-Stake system parameters. This includes parameters that are used in
@@ -28,7 +29,15 @@ pub struct PosParams {
 
 /// Proof-of-Stake system parameters owned by the PoS address, set at genesis
 /// and can only be changed via governance
-#[derive(Debug, Clone, BorshDeserialize, BorshDeserializer, BorshSerialize)]
+#[derive(
+    Debug,
+    Clone,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    Serialize,
+    Deserialize,
+)]
 pub struct OwnedPosParams {
     /// A maximum number of consensus validators
     pub max_validator_slots: u64,
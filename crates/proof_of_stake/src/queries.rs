@@ -5,6 +5,7 @@ use std::collections::BTreeMap;
 
 use borsh::BorshDeserialize;
 use namada_core::address::Address;
+use namada_core::arith::checked;
 use namada_core::collections::{HashMap, HashSet};
 use namada_core::dec::Dec;
 use namada_core::key::common;
@@ -14,15 +15,22 @@ use namada_storage::collections::lazy_map::{NestedSubKey, SubKey};
 use namada_storage::StorageRead;
 use namada_systems::governance;
 
+use crate::epoched::{
+    EpochOffset, OffsetMaxProposalPeriodOrSlashProcessingLenPlus,
+};
 use crate::slashing::{find_validator_slashes, get_slashed_amount};
 use crate::storage::{
     bond_handle, delegation_targets_handle,
-    read_consensus_validator_set_addresses, read_pos_params, unbond_handle,
-    validator_eth_hot_key_handle,
+    read_below_capacity_validator_set_addresses_with_stake,
+    read_consensus_validator_set_addresses,
+    read_consensus_validator_set_addresses_with_stake, read_pos_params,
+    unbond_handle, validator_commission_rate_handle, validator_deltas_handle,
+    validator_eth_hot_key_handle, validator_state_handle,
 };
 use crate::types::{
-    BondDetails, BondId, BondsAndUnbondsDetail, BondsAndUnbondsDetails,
-    DelegationEpochs, Slash, UnbondDetails,
+    BondDetails, BondEpochData, BondId, BondsAndUnbondsDetail,
+    BondsAndUnbondsDetails, DelegationEpochs, Slash, UnbondDetails,
+    ValidatorEpochData,
 };
 use crate::{raw_bond_amount, storage_key, PosParams};
 
@@ -623,3 +631,139 @@ where
         .cloned();
     Ok(address)
 }
+
+/// Find a validator's stake, commission rate, state and rank at every epoch of
+/// the given inclusive range. The range is clamped to the epochs whose data
+/// may still be retained in storage, up to the pipeline epoch.
+pub fn validator_history<S, Gov>(
+    storage: &S,
+    validator: &Address,
+    start: Epoch,
+    end: Epoch,
+) -> namada_storage::Result<Vec<ValidatorEpochData>>
+where
+    S: StorageRead,
+    Gov: governance::Read<S>,
+{
+    let params = read_pos_params::<S, Gov>(storage)?;
+    let current_epoch = storage.get_block_epoch()?;
+    let deltas = validator_deltas_handle(validator);
+    let commission_rates = validator_commission_rate_handle(validator);
+    let states = validator_state_handle(validator);
+
+    retained_epochs(&params, current_epoch, start, end)
+        .map(|epoch| {
+            let stake = deltas
+                .get_sum(storage, epoch, &params)?
+                .map(token::Amount::from_change);
+            let commission_rate =
+                commission_rates.get(storage, epoch, &params)?;
+            let state = states.get(storage, epoch, &params)?;
+            let rank = validator_rank(storage, validator, epoch)?;
+            Ok(ValidatorEpochData {
+                epoch,
+                stake,
+                commission_rate,
+                state,
+                rank,
+            })
+        })
+        .collect()
+}
+
+/// Find the bonded, unbonding and withdrawable amounts of a bond at every
+/// epoch of the given inclusive range. The range is clamped the same way as in
+/// [`validator_history`]. Unbonds that have already been withdrawn are no
+/// longer in storage and so they are not accounted for.
+pub fn bond_history<S, Gov>(
+    storage: &S,
+    source: &Address,
+    validator: &Address,
+    start: Epoch,
+    end: Epoch,
+) -> namada_storage::Result<Vec<BondEpochData>>
+where
+    S: StorageRead,
+    Gov: governance::Read<S>,
+{
+    let params = read_pos_params::<S, Gov>(storage)?;
+    let current_epoch = storage.get_block_epoch()?;
+    let bonds = find_bonds(storage, source, validator)?;
+    let unbonds = find_unbonds(storage, source, validator)?;
+    // The number of epochs between an unbond's removal from the validator's
+    // stake and its withdrawable epoch
+    let unbonding_len =
+        checked!(params.unbonding_len + params.cubic_slashing_window_length)?;
+
+    retained_epochs(&params, current_epoch, start, end)
+        .map(|epoch| {
+            let mut data = BondEpochData {
+                epoch,
+                ..Default::default()
+            };
+            for amount in bonds.range(..=epoch).map(|(_, amount)| amount) {
+                data.bonded = checked!(data.bonded + *amount)?;
+            }
+            for ((bond_start, withdraw_epoch), amount) in &unbonds {
+                if *bond_start > epoch {
+                    continue;
+                }
+                let unbond_epoch = withdraw_epoch
+                    .checked_sub(unbonding_len)
+                    .unwrap_or_default();
+                if epoch < unbond_epoch {
+                    data.bonded = checked!(data.bonded + *amount)?;
+                } else if epoch < *withdraw_epoch {
+                    data.unbonding = checked!(data.unbonding + *amount)?;
+                } else {
+                    data.withdrawable = checked!(data.withdrawable + *amount)?;
+                }
+            }
+            Ok(data)
+        })
+        .collect()
+}
+
+/// Clamp the given inclusive epoch range to the epochs from the oldest past
+/// epoch that may still be retained for validator data up to the pipeline
+/// epoch.
+fn retained_epochs(
+    params: &PosParams,
+    current_epoch: Epoch,
+    start: Epoch,
+    end: Epoch,
+) -> impl Iterator<Item = Epoch> {
+    let oldest_epoch = current_epoch
+        .checked_sub(OffsetMaxProposalPeriodOrSlashProcessingLenPlus::value(
+            params,
+        ))
+        .unwrap_or_default();
+    let pipeline_epoch = current_epoch.unchecked_add(params.pipeline_len);
+    let start = cmp::max(start, oldest_epoch);
+    let end = cmp::min(end, pipeline_epoch);
+    (start.0..=end.0).map(Epoch)
+}
+
+/// Find the 1-based position of a validator by stake in the consensus set,
+/// followed by the below-capacity set, at the given epoch.
+fn validator_rank<S>(
+    storage: &S,
+    validator: &Address,
+    epoch: Epoch,
+) -> namada_storage::Result<Option<u64>>
+where
+    S: StorageRead,
+{
+    let consensus =
+        read_consensus_validator_set_addresses_with_stake(storage, epoch)?;
+    let below_capacity =
+        read_below_capacity_validator_set_addresses_with_stake(storage, epoch)?;
+    Ok(consensus
+        .iter()
+        .rev()
+        .chain(below_capacity.iter().rev())
+        .zip(1_u64..)
+        .find_map(|(weighted, rank)| {
+            (&weighted.address == validator).then_some(rank)
+        }))
+}
@@ -8,23 +8,27 @@ use namada_core::dec::Dec;
 use namada_core::storage::{BlockHeight, Epoch};
 use namada_core::token;
 use namada_core::uint::{Uint, I256};
+use namada_events::EmitEvents;
 use namada_storage::collections::lazy_map::NestedSubKey;
 use namada_storage::{ResultExt, StorageRead, StorageWrite};
 use namada_systems::{governance, parameters, trans_token};
 use thiserror::Error;
 
+use crate::event::PosEvent;
 use crate::storage::{
     consensus_validator_set_handle, get_last_reward_claim_epoch,
-    read_last_pos_inflation_amount, read_last_staked_ratio, read_pos_params,
+    read_auto_restake_bonds, read_last_pos_inflation_amount,
+    read_last_staked_ratio, read_pos_params, read_restake_cursor,
     read_total_stake, read_validator_stake, rewards_accumulator_handle,
     validator_commission_rate_handle, validator_rewards_products_handle,
     validator_state_handle, write_last_pos_inflation_amount,
-    write_last_staked_ratio,
+    write_last_staked_ratio, write_restake_cursor,
 };
 use crate::types::{into_tm_voting_power, BondId, ValidatorState, VoteInfo};
 use crate::{
-    bond_amounts_for_rewards, get_total_consensus_stake, staking_token_address,
-    storage, storage_key, InflationError, PosParams,
+    bond_amounts_for_rewards, bond_tokens, claim_reward_tokens,
+    get_total_consensus_stake, is_validator, staking_token_address, storage,
+    storage_key, InflationError, PosParams,
 };
 
 /// This is equal to 0.01.
@@ -610,6 +614,80 @@ where
     Ok(reward_tokens)
 }
 
+/// The max number of bonds whose rewards are automatically restaked in a single
+/// epoch
+pub const MAX_RESTAKED_BONDS_PER_EPOCH: usize = 100;
+
+/// Claim the rewards of the bonds that opted into automatic restaking and bond
+/// them to the same validator. This must be applied at the start of a new
+/// epoch, after the inflation for the last epoch has been applied. At most
+/// [`MAX_RESTAKED_BONDS_PER_EPOCH`] bonds are restaked, resuming after the
+/// last restaked bond of the previous epoch.
+pub fn restake_rewards<S, Gov, Token>(
+    storage: &mut S,
+    events: &mut impl EmitEvents,
+    current_epoch: Epoch,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+    Gov: governance::Read<S>,
+    Token: trans_token::Write<S>,
+{
+    let bonds = read_auto_restake_bonds(storage)?;
+    let num_bonds = bonds.len();
+    let cursor = read_restake_cursor(storage)?;
+    // Order the bonds from the one after the cursor, wrapping around
+    let (next, wrapped): (Vec<_>, Vec<_>) = bonds
+        .into_iter()
+        .partition(|bond| cursor.as_ref().map_or(true, |cursor| bond > cursor));
+    let batch: Vec<BondId> = next
+        .into_iter()
+        .chain(wrapped)
+        .take(MAX_RESTAKED_BONDS_PER_EPOCH)
+        .collect();
+    let cursor = if num_bonds > MAX_RESTAKED_BONDS_PER_EPOCH {
+        batch.last()
+    } else {
+        None
+    };
+    write_restake_cursor(storage, cursor)?;
+
+    for BondId { source, validator } in batch {
+        // A delegator that has since become a validator can no longer bond to
+        // other validators, its rewards are left to be claimed
+        if source != validator && is_validator(storage, &source)? {
+            continue;
+        }
+        let amount = claim_reward_tokens::<S, Gov, Token>(
+            storage,
+            Some(&source),
+            &validator,
+            current_epoch,
+        )?;
+        if amount.is_zero() {
+            continue;
+        }
+        bond_tokens::<S, Gov, Token>(
+            storage,
+            Some(&source),
+            &validator,
+            amount,
+            current_epoch,
+            None,
+        )?;
+        tracing::debug!(
+            "Restaked {} reward tokens of bond {source} --> {validator}",
+            amount.to_string_native()
+        );
+        events.emit(PosEvent::RewardsRestaked {
+            source,
+            validator,
+            amount,
+        });
+    }
+    Ok(())
+}
+
 /// Add tokens to a rewards counter.
 pub fn add_rewards_to_counter<S>(
     storage: &mut S,
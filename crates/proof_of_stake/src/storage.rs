@@ -999,6 +999,81 @@ where
     storage.write(&key, epoch)
 }
 
+/// Read the epoch from which the rewards of the delegator-validator pair are
+/// automatically restaked, if enabled
+pub fn read_auto_restake_epoch<S>(
+    storage: &S,
+    delegator: &Address,
+    validator: &Address,
+) -> namada_storage::Result<Option<Epoch>>
+where
+    S: StorageRead,
+{
+    let key = storage_key::auto_restake_key(delegator, validator);
+    storage.read(&key)
+}
+
+/// Enable the automatic restaking of the rewards of the delegator-validator
+/// pair from the given epoch, or disable it when `None`
+pub fn write_auto_restake_epoch<S>(
+    storage: &mut S,
+    delegator: &Address,
+    validator: &Address,
+    epoch: Option<Epoch>,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage_key::auto_restake_key(delegator, validator);
+    match epoch {
+        Some(epoch) => storage.write(&key, epoch),
+        None => storage.delete(&key),
+    }
+}
+
+/// Read the last bond whose rewards have been automatically restaked, from
+/// which the restaking resumes in the next epoch
+pub fn read_restake_cursor<S>(
+    storage: &S,
+) -> namada_storage::Result<Option<BondId>>
+where
+    S: StorageRead,
+{
+    storage.read(&storage_key::restake_cursor_key())
+}
+
+/// Write the last bond whose rewards have been automatically restaked, or
+/// delete it when `None`
+pub fn write_restake_cursor<S>(
+    storage: &mut S,
+    bond_id: Option<&BondId>,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage_key::restake_cursor_key();
+    match bond_id {
+        Some(bond_id) => storage.write(&key, bond_id),
+        None => storage.delete(&key),
+    }
+}
+
+/// Find all the bonds whose rewards are automatically restaked
+pub fn read_auto_restake_bonds<S>(
+    storage: &S,
+) -> namada_storage::Result<BTreeSet<BondId>>
+where
+    S: StorageRead,
+{
+    let prefix = storage_key::auto_restake_prefix();
+    namada_storage::iter_prefix::<Epoch>(storage, &prefix)?
+        .filter_map(|result| match result {
+            Ok((key, _epoch)) => storage_key::is_auto_restake_key(&key).map(Ok),
+            Err(err) => Some(Err(err)),
+        })
+        .collect()
+}
+
 /// Check if the given consensus key is already being used to ensure uniqueness.
 ///
 /// If it's not being used, it will be inserted into the set that's being used
@@ -43,6 +43,8 @@ const CONSENSUS_VALIDATOR_SET_ACCUMULATOR_STORAGE_KEY: &str =
     "validator_rewards_accumulator";
 const LAST_REWARD_CLAIM_EPOCH: &str = "last_reward_claim_epoch";
 const REWARDS_COUNTER_KEY: &str = "validator_rewards_commissions";
+const AUTO_RESTAKE_KEY: &str = "auto_restake";
+const RESTAKE_CURSOR_KEY: &str = "restake_cursor";
 const VALIDATOR_INCOMING_REDELEGATIONS_KEY: &str = "incoming_redelegations";
 const VALIDATOR_OUTGOING_REDELEGATIONS_KEY: &str = "outgoing_redelegations";
 const VALIDATOR_TOTAL_REDELEGATED_BONDED_KEY: &str = "total_redelegated_bonded";
@@ -951,6 +953,48 @@ pub fn is_last_pos_reward_claim_epoch_key(key: &Key) -> Option<BondId> {
     }
 }
 
+/// Storage prefix for the bonds whose rewards are automatically restaked.
+pub fn auto_restake_prefix() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&AUTO_RESTAKE_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for the epoch from which a bond's rewards are automatically
+/// restaked.
+pub fn auto_restake_key(delegator: &Address, validator: &Address) -> Key {
+    auto_restake_prefix()
+        .push(&delegator.to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&validator.to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for the last bond whose rewards have been automatically
+/// restaked, if the restaking of all the bonds didn't fit in an epoch.
+pub fn restake_cursor_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&RESTAKE_CURSOR_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Is the storage key for the epoch from which a bond's rewards are
+/// automatically restaked? Return the bond ID if so.
+pub fn is_auto_restake_key(key: &Key) -> Option<BondId> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(key),
+            DbKeySeg::AddressSeg(source),
+            DbKeySeg::AddressSeg(validator),
+        ] if addr == &ADDRESS && key == AUTO_RESTAKE_KEY => Some(BondId {
+            source: source.clone(),
+            validator: validator.clone(),
+        }),
+        _ => None,
+    }
+}
+
 /// Get validator address from bond key
 pub fn get_validator_address_from_bond(key: &Key) -> Option<Address> {
     match key.get_at(3) {
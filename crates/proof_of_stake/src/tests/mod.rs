@@ -326,6 +326,42 @@ where
     )
 }
 
+/// DI indirection
+pub fn restake_rewards<S>(
+    storage: &mut S,
+    events: &mut impl EmitEvents,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    crate::rewards::restake_rewards::<S, GovStore<S>, token::Store<_>>(
+        storage,
+        events,
+        current_epoch,
+    )
+}
+
+/// DI indirection
+pub fn set_auto_restake<S>(
+    storage: &mut S,
+    source: Option<&Address>,
+    validator: &Address,
+    enabled: bool,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    crate::set_auto_restake::<S, GovStore<S>>(
+        storage,
+        source,
+        validator,
+        enabled,
+        current_epoch,
+    )
+}
+
 /// DI indirection
 pub fn find_delegations<S>(
     storage: &S,
@@ -28,14 +28,16 @@ use test_log::test;
 use crate::epoched::EpochOffset;
 use crate::parameters::testing::arb_pos_params;
 use crate::parameters::OwnedPosParams;
-use crate::queries::find_delegation_validators;
+use crate::queries::{
+    bond_history, find_delegation_validators, validator_history,
+};
 use crate::rewards::{
     log_block_rewards_aux, update_rewards_products_and_mint_inflation,
     PosRewardsCalculator,
 };
 use crate::storage::{
     delegation_targets_handle, get_consensus_key_set,
-    liveness_sum_missed_votes_handle,
+    liveness_sum_missed_votes_handle, read_auto_restake_epoch,
     read_consensus_validator_set_addresses_with_stake, read_total_stake,
     read_validator_deltas_value, rewards_accumulator_handle,
     total_deltas_handle,
@@ -47,21 +49,22 @@ use crate::tests::helpers::{
 use crate::tests::{
     bond_amount, bond_tokens, bonds_and_unbonds, change_consensus_key,
     find_delegations, process_slashes,
-    read_below_threshold_validator_set_addresses, redelegate_tokens, slash,
-    test_init_genesis, unbond_tokens, unjail_validator, withdraw_tokens,
-    GovStore,
+    read_below_threshold_validator_set_addresses, redelegate_tokens,
+    restake_rewards, set_auto_restake, slash, test_init_genesis, unbond_tokens,
+    unjail_validator, withdraw_tokens, GovStore,
 };
 use crate::types::{
-    into_tm_voting_power, BondDetails, BondId, BondsAndUnbondsDetails,
-    GenesisValidator, SlashType, UnbondDetails, ValidatorState, VoteInfo,
-    WeightedValidator,
+    into_tm_voting_power, BondDetails, BondEpochData, BondId,
+    BondsAndUnbondsDetails, GenesisValidator, SlashType, UnbondDetails,
+    ValidatorState, VoteInfo, WeightedValidator,
 };
 use crate::{
     below_capacity_validator_set_handle, bond_handle,
     consensus_validator_set_handle, is_delegator, is_validator,
-    jail_for_liveness, read_validator_stake, staking_token_address,
-    unbond_handle, validator_consensus_key_handle,
-    validator_set_positions_handle, validator_state_handle,
+    jail_for_liveness, query_reward_tokens, read_validator_stake,
+    staking_token_address, unbond_handle,
+    validator_consensus_key_handle, validator_set_positions_handle,
+    validator_state_handle,
 };
 
 proptest! {
@@ -2210,3 +2213,324 @@ fn test_delegation_targets() {
     assert!(de_2.prev_ranges.is_empty());
     assert_eq!(de_2.last_range.1, None);
 }
+
+#[test]
+fn test_validator_and_bond_history() {
+    let stakes = vec![
+        token::Amount::native_whole(1),
+        token::Amount::native_whole(2),
+    ];
+    let mut storage = TestState::default();
+    let current_epoch = storage.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+
+    let genesis_validators = get_genesis_validators(2, stakes.clone());
+    let validator = genesis_validators[0].address.clone();
+    let commission_rate = genesis_validators[0].commission_rate;
+
+    let delegator = address::testing::gen_implicit_address();
+    let staking_token = staking_token_address(&storage);
+    credit_tokens(
+        &mut storage,
+        &staking_token,
+        &delegator,
+        token::Amount::native_whole(20),
+    )
+    .unwrap();
+
+    let params = test_init_genesis(
+        &mut storage,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+
+    // Bond from a delegator in epoch 1
+    let mut current_epoch = advance_epoch(&mut storage, &params);
+    let bond_epoch = current_epoch + params.pipeline_len;
+    bond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        token::Amount::native_whole(3),
+        current_epoch,
+        None,
+    )
+    .unwrap();
+
+    // The range is clamped to the pipeline epoch
+    let history = validator_history::<_, GovStore<_>>(
+        &storage,
+        &validator,
+        Epoch::default(),
+        Epoch(u64::MAX),
+    )
+    .unwrap();
+    assert_eq!(history.len() as u64, bond_epoch.0 + 1);
+    for data in &history {
+        let (stake, rank) = if data.epoch < bond_epoch {
+            (stakes[0], 2)
+        } else {
+            (stakes[0] + token::Amount::native_whole(3), 1)
+        };
+        assert_eq!(data.stake, Some(stake));
+        assert_eq!(data.commission_rate, Some(commission_rate));
+        assert_eq!(data.state, Some(ValidatorState::Consensus));
+        assert_eq!(data.rank, Some(rank));
+    }
+
+    // Unbond a part of the bond once it contributes to stake
+    while current_epoch < bond_epoch {
+        current_epoch = advance_epoch(&mut storage, &params);
+    }
+    unbond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        token::Amount::native_whole(1),
+        current_epoch,
+        false,
+    )
+    .unwrap();
+    let unbond_epoch = current_epoch + params.pipeline_len;
+
+    let history = bond_history::<_, GovStore<_>>(
+        &storage,
+        &delegator,
+        &validator,
+        Epoch::default(),
+        Epoch(u64::MAX),
+    )
+    .unwrap();
+    let expected = Epoch::iter_bounds_inclusive(Epoch::default(), unbond_epoch)
+        .map(|epoch| {
+            let (bonded, unbonding) = if epoch < bond_epoch {
+                (0, 0)
+            } else if epoch < unbond_epoch {
+                (3, 0)
+            } else {
+                (2, 1)
+            };
+            BondEpochData {
+                epoch,
+                bonded: token::Amount::native_whole(bonded),
+                unbonding: token::Amount::native_whole(unbonding),
+                withdrawable: token::Amount::zero(),
+            }
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(history, expected);
+
+    // An empty range
+    let history = bond_history::<_, GovStore<_>>(
+        &storage,
+        &delegator,
+        &validator,
+        unbond_epoch.next(),
+        Epoch(u64::MAX),
+    )
+    .unwrap();
+    assert!(history.is_empty());
+}
+
+#[test]
+fn test_auto_restake() {
+    let mut storage = TestState::default();
+    let current_epoch = storage.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+
+    let genesis_validators =
+        get_genesis_validators(1, vec![token::Amount::native_whole(1_000)]);
+    let validator = genesis_validators[0].address.clone();
+
+    let delegator = address::testing::gen_implicit_address();
+    let staking_token = staking_token_address(&storage);
+    credit_tokens(
+        &mut storage,
+        &staking_token,
+        &delegator,
+        token::Amount::native_whole(1_000),
+    )
+    .unwrap();
+
+    let params = test_init_genesis(
+        &mut storage,
+        params,
+        genesis_validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+
+    // Auto-restake can only be enabled for bonds to a validator
+    assert!(
+        set_auto_restake(
+            &mut storage,
+            Some(&delegator),
+            &delegator,
+            true,
+            current_epoch,
+        )
+        .is_err()
+    );
+    // ... and only for an existing bond
+    assert!(
+        set_auto_restake(
+            &mut storage,
+            Some(&delegator),
+            &validator,
+            true,
+            current_epoch,
+        )
+        .is_err()
+    );
+
+    let mut current_epoch = current_epoch;
+    bond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        token::Amount::native_whole(1_000),
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    set_auto_restake(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        true,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(
+        read_auto_restake_epoch(&storage, &delegator, &validator).unwrap(),
+        Some(current_epoch)
+    );
+
+    // Advance until the bond contributes to the validator's stake
+    for _ in 0..params.pipeline_len {
+        current_epoch = advance_epoch(&mut storage, &params);
+    }
+
+    // Assign rewards to the validator for the last epoch and mint inflation
+    let num_blocks_in_last_epoch = 1000;
+    rewards_accumulator_handle()
+        .insert(
+            &mut storage,
+            validator.clone(),
+            Dec::from(num_blocks_in_last_epoch),
+        )
+        .unwrap();
+    current_epoch = advance_epoch(&mut storage, &params);
+    let total_native_tokens =
+        get_effective_total_native_supply(&storage).unwrap();
+    update_rewards_products_and_mint_inflation::<_, token::Store<_>>(
+        &mut storage,
+        &params,
+        current_epoch.prev().unwrap(),
+        num_blocks_in_last_epoch,
+        token::Amount::native_whole(10_000),
+        &staking_token,
+        total_native_tokens,
+    )
+    .unwrap();
+
+    let rewards = query_reward_tokens::<_, GovStore<_>>(
+        &storage,
+        Some(&delegator),
+        &validator,
+        current_epoch,
+    )
+    .unwrap();
+    assert!(!rewards.is_zero());
+    let self_rewards = query_reward_tokens::<_, GovStore<_>>(
+        &storage,
+        None,
+        &validator,
+        current_epoch,
+    )
+    .unwrap();
+    assert!(!self_rewards.is_zero());
+
+    let bond_id = BondId {
+        source: delegator.clone(),
+        validator: validator.clone(),
+    };
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    let bond_pre = bond_amount(&storage, &bond_id, pipeline_epoch).unwrap();
+
+    let mut events = vec![];
+    restake_rewards(&mut storage, &mut events, current_epoch).unwrap();
+
+    // The delegator's rewards are bonded, the self-bond's rewards which
+    // didn't opt-in are left to be claimed
+    let bond_post = bond_amount(&storage, &bond_id, pipeline_epoch).unwrap();
+    assert_eq!(bond_post, bond_pre + rewards);
+    assert!(
+        query_reward_tokens::<_, GovStore<_>>(
+            &storage,
+            Some(&delegator),
+            &validator,
+            current_epoch,
+        )
+        .unwrap()
+        .is_zero()
+    );
+    assert_eq!(
+        query_reward_tokens::<_, GovStore<_>>(
+            &storage,
+            None,
+            &validator,
+            current_epoch,
+        )
+        .unwrap(),
+        self_rewards
+    );
+    assert_eq!(events.len(), 1);
+
+    // Once disabled, nothing is restaked anymore
+    set_auto_restake(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        false,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(
+        read_auto_restake_epoch(&storage, &delegator, &validator).unwrap(),
+        None
+    );
+    let mut events = vec![];
+    restake_rewards(&mut storage, &mut events, current_epoch).unwrap();
+    assert!(events.is_empty());
+
+    // Once the bond is fully withdrawn, its auto-restake is cleared
+    set_auto_restake(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        true,
+        current_epoch,
+    )
+    .unwrap();
+    unbond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        bond_post,
+        current_epoch,
+        false,
+    )
+    .unwrap();
+    for _ in 0..params.withdrawable_epoch_offset() {
+        current_epoch = advance_epoch(&mut storage, &params);
+    }
+    withdraw_tokens(&mut storage, Some(&delegator), &validator, current_epoch)
+        .unwrap();
+    assert_eq!(
+        read_auto_restake_epoch(&storage, &delegator, &validator).unwrap(),
+        None
+    );
+}
@@ -305,6 +305,57 @@ pub struct CommissionPair {
     pub epoch: Epoch,
 }
 
+/// A validator's PoS data at a single epoch, as returned from a history query.
+/// The fields are `None` for an epoch whose data is no longer retained in
+/// storage.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+pub struct ValidatorEpochData {
+    /// The epoch of the data
+    pub epoch: Epoch,
+    /// Validator's total bonded stake
+    pub stake: Option<token::Amount>,
+    /// Validator commission rate
+    pub commission_rate: Option<Dec>,
+    /// Validator state
+    pub state: Option<ValidatorState>,
+    /// 1-based position of the validator by stake in the consensus set,
+    /// followed by the below-capacity set. `None` when the validator is in
+    /// neither of these sets.
+    pub rank: Option<u64>,
+}
+
+/// A bond's amounts at a single epoch, as returned from a history query. The
+/// amounts are not reduced by slashes.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+pub struct BondEpochData {
+    /// The epoch of the data
+    pub epoch: Epoch,
+    /// Amount contributing to the validator's stake
+    pub bonded: token::Amount,
+    /// Amount that has been unbonded, but is not yet withdrawable
+    pub unbonding: token::Amount,
+    /// Amount that has been unbonded and can be withdrawn, but hasn't been
+    /// yet
+    pub withdrawable: token::Amount,
+}
+
 /// Epoched rewards products
 pub type RewardsProducts = LazyMap<Epoch, Dec>;
 
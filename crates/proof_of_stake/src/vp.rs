@@ -9,7 +9,8 @@ use namada_core::storage::Key;
 use namada_state::StateRead;
 use namada_systems::governance;
 use namada_tx::action::{
-    Action, Bond, ClaimRewards, PosAction, Read, Redelegation, Unbond, Withdraw,
+    Action, AutoRestake, Bond, ClaimRewards, PosAction, Read, Redelegation,
+    Unbond, Withdraw,
 };
 use namada_tx::BatchedTxRef;
 use namada_vp::native_vp::{
@@ -113,6 +114,7 @@ where
         let mut redelegations: BTreeMap<BondId, (Address, token::Amount)> =
             Default::default();
         let mut claimed_rewards: BTreeSet<BondId> = Default::default();
+        let mut auto_restake_updates: BTreeSet<BondId> = Default::default();
         let mut changed_commission: BTreeSet<Address> = Default::default();
         let mut changed_metadata: BTreeSet<Address> = Default::default();
         let mut changed_consensus_key: BTreeSet<Address> = Default::default();
@@ -256,6 +258,26 @@ where
                         }
                         claimed_rewards.insert(bond_id);
                     }
+                    PosAction::AutoRestake(AutoRestake {
+                        validator,
+                        source,
+                        enabled: _,
+                    }) => {
+                        let bond_id = BondId {
+                            source: source.unwrap_or_else(|| validator.clone()),
+                            validator,
+                        };
+                        if !verifiers.contains(&bond_id.source) {
+                            tracing::info!(
+                                "Unauthorized PosAction::AutoRestake"
+                            );
+                            return Err(Error::Unauthorized(
+                                "AutoRestake",
+                                bond_id.source,
+                            ));
+                        }
+                        auto_restake_updates.insert(bond_id);
+                    }
                     PosAction::CommissionChange(validator) => {
                         if !verifiers.contains(&validator) {
                             tracing::info!(
@@ -10,6 +10,7 @@ use namada_core::chain::ChainId;
 use namada_core::collections::HashMap;
 use namada_core::dec::Dec;
 use namada_core::ethereum_events::EthAddress;
+use namada_core::hash::Hash;
 use namada_core::keccak::KeccakHash;
 use namada_core::key::{common, SchemeType};
 use namada_core::masp::{MaspEpoch, PaymentAddress};
@@ -17,7 +18,8 @@ use namada_core::storage::{BlockHeight, Epoch};
 use namada_core::time::DateTimeUtc;
 use namada_core::{storage, token};
 use namada_governance::cli::onchain::{
-    DefaultProposal, PgfFundingProposal, PgfStewardProposal,
+    DefaultProposal, ParameterChangeProposal, PgfFundingProposal,
+    PgfStewardProposal,
 };
 use namada_ibc::IbcShieldingData;
 use namada_tx::data::GasLimit;
@@ -26,7 +28,9 @@ use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing;
 
 use crate::eth_bridge::bridge_pool;
-use crate::ibc::core::host::types::identifiers::{ChannelId, PortId};
+use crate::ibc::core::host::types::identifiers::{
+    ChannelId, ConnectionId, PortId,
+};
 use crate::signing::SigningTxData;
 use crate::{rpc, tx, Namada};
 
@@ -470,6 +474,14 @@ pub struct TxIbcTransfer<C: NamadaTypes = SdkTypes> {
     pub ibc_shielding_data: Option<IbcShieldingData>,
     /// Memo for IBC transfer packet
     pub ibc_memo: Option<String>,
+    /// Token of the fees paid to the relayers of the packet
+    pub relayer_fee_token: Option<C::Address>,
+    /// Fee paid to the relayer delivering the packet
+    pub recv_fee: Option<InputAmount>,
+    /// Fee paid to the relayer delivering the acknowledgement
+    pub ack_fee: Option<InputAmount>,
+    /// Fee paid to the relayer delivering the timeout
+    pub timeout_fee: Option<InputAmount>,
     /// Optional additional keys for gas payment
     pub gas_spending_keys: Vec<C::SpendingKey>,
     /// Path to the TX WASM code file
@@ -559,6 +571,38 @@ impl<C: NamadaTypes> TxIbcTransfer<C> {
         }
     }
 
+    /// Token of the relayer fees
+    pub fn relayer_fee_token(self, relayer_fee_token: C::Address) -> Self {
+        Self {
+            relayer_fee_token: Some(relayer_fee_token),
+            ..self
+        }
+    }
+
+    /// Fee paid to the relayer delivering the packet
+    pub fn recv_fee(self, recv_fee: InputAmount) -> Self {
+        Self {
+            recv_fee: Some(recv_fee),
+            ..self
+        }
+    }
+
+    /// Fee paid to the relayer delivering the acknowledgement
+    pub fn ack_fee(self, ack_fee: InputAmount) -> Self {
+        Self {
+            ack_fee: Some(ack_fee),
+            ..self
+        }
+    }
+
+    /// Fee paid to the relayer delivering the timeout
+    pub fn timeout_fee(self, timeout_fee: InputAmount) -> Self {
+        Self {
+            timeout_fee: Some(timeout_fee),
+            ..self
+        }
+    }
+
     /// Gas spending keys
     pub fn gas_spending_keys(
         self,
@@ -590,6 +634,491 @@ impl TxIbcTransfer {
     }
 }
 
+/// Transaction to register an interchain account on a counterparty chain
+#[derive(Clone, Debug)]
+pub struct TxRegisterInterchainAccount<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The owner of the interchain account
+    pub owner: C::Address,
+    /// The connection ID to the host chain
+    pub connection_id: ConnectionId,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxRegisterInterchainAccount<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxRegisterInterchainAccount {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl TxRegisterInterchainAccount {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_register_interchain_account(context, self).await
+    }
+}
+
+/// Transaction to send tokens from an interchain account on the host chain
+#[derive(Clone, Debug)]
+pub struct TxSendInterchainAccountTx<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The owner of the interchain account
+    pub owner: C::Address,
+    /// The connection ID to the host chain
+    pub connection_id: ConnectionId,
+    /// The receiver address on the host chain
+    pub receiver: String,
+    /// The denomination of the token on the host chain
+    pub denom: String,
+    /// The amount in the smallest unit of the denomination
+    pub amount: String,
+    /// Timeout timestamp offset
+    pub timeout_sec_offset: Option<u64>,
+    /// Memo for the IBC packet
+    pub ibc_memo: Option<String>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxSendInterchainAccountTx<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxSendInterchainAccountTx {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl TxSendInterchainAccountTx {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_send_interchain_account_tx(context, self).await
+    }
+}
+
+/// Transaction to register the payee of a relayer for the relayer fees
+#[derive(Clone, Debug)]
+pub struct TxRegisterRelayerPayee<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The relayer address
+    pub relayer: C::Address,
+    /// Port ID of the fee-enabled channel
+    pub port_id: PortId,
+    /// Channel ID of the fee-enabled channel
+    pub channel_id: ChannelId,
+    /// The payee on this chain to which the fees are paid
+    pub payee: Option<C::Address>,
+    /// The payee on the counterparty chain to which the receive fees are
+    /// paid
+    pub counterparty_payee: Option<String>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxRegisterRelayerPayee<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxRegisterRelayerPayee {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxRegisterRelayerPayee<C> {
+    /// The payee on this chain
+    pub fn payee(self, payee: C::Address) -> Self {
+        Self {
+            payee: Some(payee),
+            ..self
+        }
+    }
+
+    /// The payee on the counterparty chain
+    pub fn counterparty_payee(self, counterparty_payee: String) -> Self {
+        Self {
+            counterparty_payee: Some(counterparty_payee),
+            ..self
+        }
+    }
+}
+
+impl TxRegisterRelayerPayee {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_register_relayer_payee(context, self).await
+    }
+}
+
+/// Transaction to create a new token with the token factory
+#[derive(Clone, Debug)]
+pub struct CreateToken<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The admin of the new token
+    pub admin: C::Address,
+    /// The minter of the new token. Defaults to the admin.
+    pub minter: Option<C::Address>,
+    /// Human readable name of the token
+    pub name: String,
+    /// Ticker symbol of the token
+    pub symbol: String,
+    /// Optional description of the token
+    pub description: Option<String>,
+    /// Denomination of the token
+    pub denom: token::Denomination,
+    /// Optional cap on the total supply of the token
+    pub supply_cap: Option<token::DenominatedAmount>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for CreateToken<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        CreateToken {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl CreateToken {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_create_token(context, self).await
+    }
+}
+
+/// Transaction to mint tokens issued with the token factory
+#[derive(Clone, Debug)]
+pub struct MintTokens<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The factory token
+    pub token: C::Address,
+    /// The minter or the admin of the token
+    pub authority: C::Address,
+    /// The account receiving the minted tokens
+    pub target: C::Address,
+    /// The amount to mint
+    pub amount: InputAmount,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for MintTokens<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        MintTokens {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl MintTokens {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_mint_tokens(context, self).await
+    }
+}
+
+/// Transaction to burn tokens issued with the token factory
+#[derive(Clone, Debug)]
+pub struct BurnTokens<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The factory token
+    pub token: C::Address,
+    /// The minter or the admin of the token
+    pub authority: C::Address,
+    /// The account whose tokens are burned. Defaults to the authority.
+    pub source: Option<C::Address>,
+    /// The amount to burn
+    pub amount: InputAmount,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for BurnTokens<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        BurnTokens {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl BurnTokens {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_burn_tokens(context, self).await
+    }
+}
+
+/// Transaction to update the roles or the metadata of a token issued with
+/// the token factory
+#[derive(Clone, Debug)]
+pub struct UpdateToken<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The factory token
+    pub token: C::Address,
+    /// The current admin of the token
+    pub admin: C::Address,
+    /// The new admin of the token
+    pub new_admin: Option<C::Address>,
+    /// The new minter of the token
+    pub new_minter: Option<C::Address>,
+    /// The new name of the token
+    pub name: Option<String>,
+    /// The new ticker symbol of the token
+    pub symbol: Option<String>,
+    /// The new description of the token
+    pub description: Option<String>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for UpdateToken<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        UpdateToken {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl UpdateToken {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_update_token(context, self).await
+    }
+}
+
+/// Transaction to grant, update or revoke a spending allowance
+#[derive(Clone, Debug)]
+pub struct Approve<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The token of the allowance
+    pub token: C::Address,
+    /// The owner of the balance that can be spent
+    pub owner: C::Address,
+    /// The account allowed to spend from the owner's balance
+    pub spender: C::Address,
+    /// The allowed amount. A zero amount revokes the allowance.
+    pub amount: InputAmount,
+    /// The last epoch in which the allowance can be spent
+    pub expiry: Option<Epoch>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for Approve<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        Approve {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl Approve {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_approve(context, self).await
+    }
+}
+
+/// Transaction to transfer tokens out of an owner's balance with a spending
+/// allowance
+#[derive(Clone, Debug)]
+pub struct TransferFrom<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The token to transfer
+    pub token: C::Address,
+    /// The account spending its allowance
+    pub spender: C::Address,
+    /// The owner of the debited balance
+    pub owner: C::Address,
+    /// The account receiving the tokens
+    pub target: C::Address,
+    /// The amount to transfer
+    pub amount: InputAmount,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TransferFrom<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TransferFrom {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl TransferFrom {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_transfer_from(context, self).await
+    }
+}
+
+/// Transaction to grant tokens that are released to the target by a vesting
+/// schedule
+#[derive(Clone, Debug)]
+pub struct VestTokens<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The token to grant
+    pub token: C::Address,
+    /// The account granting the tokens
+    pub source: C::Address,
+    /// The account receiving the vesting tokens
+    pub target: C::Address,
+    /// The total amount of vesting tokens
+    pub amount: InputAmount,
+    /// The epoch from which the tokens start vesting. Defaults to the current
+    /// epoch.
+    pub start_epoch: Option<Epoch>,
+    /// The epoch before which no tokens are released. Defaults to the start
+    /// epoch.
+    pub cliff_epoch: Option<Epoch>,
+    /// The epoch from which all the tokens are released
+    pub end_epoch: Epoch,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for VestTokens<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        VestTokens {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl VestTokens {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_vest_tokens(context, self).await
+    }
+}
+
+/// Transaction to grant, update or revoke a fee grant that lets a sponsor pay
+/// the fees of the grantee's wrapper txs
+#[derive(Clone, Debug)]
+pub struct GrantFee<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The token of the fees
+    pub token: C::Address,
+    /// The account paying the fees
+    pub sponsor: C::Address,
+    /// The account whose fees are paid
+    pub grantee: C::Address,
+    /// The allowed amount of fees. A zero amount revokes the grant.
+    pub amount: InputAmount,
+    /// The code hashes of the inner txs that can be sponsored. Any tx can be
+    /// sponsored if empty.
+    pub allowed_code_hashes: Vec<Hash>,
+    /// The last epoch in which the grant can be spent
+    pub expiry: Option<Epoch>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for GrantFee<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        GrantFee {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl GrantFee {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_grant_fee(context, self).await
+    }
+}
+
 /// Transaction to initialize create a new proposal
 #[derive(Clone, Debug)]
 pub struct InitProposal<C: NamadaTypes = SdkTypes> {
@@ -601,6 +1130,8 @@ pub struct InitProposal<C: NamadaTypes = SdkTypes> {
     pub is_pgf_stewards: bool,
     /// Flag if proposal is of type Pgf funding
     pub is_pgf_funding: bool,
+    /// Flag if proposal is of type parameter change
+    pub is_parameter_change: bool,
     /// Path to the tx WASM file
     pub tx_code_path: PathBuf,
 }
@@ -642,6 +1173,14 @@ impl<C: NamadaTypes> InitProposal<C> {
         }
     }
 
+    /// Flag if proposal is of type parameter change
+    pub fn is_parameter_change(self, is_parameter_change: bool) -> Self {
+        Self {
+            is_parameter_change,
+            ..self
+        }
+    }
+
     /// Path to the tx WASM file
     pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
         Self {
@@ -704,6 +1243,34 @@ impl InitProposal {
                 })?;
 
             tx::build_pgf_stewards_proposal(context, self, proposal).await
+        } else if self.is_parameter_change {
+            let proposal = ParameterChangeProposal::try_from(
+                self.proposal_data.as_ref(),
+            )
+            .map_err(|e| {
+                crate::error::TxSubmitError::FailedGovernaneProposalDeserialize(
+                    e.to_string(),
+                )
+            })?;
+            let nam_address = context.native_token();
+            let author_balance = rpc::get_token_balance(
+                context.client(),
+                &nam_address,
+                &proposal.proposal.author,
+            )
+            .await?;
+            let proposal = proposal
+                .validate(
+                    &governance_parameters,
+                    current_epoch,
+                    author_balance,
+                    self.tx.force,
+                )
+                .map_err(|e| {
+                    crate::error::TxSubmitError::InvalidProposal(e.to_string())
+                })?;
+
+            tx::build_parameter_change_proposal(context, self, proposal).await
         } else {
             let proposal = DefaultProposal::try_from(
                 self.proposal_data.as_ref(),
@@ -804,6 +1371,69 @@ impl VoteProposal {
     }
 }
 
+/// Transaction to cancel or amend a proposal before its voting period
+#[derive(Clone, Debug)]
+pub struct UpdateProposal<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Proposal id
+    pub proposal_id: u64,
+    /// The new proposal content (json). The proposal is cancelled and its
+    /// deposit refunded if no content is given.
+    pub proposal_content: Option<C::Data>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for UpdateProposal<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        UpdateProposal {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> UpdateProposal<C> {
+    /// Proposal id
+    pub fn proposal_id(self, proposal_id: u64) -> Self {
+        Self {
+            proposal_id,
+            ..self
+        }
+    }
+
+    /// The new proposal content
+    pub fn proposal_content(self, proposal_content: C::Data) -> Self {
+        Self {
+            proposal_content: Some(proposal_content),
+            ..self
+        }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl UpdateProposal {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        let current_epoch = rpc::query_epoch(context.client()).await?;
+        tx::build_update_proposal(context, self, current_epoch).await
+    }
+}
+
 /// Transaction to initialize a new account
 #[derive(Clone, Debug)]
 pub struct TxInitAccount<C: NamadaTypes = SdkTypes> {
@@ -1545,6 +2175,44 @@ impl ClaimRewards {
     }
 }
 
+/// Auto-restake arguments
+#[derive(Clone, Debug)]
+pub struct AutoRestake<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Validator address
+    pub validator: C::Address,
+    /// Source address of the bond. For self-bonds, the validator is also the
+    /// source
+    pub source: Option<C::Address>,
+    /// Whether the rewards of the bond should be automatically restaked
+    pub enabled: bool,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for AutoRestake<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        AutoRestake {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl AutoRestake {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_auto_restake(context, self).await
+    }
+}
+
 /// Query asset conversions
 #[derive(Clone, Debug)]
 pub struct QueryConversions<C: NamadaTypes = SdkTypes> {
@@ -2113,6 +2781,21 @@ pub struct SignTx<C: NamadaTypes = SdkTypes> {
     pub owner: C::Address,
 }
 
+#[derive(Clone, Debug)]
+/// Validate and merge the offline signatures of a multisig account
+pub struct MergeSignatures<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Transaction data
+    pub tx_data: C::Data,
+    /// The account address
+    pub owner: C::Address,
+    /// The serialized signatures
+    pub signatures: Vec<C::Data>,
+    /// The output folder of the merged transaction
+    pub output_folder: Option<PathBuf>,
+}
+
 #[derive(Clone, Debug)]
 /// Sync notes from MASP owned by the provided spending /
 /// viewing keys. Syncing can be told to stop at a given
@@ -2146,6 +2829,34 @@ pub struct QueryCommissionRate<C: NamadaTypes = SdkTypes> {
     pub epoch: Option<Epoch>,
 }
 
+/// Query a PoS validator's history over a range of epochs
+#[derive(Clone, Debug)]
+pub struct QueryValidatorHistory<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of a validator
+    pub validator: C::Address,
+    /// First epoch of the range (the oldest retained epoch, if not specified)
+    pub from_epoch: Option<Epoch>,
+    /// Last epoch of the range (the pipeline epoch, if not specified)
+    pub to_epoch: Option<Epoch>,
+}
+
+/// Query a bond's history over a range of epochs
+#[derive(Clone, Debug)]
+pub struct QueryBondHistory<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of the bond owner
+    pub owner: C::Address,
+    /// Address of a validator
+    pub validator: C::Address,
+    /// First epoch of the range (the oldest retained epoch, if not specified)
+    pub from_epoch: Option<Epoch>,
+    /// Last epoch of the range (the pipeline epoch, if not specified)
+    pub to_epoch: Option<Epoch>,
+}
+
 /// Query validator metadata
 #[derive(Clone, Debug)]
 pub struct QueryMetaData<C: NamadaTypes = SdkTypes> {
@@ -2175,6 +2886,17 @@ pub struct QueryRewards<C: NamadaTypes = SdkTypes> {
     pub validator: C::Address,
 }
 
+/// Query PoS rewards auto-restaking status of a bond
+#[derive(Clone, Debug)]
+pub struct QueryAutoRestake<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of the source
+    pub source: Option<C::Address>,
+    /// Address of the validator
+    pub validator: C::Address,
+}
+
 /// Query PoS delegations
 #[derive(Clone, Debug)]
 pub struct QueryDelegations<C: NamadaTypes = SdkTypes> {
@@ -2259,6 +2981,8 @@ pub struct Tx<C: NamadaTypes = SdkTypes> {
     pub fee_amount: Option<InputAmount>,
     /// The fee payer signing key
     pub wrapper_fee_payer: Option<C::PublicKey>,
+    /// The account sponsoring the fees with a fee grant to the fee payer
+    pub fee_sponsor: Option<C::Address>,
     /// The token in which the fee is being paid
     pub fee_token: C::AddrOrNativeToken,
     /// The max amount of gas used to process tx
@@ -2363,6 +3087,13 @@ pub trait TxBuilder<C: NamadaTypes>: Sized {
             ..x
         })
     }
+    /// The account sponsoring the fees with a fee grant to the fee payer
+    fn fee_sponsor(self, fee_sponsor: C::Address) -> Self {
+        self.tx(|x| Tx {
+            fee_sponsor: Some(fee_sponsor),
+            ..x
+        })
+    }
     /// The token in which the fee is being paid
     fn fee_token(self, fee_token: C::Address) -> Self {
         self.tx(|x| Tx {
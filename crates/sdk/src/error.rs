@@ -201,9 +201,24 @@ pub enum TxSubmitError {
          required for fees. Amount of the fees is {2} and the balance is {3}."
     )]
     BalanceTooLowForFees(Address, Address, String, String),
+    /// The fee grant of the sponsor can't pay the fees
+    #[error("The fee grant from {0} to {1} can't pay the fees: {2}")]
+    InvalidFeeGrant(Address, Address, String),
     /// Token Address does not exist on chain
     #[error("The token address {0} doesn't exist on chain.")]
     TokenDoesNotExist(Address),
+    /// Factory token already exists on chain
+    #[error("The token {0} already exists on chain.")]
+    TokenAlreadyExists(Address),
+    /// Invalid authority of a factory token
+    #[error("The address {0} is not authorized to manage the token {1}.")]
+    InvalidTokenAuthority(Address, Address),
+    /// Missing, expired or insufficient spending allowance
+    #[error(
+        "The allowance of {0} to spend the token {2} from {1} is missing, \
+         expired or insufficient."
+    )]
+    InvalidAllowance(Address, Address, Address),
     /// Source address does not exist on chain
     #[error("The address {0} doesn't exist on chain.")]
     LocationDoesNotExist(Address),
@@ -246,6 +261,9 @@ pub enum TxSubmitError {
     /// The proposal can't be found
     #[error("Proposal {0} can't be found")]
     ProposalDoesNotExist(u64),
+    /// The proposal can't be updated anymore
+    #[error("Proposal {0} can't be updated after its voting period started")]
+    ProposalCannotBeUpdated(u64),
     /// Updating an VP of an implicit account
     #[error(
         "A validity predicate of an implicit address cannot be directly \
@@ -284,6 +302,15 @@ pub enum TxSubmitError {
     /// Invalid owner account
     #[error("The source account {0} is not valid or doesn't exist.")]
     InvalidAccount(String),
+    /// Invalid offline signature
+    #[error("The signature of {0} is invalid: {1}")]
+    InvalidSignature(String, String),
+    /// Offline signature of a key that doesn't belong to the account
+    #[error("The public key {0} is not a member of the account {1}.")]
+    NonMemberSignature(String, String),
+    /// More than one offline signature of the same key
+    #[error("The public key {0} has already signed.")]
+    DuplicateSignature(String),
     /// The redelegation amount is larger than the remaining bond amount
     #[error(
         "The redelegation amount is larger than the remaining bond amount. \
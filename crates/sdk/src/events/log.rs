@@ -1,152 +1,433 @@
 //! A log to store events emitted by `FinalizeBlock` calls in the ledger.
 //!
-//! The log will hold up to `N` events of a certain kind at a time, before
-//! resorting to pruning older events contained within.
+//! The log is persisted in the events index of the DB, which is local to a
+//! node. Every event is stored under the height of its block and its index
+//! within the block. The events are also indexed by their type and by each
+//! of their attributes, such that queries only need to read the events that
+//! may match. The events below some height can be pruned from the log, and
+//! the oldest height whose events are retained is returned with the queries.
+//!
+//! The events of a block are written to the DB in the same batch as the
+//! block, and a query reads at most [`MAX_QUERY_LIMIT`] events. A query
+//! stops scanning the log at the first block boundary past
+//! [`MAX_QUERY_SCANNED_KEYS`], and returns the height to resume from.
 
-use circular_queue::CircularQueue;
-use patricia_tree::map::StringPatriciaMap;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize, BorshSerializeExt};
+use namada_core::hash::Hash;
+use namada_core::storage::{BlockHeight, KeySeg};
+use namada_storage::{DBIter, ResultExt, DB};
 
-use super::{EmitEvents, Event, EventType};
+use super::{Event, EventType};
 
 pub mod dumb_queries;
 
+/// The key prefix of the events, stored under their height and their index
+/// in the block.
+const EVENT_PREFIX: &str = "event";
+/// The key prefix of the index of the events by their type.
+const TYPE_INDEX_PREFIX: &str = "type";
+/// The key prefix of the index of the events by their attributes.
+const ATTRIBUTE_INDEX_PREFIX: &str = "attribute";
+/// The key of the oldest height whose events are retained in the log.
+const OLDEST_HEIGHT_KEY: &str = "oldest_height";
+
+/// The position of an event in the log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct EventId {
+    /// The height of the block that emitted the event
+    height: BlockHeight,
+    /// The index of the event in the block
+    index: u64,
+}
+
+impl EventId {
+    /// Get the key of the event.
+    fn event_key(&self) -> String {
+        format!("{EVENT_PREFIX}/{}", self.raw())
+    }
+
+    /// Get the key of the event in the index of its type.
+    fn type_index_key(&self, event_type: &EventType) -> String {
+        format!("{TYPE_INDEX_PREFIX}/{event_type}/{}", self.raw())
+    }
+
+    /// Get the key of the event in the index of one of its attributes. The
+    /// value is hashed to bound the length of the key.
+    fn attribute_index_key(&self, key: &str, value: &str) -> String {
+        format!("{}/{}", attribute_index_prefix(key, value), self.raw())
+    }
+
+    /// Get the last segments of the keys of the event. They preserve the
+    /// order of the events.
+    fn raw(&self) -> String {
+        format!("{}/{}", self.height.raw(), self.index.raw())
+    }
+
+    /// Parse the id of an event from the last segments of a key.
+    fn parse(key: &str) -> Option<Self> {
+        let mut segments = key.rsplitn(3, '/');
+        let index = u64::parse(segments.next()?.to_owned()).ok()?;
+        let height = BlockHeight(u64::parse(segments.next()?.to_owned()).ok()?);
+        Some(Self { height, index })
+    }
+
+    /// Get all the keys of the event.
+    fn keys(&self, event: &Event) -> Vec<String> {
+        [self.event_key(), self.type_index_key(event.kind())]
+            .into_iter()
+            .chain(
+                event
+                    .attributes()
+                    .iter()
+                    .map(|(key, value)| self.attribute_index_key(key, value)),
+            )
+            .collect()
+    }
+}
+
+/// Get the key prefix of the index of an attribute.
+fn attribute_index_prefix(key: &str, value: &str) -> String {
+    let value_hash = Hash::sha256(value.as_bytes());
+    format!("{ATTRIBUTE_INDEX_PREFIX}/{key}/{value_hash}")
+}
+
+/// The default number of last blocks whose events are kept in the log.
+pub const DEFAULT_RETENTION_BLOCKS: u64 = 100_000;
+
+/// The max number of events returned by a query.
+pub const MAX_QUERY_LIMIT: u64 = 1_000;
+
+/// The number of keys of the log scanned by a query, past which the query
+/// stops at the next block.
+pub const MAX_QUERY_SCANNED_KEYS: u64 = 10_000;
+
 /// Parameters to configure the pruning of the event log.
 #[derive(Debug, Copy, Clone)]
 pub struct Params {
-    /// Soft limit on the maximum number of events the event log can hold,
-    /// for a given event kind.
-    ///
-    /// If the number of events of a given type in the log exceeds this value,
-    /// events of that kind in the log will be pruned.
-    pub max_log_events_per_kind: usize,
+    /// The number of last blocks whose events are kept in the log.
+    pub retention_blocks: u64,
 }
 
 impl Default for Params {
     fn default() -> Self {
-        // TODO(namada#3237): tune the default params
         Self {
-            max_log_events_per_kind: 50000,
+            retention_blocks: DEFAULT_RETENTION_BLOCKS,
         }
     }
 }
 
-/// Represents a log of [`Event`] instances emitted by
-/// `FinalizeBlock` calls, in the ledger.
-#[derive(Debug)]
-pub struct EventLog {
-    cap: usize,
-    map: StringPatriciaMap<CircularQueue<Event>>,
+impl Params {
+    /// Get the oldest height whose events must be kept at the given last
+    /// block height, if any events may be pruned.
+    pub fn oldest_height_to_keep(
+        &self,
+        last_height: BlockHeight,
+    ) -> Option<BlockHeight> {
+        last_height
+            .0
+            .checked_sub(self.retention_blocks)
+            .map(BlockHeight)
+    }
 }
 
-impl Default for EventLog {
-    fn default() -> Self {
-        Self::new(Default::default())
-    }
+/// A query over the events in the log.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct EventQuery {
+    matcher: dumb_queries::QueryMatcher,
+    from_height: Option<BlockHeight>,
+    to_height: Option<BlockHeight>,
+    offset: u64,
+    limit: Option<u64>,
 }
 
-impl EmitEvents for EventLog {
-    #[inline]
-    fn emit<E>(&mut self, event: E)
-    where
-        E: Into<Event>,
-    {
-        self.log_events(core::iter::once(event.into()));
+impl EventQuery {
+    /// Create a new [`EventQuery`] over all the events in the log matching
+    /// the given [matcher](dumb_queries::QueryMatcher).
+    pub fn new(matcher: dumb_queries::QueryMatcher) -> Self {
+        Self {
+            matcher,
+            from_height: None,
+            to_height: None,
+            offset: 0,
+            limit: None,
+        }
+    }
+
+    /// Only match the events emitted at or above the given height.
+    pub fn from_height(mut self, height: BlockHeight) -> Self {
+        self.from_height = Some(height);
+        self
     }
 
-    /// Emit a batch of [events](Event).
-    #[inline]
-    fn emit_many<B, E>(&mut self, event_batch: B)
-    where
-        B: IntoIterator<Item = E>,
-        E: Into<Event>,
-    {
-        self.log_events(event_batch.into_iter().map(Into::into));
+    /// Only match the events emitted at or below the given height.
+    pub fn to_height(mut self, height: BlockHeight) -> Self {
+        self.to_height = Some(height);
+        self
     }
-}
 
-impl EventLog {
-    /// Retrieve an event queue of a given type.
-    fn get_queue_of_type(
-        &mut self,
-        event_type: &EventType,
-    ) -> &mut CircularQueue<Event> {
-        let event_type = event_type.to_string();
-
-        if namada_core::hints::unlikely(!self.map.contains_key(&event_type)) {
-            // some monkey business
-            self.map
-                .insert(&event_type, CircularQueue::with_capacity(self.cap));
-        }
+    /// Skip the given number of the first matching events.
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
 
-        self.map.get_mut(&event_type).unwrap()
+    /// Return at most the given number of matching events. The limit is
+    /// capped at [`MAX_QUERY_LIMIT`].
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
     }
 
-    /// Return a new event log.
-    pub fn new(params: Params) -> Self {
-        Self {
-            cap: params.max_log_events_per_kind,
-            map: StringPatriciaMap::new(),
+    /// Get the max number of events to return.
+    fn capped_limit(&self) -> u64 {
+        self.limit
+            .map_or(MAX_QUERY_LIMIT, |limit| limit.min(MAX_QUERY_LIMIT))
+    }
+
+    /// Get the prefix of the keys that are read to find the events matching
+    /// the query, in the order of the events, and whether the keys are of an
+    /// index of the events rather than of the events themselves.
+    fn index_prefix(&self) -> (String, bool) {
+        if let Some((key, value)) = self.matcher.attributes().iter().next() {
+            return (format!("{}/", attribute_index_prefix(key, value)), true);
+        }
+        let event_type = self.matcher.event_type();
+        match self.matcher.match_type() {
+            dumb_queries::MatchType::Exact => {
+                (format!("{TYPE_INDEX_PREFIX}/{event_type}/"), true)
+            }
+            dumb_queries::MatchType::Prefix => {
+                (format!("{EVENT_PREFIX}/"), false)
+            }
         }
     }
+}
+
+/// A page of the events matching an [`EventQuery`].
+#[derive(Debug, Clone, Default, BorshSerialize, BorshDeserialize)]
+pub struct EventsPage {
+    /// The matching events with the height of their block
+    pub events: Vec<(BlockHeight, Event)>,
+    /// The height of the first block that wasn't scanned, if the query
+    /// stopped after [`MAX_QUERY_SCANNED_KEYS`] before reaching its limit.
+    /// All the matching events below this height were returned.
+    pub next_height: Option<BlockHeight>,
+    /// The oldest height whose events are retained in the log, if any
+    /// events were pruned. The events below this height are not returned.
+    pub oldest_height: Option<BlockHeight>,
+}
 
-    /// Log a new batch of events into the event log.
-    pub fn log_events<E>(&mut self, events: E)
-    where
-        E: IntoIterator<Item = Event>,
-    {
-        let mut num_entries = 0;
-        for event in events.into_iter() {
-            self.get_queue_of_type(event.kind()).push(event);
-            num_entries += 1;
+/// Batch write the events emitted by the block at the given height to the
+/// log.
+pub fn write_events<D>(
+    db: &D,
+    batch: &mut D::WriteBatch,
+    height: BlockHeight,
+    events: &[Event],
+) -> namada_storage::Result<()>
+where
+    D: DB,
+{
+    let mut entries = vec![];
+    for (index, event) in (0_u64..).zip(events) {
+        let id = EventId { height, index };
+        let mut keys = id.keys(event).into_iter();
+        if let Some(event_key) = keys.next() {
+            entries.push((event_key, event.serialize_to_vec()));
         }
-        tracing::debug!(num_entries, "Added new entries to the event log");
+        entries.extend(keys.map(|key| (key, vec![])));
     }
+    db.batch_write_events_index(batch, entries)
+        .into_storage_result()?;
+    tracing::debug!(
+        num_entries = events.len(),
+        %height,
+        "Added new entries to the event log"
+    );
+    Ok(())
+}
+
+/// Batch delete the events emitted by the block at the given height from the
+/// log.
+pub fn delete_events<D>(
+    db: &D,
+    batch: &mut D::WriteBatch,
+    height: BlockHeight,
+) -> namada_storage::Result<()>
+where
+    D: DB + for<'iter> DBIter<'iter>,
+{
+    let prefix = format!("{EVENT_PREFIX}/{}/", height.raw());
+    let keys = events_keys(db, &prefix, height.next_height())?;
+    db.batch_delete_events_index(batch, keys)
+        .into_storage_result()
+}
 
-    /// Returns a new iterator over this [`EventLog`].
-    #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = &Event> {
-        self.map.values().flat_map(|queue| queue.iter())
+/// Prune the events emitted below the given height from the log, and record
+/// the height as the oldest one whose events are retained.
+pub fn prune_events<D>(
+    db: &D,
+    oldest_height: BlockHeight,
+) -> namada_storage::Result<()>
+where
+    D: DB + for<'iter> DBIter<'iter>,
+{
+    if read_oldest_height(db)?.is_some_and(|oldest| oldest_height <= oldest) {
+        return Ok(());
     }
+    let keys = events_keys(db, &format!("{EVENT_PREFIX}/"), oldest_height)?;
+    let mut batch = D::batch();
+    db.batch_delete_events_index(&mut batch, keys)
+        .into_storage_result()?;
+    db.batch_write_events_index(
+        &mut batch,
+        [(
+            OLDEST_HEIGHT_KEY.to_owned(),
+            oldest_height.serialize_to_vec(),
+        )],
+    )
+    .into_storage_result()?;
+    db.exec_batch(batch).into_storage_result()
+}
 
-    /// Returns an adapter that turns this [`EventLog`] into
-    /// a filtering iterator over the events contained within.
-    #[inline]
-    pub fn with_matcher(
-        &self,
-        matcher: dumb_queries::QueryMatcher,
-    ) -> WithMatcher<'_> {
-        WithMatcher { matcher, log: self }
+/// Read the oldest height whose events are retained in the log, if any
+/// events were pruned.
+pub fn read_oldest_height<D>(
+    db: &D,
+) -> namada_storage::Result<Option<BlockHeight>>
+where
+    D: DB,
+{
+    db.read_events_index(OLDEST_HEIGHT_KEY)
+        .into_storage_result()?
+        .map(|value| BlockHeight::try_from_slice(&value).into_storage_result())
+        .transpose()
+}
+
+/// Get all the keys of the events under the given prefix of the log, that
+/// were emitted below the given height.
+fn events_keys<D>(
+    db: &D,
+    prefix: &str,
+    below_height: BlockHeight,
+) -> namada_storage::Result<Vec<String>>
+where
+    D: DB + for<'iter> DBIter<'iter>,
+{
+    let mut keys = vec![];
+    let iter = db.iter_events_index(prefix, None).into_storage_result()?;
+    for (key, value, _gas) in iter {
+        let id = EventId::parse(&key).ok_or_else(|| {
+            namada_storage::Error::new_alloc(format!(
+                "Invalid key in the event log: {key}"
+            ))
+        })?;
+        if id.height >= below_height {
+            break;
+        }
+        let event = Event::try_from_slice(&value).into_storage_result()?;
+        keys.extend(id.keys(&event));
     }
+    Ok(keys)
 }
 
-/// Iterator over an [`EventLog`] taking a [matcher](dumb_queries::QueryMatcher)
-/// in order to filter events within.
-pub struct WithMatcher<'log> {
-    log: &'log EventLog,
-    matcher: dumb_queries::QueryMatcher,
+/// Read the event with the given id from the log.
+fn read_event<D>(db: &D, id: EventId) -> namada_storage::Result<Option<Event>>
+where
+    D: DB,
+{
+    db.read_events_index(&id.event_key())
+        .into_storage_result()?
+        .map(|value| Event::try_from_slice(&value).into_storage_result())
+        .transpose()
+}
+
+/// Query the events in the log, ordered by the height of their block and
+/// their index in the block. Returns the matching events with their height,
+/// at most [`MAX_QUERY_LIMIT`] of them.
+pub fn query_events<D>(
+    db: &D,
+    query: &EventQuery,
+) -> namada_storage::Result<Vec<(BlockHeight, Event)>>
+where
+    D: DB + for<'iter> DBIter<'iter>,
+{
+    query_events_page(db, query).map(|page| page.events)
 }
 
-impl<'log> WithMatcher<'log> {
-    /// Iterates and filters events in the associated [`EventLog`]
-    /// using the provided [event matcher](dumb_queries::QueryMatcher).
-    pub fn iter<'this: 'log>(
-        &'this self,
-    ) -> impl Iterator<Item = &'log Event> + 'log {
-        self.log
-            .map
-            .iter_prefix(self.matcher.event_type())
-            .flat_map(|(_, queue)| {
-                queue.iter().filter(|&event| self.matcher.matches(event))
-            })
+/// Query a page of the events in the log, ordered by the height of their
+/// block and their index in the block. The page holds at most
+/// [`MAX_QUERY_LIMIT`] events, and the query stops at the first block
+/// boundary after scanning [`MAX_QUERY_SCANNED_KEYS`] keys of the log.
+pub fn query_events_page<D>(
+    db: &D,
+    query: &EventQuery,
+) -> namada_storage::Result<EventsPage>
+where
+    D: DB + for<'iter> DBIter<'iter>,
+{
+    let limit = query.capped_limit();
+    let mut page = EventsPage {
+        oldest_height: read_oldest_height(db)?,
+        ..Default::default()
+    };
+    if limit == 0 {
+        return Ok(page);
     }
+    let (prefix, is_index) = query.index_prefix();
+    let start = query
+        .from_height
+        .map(|from| format!("{prefix}{}", from.raw()));
+    let iter = db
+        .iter_events_index(&prefix, start.as_deref())
+        .into_storage_result()?;
+    let mut to_skip = query.offset;
+    let mut scanned_keys = 0_u64;
+    let mut last_height = None;
+    for (key, value, _gas) in iter {
+        let Some(id) = EventId::parse(&key) else {
+            continue;
+        };
+        if query.to_height.is_some_and(|to| to < id.height) {
+            break;
+        }
+        if scanned_keys >= MAX_QUERY_SCANNED_KEYS
+            && last_height.is_some_and(|last| last < id.height)
+        {
+            page.next_height = Some(id.height);
+            break;
+        }
+        scanned_keys += 1;
+        last_height = Some(id.height);
+        let event = if is_index {
+            let Some(event) = read_event(db, id)? else {
+                continue;
+            };
+            event
+        } else {
+            Event::try_from_slice(&value).into_storage_result()?
+        };
+        if !query.matcher.matches(&event) {
+            continue;
+        }
+        if to_skip > 0 {
+            to_skip -= 1;
+            continue;
+        }
+        page.events.push((id.height, event));
+        if page.events.len() as u64 >= limit {
+            break;
+        }
+    }
+    Ok(page)
 }
 
 #[cfg(test)]
 mod event_log_tests {
-    use namada_core::hash::Hash;
     use namada_core::keccak::KeccakHash;
     use namada_ethereum_bridge::event::types::BRIDGE_POOL_RELAYED;
     use namada_ethereum_bridge::event::BridgePoolTxHash;
+    use namada_storage::mockdb::MockDB;
 
     use super::*;
     use crate::events::extend::{ComposeEvent, TxHash};
@@ -159,16 +440,18 @@ mod event_log_tests {
     /// An applied tx hash query.
     macro_rules! applied {
         ($hash:expr) => {
-            dumb_queries::QueryMatcher::applied(Hash::try_from($hash).unwrap())
+            EventQuery::new(dumb_queries::QueryMatcher::applied(
+                Hash::try_from($hash).unwrap(),
+            ))
         };
     }
 
     /// An applied tx hash query.
     macro_rules! bridge_pool_relayed {
         ($hash:expr) => {
-            dumb_queries::QueryMatcher::bridge_pool_relayed(
+            EventQuery::new(dumb_queries::QueryMatcher::bridge_pool_relayed(
                 &KeccakHash::try_from($hash).unwrap(),
-            )
+            ))
         };
     }
 
@@ -190,92 +473,216 @@ mod event_log_tests {
         ]
     }
 
+    /// Write the events of the block at the given height to the log.
+    fn write_block_events(db: &MockDB, height: u64, events: &[Event]) {
+        let mut batch = MockDB::batch();
+        write_events(db, &mut batch, BlockHeight(height), events).unwrap();
+        db.exec_batch(batch).unwrap();
+    }
+
+    /// Return a query over all the events in the log.
+    fn all_events() -> EventQuery {
+        EventQuery::new(dumb_queries::QueryMatcher::with_prefix(
+            EventType::new(""),
+        ))
+    }
+
     /// Test adding a couple of events to the event log, and
     /// reading those events back.
     #[test]
     fn test_log_add() {
-        const NUM_HEIGHTS: usize = 4;
+        const NUM_HEIGHTS: u64 = 4;
 
-        let mut log = EventLog::new(Params::default());
+        let db = MockDB::default();
 
         // add new events to the log
         let events = mock_tx_events(HASH);
 
-        for _ in 0..NUM_HEIGHTS {
-            log.log_events(events.clone());
+        for height in 1..=NUM_HEIGHTS {
+            write_block_events(&db, height, &events);
         }
 
         // inspect log
-        assert_eq!(log.iter().count(), NUM_HEIGHTS * events.len());
+        let events_in_log = query_events(&db, &all_events()).unwrap();
+        assert_eq!(events_in_log.len() as u64, NUM_HEIGHTS * 2);
+        for (i, (height, event)) in events_in_log.into_iter().enumerate() {
+            assert_eq!(height, BlockHeight(i as u64 / 2 + 1));
+            assert_eq!(event, events[i % 2]);
+        }
 
-        let events_in_log: Vec<_> = log
-            .with_matcher(bridge_pool_relayed!(HASH))
-            .iter()
-            .cloned()
-            .collect();
+        let events_in_log =
+            query_events(&db, &bridge_pool_relayed!(HASH)).unwrap();
 
-        assert_eq!(events_in_log.len(), NUM_HEIGHTS);
+        assert_eq!(events_in_log.len() as u64, NUM_HEIGHTS);
 
-        for event in events_in_log {
+        for (_, event) in events_in_log {
             assert_eq!(events[0], event);
         }
 
-        let events_in_log: Vec<_> =
-            log.with_matcher(applied!(HASH)).iter().cloned().collect();
+        let events_in_log = query_events(&db, &applied!(HASH)).unwrap();
 
-        assert_eq!(events_in_log.len(), NUM_HEIGHTS);
+        assert_eq!(events_in_log.len() as u64, NUM_HEIGHTS);
 
-        for event in events_in_log {
+        for (_, event) in events_in_log {
             assert_eq!(events[1], event);
         }
+
+        // the events of other txs don't match
+        let other_hash = format!("{:064X}", 1);
+        assert!(
+            query_events(&db, &applied!(other_hash.as_str()))
+                .unwrap()
+                .is_empty()
+        );
     }
 
-    /// Test pruning old events from the log.
+    /// Test querying a range of heights with pagination.
     #[test]
-    fn test_log_prune() {
-        const LOG_CAP: usize = 4;
+    fn test_log_query_pages() {
+        let db = MockDB::default();
 
-        if LOG_CAP == 0 {
-            panic!();
+        for height in 1..=40 {
+            let hash = format!("{height:064X}");
+            write_block_events(&db, height, &mock_tx_events(&hash));
         }
 
-        let mut log = EventLog::new(Params {
-            max_log_events_per_kind: LOG_CAP,
-        });
-
-        // completely fill the log with events
-        for i in 0..LOG_CAP {
-            log.emit(mock_event(APPLIED_TX, format!("{i:064X}")));
-        }
+        let query = EventQuery::new(
+            dumb_queries::QueryMatcher::with_event_type(APPLIED_TX),
+        )
+        .from_height(BlockHeight(3))
+        .to_height(BlockHeight(8));
+        let heights = |query: &EventQuery| -> Vec<u64> {
+            query_events(&db, query)
+                .unwrap()
+                .into_iter()
+                .map(|(height, _)| height.0)
+                .collect()
+        };
+        assert_eq!(heights(&query), vec![3, 4, 5, 6, 7, 8]);
+        assert_eq!(heights(&query.clone().limit(4)), vec![3, 4, 5, 6]);
+        assert_eq!(heights(&query.clone().offset(4).limit(4)), vec![7, 8]);
+
+        // the prefix matches are ordered by height too
+        let query = all_events().from_height(BlockHeight(39));
+        assert_eq!(heights(&query), vec![39, 39, 40, 40]);
+
+        // the limit is capped
+        assert_eq!(heights(&all_events().limit(3)), vec![1, 1, 2]);
+        assert_eq!(all_events().capped_limit(), MAX_QUERY_LIMIT);
+        assert_eq!(
+            all_events().limit(MAX_QUERY_LIMIT + 1).capped_limit(),
+            MAX_QUERY_LIMIT
+        );
+    }
 
-        // inspect log - it should be full
-        let events_in_log: Vec<_> = log.iter().cloned().collect();
+    /// Test that a query stops at a block boundary after scanning too many
+    /// keys, and that it can be resumed from the returned height.
+    #[test]
+    fn test_log_query_scanned_keys_cap() {
+        const EVENTS_PER_BLOCK: u64 = 100;
+        const NUM_HEIGHTS: u64 = MAX_QUERY_SCANNED_KEYS / EVENTS_PER_BLOCK + 10;
 
-        assert_eq!(events_in_log.len(), LOG_CAP);
+        let db = MockDB::default();
 
-        // iter in reverse since the ringbuf gives us items
-        // in the order of the last insertion
-        for (i, event) in events_in_log.into_iter().rev().enumerate() {
-            assert_eq!(mock_event(APPLIED_TX, format!("{i:064X}")), event);
+        let filler = Event::new(EventType::new("filler"), EventLevel::Block);
+        for height in 1..NUM_HEIGHTS {
+            let events = vec![filler.clone(); EVENTS_PER_BLOCK as usize];
+            write_block_events(&db, height, &events);
         }
+        let other = Event::new(EventType::new("other"), EventLevel::Block);
+        write_block_events(&db, NUM_HEIGHTS, &[other.clone()]);
+
+        let query = EventQuery::new(dumb_queries::QueryMatcher::with_prefix(
+            EventType::new("other"),
+        ));
+        let page = query_events_page(&db, &query).unwrap();
+        let next_height =
+            BlockHeight(MAX_QUERY_SCANNED_KEYS / EVENTS_PER_BLOCK + 1);
+        assert!(page.events.is_empty());
+        assert_eq!(page.next_height, Some(next_height));
+
+        let page =
+            query_events_page(&db, &query.from_height(next_height)).unwrap();
+        assert_eq!(page.events, vec![(BlockHeight(NUM_HEIGHTS), other)]);
+        assert_eq!(page.next_height, None);
+    }
 
-        // add a new APPLIED event to the log
-        log.emit(mock_event(APPLIED_TX, HASH));
+    /// Test deleting the events of a block from the log.
+    #[test]
+    fn test_log_delete() {
+        let db = MockDB::default();
 
-        // inspect log - oldest event should have been pruned
-        let events_in_log: Vec<_> = log.iter().cloned().collect();
+        for height in 1..=3 {
+            let hash = format!("{height:064X}");
+            write_block_events(&db, height, &mock_tx_events(&hash));
+        }
 
-        assert_eq!(events_in_log.len(), LOG_CAP);
-        assert_eq!(events_in_log[0], mock_event(APPLIED_TX, HASH));
+        let mut batch = MockDB::batch();
+        delete_events(&db, &mut batch, BlockHeight(2)).unwrap();
+        db.exec_batch(batch).unwrap();
 
-        for (i, event) in events_in_log
+        let heights: Vec<_> = query_events(&db, &all_events())
+            .unwrap()
             .into_iter()
-            .rev()
-            .enumerate()
-            .take(LOG_CAP - 1)
-        {
-            let i = i + 1; // last elem was pruned
-            assert_eq!(mock_event(APPLIED_TX, format!("{i:064X}")), event);
+            .map(|(height, _)| height.0)
+            .collect();
+        assert_eq!(heights, vec![1, 1, 3, 3]);
+        let hash = format!("{:064X}", 2);
+        assert!(
+            query_events(&db, &applied!(hash.as_str()))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    /// Test pruning old events from the log.
+    #[test]
+    fn test_log_prune() {
+        const NUM_HEIGHTS: u64 = 4;
+
+        let db = MockDB::default();
+
+        for height in 1..=NUM_HEIGHTS {
+            let hash = format!("{height:064X}");
+            write_block_events(&db, height, &mock_tx_events(&hash));
         }
+
+        assert_eq!(read_oldest_height(&db).unwrap(), None);
+        prune_events(&db, BlockHeight(3)).unwrap();
+        assert_eq!(read_oldest_height(&db).unwrap(), Some(BlockHeight(3)));
+
+        // the oldest height doesn't go back
+        prune_events(&db, BlockHeight(2)).unwrap();
+        assert_eq!(
+            query_events_page(&db, &all_events()).unwrap().oldest_height,
+            Some(BlockHeight(3))
+        );
+
+        // inspect log - the events below the height should have been pruned
+        let events_in_log = query_events(&db, &all_events()).unwrap();
+        assert_eq!(events_in_log.len(), 4);
+        assert!(
+            events_in_log
+                .iter()
+                .all(|(height, _)| *height >= BlockHeight(3))
+        );
+
+        // the indices of the pruned events should have been pruned as well
+        let hash = format!("{:064X}", 1);
+        assert!(
+            query_events(&db, &applied!(hash.as_str()))
+                .unwrap()
+                .is_empty()
+        );
+        // only the keys of the retained events and the oldest height are left
+        assert_eq!(
+            db.iter_events_index("", None).unwrap().count(),
+            query_events(&db, &all_events())
+                .unwrap()
+                .iter()
+                .map(|(_, event)| 2 + event.attributes().len())
+                .sum::<usize>()
+                + 1
+        );
     }
 }
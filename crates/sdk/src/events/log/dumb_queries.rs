@@ -1,6 +1,8 @@
 //! Silly simple event matcher.
 
-use namada_core::collections::HashMap;
+use std::collections::BTreeMap;
+
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::hash::Hash;
 use namada_core::keccak::KeccakHash;
 use namada_core::storage::BlockHeight;
@@ -27,16 +29,16 @@ use crate::tx::event::types::APPLIED as APPLIED_TX;
 
 /// A [`QueryMatcher`] verifies if a Namada event matches a
 /// given Tendermint query.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct QueryMatcher {
     event_type_match: MatchType,
     event_type: EventType,
-    attributes: HashMap<String, String>,
+    attributes: BTreeMap<String, String>,
 }
 
 /// Determine which kind of match will be performed over a series of event
 /// types.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub enum MatchType {
     /// Exact match.
     Exact,
@@ -67,6 +69,12 @@ impl QueryMatcher {
         &self.event_type_match
     }
 
+    /// Returns the attributes that this [`QueryMatcher`]
+    /// attempts to match.
+    pub fn attributes(&self) -> &BTreeMap<String, String> {
+        &self.attributes
+    }
+
     /// Create a new [`QueryMatcher`] matching event types
     /// with the given `prefix`.
     pub fn of_event_type<E: EventToEmit>() -> Self {
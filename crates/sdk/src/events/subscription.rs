@@ -0,0 +1,415 @@
+//! Subscriptions to the events emitted by the ledger.
+//!
+//! A subscription streams the events matching a
+//! [`QueryMatcher`](dumb_queries::QueryMatcher) from the event log of a node,
+//! as the blocks get committed. The subscription is driven by the
+//! notifications of the new blocks of the node, such as the heights of the
+//! blocks of a CometBFT `NewBlock` websocket subscription, so the node is
+//! never polled. On every new block, the events from the position of the
+//! subscription up to that block are read from the event log in pages.
+//!
+//! The position of a subscription is tracked by an [`EventCursor`], which can
+//! be stored to resume the subscription later on, as long as the node hasn't
+//! pruned the events of the cursor's block from its log. A resumed
+//! subscription catches up with the events it missed on the first new block,
+//! and its stream fails if the node already pruned some of them.
+
+use std::collections::VecDeque;
+
+use futures::stream::{self, Stream, StreamExt};
+use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use namada_core::storage::BlockHeight;
+use serde::{Deserialize, Serialize};
+
+use super::log::{dumb_queries, EventQuery, MAX_QUERY_LIMIT};
+use super::Event;
+use crate::error::Error;
+use crate::queries::Client;
+use crate::rpc;
+
+/// The position of a subscription in the event log.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshSchema,
+)]
+pub struct EventCursor {
+    /// The height of the block of the next event
+    pub height: BlockHeight,
+    /// The number of the matching events of the block that were already
+    /// streamed
+    pub offset: u64,
+}
+
+impl EventCursor {
+    /// Create a cursor at the first event of the block at the given height.
+    pub fn at_height(height: BlockHeight) -> Self {
+        Self { height, offset: 0 }
+    }
+
+    /// Move the cursor past an event of the block at the given height.
+    fn advance(&mut self, height: BlockHeight) -> Result<(), Error> {
+        if self.height == height {
+            self.offset = self.offset.checked_add(1).ok_or_else(|| {
+                Error::Other(format!(
+                    "The offset of the event cursor at height {height} \
+                     overflowed"
+                ))
+            })?;
+        } else {
+            *self = Self { height, offset: 1 };
+        }
+        Ok(())
+    }
+}
+
+/// An event streamed by an [`EventSubscription`].
+#[derive(Debug, Clone)]
+pub struct SubscribedEvent {
+    /// The height of the block that emitted the event
+    pub height: BlockHeight,
+    /// The event
+    pub event: Event,
+    /// The cursor to resume the subscription right after this event
+    pub cursor: EventCursor,
+}
+
+/// A subscription to the events matching a
+/// [`QueryMatcher`](dumb_queries::QueryMatcher).
+#[derive(Debug, Clone)]
+pub struct EventSubscription {
+    matcher: dumb_queries::QueryMatcher,
+    cursor: Option<EventCursor>,
+    last_block: Option<BlockHeight>,
+    oldest_height: Option<BlockHeight>,
+    page_size: u64,
+}
+
+impl EventSubscription {
+    /// The default max number of events read from the node in one query.
+    pub const DEFAULT_PAGE_SIZE: u64 = 100;
+
+    /// Create a new subscription to the events matching the given
+    /// [matcher](dumb_queries::QueryMatcher). Unless a cursor is given, the
+    /// subscription starts with the events of the first notified block.
+    pub fn new(matcher: dumb_queries::QueryMatcher) -> Self {
+        Self {
+            matcher,
+            cursor: None,
+            last_block: None,
+            oldest_height: None,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// Start the subscription from the given cursor.
+    pub fn from_cursor(mut self, cursor: EventCursor) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Set the max number of events read from the node in one query. The
+    /// page size is capped at [`MAX_QUERY_LIMIT`].
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = page_size.clamp(1, MAX_QUERY_LIMIT);
+        self
+    }
+
+    /// Get the cursor of the next event of the subscription, if it has been
+    /// started.
+    pub fn cursor(&self) -> Option<EventCursor> {
+        self.cursor
+    }
+
+    /// Stream the events of the subscription from the given client, as the
+    /// heights of the new committed blocks are notified by `new_blocks`. The
+    /// stream ends with the notifications. Failed queries are yielded as
+    /// errors, and they are retried when the stream is polled again. If the
+    /// node pruned the events at the cursor of the subscription, the stream
+    /// ends with an error.
+    pub fn stream<'a, C, B>(
+        self,
+        client: &'a C,
+        new_blocks: B,
+    ) -> impl Stream<Item = Result<SubscribedEvent, Error>> + 'a
+    where
+        C: Client + Sync,
+        B: Stream<Item = BlockHeight> + Unpin + 'a,
+    {
+        stream::unfold(
+            (Some(self), new_blocks, VecDeque::new()),
+            move |(subscription, mut new_blocks, mut pending)| async move {
+                let mut subscription = subscription?;
+                loop {
+                    if let Some(event) = pending.pop_front() {
+                        return Some((
+                            Ok(event),
+                            (Some(subscription), new_blocks, pending),
+                        ));
+                    }
+                    let Some((cursor, last_height)) =
+                        subscription.blocks_to_catch_up()
+                    else {
+                        let height = new_blocks.next().await?;
+                        subscription.notify_block(height);
+                        continue;
+                    };
+                    match subscription
+                        .next_page(client, cursor, last_height)
+                        .await
+                    {
+                        Ok(events) => pending.extend(events),
+                        Err(err) => {
+                            // the pruned events can't be read anymore
+                            let subscription = (!subscription.is_pruned())
+                                .then_some(subscription);
+                            return Some((
+                                Err(err),
+                                (subscription, new_blocks, pending),
+                            ));
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Record the notification of a new block at the given height.
+    fn notify_block(&mut self, height: BlockHeight) {
+        self.cursor
+            .get_or_insert_with(|| EventCursor::at_height(height));
+        self.last_block = std::cmp::max(self.last_block, Some(height));
+    }
+
+    /// Get the cursor of the subscription and the height of the last notified
+    /// block, if the subscription hasn't caught up with the block yet.
+    fn blocks_to_catch_up(&self) -> Option<(EventCursor, BlockHeight)> {
+        let cursor = self.cursor?;
+        let last_height = self.last_block?;
+        (cursor.height <= last_height).then_some((cursor, last_height))
+    }
+
+    /// Check if the node pruned the events at the cursor of the subscription
+    /// from its log.
+    fn is_pruned(&self) -> bool {
+        self.cursor.zip(self.oldest_height).is_some_and(
+            |(cursor, oldest_height)| cursor.height < oldest_height,
+        )
+    }
+
+    /// Read the next page of events from the given cursor up to the block at
+    /// the given height and move the cursor past them. If the node stopped
+    /// the query at some block, the cursor is moved to that block. Once all
+    /// the events up to the last block have been read, the cursor is moved
+    /// to the next block. Fails if the node pruned the events at the cursor.
+    async fn next_page<C>(
+        &mut self,
+        client: &C,
+        mut cursor: EventCursor,
+        last_height: BlockHeight,
+    ) -> Result<Vec<SubscribedEvent>, Error>
+    where
+        C: Client + Sync,
+    {
+        let query = EventQuery::new(self.matcher.clone())
+            .from_height(cursor.height)
+            .to_height(last_height)
+            .offset(cursor.offset)
+            .limit(self.page_size);
+        let page = rpc::query_events(client, &query).await?;
+        self.oldest_height = page.oldest_height;
+        if let Some(oldest_height) = self.oldest_height {
+            if cursor.height < oldest_height {
+                return Err(Error::Other(format!(
+                    "The events of the subscription from height {} were \
+                     pruned from the event log of the node, which only \
+                     retains the events from height {oldest_height}",
+                    cursor.height
+                )));
+            }
+        }
+        let is_last_page = (page.events.len() as u64) < self.page_size;
+        let mut subscribed_events = Vec::with_capacity(page.events.len());
+        for (height, event) in page.events {
+            cursor.advance(height)?;
+            subscribed_events.push(SubscribedEvent {
+                height,
+                event,
+                cursor,
+            });
+        }
+        if let Some(next_height) = page.next_height {
+            // all the events below the block have been read
+            cursor = EventCursor::at_height(next_height);
+        } else if is_last_page {
+            // all the events up to the last block have been read
+            cursor = EventCursor::at_height(last_height.next_height());
+        }
+        self.cursor = Some(cursor);
+        Ok(subscribed_events)
+    }
+}
+
+#[cfg(test)]
+mod test_subscription {
+    use namada_core::hash::Hash;
+    use namada_storage::mockdb::MockDBWriteBatch;
+
+    use super::*;
+    use crate::events::extend::{ComposeEvent, TxHash};
+    use crate::events::{log, EventLevel, EventType};
+    use crate::queries::testing::TestClient;
+    use crate::queries::RPC;
+    use crate::tx::event::types::APPLIED as APPLIED_TX;
+
+    /// Return a mock applied tx event of a tx with the given hash.
+    fn applied_event(hash: u64) -> Event {
+        let hash = Hash::try_from(format!("{hash:064X}").as_str()).unwrap();
+        Event::new(APPLIED_TX, EventLevel::Tx)
+            .with(TxHash(hash))
+            .into()
+    }
+
+    /// Return the notifications of the new blocks at the given heights.
+    fn new_blocks(
+        heights: impl IntoIterator<Item = u64>,
+    ) -> impl Stream<Item = BlockHeight> + Unpin {
+        stream::iter(heights.into_iter().map(BlockHeight))
+    }
+
+    /// Test streaming the events of the notified blocks in pages, and
+    /// resuming a subscription from a cursor.
+    #[tokio::test]
+    async fn test_subscription_stream() {
+        let client = TestClient::new(RPC);
+        for height in 1..=40 {
+            let events = [
+                applied_event(2 * height),
+                Event::new(EventType::new("other"), EventLevel::Block),
+                applied_event(2 * height + 1),
+            ];
+            log::write_events(
+                client.state.db(),
+                &mut MockDBWriteBatch,
+                BlockHeight(height),
+                &events,
+            )
+            .unwrap();
+        }
+
+        let matcher = dumb_queries::QueryMatcher::with_event_type(APPLIED_TX);
+        let stream_heights = |subscription: EventSubscription, blocks| {
+            let client = &client;
+            async move {
+                subscription
+                    .stream(client, new_blocks(blocks))
+                    .map(|event| event.unwrap())
+                    .collect::<Vec<_>>()
+                    .await
+            }
+        };
+
+        // a new subscription starts at the first notified block
+        let events = stream_heights(
+            EventSubscription::new(matcher.clone()).page_size(3),
+            vec![38, 40],
+        )
+        .await;
+        let heights: Vec<_> =
+            events.iter().map(|event| event.height.0).collect();
+        assert_eq!(heights, vec![38, 38, 39, 39, 40, 40]);
+
+        // a resumed subscription catches up from its cursor
+        let events = stream_heights(
+            EventSubscription::new(matcher.clone())
+                .from_cursor(EventCursor::at_height(BlockHeight(34)))
+                .page_size(3),
+            vec![35],
+        )
+        .await;
+        let heights: Vec<_> =
+            events.iter().map(|event| event.height.0).collect();
+        assert_eq!(heights, vec![34, 34, 35, 35]);
+        assert_eq!(events[3].event, applied_event(71));
+        assert_eq!(
+            events[2].cursor,
+            EventCursor {
+                height: BlockHeight(35),
+                offset: 1,
+            }
+        );
+
+        // resume after the third event
+        let events = stream_heights(
+            EventSubscription::new(matcher).from_cursor(events[2].cursor),
+            vec![35],
+        )
+        .await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].height, BlockHeight(35));
+        assert_eq!(events[0].event, applied_event(71));
+    }
+
+    /// Test that resuming a subscription from a cursor whose events were
+    /// pruned by the node fails the stream.
+    #[tokio::test]
+    async fn test_subscription_pruned_cursor() {
+        let client = TestClient::new(RPC);
+        for height in 1..=10 {
+            log::write_events(
+                client.state.db(),
+                &mut MockDBWriteBatch,
+                BlockHeight(height),
+                &[applied_event(height)],
+            )
+            .unwrap();
+        }
+        log::prune_events(client.state.db(), BlockHeight(5)).unwrap();
+
+        let matcher = dumb_queries::QueryMatcher::with_event_type(APPLIED_TX);
+        let results: Vec<_> = EventSubscription::new(matcher.clone())
+            .from_cursor(EventCursor::at_height(BlockHeight(4)))
+            .stream(&client, new_blocks(vec![10]))
+            .collect()
+            .await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+
+        // the retained events can still be streamed
+        let heights: Vec<_> = EventSubscription::new(matcher)
+            .from_cursor(EventCursor::at_height(BlockHeight(5)))
+            .stream(&client, new_blocks(vec![10]))
+            .map(|event| event.unwrap().height.0)
+            .collect()
+            .await;
+        assert_eq!(heights, vec![5, 6, 7, 8, 9, 10]);
+    }
+
+    /// Test that the offset of a cursor doesn't overflow.
+    #[test]
+    fn test_cursor_offset_overflow() {
+        let mut cursor = EventCursor {
+            height: BlockHeight(1),
+            offset: u64::MAX,
+        };
+        assert!(cursor.advance(BlockHeight(1)).is_err());
+        assert!(cursor.advance(BlockHeight(2)).is_ok());
+        assert_eq!(
+            cursor,
+            EventCursor {
+                height: BlockHeight(2),
+                offset: 1,
+            }
+        );
+    }
+}
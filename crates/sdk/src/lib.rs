@@ -59,9 +59,12 @@ use namada_core::collections::HashSet;
 pub use namada_core::control_flow;
 use namada_core::dec::Dec;
 use namada_core::ethereum_events::EthAddress;
-use namada_core::ibc::core::host::types::identifiers::{ChannelId, PortId};
+use namada_core::ibc::core::host::types::identifiers::{
+    ChannelId, ConnectionId, PortId,
+};
 use namada_core::key::*;
 use namada_core::masp::{ExtendedSpendingKey, PaymentAddress, TransferSource};
+use namada_core::storage::Epoch;
 use namada_tx::data::wrapper::GasLimit;
 use namada_tx::Tx;
 use rpc::{denominate_amount, format_denominated_amount, query_native_token};
@@ -69,15 +72,17 @@ use signing::SigningTxData;
 use token::{DenominatedAmount, NATIVE_MAX_DECIMAL_PLACES};
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use tx::{
-    ProcessTxResponse, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM,
-    TX_BRIDGE_POOL_WASM, TX_CHANGE_COMMISSION_WASM,
+    ProcessTxResponse, TX_APPROVE_WASM, TX_AUTO_RESTAKE_WASM,
+    TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
+    TX_BURN_TOKENS_WASM, TX_CHANGE_COMMISSION_WASM,
     TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
-    TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM,
-    TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM,
-    TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM,
+    TX_CLAIM_REWARDS_WASM, TX_CREATE_TOKEN_WASM, TX_DEACTIVATE_VALIDATOR_WASM,
+    TX_GRANT_FEE_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL,
+    TX_MINT_TOKENS_WASM, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
+    TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_FROM_WASM, TX_TRANSFER_WASM,
     TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
-    TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
-    VP_USER_WASM,
+    TX_UPDATE_PROPOSAL, TX_UPDATE_STEWARD_COMMISSION, TX_UPDATE_TOKEN_WASM,
+    TX_VEST_TOKENS_WASM, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM, VP_USER_WASM,
 };
 use wallet::{Wallet, WalletIo, WalletStorage};
 
@@ -157,6 +162,7 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             wallet_alias_force: false,
             fee_amount: None,
             wrapper_fee_payer: None,
+            fee_sponsor: None,
             fee_token: self.native_token(),
             gas_limit: GasLimit::from(DEFAULT_GAS_LIMIT),
             expiration: Default::default(),
@@ -328,18 +334,80 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             refund_target: None,
             ibc_shielding_data: None,
             ibc_memo: None,
+            relayer_fee_token: None,
+            recv_fee: None,
+            ack_fee: None,
+            timeout_fee: None,
             gas_spending_keys: Default::default(),
             tx: self.tx_builder(),
             tx_code_path: PathBuf::from(TX_IBC_WASM),
         }
     }
 
+    /// Make a TxRegisterInterchainAccount builder from the given minimum set
+    /// of arguments
+    fn new_register_interchain_account(
+        &self,
+        owner: Address,
+        connection_id: ConnectionId,
+    ) -> args::TxRegisterInterchainAccount {
+        args::TxRegisterInterchainAccount {
+            owner,
+            connection_id,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_IBC_WASM),
+        }
+    }
+
+    /// Make a TxSendInterchainAccountTx builder from the given minimum set of
+    /// arguments
+    fn new_send_interchain_account_tx(
+        &self,
+        owner: Address,
+        connection_id: ConnectionId,
+        receiver: String,
+        denom: String,
+        amount: String,
+    ) -> args::TxSendInterchainAccountTx {
+        args::TxSendInterchainAccountTx {
+            owner,
+            connection_id,
+            receiver,
+            denom,
+            amount,
+            timeout_sec_offset: None,
+            ibc_memo: None,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_IBC_WASM),
+        }
+    }
+
+    /// Make a TxRegisterRelayerPayee builder from the given minimum set of
+    /// arguments
+    fn new_register_relayer_payee(
+        &self,
+        relayer: Address,
+        port_id: PortId,
+        channel_id: ChannelId,
+    ) -> args::TxRegisterRelayerPayee {
+        args::TxRegisterRelayerPayee {
+            relayer,
+            port_id,
+            channel_id,
+            payee: None,
+            counterparty_payee: None,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_IBC_WASM),
+        }
+    }
+
     /// Make a InitProposal builder from the given minimum set of arguments
     fn new_init_proposal(&self, proposal_data: Vec<u8>) -> args::InitProposal {
         args::InitProposal {
             proposal_data,
             is_pgf_stewards: false,
             is_pgf_funding: false,
+            is_parameter_change: false,
             tx_code_path: PathBuf::from(TX_INIT_PROPOSAL),
             tx: self.tx_builder(),
         }
@@ -378,6 +446,16 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a UpdateProposal builder from the given minimum set of arguments
+    fn new_update_proposal(&self, proposal_id: u64) -> args::UpdateProposal {
+        args::UpdateProposal {
+            proposal_id,
+            proposal_content: None,
+            tx_code_path: PathBuf::from(TX_UPDATE_PROPOSAL),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a CommissionRateChange builder from the given minimum set of
     /// arguments
     fn new_change_commission_rate(
@@ -550,6 +628,177 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make an Auto-restake builder from the given minimum set of arguments
+    fn new_auto_restake(
+        &self,
+        validator: Address,
+        enabled: bool,
+    ) -> args::AutoRestake {
+        args::AutoRestake {
+            validator,
+            source: None,
+            enabled,
+            tx_code_path: PathBuf::from(TX_AUTO_RESTAKE_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a CreateToken builder from the given minimum set of arguments
+    fn new_create_token(
+        &self,
+        admin: Address,
+        name: String,
+        symbol: String,
+        denom: token::Denomination,
+    ) -> args::CreateToken {
+        args::CreateToken {
+            admin,
+            minter: None,
+            name,
+            symbol,
+            description: None,
+            denom,
+            supply_cap: None,
+            tx_code_path: PathBuf::from(TX_CREATE_TOKEN_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a MintTokens builder from the given minimum set of arguments
+    fn new_mint_tokens(
+        &self,
+        token: Address,
+        authority: Address,
+        target: Address,
+        amount: InputAmount,
+    ) -> args::MintTokens {
+        args::MintTokens {
+            token,
+            authority,
+            target,
+            amount,
+            tx_code_path: PathBuf::from(TX_MINT_TOKENS_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a BurnTokens builder from the given minimum set of arguments
+    fn new_burn_tokens(
+        &self,
+        token: Address,
+        authority: Address,
+        amount: InputAmount,
+    ) -> args::BurnTokens {
+        args::BurnTokens {
+            token,
+            authority,
+            source: None,
+            amount,
+            tx_code_path: PathBuf::from(TX_BURN_TOKENS_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make an UpdateToken builder from the given minimum set of arguments
+    fn new_update_token(
+        &self,
+        token: Address,
+        admin: Address,
+    ) -> args::UpdateToken {
+        args::UpdateToken {
+            token,
+            admin,
+            new_admin: None,
+            new_minter: None,
+            name: None,
+            symbol: None,
+            description: None,
+            tx_code_path: PathBuf::from(TX_UPDATE_TOKEN_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make an Approve builder from the given minimum set of arguments
+    fn new_approve(
+        &self,
+        token: Address,
+        owner: Address,
+        spender: Address,
+        amount: InputAmount,
+    ) -> args::Approve {
+        args::Approve {
+            token,
+            owner,
+            spender,
+            amount,
+            expiry: None,
+            tx_code_path: PathBuf::from(TX_APPROVE_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a TransferFrom builder from the given minimum set of arguments
+    fn new_transfer_from(
+        &self,
+        token: Address,
+        spender: Address,
+        owner: Address,
+        target: Address,
+        amount: InputAmount,
+    ) -> args::TransferFrom {
+        args::TransferFrom {
+            token,
+            spender,
+            owner,
+            target,
+            amount,
+            tx_code_path: PathBuf::from(TX_TRANSFER_FROM_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a VestTokens builder from the given minimum set of arguments
+    fn new_vest_tokens(
+        &self,
+        token: Address,
+        source: Address,
+        target: Address,
+        amount: InputAmount,
+        end_epoch: Epoch,
+    ) -> args::VestTokens {
+        args::VestTokens {
+            token,
+            source,
+            target,
+            amount,
+            start_epoch: None,
+            cliff_epoch: None,
+            end_epoch,
+            tx_code_path: PathBuf::from(TX_VEST_TOKENS_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a GrantFee builder from the given minimum set of arguments
+    fn new_grant_fee(
+        &self,
+        token: Address,
+        sponsor: Address,
+        grantee: Address,
+        amount: InputAmount,
+    ) -> args::GrantFee {
+        args::GrantFee {
+            token,
+            sponsor,
+            grantee,
+            amount,
+            allowed_code_hashes: vec![],
+            expiry: None,
+            tx_code_path: PathBuf::from(TX_GRANT_FEE_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a Withdraw builder from the given minimum set of arguments
     fn new_add_erc20_transfer(
         &self,
@@ -728,6 +977,7 @@ where
                 wallet_alias_force: false,
                 fee_amount: None,
                 wrapper_fee_payer: None,
+                fee_sponsor: None,
                 fee_token: native_token,
                 gas_limit: GasLimit::from(DEFAULT_GAS_LIMIT),
                 expiration: Default::default(),
@@ -855,9 +1105,11 @@ pub mod testing {
     use namada_core::key::testing::arb_common_keypair;
     use namada_core::masp::AssetData;
     use namada_governance::storage::proposal::testing::{
-        arb_init_proposal, arb_vote_proposal,
+        arb_init_proposal, arb_update_proposal, arb_vote_proposal,
+    };
+    use namada_governance::{
+        InitProposalData, UpdateProposalData, VoteProposalData,
     };
-    use namada_governance::{InitProposalData, VoteProposalData};
     use namada_ibc::testing::{arb_ibc_msg_nft_transfer, arb_ibc_msg_transfer};
     use namada_ibc::{MsgNftTransfer, MsgTransfer};
     use namada_token::testing::arb_denominated_amount;
@@ -911,6 +1163,7 @@ pub mod testing {
         UnjailValidator(Address),
         UpdateAccount(UpdateAccount),
         VoteProposal(VoteProposalData),
+        UpdateProposal(UpdateProposalData),
         Withdraw(Withdraw),
         Transfer(Transfer, Option<(StoredBuildParams, String)>),
         Bond(Bond),
@@ -1247,6 +1500,25 @@ pub mod testing {
         }
     }
 
+    prop_compose! {
+        /// Generate an arbitrary update proposal transaction
+        pub fn arb_update_proposal_tx()(
+            mut header in arb_header(),
+            wrapper in arb_wrapper_tx(),
+            update_proposal in arb_update_proposal(),
+            code_hash in arb_hash(),
+        ) -> (Tx, TxData) {
+            header.tx_type = TxType::Wrapper(Box::new(wrapper));
+            let mut tx = Tx { header, sections: vec![] };
+            tx.add_data(update_proposal.clone());
+            tx.add_code_from_hash(
+                code_hash,
+                Some(TX_UPDATE_PROPOSAL.to_owned()),
+            );
+            (tx, TxData::UpdateProposal(update_proposal))
+        }
+    }
+
     prop_compose! {
         /// Generate an arbitrary reveal public key transaction
         pub fn arb_reveal_pk_tx()(
@@ -1486,9 +1758,9 @@ pub mod testing {
             Option<(ShieldedTransfer, HashMap<AssetData, u64>, StoredBuildParams)>,
         ) {
             if let Some((transfer, aux)) = transfer_aux {
-                (MsgTransfer { message, transfer: Some(transfer) }, aux)
+                (MsgTransfer { message, transfer: Some(transfer), fee: None }, aux)
             } else {
-                (MsgTransfer { message, transfer: None }, None)
+                (MsgTransfer { message, transfer: None, fee: None }, None)
             }
         }
     }
@@ -1536,9 +1808,9 @@ pub mod testing {
             Option<(ShieldedTransfer, HashMap<AssetData, u64>, StoredBuildParams)>,
         ) {
             if let Some((transfer, aux)) = transfer_aux {
-                (MsgNftTransfer { message, transfer: Some(transfer) }, aux)
+                (MsgNftTransfer { message, transfer: Some(transfer), fee: None }, aux)
             } else {
-                (MsgNftTransfer { message, transfer: None }, None)
+                (MsgNftTransfer { message, transfer: None, fee: None }, None)
             }
         }
     }
@@ -1586,6 +1858,7 @@ pub mod testing {
             arb_become_validator_tx(),
             arb_init_proposal_tx(),
             arb_vote_proposal_tx(),
+            arb_update_proposal_tx(),
             arb_reveal_pk_tx(),
             arb_update_account_tx(),
             arb_withdraw_tx(),
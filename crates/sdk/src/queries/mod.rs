@@ -103,7 +103,6 @@ pub(crate) mod testing {
     use tendermint_rpc::Response;
 
     use super::*;
-    use crate::events::log::EventLog;
     use crate::tendermint_rpc::error::Error as RpcError;
 
     /// A test client that has direct access to the storage
@@ -115,8 +114,6 @@ pub(crate) mod testing {
         pub rpc: RPC,
         /// state
         pub state: TestState,
-        /// event log
-        pub event_log: EventLog,
     }
 
     impl<RPC> TestClient<RPC>
@@ -137,12 +134,7 @@ pub(crate) mod testing {
                 .expect(
                     "Max block gas parameter must be initialized in storage",
                 );
-            let event_log = EventLog::default();
-            Self {
-                rpc,
-                state,
-                event_log,
-            }
+            Self { rpc, state }
         }
     }
 
@@ -177,7 +169,6 @@ pub(crate) mod testing {
             };
             let ctx = RequestCtx {
                 state: self.state.read_only(),
-                event_log: &self.event_log,
                 vp_wasm_cache: (),
                 tx_wasm_cache: (),
                 storage_read_past_height_limit: None,
@@ -1042,7 +1042,6 @@ mod test {
             prove: Default::default(),
         };
         let ctx = RequestCtx {
-            event_log: &client.event_log,
             state: &client.state,
             vp_wasm_cache: (),
             tx_wasm_cache: (),
@@ -1061,7 +1060,6 @@ mod test {
             prove: Default::default(),
         };
         let ctx = RequestCtx {
-            event_log: &client.event_log,
             state: &client.state,
             vp_wasm_cache: (),
             tx_wasm_cache: (),
@@ -1078,7 +1076,6 @@ mod test {
             prove: Default::default(),
         };
         let ctx = RequestCtx {
-            event_log: &client.event_log,
             state: &client.state,
             vp_wasm_cache: (),
             tx_wasm_cache: (),
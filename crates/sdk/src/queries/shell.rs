@@ -18,7 +18,7 @@ use namada_core::storage::{
     self, BlockHeight, BlockResults, Epoch, Header, KeySeg, PrefixValue,
 };
 use namada_core::time::DurationSecs;
-use namada_core::token::{Denomination, MaspDigitPos};
+use namada_core::token::{self, Denomination, MaspDigitPos};
 use namada_core::uint::Uint;
 use namada_ibc::event::IbcEventType;
 use namada_state::{DBIter, LastBlock, StateRead, StorageHasher, DB};
@@ -27,7 +27,7 @@ use namada_token::storage_key::masp_token_map_key;
 use namada_tx::data::DryRunResult;
 
 use self::eth_bridge::{EthBridge, ETH_BRIDGE};
-use crate::events::log::dumb_queries;
+use crate::events::log::{self, dumb_queries};
 use crate::events::Event;
 use crate::ibc::core::host::types::identifiers::{
     ChannelId, ClientId, PortId, Sequence,
@@ -105,6 +105,10 @@ router! {SHELL,
     // Block results access - read bit-vec
     ( "results" ) -> Vec<BlockResults> = read_results,
 
+    // A page of the events in the event log matching a borsh encoded
+    // `EventQuery`
+    ( "events" ) -> log::EventsPage = (with_options events),
+
     // was the transaction applied?
     ( "applied" / [tx_hash: Hash] ) -> Option<Event> = applied,
 
@@ -125,6 +129,9 @@ router! {SHELL,
 
     // Return an estimate of the maximum time taken to decide a block
     ( "max_block_time" ) -> DurationSecs = max_block_time,
+
+    // The base fee per unit of gas of a fee token for the next block
+    ( "base_fee" / [token: Address] ) -> Option<token::Amount> = base_fee,
 }
 
 // Handlers:
@@ -158,6 +165,19 @@ where
     )
 }
 
+/// Get the base fee per unit of gas of the token for the next block, if the
+/// token is allowed for fee payment
+fn base_fee<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    token: Address,
+) -> namada_storage::Result<Option<token::Amount>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_parameters::read_base_fee(ctx.state, &token)
+}
+
 /// Get the block header associated with the requested height
 fn block_header<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -465,6 +485,24 @@ where
         }
     }
 
+    if let Some(oldest_height) = ctx
+        .state
+        .db()
+        .read_oldest_height()
+        .map_err(namada_storage::Error::new)?
+    {
+        if queried_height < oldest_height {
+            return Err(namada_storage::Error::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "The state at height {queried_height} has been pruned. \
+                     The oldest queryable height is {oldest_height} \
+                     (configured via `ledger.shell.pruning`)."
+                ),
+            )));
+        }
+    }
+
     match ctx
         .state
         .db_read_with_height(&storage_key, queried_height)
@@ -572,6 +610,25 @@ where
     Ok(data)
 }
 
+/// Query a page of the events in the event log. The request data must be a
+/// borsh encoded [`log::EventQuery`].
+fn events<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    request: &RequestQuery,
+) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let query =
+        log::EventQuery::try_from_slice(&request.data).into_storage_result()?;
+    let page = log::query_events_page(ctx.state.db(), &query)?;
+    Ok(EncodedResponseQuery {
+        data: page.serialize_to_vec(),
+        ..Default::default()
+    })
+}
+
 fn applied<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     tx_hash: Hash,
@@ -581,7 +638,9 @@ where
     H: 'static + StorageHasher + Sync,
 {
     let matcher = dumb_queries::QueryMatcher::applied(tx_hash);
-    Ok(ctx.event_log.with_matcher(matcher).iter().next().cloned())
+    let query = log::EventQuery::new(matcher).limit(1);
+    let events = log::query_events(ctx.state.db(), &query)?;
+    Ok(events.into_iter().next().map(|(_height, event)| event))
 }
 
 fn ibc_client_update<D, H, V, T>(
@@ -597,7 +656,9 @@ where
         client_id,
         consensus_height,
     );
-    Ok(ctx.event_log.with_matcher(matcher).iter().next().cloned())
+    let query = log::EventQuery::new(matcher).limit(1);
+    let events = log::query_events(ctx.state.db(), &query)?;
+    Ok(events.into_iter().next().map(|(_height, event)| event))
 }
 
 fn ibc_packet<D, H, V, T>(
@@ -621,7 +682,9 @@ where
         destination_channel,
         sequence,
     );
-    Ok(ctx.event_log.with_matcher(matcher).iter().next().cloned())
+    let query = log::EventQuery::new(matcher).limit(1);
+    let events = log::query_events(ctx.state.db(), &query)?;
+    Ok(events.into_iter().next().map(|(_height, event)| event))
 }
 
 fn account<D, H, V, T>(
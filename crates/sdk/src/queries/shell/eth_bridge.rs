@@ -18,7 +18,6 @@ use namada_core::storage::{BlockHeight, DbKeySeg, Epoch, Key};
 use namada_core::token::Amount;
 use namada_core::voting_power::FractionalVotingPower;
 use namada_core::{ethereum_structs, hints};
-use namada_ethereum_bridge::event::{BpTransferStatus, BridgePoolTxHash};
 use namada_ethereum_bridge::protocol::transactions::votes::{
     EpochedVotingPower, EpochedVotingPowerExt,
 };
@@ -42,6 +41,7 @@ use namada_vote_ext::validator_set_update::{
 use serde::{Deserialize, Serialize};
 
 use crate::eth_bridge::ethers::abi::AbiDecode;
+use crate::events::log::{self, dumb_queries::QueryMatcher};
 use crate::governance;
 use crate::queries::{EncodedResponseQuery, RequestCtx, RequestQuery};
 
@@ -298,39 +298,25 @@ where
 
     // INVARIANT: transfers that are in the event log will have already
     // been processed and therefore removed from the Bridge pool at the
-    // time of this query
-    let completed_transfers = ctx.event_log.iter().filter_map(|ev| {
-        let Ok(transfer_status) = BpTransferStatus::try_from(ev.kind()) else {
-            return None;
-        };
-        let tx_hash: KeccakHash = ev
-            .read_attribute::<BridgePoolTxHash<'_>>()
-            .expect("The transfer hash must be available");
-        if !transfer_hashes.swap_remove(&tx_hash) {
-            return None;
-        }
-        Some((tx_hash, transfer_status, transfer_hashes.is_empty()))
-    });
-    for (hash, transfer_status, early_exit) in completed_transfers {
-        if hints::likely(matches!(transfer_status, BpTransferStatus::Relayed)) {
-            status.relayed.insert(hash.clone());
+    // time of this query. The events of each transfer are looked up in the
+    // index of their hash, so that we never scan the whole event log.
+    let has_event = |matcher: QueryMatcher| {
+        let query = log::EventQuery::new(matcher).limit(1);
+        log::query_events(ctx.state.db(), &query)
+            .map(|events| !events.is_empty())
+    };
+    for hash in transfer_hashes {
+        if has_event(QueryMatcher::bridge_pool_relayed(&hash))? {
+            status.relayed.insert(hash);
+        } else if has_event(QueryMatcher::bridge_pool_expired(&hash))? {
+            status.expired.insert(hash);
         } else {
-            status.expired.insert(hash.clone());
-        }
-        if early_exit {
-            // early drop of the transfer hashes, in
-            // case its storage capacity was big
-            transfer_hashes = Default::default();
-            break;
+            // any remaining transfers are returned as
+            // unrecognized hashes
+            status.unrecognized.insert(hash);
         }
     }
 
-    let status = {
-        // any remaining transfers are returned as
-        // unrecognized hashes
-        status.unrecognized = transfer_hashes;
-        status
-    };
     Ok(EncodedResponseQuery {
         data: status.serialize_to_vec(),
         height: last_committed_height,
@@ -844,6 +830,7 @@ mod test_ethbridge_router {
         GasFee, TransferToEthereum, TransferToEthereumKind,
     };
     use namada_core::voting_power::EthBridgeVotingPower;
+    use namada_ethereum_bridge::event::{BpTransferStatus, EthBridgeEvent};
     use namada_ethereum_bridge::protocol::transactions::validator_set_update::aggregate_votes;
     use namada_ethereum_bridge::storage::bridge_pool::{
         get_pending_key, get_signed_root_key, BridgePoolTree,
@@ -1686,18 +1673,24 @@ mod test_ethbridge_router {
         transfer2.transfer.amount = 1.into();
         let mut transfer3 = transfer.clone();
         transfer3.transfer.amount = 2.into();
-        client.event_log.log_events(vec![
-            crate::eth_bridge::event::EthBridgeEvent::BridgePool {
-                tx_hash: transfer2.keccak256(),
-                status: crate::eth_bridge::event::BpTransferStatus::Expired,
-            }
-            .into(),
-            crate::eth_bridge::event::EthBridgeEvent::BridgePool {
-                tx_hash: transfer3.keccak256(),
-                status: crate::eth_bridge::event::BpTransferStatus::Relayed,
-            }
-            .into(),
-        ]);
+        log::write_events(
+            client.state.db(),
+            &mut MockDBWriteBatch,
+            0.into(),
+            &[
+                EthBridgeEvent::BridgePool {
+                    tx_hash: transfer2.keccak256(),
+                    status: BpTransferStatus::Expired,
+                }
+                .into(),
+                EthBridgeEvent::BridgePool {
+                    tx_hash: transfer3.keccak256(),
+                    status: BpTransferStatus::Relayed,
+                }
+                .into(),
+            ],
+        )
+        .expect("Test failed");
 
         // some arbitrary transfer - since it's neither in the
         // Bridge pool nor in the event log, it is assumed it has
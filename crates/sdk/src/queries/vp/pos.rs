@@ -17,7 +17,7 @@ use namada_proof_of_stake::slashing::{
     find_all_enqueued_slashes, find_all_slashes,
 };
 use namada_proof_of_stake::storage::{
-    bond_handle, read_all_validator_addresses,
+    bond_handle, read_all_validator_addresses, read_auto_restake_epoch,
     read_below_capacity_validator_set_addresses_with_stake,
     read_consensus_validator_set_addresses_with_stake, read_pos_params,
     read_total_active_stake, read_total_stake, read_validator_avatar,
@@ -30,8 +30,9 @@ use namada_proof_of_stake::storage::{
 };
 pub use namada_proof_of_stake::types::ValidatorStateInfo;
 use namada_proof_of_stake::types::{
-    BondId, BondsAndUnbondsDetail, BondsAndUnbondsDetails, CommissionPair,
-    Slash, ValidatorMetaData, WeightedValidator,
+    BondEpochData, BondId, BondsAndUnbondsDetail, BondsAndUnbondsDetails,
+    CommissionPair, Slash, ValidatorEpochData, ValidatorMetaData,
+    WeightedValidator,
 };
 use namada_proof_of_stake::{bond_amount, query_reward_tokens};
 use namada_state::{DBIter, KeySeg, StorageHasher, DB};
@@ -71,6 +72,9 @@ router! {POS,
 
         ( "last_infraction_epoch" / [validator: Address] )
             -> Option<Epoch> = validator_last_infraction_epoch,
+
+        ( "history" / [validator: Address] / [start: Epoch] / [end: Epoch] )
+            -> Vec<ValidatorEpochData> = validator_history,
     },
 
     ( "validator_set" ) = {
@@ -104,9 +108,15 @@ router! {POS,
     ( "rewards" / [validator: Address] / [source: opt Address] )
         -> token::Amount = rewards,
 
+    ( "auto_restake" / [source: Address] / [validator: Address] )
+        -> Option<Epoch> = auto_restake,
+
     ( "bond_with_slashing" / [source: Address] / [validator: Address] / [epoch: opt Epoch] )
         -> token::Amount = bond_with_slashing,
 
+    ( "bond_history" / [source: Address] / [validator: Address] / [start: Epoch] / [end: Epoch] )
+        -> Vec<BondEpochData> = bond_history,
+
     ( "unbond" / [source: Address] / [validator: Address] )
         -> HashMap<(Epoch, Epoch), token::Amount> = unbond,
 
@@ -358,6 +368,24 @@ where
     }
 }
 
+/// Get the validator's stake, commission rate, state and rank at every epoch
+/// of the given inclusive range, clamped to the epochs whose data may still be
+/// retained in storage.
+fn validator_history<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    start: Epoch,
+    end: Epoch,
+) -> namada_storage::Result<Vec<ValidatorEpochData>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_proof_of_stake::queries::validator_history::<_, governance::Store<_>>(
+        ctx.state, &validator, start, end,
+    )
+}
+
 /// Get the incoming redelegation epoch for a source validator - delegator pair,
 /// if there is any.
 fn validator_incoming_redelegation<D, H, V, T>(
@@ -482,6 +510,25 @@ where
     bond_amount::<_, governance::Store<_>>(ctx.state, &bond_id, epoch)
 }
 
+/// Get the bonded, unbonding and withdrawable amounts of a bond at every epoch
+/// of the given inclusive range, clamped to the epochs whose data may still be
+/// retained in storage.
+fn bond_history<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    source: Address,
+    validator: Address,
+    start: Epoch,
+    end: Epoch,
+) -> namada_storage::Result<Vec<BondEpochData>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_proof_of_stake::queries::bond_history::<_, governance::Store<_>>(
+        ctx.state, &source, &validator, start, end,
+    )
+}
+
 fn unbond<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     source: Address,
@@ -580,6 +627,18 @@ where
     )
 }
 
+fn auto_restake<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    source: Address,
+    validator: Address,
+) -> namada_storage::Result<Option<Epoch>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    read_auto_restake_epoch(ctx.state, &source, &validator)
+}
+
 fn bonds_and_unbonds<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     source: Option<Address>,
@@ -829,7 +888,6 @@ mod test {
             prove: Default::default(),
         };
         let ctx = RequestCtx {
-            event_log: &client.event_log,
             state: &client.state,
             vp_wasm_cache: (),
             tx_wasm_cache: (),
@@ -5,6 +5,7 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::ops::ControlFlow;
 
 use borsh::BorshDeserialize;
+use borsh_ext::BorshSerializeExt;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
@@ -13,6 +14,7 @@ use namada_core::address::{Address, InternalAddress};
 use namada_core::arith::checked;
 use namada_core::collections::{HashMap, HashSet};
 use namada_core::hash::Hash;
+use namada_core::ibc::core::host::types::identifiers::{ChannelId, PortId};
 use namada_core::ibc::IbcTokenHash;
 use namada_core::key::common;
 use namada_core::masp::MaspEpoch;
@@ -33,13 +35,20 @@ use namada_governance::storage::proposal::StorageProposal;
 use namada_governance::utils::{
     compute_proposal_result, ProposalResult, ProposalVotes, Vote,
 };
+use namada_ibc::context::client::TENDERMINT_CLIENT_TYPE;
+use namada_ibc::fee::PacketFee;
+use namada_ibc::rate_limit::{ChannelFlow, ChannelRateLimit};
 use namada_ibc::storage::{
-    ibc_trace_key, ibc_trace_key_prefix, is_ibc_trace_key,
+    allowed_client_type_prefix, channel_flow_key, counterparty_payee_key,
+    denied_channel_key, denied_channel_prefix, denied_denom_key,
+    denied_denom_prefix, fee_enabled_key, fees_in_escrow_key, ibc_trace_key,
+    ibc_trace_key_prefix, is_ibc_trace_key, payee_key, rate_limit_key,
 };
 use namada_parameters::{storage as params_storage, EpochDuration};
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::types::{
-    BondsAndUnbondsDetails, CommissionPair, ValidatorMetaData,
+    BondEpochData, BondsAndUnbondsDetails, CommissionPair, ValidatorEpochData,
+    ValidatorMetaData,
 };
 use namada_state::LastBlock;
 use namada_tx::data::{BatchedTxResult, DryRunResult, ResultCode, TxResult};
@@ -49,6 +58,7 @@ use serde::Serialize;
 use crate::args::InputAmount;
 use crate::control_flow::time;
 use crate::error::{EncodingError, Error, QueryError, TxSubmitError};
+use crate::events::log::{EventQuery, EventsPage};
 use crate::events::{extend, Event};
 use crate::internal_macros::echo_error;
 use crate::io::Io;
@@ -162,6 +172,15 @@ pub async fn query_native_token<C: crate::queries::Client + Sync>(
     convert_response::<C, _>(RPC.shell().native_token(client).await)
 }
 
+/// Query the base fee per unit of gas of the token for the next block. Returns
+/// `None` if the token is not allowed for fee payment.
+pub async fn query_base_fee<C: crate::queries::Client + Sync>(
+    client: &C,
+    token: &Address,
+) -> Result<Option<token::Amount>, error::Error> {
+    convert_response::<C, _>(RPC.shell().base_fee(client, token).await)
+}
+
 /// Query the epoch of the given block height, if it exists.
 /// Will return none if the input block height is greater than
 /// the latest committed block height.
@@ -545,6 +564,19 @@ pub async fn query_tx_events<C: crate::queries::Client + Sync>(
     }
 }
 
+/// Query a page of the events in the event log of the node matching the given
+/// query, with the heights of the blocks that emitted them
+pub async fn query_events<C: crate::queries::Client + Sync>(
+    client: &C,
+    query: &EventQuery,
+) -> Result<EventsPage, Error> {
+    let data = Some(query.serialize_to_vec());
+    let response = convert_response::<C, _>(
+        RPC.shell().events(client, data, None, false).await,
+    )?;
+    Ok(response.data)
+}
+
 /// Dry run a transaction
 pub async fn dry_run_tx<N: Namada>(
     context: &N,
@@ -842,6 +874,23 @@ pub async fn query_commission_rate<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query a validator's stake, commission rate, state and rank at every epoch of
+/// the given inclusive range. The node clamps the range to the epochs whose
+/// data is still retained in its storage.
+pub async fn query_validator_history<C: crate::queries::Client + Sync>(
+    client: &C,
+    validator: &Address,
+    start: Epoch,
+    end: Epoch,
+) -> Result<Vec<ValidatorEpochData>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .validator_history(client, validator, &start, &end)
+            .await,
+    )
+}
+
 /// Query and return validator's metadata, including the commission rate and max
 /// commission rate change
 pub async fn query_metadata<C: crate::queries::Client + Sync>(
@@ -888,6 +937,36 @@ pub async fn query_bond<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query the bonded, unbonding and withdrawable amounts of a bond at every
+/// epoch of the given inclusive range. The node clamps the range to the epochs
+/// whose data is still retained in its storage.
+pub async fn query_bond_history<C: crate::queries::Client + Sync>(
+    client: &C,
+    source: &Address,
+    validator: &Address,
+    start: Epoch,
+    end: Epoch,
+) -> Result<Vec<BondEpochData>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .bond_history(client, source, validator, &start, &end)
+            .await,
+    )
+}
+
+/// Query the epoch from which the rewards of a bond have been automatically
+/// restaked, if enabled.
+pub async fn query_auto_restake<C: crate::queries::Client + Sync>(
+    client: &C,
+    source: &Address,
+    validator: &Address,
+) -> Result<Option<Epoch>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp().pos().auto_restake(client, source, validator).await,
+    )
+}
+
 /// Query a validator's bonds for a given epoch
 pub async fn query_last_infraction_epoch<C: crate::queries::Client + Sync>(
     client: &C,
@@ -1426,3 +1505,152 @@ pub async fn query_ibc_denom<N: Namada>(
 
     token.as_ref().to_string()
 }
+
+/// Check if the IBC channel is fee-enabled
+pub async fn query_ibc_fee_enabled<C: crate::queries::Client + Sync>(
+    client: &C,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<bool, Error> {
+    query_has_storage_key(client, &fee_enabled_key(port_id, channel_id)).await
+}
+
+/// Query the relayer fees escrowed for the IBC packet
+pub async fn query_ibc_packet_fees<C: crate::queries::Client + Sync>(
+    client: &C,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: u64,
+) -> Result<Vec<PacketFee>, Error> {
+    let key = fees_in_escrow_key(port_id, channel_id, sequence.into());
+    if query_has_storage_key(client, &key).await? {
+        query_storage_value(client, &key).await
+    } else {
+        Ok(vec![])
+    }
+}
+
+/// Query the payee of the relayer on this chain. The relayer itself is the
+/// payee if it hasn't registered any payee.
+pub async fn query_ibc_payee<C: crate::queries::Client + Sync>(
+    client: &C,
+    channel_id: &ChannelId,
+    relayer: &Address,
+) -> Result<Address, Error> {
+    let key = payee_key(channel_id, relayer);
+    if query_has_storage_key(client, &key).await? {
+        query_storage_value(client, &key).await
+    } else {
+        Ok(relayer.clone())
+    }
+}
+
+/// Query the payee on the counterparty chain registered by the relayer
+pub async fn query_ibc_counterparty_payee<C: crate::queries::Client + Sync>(
+    client: &C,
+    channel_id: &ChannelId,
+    relayer: &Address,
+) -> Result<Option<String>, Error> {
+    let key = counterparty_payee_key(channel_id, relayer);
+    if query_has_storage_key(client, &key).await? {
+        query_storage_value(client, &key).await.map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Query the rate limit of the token over the IBC channel
+pub async fn query_ibc_rate_limit<C: crate::queries::Client + Sync>(
+    client: &C,
+    channel_id: &ChannelId,
+    token: &Address,
+) -> Result<Option<ChannelRateLimit>, Error> {
+    let key = rate_limit_key(channel_id, token);
+    if query_has_storage_key(client, &key).await? {
+        query_storage_value(client, &key).await.map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Query the flow of the token over the IBC channel in the current rate
+/// limit window
+pub async fn query_ibc_channel_flow<C: crate::queries::Client + Sync>(
+    client: &C,
+    channel_id: &ChannelId,
+    token: &Address,
+) -> Result<Option<ChannelFlow>, Error> {
+    let key = channel_flow_key(channel_id, token);
+    if query_has_storage_key(client, &key).await? {
+        query_storage_value(client, &key).await.map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Check if the IBC channel is in the deny list
+pub async fn query_ibc_channel_denied<C: crate::queries::Client + Sync>(
+    client: &C,
+    channel_id: &ChannelId,
+) -> Result<bool, Error> {
+    query_has_storage_key(client, &denied_channel_key(channel_id)).await
+}
+
+/// Check if the denom is in the IBC deny list
+pub async fn query_ibc_denom_denied<C: crate::queries::Client + Sync>(
+    client: &C,
+    denom: impl AsRef<str>,
+) -> Result<bool, Error> {
+    query_has_storage_key(client, &denied_denom_key(denom)).await
+}
+
+/// Query all the channels in the IBC deny list
+pub async fn query_ibc_denied_channels<N: Namada>(
+    context: &N,
+) -> Result<Vec<ChannelId>, Error> {
+    let prefix = denied_channel_prefix();
+    let Some(channels) =
+        query_storage_prefix::<_, bool>(context, &prefix).await?
+    else {
+        return Ok(vec![]);
+    };
+    Ok(channels
+        .filter_map(|(key, _)| match key.last() {
+            Some(storage::DbKeySeg::StringSeg(channel_id)) => {
+                channel_id.parse().ok()
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+/// Query all the denoms in the IBC deny list
+pub async fn query_ibc_denied_denoms<N: Namada>(
+    context: &N,
+) -> Result<Vec<String>, Error> {
+    let prefix = denied_denom_prefix();
+    let denoms = query_storage_prefix::<_, String>(context, &prefix).await?;
+    Ok(denoms
+        .map(|denoms| denoms.map(|(_, denom)| denom).collect())
+        .unwrap_or_default())
+}
+
+/// Query the IBC client types allowed by governance. The Tendermint client
+/// type is always allowed.
+pub async fn query_ibc_allowed_client_types<N: Namada>(
+    context: &N,
+) -> Result<Vec<String>, Error> {
+    let prefix = allowed_client_type_prefix();
+    let mut client_types = vec![TENDERMINT_CLIENT_TYPE.to_string()];
+    if let Some(allowed) =
+        query_storage_prefix::<_, bool>(context, &prefix).await?
+    {
+        client_types.extend(allowed.filter_map(|(key, _)| match key.last() {
+            Some(storage::DbKeySeg::StringSeg(client_type)) => {
+                Some(client_type.clone())
+            }
+            _ => None,
+        }));
+    }
+    Ok(client_types)
+}
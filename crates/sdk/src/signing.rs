@@ -19,17 +19,18 @@ use namada_core::masp::{AssetData, ExtendedViewingKey, PaymentAddress, TxId};
 use namada_core::sign::SignatureIndex;
 use namada_core::token::{Amount, DenominatedAmount};
 use namada_governance::storage::proposal::{
-    InitProposalData, ProposalType, VoteProposalData,
+    InitProposalData, ProposalType, ProposalUpdate, UpdateProposalData,
+    VoteProposalData,
 };
 use namada_governance::storage::vote::ProposalVote;
-use namada_ibc::{MsgNftTransfer, MsgTransfer};
-use namada_parameters::storage as parameter_storage;
+use namada_ibc::fee::PacketFee;
+use namada_ibc::{MsgFee, MsgInterchainAccount, MsgNftTransfer, MsgTransfer};
 use namada_token as token;
-use namada_token::storage_key::balance_key;
+use namada_token::storage_key::{balance_key, fee_grant_key};
 use namada_tx::data::pgf::UpdateStewardCommission;
 use namada_tx::data::pos::BecomeValidator;
 use namada_tx::data::{pos, Fee};
-use namada_tx::{MaspBuilder, Section, Tx};
+use namada_tx::{MaspBuilder, Section, Signer, Tx};
 use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
@@ -42,15 +43,17 @@ use crate::io::*;
 use crate::rpc::validate_amount;
 use crate::token::Account;
 use crate::tx::{
-    Commitment, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
-    TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
-    TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM,
-    TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
-    TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
-    TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
-    TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
-    TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
-    VP_USER_WASM,
+    Commitment, TX_APPROVE_WASM, TX_AUTO_RESTAKE_WASM,
+    TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
+    TX_BURN_TOKENS_WASM, TX_CHANGE_COMMISSION_WASM,
+    TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
+    TX_CLAIM_REWARDS_WASM, TX_CREATE_TOKEN_WASM, TX_DEACTIVATE_VALIDATOR_WASM,
+    TX_GRANT_FEE_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL,
+    TX_MINT_TOKENS_WASM, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
+    TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_FROM_WASM, TX_TRANSFER_WASM,
+    TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
+    TX_UPDATE_PROPOSAL, TX_UPDATE_STEWARD_COMMISSION, TX_UPDATE_TOKEN_WASM,
+    TX_VEST_TOKENS_WASM, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM, VP_USER_WASM,
 };
 pub use crate::wallet::store::AddressVpType;
 use crate::wallet::{Wallet, WalletIo};
@@ -294,6 +297,136 @@ where
     Ok(())
 }
 
+/// The signatures of a multisig account over a transaction
+#[derive(Clone, Debug)]
+pub struct MultisigSignatures {
+    /// The valid signatures already attached to the transaction
+    pub attached: Vec<SignatureIndex>,
+    /// The valid signatures to be attached to the transaction
+    pub added: Vec<SignatureIndex>,
+    /// The signature threshold of the account
+    pub threshold: u8,
+}
+
+impl MultisigSignatures {
+    /// The number of valid signatures
+    pub fn count(&self) -> usize {
+        self.attached.len().saturating_add(self.added.len())
+    }
+
+    /// The number of signatures still needed to reach the threshold
+    pub fn missing(&self) -> usize {
+        usize::from(self.threshold).saturating_sub(self.count())
+    }
+
+    /// Check if the threshold has been reached
+    pub fn is_complete(&self) -> bool {
+        self.missing() == 0
+    }
+}
+
+/// Collect the valid signatures of the account members already attached to
+/// the raw header of the transaction
+pub fn attached_signatures(
+    tx: &Tx,
+    owner: &Address,
+    account_public_keys_map: &AccountPublicKeysMap,
+) -> Vec<SignatureIndex> {
+    let raw_header_hash = tx.raw_header_hash();
+    let mut signatures: Vec<SignatureIndex> = vec![];
+    for section in &tx.sections {
+        let Section::Authorization(auth) = section else {
+            continue;
+        };
+        if auth.targets != [raw_header_hash] {
+            continue;
+        }
+        let pubkeys: Vec<(u8, common::PublicKey)> = match &auth.signer {
+            Signer::Address(addr) if addr == owner => auth
+                .signatures
+                .keys()
+                .filter_map(|idx| {
+                    account_public_keys_map
+                        .get_public_key_from_index(*idx)
+                        .map(|pk| (*idx, pk))
+                })
+                .collect(),
+            Signer::Address(_) => vec![],
+            Signer::PubKeys(pks) => (0..).zip(pks.iter().cloned()).collect(),
+        };
+        for (sig_idx, pubkey) in pubkeys {
+            let Some(idx) =
+                account_public_keys_map.get_index_from_public_key(&pubkey)
+            else {
+                continue;
+            };
+            let Some(signature) = auth.signatures.get(&sig_idx) else {
+                continue;
+            };
+            let signature = SignatureIndex {
+                pubkey,
+                index: Some((owner.clone(), idx)),
+                signature: signature.clone(),
+            };
+            if tx.verify_section_signature(&signature).is_ok()
+                && !signatures.iter().any(|sig| sig.pubkey == signature.pubkey)
+            {
+                signatures.push(signature);
+            }
+        }
+    }
+    signatures
+}
+
+/// Validate the offline signatures of the members of a multisig account and
+/// merge them with the ones already attached to the transaction. A signature
+/// is rejected if it doesn't verify, if its key isn't one of the account keys
+/// or if its key has already signed.
+pub fn merge_signatures(
+    tx: &Tx,
+    owner: &Address,
+    account_public_keys_map: &AccountPublicKeysMap,
+    threshold: u8,
+    signatures: Vec<SignatureIndex>,
+) -> Result<MultisigSignatures, Error> {
+    let attached = attached_signatures(tx, owner, account_public_keys_map);
+    let mut signed: HashSet<common::PublicKey> =
+        attached.iter().map(|sig| sig.pubkey.clone()).collect();
+    let mut added = vec![];
+    for mut signature in signatures {
+        let idx = account_public_keys_map
+            .get_index_from_public_key(&signature.pubkey)
+            .filter(|idx| match &signature.index {
+                Some((addr, sig_idx)) => addr == owner && sig_idx == idx,
+                None => true,
+            })
+            .ok_or_else(|| {
+                Error::from(TxSubmitError::NonMemberSignature(
+                    signature.pubkey.to_string(),
+                    owner.encode(),
+                ))
+            })?;
+        tx.verify_section_signature(&signature).map_err(|err| {
+            Error::from(TxSubmitError::InvalidSignature(
+                signature.pubkey.to_string(),
+                err.to_string(),
+            ))
+        })?;
+        if !signed.insert(signature.pubkey.clone()) {
+            return Err(Error::from(TxSubmitError::DuplicateSignature(
+                signature.pubkey.to_string(),
+            )));
+        }
+        signature.index = Some((owner.clone(), idx));
+        added.push(signature);
+    }
+    Ok(MultisigSignatures {
+        attached,
+        added,
+        threshold,
+    })
+}
+
 /// Return the necessary data regarding an account to be able to generate a
 /// multisignature section
 pub async fn aux_signing_data(
@@ -417,22 +550,17 @@ pub async fn validate_fee<N: Namada>(
     context: &N,
     args: &args::Tx<SdkTypes>,
 ) -> Result<DenominatedAmount, Error> {
-    let gas_cost_key = parameter_storage::get_gas_cost_key();
-    let minimum_fee = match rpc::query_storage_value::<
-        _,
-        BTreeMap<Address, Amount>,
-    >(context.client(), &gas_cost_key)
-    .await
-    .and_then(|map| {
-        map.get(&args.fee_token)
-            .map(ToOwned::to_owned)
-            .ok_or_else(|| {
+    let base_fee = rpc::query_base_fee(context.client(), &args.fee_token)
+        .await
+        .and_then(|base_fee| {
+            base_fee.ok_or_else(|| {
                 Error::Other(format!(
                     "Could not retrieve from storage the gas cost for token {}",
                     args.fee_token
                 ))
             })
-    }) {
+        });
+    let minimum_fee = match base_fee {
         Ok(amount) => amount,
         Err(e) => {
             if !args.force {
@@ -484,16 +612,28 @@ pub async fn validate_transparent_fee<N: Namada>(
 ) -> Result<(DenominatedAmount, TxSourcePostBalance), Error> {
     let fee_amount = validate_fee(context, args).await?;
     let fee_payer_address = Address::from(fee_payer);
+    let total_fee = checked!(fee_amount.amount() * u64::from(args.gas_limit))?;
+
+    // The fees are paid by the sponsor, if any
+    if let Some(sponsor) = &args.fee_sponsor {
+        validate_fee_grant(
+            context,
+            args,
+            sponsor,
+            &fee_payer_address,
+            total_fee,
+        )
+        .await?;
+    }
+    let fee_source = args.fee_sponsor.as_ref().unwrap_or(&fee_payer_address);
 
-    let balance_key = balance_key(&args.fee_token, &fee_payer_address);
     let balance = rpc::query_storage_value::<_, token::Amount>(
         context.client(),
-        &balance_key,
+        &balance_key(&args.fee_token, fee_source),
     )
     .await
     .unwrap_or_default();
 
-    let total_fee = checked!(fee_amount.amount() * u64::from(args.gas_limit))?;
     let mut updated_balance = TxSourcePostBalance {
         post_balance: balance,
         source: fee_payer_address.clone(),
@@ -509,7 +649,7 @@ pub async fn validate_transparent_fee<N: Namada>(
 
                 let balance = context.format_amount(&token_addr, balance).await;
                 return Err(Error::from(TxSubmitError::BalanceTooLowForFees(
-                    fee_payer_address,
+                    fee_source.clone(),
                     token_addr,
                     fee_amount,
                     balance,
@@ -524,9 +664,66 @@ pub async fn validate_transparent_fee<N: Namada>(
         }
     };
 
+    if args.fee_sponsor.is_some() {
+        // The balance of the fee payer is left untouched
+        updated_balance.post_balance =
+            rpc::query_storage_value::<_, token::Amount>(
+                context.client(),
+                &balance_key(&args.fee_token, &fee_payer_address),
+            )
+            .await
+            .unwrap_or_default();
+    }
+
     Ok((fee_amount, updated_balance))
 }
 
+/// Validate that the fee grant of the sponsor to the fee payer can pay the
+/// fees. The allowed tx codes of the grant are only checked by the protocol.
+async fn validate_fee_grant<N: Namada>(
+    context: &N,
+    args: &args::Tx<SdkTypes>,
+    sponsor: &Address,
+    fee_payer: &Address,
+    total_fee: Amount,
+) -> Result<(), Error> {
+    let invalid_grant = |reason: String| {
+        Error::from(TxSubmitError::InvalidFeeGrant(
+            sponsor.clone(),
+            fee_payer.clone(),
+            reason,
+        ))
+    };
+    let grant_key = fee_grant_key(&args.fee_token, sponsor, fee_payer);
+    if !rpc::query_has_storage_key(context.client(), &grant_key).await? {
+        if args.force {
+            return Ok(());
+        }
+        return Err(invalid_grant("the fee grant doesn't exist".to_string()));
+    }
+    let grant = rpc::query_storage_value::<_, token::FeeGrant>(
+        context.client(),
+        &grant_key,
+    )
+    .await?;
+    let epoch = rpc::query_epoch(context.client()).await?;
+    let reason = if grant.is_expired(epoch) {
+        "the fee grant has expired".to_string()
+    } else if grant.amount < total_fee {
+        let allowance =
+            context.format_amount(&args.fee_token, grant.amount).await;
+        let fees = context.format_amount(&args.fee_token, total_fee).await;
+        format!("the allowance {allowance} is lower than the fees {fees}")
+    } else {
+        return Ok(());
+    };
+    if args.force {
+        Ok(())
+    } else {
+        Err(invalid_grant(reason))
+    }
+}
+
 /// Create a wrapper tx from a normal tx. Get the hash of the
 /// wrapper and its payload which is needed for monitoring its
 /// progress on chain.
@@ -545,6 +742,9 @@ pub async fn wrap_tx(
         // TODO(namada#1625): partially validate the gas limit in client
         args.gas_limit,
     );
+    if let Some(sponsor) = &args.fee_sponsor {
+        tx.add_fee_sponsor(sponsor.clone());
+    }
 
     Ok(())
 }
@@ -598,6 +798,32 @@ fn make_ledger_amount_addr(
     }
 }
 
+/// Adds Ledger output lines describing the relayer fees of an IBC packet
+fn make_ledger_packet_fee(
+    tokens: &HashMap<Address, String>,
+    output: &mut Vec<String>,
+    packet_fee: &PacketFee,
+) {
+    let fee = &packet_fee.fee;
+    for (amount, prefix) in [
+        (fee.recv_fee, "Recv fee "),
+        (fee.ack_fee, "Ack fee "),
+        (fee.timeout_fee, "Timeout fee "),
+    ] {
+        make_ledger_amount_addr(
+            tokens,
+            output,
+            amount.into(),
+            &fee.token,
+            prefix,
+        );
+    }
+    output.push(format!(
+        "Fee refund address : {}",
+        packet_fee.refund_address
+    ));
+}
+
 /// Adds a Ledger output line describing a given transaction amount and asset
 /// type
 async fn make_ledger_amount_asset(
@@ -940,6 +1166,16 @@ fn proposal_type_to_ledger_vector(
                 }
             }
         }
+        ProposalType::ParameterChange(changes) => {
+            output.push("Proposal type : Parameter Change".to_string());
+            for change in changes {
+                output.push(format!(
+                    "Parameter : {}.{}",
+                    change.module, change.field
+                ));
+                output.push(format!("Value : {}", change.value));
+            }
+        }
     }
 }
 
@@ -1195,6 +1431,35 @@ pub async fn to_ledger_vector(
                 format!("Vote : {}", LedgerProposalVote(&vote_proposal.vote)),
                 format!("Voter : {}", vote_proposal.voter),
             ]);
+        } else if code_sec.tag == Some(TX_UPDATE_PROPOSAL.to_string()) {
+            let update_proposal = UpdateProposalData::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Update_Proposal_0".to_string();
+
+            let update = match &update_proposal.update {
+                ProposalUpdate::Cancel => "Update : Cancel".to_string(),
+                ProposalUpdate::Amend(hash) => {
+                    format!("Content : {}", HEXLOWER.encode(&hash.0))
+                }
+            };
+            tv.output.extend(vec![
+                format!("Type : Update Proposal"),
+                format!("ID : {}", update_proposal.id),
+                format!("Author : {}", update_proposal.author),
+                update.clone(),
+            ]);
+
+            tv.output_expert.extend(vec![
+                format!("ID : {}", update_proposal.id),
+                format!("Author : {}", update_proposal.author),
+                update,
+            ]);
         } else if code_sec.tag == Some(TX_REVEAL_PK.to_string()) {
             let public_key = common::PublicKey::try_from_slice(
                 &tx.data(cmt)
@@ -1383,6 +1648,14 @@ pub async fn to_ledger_vector(
                                 .to_rfc3339())
                     ),
                 ]);
+                if let Some(packet_fee) = &transfer.fee {
+                    make_ledger_packet_fee(&tokens, &mut tv.output, packet_fee);
+                    make_ledger_packet_fee(
+                        &tokens,
+                        &mut tv.output_expert,
+                        packet_fee,
+                    );
+                }
                 if let Some(transfer) = transfer.transfer {
                     // To facilitate lookups of MASP AssetTypes
                     let mut asset_types = HashMap::new();
@@ -1555,6 +1828,14 @@ pub async fn to_ledger_vector(
                                 .to_rfc3339())
                     ),
                 ]);
+                if let Some(packet_fee) = &transfer.fee {
+                    make_ledger_packet_fee(&tokens, &mut tv.output, packet_fee);
+                    make_ledger_packet_fee(
+                        &tokens,
+                        &mut tv.output_expert,
+                        packet_fee,
+                    );
+                }
                 if let Some(transfer) = transfer.transfer {
                     // To facilitate lookups of MASP AssetTypes
                     let mut asset_types = HashMap::new();
@@ -1581,6 +1862,85 @@ pub async fn to_ledger_vector(
                     )
                     .await?;
                 }
+            } else if let Ok(msg) =
+                MsgInterchainAccount::try_from_slice(data.as_ref())
+            {
+                let other_items = match msg {
+                    MsgInterchainAccount::Register(msg) => {
+                        tv.name = "IBC_Register_ICA_0".to_string();
+                        tv.output.push(
+                            "Type : IBC Register Interchain Account"
+                                .to_string(),
+                        );
+                        vec![
+                            format!("Owner : {}", msg.owner),
+                            format!("Connection : {}", msg.connection_id),
+                        ]
+                    }
+                    MsgInterchainAccount::SendTx(msg) => {
+                        tv.name = "IBC_Send_ICA_Tx_0".to_string();
+                        tv.output.push(
+                            "Type : IBC Send Interchain Account Tx".to_string(),
+                        );
+                        vec![
+                            format!("Owner : {}", msg.owner),
+                            format!("Connection : {}", msg.connection_id),
+                            format!(
+                                "Packet data : {}",
+                                String::from_utf8_lossy(&msg.packet_data)
+                            ),
+                        ]
+                    }
+                };
+                tv.output.extend(other_items.clone());
+                tv.output_expert.extend(other_items);
+            } else if let Ok(msg) = MsgFee::try_from_slice(data.as_ref()) {
+                let other_items = match msg {
+                    MsgFee::RegisterPayee(msg) => {
+                        tv.name = "IBC_Register_Payee_0".to_string();
+                        tv.output.push("Type : IBC Register Payee".to_string());
+                        vec![
+                            format!("Port : {}", msg.port_id),
+                            format!("Channel : {}", msg.channel_id),
+                            format!("Relayer : {}", msg.relayer),
+                            format!("Payee : {}", msg.payee),
+                        ]
+                    }
+                    MsgFee::RegisterCounterpartyPayee(msg) => {
+                        tv.name =
+                            "IBC_Register_Counterparty_Payee_0".to_string();
+                        tv.output.push(
+                            "Type : IBC Register Counterparty Payee"
+                                .to_string(),
+                        );
+                        vec![
+                            format!("Port : {}", msg.port_id),
+                            format!("Channel : {}", msg.channel_id),
+                            format!("Relayer : {}", msg.relayer),
+                            format!(
+                                "Counterparty payee : {}",
+                                msg.counterparty_payee
+                            ),
+                        ]
+                    }
+                    MsgFee::PayPacketFeeAsync(msg) => {
+                        tv.name = "IBC_Pay_Packet_Fee_0".to_string();
+                        tv.output.push("Type : IBC Pay Packet Fee".to_string());
+                        let mut items = vec![
+                            format!("Port : {}", msg.port_id),
+                            format!("Channel : {}", msg.channel_id),
+                            format!("Sequence : {}", msg.sequence),
+                        ];
+                        make_ledger_packet_fee(
+                            &tokens,
+                            &mut items,
+                            &msg.packet_fee,
+                        );
+                        items
+                    }
+                };
+                tv.output.extend(other_items.clone());
+                tv.output_expert.extend(other_items);
             } else {
                 return Result::Err(Error::Other("Invalid Data".to_string()));
             }
@@ -1695,6 +2055,226 @@ pub async fn to_ledger_vector(
             }
             tv.output_expert
                 .push(format!("Validator : {}", claim.validator));
+        } else if code_sec.tag == Some(TX_AUTO_RESTAKE_WASM.to_string()) {
+            let auto_restake = pos::AutoRestake::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Auto_Restake_0".to_string();
+
+            tv.output.push("Type : Auto Restake".to_string());
+            if let Some(source) = auto_restake.source.as_ref() {
+                tv.output.push(format!("Source : {}", source));
+            }
+            tv.output
+                .push(format!("Validator : {}", auto_restake.validator));
+            tv.output
+                .push(format!("Enabled : {}", auto_restake.enabled));
+
+            if let Some(source) = auto_restake.source.as_ref() {
+                tv.output_expert.push(format!("Source : {}", source));
+            }
+            tv.output_expert
+                .push(format!("Validator : {}", auto_restake.validator));
+            tv.output_expert
+                .push(format!("Enabled : {}", auto_restake.enabled));
+        } else if code_sec.tag == Some(TX_CREATE_TOKEN_WASM.to_string()) {
+            let create_token = token::CreateToken::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Create_Token_0".to_string();
+
+            let mut other_items = vec![
+                format!("Admin : {}", create_token.admin),
+                format!("Token : {}", create_token.token()),
+                format!("Name : {}", create_token.metadata.name),
+                format!("Symbol : {}", create_token.metadata.symbol),
+                format!("Denomination : {}", create_token.denom),
+            ];
+            if let Some(minter) = &create_token.minter {
+                other_items.push(format!("Minter : {}", minter));
+            }
+            if let Some(description) = &create_token.metadata.description {
+                other_items.push(format!("Description : {}", description));
+            }
+            if let Some(supply_cap) = &create_token.supply_cap {
+                other_items.push(format!("Supply cap : {}", supply_cap));
+            }
+
+            tv.output.push("Type : Create Token".to_string());
+            tv.output.extend(other_items.clone());
+            tv.output_expert.extend(other_items);
+        } else if code_sec.tag == Some(TX_MINT_TOKENS_WASM.to_string())
+            || code_sec.tag == Some(TX_BURN_TOKENS_WASM.to_string())
+        {
+            let supply_change = token::MintTokens::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            let (name, tx_type, owner_label) =
+                if code_sec.tag == Some(TX_MINT_TOKENS_WASM.to_string()) {
+                    ("Mint_Tokens_0", "Mint Tokens", "Target")
+                } else {
+                    ("Burn_Tokens_0", "Burn Tokens", "Source")
+                };
+            tv.name = name.to_string();
+
+            let other_items = vec![
+                format!("Token : {}", supply_change.token),
+                format!("Authority : {}", supply_change.authority),
+                format!("{} : {}", owner_label, supply_change.owner),
+                format!("Amount : {}", supply_change.amount),
+            ];
+
+            tv.output.push(format!("Type : {}", tx_type));
+            tv.output.extend(other_items.clone());
+            tv.output_expert.extend(other_items);
+        } else if code_sec.tag == Some(TX_UPDATE_TOKEN_WASM.to_string()) {
+            let update_token = token::UpdateToken::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Update_Token_0".to_string();
+
+            let mut other_items = vec![
+                format!("Token : {}", update_token.token),
+                format!("Admin : {}", update_token.admin),
+            ];
+            if let Some(new_admin) = &update_token.new_admin {
+                other_items.push(format!("New admin : {}", new_admin));
+            }
+            if let Some(new_minter) = &update_token.new_minter {
+                other_items.push(format!("New minter : {}", new_minter));
+            }
+            if let Some(metadata) = &update_token.new_metadata {
+                other_items.push(format!("Name : {}", metadata.name));
+                other_items.push(format!("Symbol : {}", metadata.symbol));
+                if let Some(description) = &metadata.description {
+                    other_items.push(format!("Description : {}", description));
+                }
+            }
+
+            tv.output.push("Type : Update Token".to_string());
+            tv.output.extend(other_items.clone());
+            tv.output_expert.extend(other_items);
+        } else if code_sec.tag == Some(TX_APPROVE_WASM.to_string()) {
+            let approve = token::Approve::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Approve_0".to_string();
+
+            let mut other_items = vec![
+                format!("Token : {}", approve.token),
+                format!("Owner : {}", approve.owner),
+                format!("Spender : {}", approve.spender),
+                format!("Amount : {}", approve.amount),
+            ];
+            if let Some(expiry) = &approve.expiry {
+                other_items.push(format!("Expiry : {}", expiry));
+            }
+
+            tv.output.push("Type : Approve".to_string());
+            tv.output.extend(other_items.clone());
+            tv.output_expert.extend(other_items);
+        } else if code_sec.tag == Some(TX_TRANSFER_FROM_WASM.to_string()) {
+            let transfer_from = token::TransferFrom::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Transfer_From_0".to_string();
+
+            let other_items = vec![
+                format!("Token : {}", transfer_from.token),
+                format!("Spender : {}", transfer_from.spender),
+                format!("Owner : {}", transfer_from.owner),
+                format!("Target : {}", transfer_from.target),
+                format!("Amount : {}", transfer_from.amount),
+            ];
+
+            tv.output.push("Type : Transfer From".to_string());
+            tv.output.extend(other_items.clone());
+            tv.output_expert.extend(other_items);
+        } else if code_sec.tag == Some(TX_VEST_TOKENS_WASM.to_string()) {
+            let vest = token::VestTokens::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Vest_Tokens_0".to_string();
+
+            let other_items = vec![
+                format!("Token : {}", vest.token),
+                format!("Source : {}", vest.source),
+                format!("Target : {}", vest.target),
+                format!("Amount : {}", vest.schedule.amount),
+                format!("Start epoch : {}", vest.schedule.start_epoch),
+                format!("Cliff epoch : {}", vest.schedule.cliff_epoch),
+                format!("End epoch : {}", vest.schedule.end_epoch),
+            ];
+
+            tv.output.push("Type : Vest Tokens".to_string());
+            tv.output.extend(other_items.clone());
+            tv.output_expert.extend(other_items);
+        } else if code_sec.tag == Some(TX_GRANT_FEE_WASM.to_string()) {
+            let grant_fee = token::GrantFee::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Grant_Fee_0".to_string();
+
+            let mut other_items = vec![
+                format!("Token : {}", grant_fee.token),
+                format!("Sponsor : {}", grant_fee.sponsor),
+                format!("Grantee : {}", grant_fee.grantee),
+                format!("Amount : {}", grant_fee.grant.amount),
+            ];
+            other_items.extend(
+                grant_fee
+                    .grant
+                    .allowed_code_hashes
+                    .iter()
+                    .map(|hash| format!("Allowed code : {hash}")),
+            );
+            if let Some(expiry) = grant_fee.grant.expiry {
+                other_items.push(format!("Expiry : {expiry}"));
+            }
+
+            tv.output.push("Type : Grant Fee".to_string());
+            tv.output.extend(other_items.clone());
+            tv.output_expert.extend(other_items);
         } else if code_sec.tag == Some(TX_CHANGE_COMMISSION_WASM.to_string()) {
             let commission_change = pos::CommissionChange::try_from_slice(
                 &tx.data(cmt)
@@ -2010,3 +2590,97 @@ pub async fn to_ledger_vector(
     format_outputs(&mut tv.output_expert);
     Ok(tv)
 }
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::established_address_1;
+    use namada_core::chain::ChainId;
+    use namada_core::key::testing::{
+        keypair_1, keypair_2, keypair_3, keypair_4,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_merge_signatures() {
+        let owner = established_address_1();
+        let public_keys_map = AccountPublicKeysMap::from_iter([
+            keypair_1().ref_to(),
+            keypair_2().ref_to(),
+            keypair_3().ref_to(),
+        ]);
+        let mut tx = Tx::new(ChainId::default(), None);
+        tx.add_serialized_data(vec![1, 2, 3]);
+        let sign = |tx: &Tx, keypair: common::SecretKey| {
+            tx.compute_section_signature(
+                &[keypair],
+                &public_keys_map,
+                Some(owner.clone()),
+            )
+        };
+
+        let merged = merge_signatures(
+            &tx,
+            &owner,
+            &public_keys_map,
+            2,
+            sign(&tx, keypair_1()),
+        )
+        .unwrap();
+        assert_eq!(merged.count(), 1);
+        assert_eq!(merged.missing(), 1);
+        assert!(!merged.is_complete());
+        tx.add_signatures(merged.added);
+
+        // The attached signature can't be added again
+        let err = merge_signatures(
+            &tx,
+            &owner,
+            &public_keys_map,
+            2,
+            sign(&tx, keypair_1()),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("already signed"));
+
+        // A key out of the account can't sign
+        let non_member = SignatureIndex::from_single_signature(
+            keypair_4().ref_to(),
+            sign(&tx, keypair_2()).remove(0).signature,
+        );
+        let err = merge_signatures(
+            &tx,
+            &owner,
+            &public_keys_map,
+            2,
+            vec![non_member],
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("not a member"));
+
+        // A signature over another tx is invalid
+        let mut other_tx = Tx::new(ChainId::default(), None);
+        other_tx.add_serialized_data(vec![4, 5, 6]);
+        let err = merge_signatures(
+            &tx,
+            &owner,
+            &public_keys_map,
+            2,
+            sign(&other_tx, keypair_2()),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("is invalid"));
+
+        let merged = merge_signatures(
+            &tx,
+            &owner,
+            &public_keys_map,
+            2,
+            sign(&tx, keypair_2()),
+        )
+        .unwrap();
+        assert_eq!(merged.attached.len(), 1);
+        assert_eq!(merged.added.len(), 1);
+        assert!(merged.is_complete());
+    }
+}
@@ -1,7 +1,7 @@
 //! SDK functions to construct different types of transactions
 
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
@@ -44,18 +44,29 @@ use namada_core::storage;
 use namada_core::storage::Epoch;
 use namada_core::time::DateTimeUtc;
 use namada_governance::cli::onchain::{
-    DefaultProposal, OnChainProposal, PgfFundingProposal, PgfStewardProposal,
+    DefaultProposal, OnChainProposal, ParameterChangeProposal,
+    PgfFundingProposal, PgfStewardProposal,
 };
+use namada_governance::cli::validation::is_valid_content;
+use namada_governance::parameters::GovernanceParameters;
 use namada_governance::pgf::cli::steward::Commission;
+use namada_governance::pgf::parameters::PgfParameters;
 use namada_governance::storage::proposal::{
-    InitProposalData, ProposalType, VoteProposalData,
+    InitProposalData, ParameterChange, ParameterModule, ProposalType,
+    ProposalUpdate, UpdateProposalData, VoteProposalData,
 };
 use namada_governance::storage::vote::ProposalVote;
-use namada_ibc::storage::channel_key;
+use namada_ibc::fee::{Fee, PacketFee};
+use namada_ibc::ica;
+use namada_ibc::storage::{channel_key, ica_controller_account_key};
 use namada_ibc::trace::is_nft_trace;
-use namada_ibc::{IbcShieldingData, MsgNftTransfer, MsgTransfer};
+use namada_ibc::{
+    IbcShieldingData, MsgFee, MsgInterchainAccount, MsgNftTransfer,
+    MsgRegisterCounterpartyPayee, MsgRegisterInterchainAccount,
+    MsgRegisterPayee, MsgSendTx, MsgTransfer,
+};
 use namada_proof_of_stake::parameters::{
-    PosParams, MAX_VALIDATOR_METADATA_LEN,
+    OwnedPosParams, PosParams, MAX_VALIDATOR_METADATA_LEN,
 };
 use namada_proof_of_stake::types::{CommissionPair, ValidatorState};
 use namada_token as token;
@@ -109,6 +120,8 @@ pub const TX_REACTIVATE_VALIDATOR_WASM: &str = "tx_reactivate_validator.wasm";
 pub const TX_INIT_PROPOSAL: &str = "tx_init_proposal.wasm";
 /// Vote transaction WASM path
 pub const TX_VOTE_PROPOSAL: &str = "tx_vote_proposal.wasm";
+/// Update proposal transaction WASM path
+pub const TX_UPDATE_PROPOSAL: &str = "tx_update_proposal.wasm";
 /// Reveal public key transaction WASM path
 pub const TX_REVEAL_PK: &str = "tx_reveal_pk.wasm";
 /// Update validity predicate WASM path
@@ -127,6 +140,24 @@ pub const TX_UNBOND_WASM: &str = "tx_unbond.wasm";
 pub const TX_WITHDRAW_WASM: &str = "tx_withdraw.wasm";
 /// Claim-rewards WASM path
 pub const TX_CLAIM_REWARDS_WASM: &str = "tx_claim_rewards.wasm";
+/// Auto-restake WASM path
+pub const TX_AUTO_RESTAKE_WASM: &str = "tx_auto_restake.wasm";
+/// Approve WASM path
+pub const TX_APPROVE_WASM: &str = "tx_approve.wasm";
+/// Transfer from WASM path
+pub const TX_TRANSFER_FROM_WASM: &str = "tx_transfer_from.wasm";
+/// Vest tokens WASM path
+pub const TX_VEST_TOKENS_WASM: &str = "tx_vest_tokens.wasm";
+/// Grant fee WASM path
+pub const TX_GRANT_FEE_WASM: &str = "tx_grant_fee.wasm";
+/// Create token WASM path
+pub const TX_CREATE_TOKEN_WASM: &str = "tx_create_token.wasm";
+/// Mint tokens WASM path
+pub const TX_MINT_TOKENS_WASM: &str = "tx_mint_tokens.wasm";
+/// Burn tokens WASM path
+pub const TX_BURN_TOKENS_WASM: &str = "tx_burn_tokens.wasm";
+/// Update token WASM path
+pub const TX_UPDATE_TOKEN_WASM: &str = "tx_update_token.wasm";
 /// Bridge pool WASM path
 pub const TX_BRIDGE_POOL_WASM: &str = "tx_bridge_pool.wasm";
 /// Change commission WASM path
@@ -1625,6 +1656,63 @@ pub async fn build_claim_rewards(
     .map(|tx| (tx, signing_data))
 }
 
+/// Submit transaction to enable or disable the automatic restaking of a bond's
+/// rewards
+pub async fn build_auto_restake(
+    context: &impl Namada,
+    args::AutoRestake {
+        tx: tx_args,
+        validator,
+        source,
+        enabled,
+        tx_code_path,
+    }: &args::AutoRestake,
+) -> Result<(Tx, SigningTxData)> {
+    let default_address = source.clone().unwrap_or(validator.clone());
+    let default_signer = Some(default_address.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(default_address),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    // Check that the validator address is actually a validator
+    let validator =
+        known_validator_or_err(validator.clone(), tx_args.force, context)
+            .await?;
+
+    // Check that the source address exists on chain
+    let source = match source.clone() {
+        Some(source) => source_exists_or_err(source, tx_args.force, context)
+            .await
+            .map(Some),
+        None => Ok(source.clone()),
+    }?;
+
+    let data = pos::AutoRestake {
+        validator,
+        source,
+        enabled: *enabled,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
 /// Submit a transaction to unbond
 pub async fn build_unbond(
     context: &impl Namada,
@@ -1982,6 +2070,7 @@ pub async fn build_default_proposal(
         proposal_data: _,
         is_pgf_stewards: _,
         is_pgf_funding: _,
+        is_parameter_change: _,
         tx_code_path,
     }: &args::InitProposal,
     proposal: DefaultProposal,
@@ -2178,6 +2267,107 @@ pub async fn build_vote_proposal(
     .map(|tx| (tx, signing_data))
 }
 
+/// Build a transaction to cancel or amend a proposal
+pub async fn build_update_proposal(
+    context: &impl Namada,
+    args::UpdateProposal {
+        tx,
+        proposal_id,
+        proposal_content,
+        tx_code_path,
+    }: &args::UpdateProposal,
+    current_epoch: Epoch,
+) -> Result<(Tx, SigningTxData)> {
+    let proposal = if let Some(proposal) =
+        rpc::query_proposal_by_id(context.client(), *proposal_id).await?
+    {
+        proposal
+    } else {
+        return Err(Error::from(TxSubmitError::ProposalDoesNotExist(
+            *proposal_id,
+        )));
+    };
+
+    // Check that the voting period hasn't started yet
+    if current_epoch >= proposal.voting_start_epoch {
+        edisplay_line!(
+            context.io(),
+            "Proposal {} cannot be updated anymore, its voting period started \
+             at epoch {}.",
+            proposal_id,
+            proposal.voting_start_epoch
+        );
+        if !tx.force {
+            return Err(Error::from(TxSubmitError::ProposalCannotBeUpdated(
+                *proposal_id,
+            )));
+        }
+    }
+
+    let content: Option<BTreeMap<String, String>> = proposal_content
+        .as_ref()
+        .map(|content| serde_json::from_slice(content))
+        .transpose()
+        .map_err(|e| {
+            TxSubmitError::FailedGovernaneProposalDeserialize(e.to_string())
+        })?;
+    if let Some(content) = &content {
+        let governance_parameters =
+            rpc::query_governance_parameters(context.client()).await;
+        if let Err(err) = is_valid_content(
+            content,
+            governance_parameters.max_proposal_content_size,
+        ) {
+            edisplay_line!(context.io(), "Invalid proposal content: {}", err);
+            if !tx.force {
+                return Err(Error::from(TxSubmitError::InvalidProposal(
+                    err.to_string(),
+                )));
+            }
+        }
+    }
+
+    let default_signer = Some(proposal.author.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx,
+        default_signer.clone(),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx, &signing_data.fee_payer).await?;
+
+    let data = UpdateProposalData {
+        id: *proposal_id,
+        author: proposal.author,
+        update: ProposalUpdate::Cancel,
+    };
+
+    let add_section = |tx: &mut Tx, data: &mut UpdateProposalData| {
+        if let Some(content) = content {
+            let content = borsh::to_vec(&content).map_err(|e| {
+                Error::from(EncodingError::Conversion(e.to_string()))
+            })?;
+            let (_, extra_section_hash) = tx.add_extra_section(content, None);
+            data.update = ProposalUpdate::Amend(extra_section_hash);
+        }
+        Ok(())
+    };
+
+    build(
+        context,
+        tx,
+        tx_code_path.clone(),
+        data,
+        add_section,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
 /// Build a pgf funding proposal governance
 pub async fn build_become_validator(
     context: &impl Namada,
@@ -2373,6 +2563,7 @@ pub async fn build_pgf_funding_proposal(
         proposal_data: _,
         is_pgf_stewards: _,
         is_pgf_funding: _,
+        is_parameter_change: _,
         tx_code_path,
     }: &args::InitProposal,
     proposal: PgfFundingProposal,
@@ -2418,6 +2609,7 @@ pub async fn build_pgf_stewards_proposal(
         proposal_data: _,
         is_pgf_stewards: _,
         is_pgf_funding: _,
+        is_parameter_change: _,
         tx_code_path,
     }: &args::InitProposal,
     proposal: PgfStewardProposal,
@@ -2456,6 +2648,143 @@ pub async fn build_pgf_stewards_proposal(
     .map(|tx| (tx, signing_data))
 }
 
+/// Submit a proposal to change some protocol parameters
+pub async fn build_parameter_change_proposal(
+    context: &impl Namada,
+    args::InitProposal {
+        tx,
+        proposal_data: _,
+        is_pgf_stewards: _,
+        is_pgf_funding: _,
+        is_parameter_change: _,
+        tx_code_path,
+    }: &args::InitProposal,
+    proposal: ParameterChangeProposal,
+) -> Result<(Tx, SigningTxData)> {
+    let changes = proposal
+        .data
+        .iter()
+        .cloned()
+        .map(ParameterChange::from)
+        .collect();
+    check_parameter_changes(context, &changes, tx.force).await?;
+
+    let default_signer = Some(proposal.proposal.author.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx,
+        Some(proposal.proposal.author.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _updated_balance) =
+        validate_transparent_fee(context, tx, &signing_data.fee_payer).await?;
+
+    let init_proposal_data = InitProposalData::try_from(proposal.clone())
+        .map_err(|e| TxSubmitError::InvalidProposal(e.to_string()))?;
+
+    let add_section = |tx: &mut Tx, data: &mut InitProposalData| {
+        let (_, extra_section_hash) =
+            tx.add_extra_section(proposal_to_vec(proposal.proposal)?, None);
+        data.content = extra_section_hash;
+        Ok(())
+    };
+
+    build(
+        context,
+        tx,
+        tx_code_path.clone(),
+        init_proposal_data,
+        add_section,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Check that the parameters resulting from the given changes are valid
+/// against the current parameters of the chain. The MASP parameters are only
+/// checked by the protocol when the proposal gets executed.
+async fn check_parameter_changes(
+    context: &impl Namada,
+    changes: &BTreeSet<ParameterChange>,
+    force: bool,
+) -> Result<()> {
+    fn collect_errors<T, E: std::fmt::Display>(
+        module: &ParameterModule,
+        params: &T,
+        changes: &BTreeSet<ParameterChange>,
+        validate: impl FnOnce(&T) -> Vec<E>,
+    ) -> Vec<String>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        match ParameterChange::apply(module, params, changes) {
+            Ok(params) => validate(&params)
+                .iter()
+                .map(|err| format!("{module}: {err}"))
+                .collect(),
+            Err(err) => vec![err.to_string()],
+        }
+    }
+
+    let modules = changes
+        .iter()
+        .map(|change| &change.module)
+        .collect::<BTreeSet<_>>();
+
+    let mut errors = vec![];
+    for module in modules {
+        match module {
+            ParameterModule::Pos => {
+                let params = rpc::get_pos_params(context.client()).await?;
+                errors.extend(collect_errors(
+                    module,
+                    &params.owned,
+                    changes,
+                    OwnedPosParams::validate,
+                ));
+            }
+            ParameterModule::Governance => {
+                let params =
+                    rpc::query_governance_parameters(context.client()).await;
+                errors.extend(collect_errors(
+                    module,
+                    &params,
+                    changes,
+                    GovernanceParameters::validate,
+                ));
+            }
+            ParameterModule::Pgf => {
+                let params = rpc::query_pgf_parameters(context.client()).await;
+                errors.extend(collect_errors(
+                    module,
+                    &params,
+                    changes,
+                    PgfParameters::validate,
+                ));
+            }
+            ParameterModule::Masp(_) => {}
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else if force {
+        edisplay_line!(
+            context.io(),
+            "The proposed parameters are invalid: {}",
+            errors.join(", ")
+        );
+        Ok(())
+    } else {
+        Err(Error::from(TxSubmitError::InvalidProposal(
+            errors.join(", "),
+        )))
+    }
+}
+
 /// Submit an IBC transfer
 pub async fn build_ibc_transfer(
     context: &impl Namada,
@@ -2526,6 +2855,9 @@ pub async fn build_ibc_transfer(
         .await?;
     }
 
+    // The relayer fees escrowed with the packet
+    let fee = get_ibc_packet_fee(context, args).await?;
+
     let tx_code_hash =
         query_wasm_code_hash(context, args.tx_code_path.to_str().unwrap())
             .await
@@ -2693,7 +3025,12 @@ pub async fn build_ibc_transfer(
             timeout_height_on_b: timeout_height,
             timeout_timestamp_on_b: timeout_timestamp,
         };
-        MsgTransfer { message, transfer }.serialize_to_vec()
+        MsgTransfer {
+            message,
+            transfer,
+            fee,
+        }
+        .serialize_to_vec()
     } else if let Some((trace_path, base_class_id, token_id)) =
         is_nft_trace(&ibc_denom)
     {
@@ -2724,7 +3061,12 @@ pub async fn build_ibc_transfer(
             timeout_height_on_b: timeout_height,
             timeout_timestamp_on_b: timeout_timestamp,
         };
-        MsgNftTransfer { message, transfer }.serialize_to_vec()
+        MsgNftTransfer {
+            message,
+            transfer,
+            fee,
+        }
+        .serialize_to_vec()
     } else {
         return Err(Error::Other(format!("Invalid IBC denom: {ibc_denom}")));
     };
@@ -2746,55 +3088,348 @@ pub async fn build_ibc_transfer(
     Ok((tx, signing_data, shielded_tx_epoch))
 }
 
-/// Abstraction for helping build transactions
-#[allow(clippy::too_many_arguments)]
-async fn build<F, D>(
+/// Get the relayer fees paid for the IBC packet. The fees are paid by the
+/// transparent source.
+async fn get_ibc_packet_fee(
     context: &impl Namada,
-    tx_args: &crate::args::Tx,
-    path: PathBuf,
-    mut data: D,
-    on_tx: F,
-    fee_amount: DenominatedAmount,
-    gas_payer: &common::PublicKey,
-) -> Result<Tx>
-where
-    F: FnOnce(&mut Tx, &mut D) -> Result<()>,
-    D: BorshSerialize,
-{
-    let chain_id = tx_args.chain_id.clone().unwrap();
-
-    let mut tx_builder = Tx::new(chain_id, tx_args.expiration.to_datetime());
-    if let Some(memo) = &tx_args.memo {
-        tx_builder.add_memo(memo);
+    args: &args::TxIbcTransfer,
+) -> Result<Option<PacketFee>> {
+    let fees = [args.recv_fee, args.ack_fee, args.timeout_fee];
+    let Some(token) = &args.relayer_fee_token else {
+        if fees.iter().any(Option::is_some) {
+            return Err(Error::Other(
+                "The token of the relayer fees isn't specified".to_string(),
+            ));
+        }
+        return Ok(None);
+    };
+    let Some(refund_address) = args.source.address() else {
+        return Err(Error::Other(
+            "The relayer fees can't be paid from a shielded source".to_string(),
+        ));
+    };
+    if !rpc::query_ibc_fee_enabled(
+        context.client(),
+        &args.port_id,
+        &args.channel_id,
+    )
+    .await?
+    {
+        edisplay_line!(
+            context.io(),
+            "The channel {} isn't fee-enabled.",
+            args.channel_id
+        );
+        if !args.tx.force {
+            return Err(Error::Other(format!(
+                "The channel {} isn't fee-enabled",
+                args.channel_id
+            )));
+        }
     }
 
-    let tx_code_hash = query_wasm_code_hash(context, path.to_string_lossy())
-        .await
-        .map_err(|e| Error::from(QueryError::Wasm(e.to_string())))?;
-
-    on_tx(&mut tx_builder, &mut data)?;
-
-    tx_builder
-        .add_code_from_hash(
-            tx_code_hash,
-            Some(path.to_string_lossy().into_owned()),
-        )
-        .add_data(data);
+    let mut amounts = [token::Amount::zero(); 3];
+    for (amount, fee) in amounts.iter_mut().zip(fees) {
+        if let Some(fee) = fee {
+            *amount = validate_amount(context, fee, token, args.tx.force)
+                .await?
+                .amount();
+        }
+    }
+    let [recv_fee, ack_fee, timeout_fee] = amounts;
+    let fee = Fee {
+        token: token.clone(),
+        recv_fee,
+        ack_fee,
+        timeout_fee,
+    };
+    let total = fee.total().map_err(|e| Error::Other(e.to_string()))?;
+    if total.is_zero() {
+        return Err(Error::Other("All the relayer fees are zero".to_string()));
+    }
+    check_balance_too_low_err(
+        token,
+        &refund_address,
+        total,
+        CheckBalance::Query(balance_key(token, &refund_address)),
+        args.tx.force,
+        context,
+    )
+    .await?;
 
-    prepare_tx(tx_args, &mut tx_builder, fee_amount, gas_payer.clone()).await?;
-    Ok(tx_builder)
+    Ok(Some(PacketFee {
+        fee,
+        refund_address,
+    }))
 }
 
-/// Try to decode the given asset type and add its decoding to the supplied set.
-/// Returns true only if a new decoding has been added to the given set.
-async fn add_asset_type(
-    asset_types: &mut HashSet<AssetData>,
+/// Build a transaction to register the payee of a relayer for the relayer
+/// fees
+pub async fn build_register_relayer_payee(
     context: &impl Namada,
-    asset_type: AssetType,
-) -> bool {
-    if let Some(asset_type) = context
-        .shielded_mut()
-        .await
+    args::TxRegisterRelayerPayee {
+        tx: tx_args,
+        relayer,
+        port_id,
+        channel_id,
+        payee,
+        counterparty_payee,
+        tx_code_path,
+    }: &args::TxRegisterRelayerPayee,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(relayer.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(relayer.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    if !rpc::query_ibc_fee_enabled(context.client(), port_id, channel_id)
+        .await?
+    {
+        edisplay_line!(
+            context.io(),
+            "The channel {channel_id} isn't fee-enabled."
+        );
+        if !tx_args.force {
+            return Err(Error::Other(format!(
+                "The channel {channel_id} isn't fee-enabled"
+            )));
+        }
+    }
+
+    let data = match (payee, counterparty_payee) {
+        (Some(payee), None) => MsgFee::RegisterPayee(MsgRegisterPayee {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            relayer: relayer.clone(),
+            payee: payee.clone(),
+        }),
+        (None, Some(counterparty_payee)) => {
+            MsgFee::RegisterCounterpartyPayee(MsgRegisterCounterpartyPayee {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                relayer: relayer.clone(),
+                counterparty_payee: counterparty_payee.clone(),
+            })
+        }
+        _ => {
+            return Err(Error::Other(
+                "Either the payee or the counterparty payee should be \
+                 specified"
+                    .to_string(),
+            ));
+        }
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a transaction to register an interchain account on the host chain
+pub async fn build_register_interchain_account(
+    context: &impl Namada,
+    args::TxRegisterInterchainAccount {
+        tx: tx_args,
+        owner,
+        connection_id,
+        tx_code_path,
+    }: &args::TxRegisterInterchainAccount,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(owner.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(owner.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let key = ica_controller_account_key(
+        connection_id,
+        &ica::controller_port_id(owner),
+    );
+    if rpc::query_has_storage_key(context.client(), &key).await? {
+        edisplay_line!(
+            context.io(),
+            "{owner} already has an interchain account over {connection_id}."
+        );
+        if !tx_args.force {
+            return Err(Error::Other(format!(
+                "{owner} already has an interchain account over \
+                 {connection_id}"
+            )));
+        }
+    }
+
+    let data = MsgInterchainAccount::Register(MsgRegisterInterchainAccount {
+        owner: owner.clone(),
+        connection_id: connection_id.to_string(),
+    });
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a transaction to send tokens from an interchain account on the host
+/// chain
+pub async fn build_send_interchain_account_tx(
+    context: &impl Namada,
+    args::TxSendInterchainAccountTx {
+        tx: tx_args,
+        owner,
+        connection_id,
+        receiver,
+        denom,
+        amount,
+        timeout_sec_offset,
+        ibc_memo,
+        tx_code_path,
+    }: &args::TxSendInterchainAccountTx,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(owner.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(owner.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    // The account address on the host chain is set when the registration is
+    // acknowledged
+    let key = ica_controller_account_key(
+        connection_id,
+        &ica::controller_port_id(owner),
+    );
+    if !rpc::query_has_storage_key(context.client(), &key).await? {
+        return Err(Error::Other(format!(
+            "{owner} has no active interchain account over {connection_id}"
+        )));
+    }
+    let account = rpc::query_storage_value::<_, ica::RegisteredAccount>(
+        context.client(),
+        &key,
+    )
+    .await?;
+
+    // The amount is in the smallest unit of the denomination on the host
+    // chain
+    token::Amount::from_str(amount, 0u8)
+        .map_err(|e| Error::Other(format!("Invalid amount {amount}: {e}")))?;
+    let message = ica::MsgSend {
+        from_address: account.address,
+        to_address: receiver.clone(),
+        amount: vec![ica::Coin {
+            denom: denom.clone(),
+            amount: amount.clone(),
+        }],
+    };
+    let packet_data = ica::InterchainAccountPacketData::new(
+        vec![message.to_any()],
+        ibc_memo.clone().unwrap_or_default(),
+    );
+    let relative_timeout =
+        Duration::new(timeout_sec_offset.unwrap_or(3600), 0).as_nanos();
+    let data = MsgInterchainAccount::SendTx(MsgSendTx {
+        owner: owner.clone(),
+        connection_id: connection_id.to_string(),
+        packet_data: packet_data.to_bytes(),
+        relative_timeout: u64::try_from(relative_timeout)
+            .map_err(|e| Error::Other(e.to_string()))?,
+    });
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Abstraction for helping build transactions
+#[allow(clippy::too_many_arguments)]
+async fn build<F, D>(
+    context: &impl Namada,
+    tx_args: &crate::args::Tx,
+    path: PathBuf,
+    mut data: D,
+    on_tx: F,
+    fee_amount: DenominatedAmount,
+    gas_payer: &common::PublicKey,
+) -> Result<Tx>
+where
+    F: FnOnce(&mut Tx, &mut D) -> Result<()>,
+    D: BorshSerialize,
+{
+    let chain_id = tx_args.chain_id.clone().unwrap();
+
+    let mut tx_builder = Tx::new(chain_id, tx_args.expiration.to_datetime());
+    if let Some(memo) = &tx_args.memo {
+        tx_builder.add_memo(memo);
+    }
+
+    let tx_code_hash = query_wasm_code_hash(context, path.to_string_lossy())
+        .await
+        .map_err(|e| Error::from(QueryError::Wasm(e.to_string())))?;
+
+    on_tx(&mut tx_builder, &mut data)?;
+
+    tx_builder
+        .add_code_from_hash(
+            tx_code_hash,
+            Some(path.to_string_lossy().into_owned()),
+        )
+        .add_data(data);
+
+    prepare_tx(tx_args, &mut tx_builder, fee_amount, gas_payer.clone()).await?;
+    Ok(tx_builder)
+}
+
+/// Try to decode the given asset type and add its decoding to the supplied set.
+/// Returns true only if a new decoding has been added to the given set.
+async fn add_asset_type(
+    asset_types: &mut HashSet<AssetData>,
+    context: &impl Namada,
+    asset_type: AssetType,
+) -> bool {
+    if let Some(asset_type) = context
+        .shielded_mut()
+        .await
         .decode_asset_type(context.client(), asset_type)
         .await
     {
@@ -3635,16 +4270,18 @@ pub async fn build_update_account(
     .map(|tx| (tx, signing_data))
 }
 
-/// Submit a custom transaction
-pub async fn build_custom(
+/// Build a transaction to grant, update or revoke a spending allowance
+pub async fn build_approve(
     context: &impl Namada,
-    args::TxCustom {
+    args::Approve {
         tx: tx_args,
-        code_path,
-        data_path,
-        serialized_tx,
+        token,
         owner,
-    }: &args::TxCustom,
+        spender,
+        amount,
+        expiry,
+        tx_code_path,
+    }: &args::Approve,
 ) -> Result<(Tx, SigningTxData)> {
     let default_signer = Some(owner.clone());
     let signing_data = signing::aux_signing_data(
@@ -3654,53 +4291,705 @@ pub async fn build_custom(
         default_signer,
     )
     .await?;
-    let fee_amount = validate_fee(context, tx_args).await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
 
-    let mut tx = if let Some(serialized_tx) = serialized_tx {
-        Tx::deserialize(serialized_tx.as_ref()).map_err(|_| {
-            Error::Other("Invalid tx deserialization.".to_string())
-        })?
-    } else {
-        let code_path = code_path
-            .as_ref()
-            .ok_or(Error::Other("No code path supplied".to_string()))?;
-        let tx_code_hash = query_wasm_code_hash_buf(context, code_path).await?;
-        let chain_id = tx_args.chain_id.clone().unwrap();
-        let mut tx = Tx::new(chain_id, tx_args.expiration.to_datetime());
-        if let Some(memo) = &tx_args.memo {
-            tx.add_memo(memo);
+    if owner == spender {
+        return Err(Error::Other(
+            "An account cannot grant an allowance to itself".to_string(),
+        ));
+    }
+    let amount = validate_amount(context, *amount, token, tx_args.force)
+        .await?
+        .amount();
+    if let Some(expiry) = expiry {
+        let current_epoch = rpc::query_epoch(context.client()).await?;
+        if *expiry < current_epoch {
+            edisplay_line!(
+                context.io(),
+                "The allowance expiry epoch {} is before the current epoch {}.",
+                expiry,
+                current_epoch
+            );
+            if !tx_args.force {
+                return Err(Error::Other(format!(
+                    "Expiry epoch {expiry} is in the past"
+                )));
+            }
         }
-        tx.add_code_from_hash(
-            tx_code_hash,
-            Some(code_path.to_string_lossy().into_owned()),
-        );
-        data_path.clone().map(|data| tx.add_serialized_data(data));
-        tx
-    };
+    }
 
-    prepare_tx(tx_args, &mut tx, fee_amount, signing_data.fee_payer.clone())
-        .await?;
+    let data = token::Approve {
+        token: token.clone(),
+        owner: owner.clone(),
+        spender: spender.clone(),
+        amount,
+        expiry: *expiry,
+    };
 
-    Ok((tx, signing_data))
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
 }
 
-/// Generate IBC shielded transfer
-pub async fn gen_ibc_shielding_transfer<N: Namada>(
-    context: &N,
-    args: args::GenIbcShieldingTransfer,
-) -> Result<Option<MaspTransaction>> {
-    let source = IBC;
-    let (src_port_id, src_channel_id) =
-        get_ibc_src_port_channel(context, &args.port_id, &args.channel_id)
-            .await?;
-    let ibc_denom =
-        rpc::query_ibc_denom(context, &args.token, Some(&source)).await;
-    // Need to check the prefix
-    let token = namada_ibc::received_ibc_token(
-        &ibc_denom,
-        &src_port_id,
-        &src_channel_id,
-        &args.port_id,
+/// Build a transaction to transfer tokens out of an owner's balance with a
+/// spending allowance
+pub async fn build_transfer_from(
+    context: &impl Namada,
+    args::TransferFrom {
+        tx: tx_args,
+        token,
+        spender,
+        owner,
+        target,
+        amount,
+        tx_code_path,
+    }: &args::TransferFrom,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(spender.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(spender.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let amount = validate_amount(context, *amount, token, tx_args.force)
+        .await?
+        .amount();
+
+    // Check that the allowance covers the transfer
+    let allowance_key =
+        token::storage_key::allowance_key(token, owner, spender);
+    let allowance = if rpc::query_has_storage_key(
+        context.client(),
+        &allowance_key,
+    )
+    .await?
+    {
+        Some(
+            rpc::query_storage_value::<_, token::Allowance>(
+                context.client(),
+                &allowance_key,
+            )
+            .await?,
+        )
+    } else {
+        None
+    };
+    let current_epoch = rpc::query_epoch(context.client()).await?;
+    let is_valid_allowance = allowance.is_some_and(|allowance| {
+        !allowance.is_expired(current_epoch) && allowance.amount >= amount
+    });
+    if !is_valid_allowance {
+        edisplay_line!(
+            context.io(),
+            "The allowance of {} to spend the token {} from {} is missing, \
+             expired or insufficient.",
+            spender,
+            token,
+            owner
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::InvalidAllowance(
+                spender.clone(),
+                owner.clone(),
+                token.clone(),
+            )));
+        }
+    }
+    check_balance_too_low_err(
+        token,
+        owner,
+        amount,
+        CheckBalance::Query(balance_key(token, owner)),
+        tx_args.force,
+        context,
+    )
+    .await?;
+
+    let data = token::TransferFrom {
+        token: token.clone(),
+        spender: spender.clone(),
+        owner: owner.clone(),
+        target: target.clone(),
+        amount,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a transaction to grant tokens that are released to the target by a
+/// vesting schedule
+pub async fn build_vest_tokens(
+    context: &impl Namada,
+    args::VestTokens {
+        tx: tx_args,
+        token,
+        source,
+        target,
+        amount,
+        start_epoch,
+        cliff_epoch,
+        end_epoch,
+        tx_code_path,
+    }: &args::VestTokens,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(source.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(source.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    if source == target {
+        return Err(Error::Other(
+            "An account cannot vest tokens to itself".to_string(),
+        ));
+    }
+    let amount = validate_amount(context, *amount, token, tx_args.force)
+        .await?
+        .amount();
+    let current_epoch = rpc::query_epoch(context.client()).await?;
+    let start_epoch = start_epoch.unwrap_or(current_epoch);
+    let schedule = token::VestingSchedule {
+        amount,
+        start_epoch,
+        cliff_epoch: cliff_epoch.unwrap_or(start_epoch),
+        end_epoch: *end_epoch,
+    };
+    if !schedule.is_valid() {
+        return Err(Error::Other(
+            "The vesting amount must not be zero and the start, cliff and \
+             end epochs must be ordered"
+                .to_string(),
+        ));
+    }
+
+    // Check that the target has no tokens from the source vesting already
+    let vesting_key = token::storage_key::vesting_key(token, target, source);
+    if rpc::query_has_storage_key(context.client(), &vesting_key).await? {
+        let current = rpc::query_storage_value::<_, token::VestingSchedule>(
+            context.client(),
+            &vesting_key,
+        )
+        .await?;
+        if !current.is_fully_vested(current_epoch) {
+            edisplay_line!(
+                context.io(),
+                "The target {} already has tokens of {} from {} vesting \
+                 until epoch {}.",
+                target,
+                token,
+                source,
+                current.end_epoch
+            );
+            if !tx_args.force {
+                return Err(Error::Other(format!(
+                    "{target} already has tokens of {token} from {source} \
+                     vesting"
+                )));
+            }
+        }
+    }
+    check_balance_too_low_err(
+        token,
+        source,
+        amount,
+        CheckBalance::Query(balance_key(token, source)),
+        tx_args.force,
+        context,
+    )
+    .await?;
+
+    let data = token::VestTokens {
+        token: token.clone(),
+        source: source.clone(),
+        target: target.clone(),
+        schedule,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a transaction to grant, update or revoke a fee grant from a sponsor
+pub async fn build_grant_fee(
+    context: &impl Namada,
+    args::GrantFee {
+        tx: tx_args,
+        token,
+        sponsor,
+        grantee,
+        amount,
+        allowed_code_hashes,
+        expiry,
+        tx_code_path,
+    }: &args::GrantFee,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(sponsor.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(sponsor.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    if sponsor == grantee {
+        return Err(Error::Other(
+            "An account cannot grant a fee allowance to itself".to_string(),
+        ));
+    }
+    let amount = validate_amount(context, *amount, token, tx_args.force)
+        .await?
+        .amount();
+    if let Some(expiry) = expiry {
+        let current_epoch = rpc::query_epoch(context.client()).await?;
+        if *expiry < current_epoch {
+            edisplay_line!(
+                context.io(),
+                "The fee grant expiry epoch {} is before the current epoch {}.",
+                expiry,
+                current_epoch
+            );
+            if !tx_args.force {
+                return Err(Error::Other(format!(
+                    "Expiry epoch {expiry} is in the past"
+                )));
+            }
+        }
+    }
+
+    let data = token::GrantFee {
+        token: token.clone(),
+        sponsor: sponsor.clone(),
+        grantee: grantee.clone(),
+        grant: token::FeeGrant {
+            amount,
+            allowed_code_hashes: allowed_code_hashes.iter().copied().collect(),
+            expiry: *expiry,
+        },
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a transaction to create a new token with the token factory
+pub async fn build_create_token(
+    context: &impl Namada,
+    args::CreateToken {
+        tx: tx_args,
+        admin,
+        minter,
+        name,
+        symbol,
+        description,
+        denom,
+        supply_cap,
+        tx_code_path,
+    }: &args::CreateToken,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(admin.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(admin.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let supply_cap = supply_cap
+        .map(|cap| cap.scale(*denom))
+        .transpose()
+        .map_err(|err| {
+            Error::Other(format!("Invalid token supply cap: {err}"))
+        })?;
+
+    let data = token::CreateToken {
+        admin: admin.clone(),
+        minter: minter.clone(),
+        metadata: token::TokenMetadata {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            description: description.clone(),
+        },
+        denom: *denom,
+        supply_cap,
+    };
+
+    // Check that the token doesn't exist yet
+    let token = data.token();
+    let admin_key = token::storage_key::admin_key(&token);
+    if rpc::query_has_storage_key(context.client(), &admin_key).await? {
+        if tx_args.force {
+            edisplay_line!(
+                context.io(),
+                "The token {} already exists on chain.",
+                token
+            );
+        } else {
+            return Err(Error::from(TxSubmitError::TokenAlreadyExists(token)));
+        }
+    }
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a transaction to mint tokens issued with the token factory
+pub async fn build_mint_tokens(
+    context: &impl Namada,
+    args::MintTokens {
+        tx: tx_args,
+        token,
+        authority,
+        target,
+        amount,
+        tx_code_path,
+    }: &args::MintTokens,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(authority.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(authority.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let token = token_authority_or_err(
+        token.clone(),
+        authority,
+        tx_args.force,
+        context,
+    )
+    .await?;
+    let amount = validate_amount(context, *amount, &token, tx_args.force)
+        .await?
+        .amount();
+
+    let data = token::MintTokens {
+        token,
+        authority: authority.clone(),
+        owner: target.clone(),
+        amount,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a transaction to burn tokens issued with the token factory
+pub async fn build_burn_tokens(
+    context: &impl Namada,
+    args::BurnTokens {
+        tx: tx_args,
+        token,
+        authority,
+        source,
+        amount,
+        tx_code_path,
+    }: &args::BurnTokens,
+) -> Result<(Tx, SigningTxData)> {
+    let owner = source.clone().unwrap_or_else(|| authority.clone());
+    let default_signer = Some(authority.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(owner.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let token = token_authority_or_err(
+        token.clone(),
+        authority,
+        tx_args.force,
+        context,
+    )
+    .await?;
+    let amount = validate_amount(context, *amount, &token, tx_args.force)
+        .await?
+        .amount();
+    let balance_key = balance_key(&token, &owner);
+    check_balance_too_low_err(
+        &token,
+        &owner,
+        amount,
+        CheckBalance::Query(balance_key),
+        tx_args.force,
+        context,
+    )
+    .await?;
+
+    let data = token::BurnTokens {
+        token,
+        authority: authority.clone(),
+        owner,
+        amount,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a transaction to update the roles or the metadata of a token issued
+/// with the token factory
+pub async fn build_update_token(
+    context: &impl Namada,
+    args::UpdateToken {
+        tx: tx_args,
+        token,
+        admin,
+        new_admin,
+        new_minter,
+        name,
+        symbol,
+        description,
+        tx_code_path,
+    }: &args::UpdateToken,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(admin.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(admin.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let current_admin = rpc::query_storage_value::<_, Address>(
+        context.client(),
+        &token::storage_key::admin_key(token),
+    )
+    .await
+    .map_err(|_| {
+        Error::from(TxSubmitError::TokenDoesNotExist(token.clone()))
+    })?;
+    if &current_admin != admin {
+        edisplay_line!(
+            context.io(),
+            "The address {} is not the admin of the token {}.",
+            admin,
+            token
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::InvalidTokenAuthority(
+                admin.clone(),
+                token.clone(),
+            )));
+        }
+    }
+
+    // Overlay the updated fields on top of the current metadata
+    let new_metadata =
+        if name.is_some() || symbol.is_some() || description.is_some() {
+            let mut metadata =
+                rpc::query_storage_value::<_, token::TokenMetadata>(
+                    context.client(),
+                    &token::storage_key::metadata_key(token),
+                )
+                .await?;
+            if let Some(name) = name {
+                metadata.name = name.clone();
+            }
+            if let Some(symbol) = symbol {
+                metadata.symbol = symbol.clone();
+            }
+            if description.is_some() {
+                metadata.description = description.clone();
+            }
+            Some(metadata)
+        } else {
+            None
+        };
+
+    if new_admin.is_none() && new_minter.is_none() && new_metadata.is_none() {
+        edisplay_line!(context.io(), "Nothing to update for token {}.", token);
+        if !tx_args.force {
+            return Err(Error::Other(format!(
+                "Nothing to update for token {token}"
+            )));
+        }
+    }
+
+    let data = token::UpdateToken {
+        token: token.clone(),
+        admin: admin.clone(),
+        new_admin: new_admin.clone(),
+        new_minter: new_minter.clone(),
+        new_metadata,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit a custom transaction
+pub async fn build_custom(
+    context: &impl Namada,
+    args::TxCustom {
+        tx: tx_args,
+        code_path,
+        data_path,
+        serialized_tx,
+        owner,
+    }: &args::TxCustom,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(owner.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(owner.clone()),
+        default_signer,
+    )
+    .await?;
+    let fee_amount = validate_fee(context, tx_args).await?;
+
+    let mut tx = if let Some(serialized_tx) = serialized_tx {
+        Tx::deserialize(serialized_tx.as_ref()).map_err(|_| {
+            Error::Other("Invalid tx deserialization.".to_string())
+        })?
+    } else {
+        let code_path = code_path
+            .as_ref()
+            .ok_or(Error::Other("No code path supplied".to_string()))?;
+        let tx_code_hash = query_wasm_code_hash_buf(context, code_path).await?;
+        let chain_id = tx_args.chain_id.clone().unwrap();
+        let mut tx = Tx::new(chain_id, tx_args.expiration.to_datetime());
+        if let Some(memo) = &tx_args.memo {
+            tx.add_memo(memo);
+        }
+        tx.add_code_from_hash(
+            tx_code_hash,
+            Some(code_path.to_string_lossy().into_owned()),
+        );
+        data_path.clone().map(|data| tx.add_serialized_data(data));
+        tx
+    };
+
+    prepare_tx(tx_args, &mut tx, fee_amount, signing_data.fee_payer.clone())
+        .await?;
+
+    Ok((tx, signing_data))
+}
+
+/// Generate IBC shielded transfer
+pub async fn gen_ibc_shielding_transfer<N: Namada>(
+    context: &N,
+    args: args::GenIbcShieldingTransfer,
+) -> Result<Option<MaspTransaction>> {
+    let source = IBC;
+    let (src_port_id, src_channel_id) =
+        get_ibc_src_port_channel(context, &args.port_id, &args.channel_id)
+            .await?;
+    let ibc_denom =
+        rpc::query_ibc_denom(context, &args.token, Some(&source)).await;
+    // Need to check the prefix
+    let token = namada_ibc::received_ibc_token(
+        &ibc_denom,
+        &src_port_id,
+        &src_channel_id,
+        &args.port_id,
         &args.channel_id,
     )
     .map_err(|e| {
@@ -3869,6 +5158,48 @@ async fn source_exists_or_err(
     .await
 }
 
+/// Returns the given factory token if the given authority is allowed to
+/// change its supply, i.e. if it's either the token's admin or its minter,
+/// otherwise returns an error, force forces the token through regardless
+async fn token_authority_or_err(
+    token: Address,
+    authority: &Address,
+    force: bool,
+    context: &impl Namada,
+) -> Result<Address> {
+    let admin = rpc::query_storage_value::<_, Address>(
+        context.client(),
+        &token::storage_key::admin_key(&token),
+    )
+    .await
+    .map_err(|_| {
+        Error::from(TxSubmitError::TokenDoesNotExist(token.clone()))
+    })?;
+    let minter = rpc::query_storage_value::<_, Address>(
+        context.client(),
+        &token::storage_key::minter_key(&token),
+    )
+    .await?;
+    if &admin != authority && &minter != authority {
+        if force {
+            edisplay_line!(
+                context.io(),
+                "The address {} is not authorized to manage the token {}.",
+                authority,
+                token
+            );
+            Ok(token)
+        } else {
+            Err(Error::from(TxSubmitError::InvalidTokenAuthority(
+                authority.clone(),
+                token,
+            )))
+        }
+    } else {
+        Ok(token)
+    }
+}
+
 /// Returns the given target address if the given address exists on chain
 /// otherwise returns an error, force forces the address through even
 /// if it isn't on chain
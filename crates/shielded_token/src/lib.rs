@@ -62,6 +62,42 @@ pub struct ShieldedParams {
     pub locked_amount_target: u64,
 }
 
+#[allow(missing_docs)]
+#[derive(thiserror::Error, Debug)]
+pub enum ValidationError {
+    #[error("Maximum reward rate must be between 0 and 1, got {0}")]
+    InvalidMaxRewardRate(Dec),
+    #[error("Nominal proportional gain cannot be negative, got {0}")]
+    NegativeKpGain(Dec),
+    #[error("Nominal derivative gain cannot be negative, got {0}")]
+    NegativeKdGain(Dec),
+}
+
+impl ShieldedParams {
+    /// Validate shielded parameters values. Returns an empty list if the
+    /// values are valid.
+    #[must_use]
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = vec![];
+
+        if self.max_reward_rate.is_negative()
+            || self.max_reward_rate > Dec::one()
+        {
+            errors.push(ValidationError::InvalidMaxRewardRate(
+                self.max_reward_rate,
+            ));
+        }
+        if self.kp_gain_nom.is_negative() {
+            errors.push(ValidationError::NegativeKpGain(self.kp_gain_nom));
+        }
+        if self.kd_gain_nom.is_negative() {
+            errors.push(ValidationError::NegativeKdGain(self.kd_gain_nom));
+        }
+
+        errors
+    }
+}
+
 impl Default for ShieldedParams {
     fn default() -> Self {
         Self {
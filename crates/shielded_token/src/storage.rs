@@ -22,12 +22,6 @@ where
     S: StorageRead + StorageWrite,
     TransToken: trans_token::Keys,
 {
-    let ShieldedParams {
-        max_reward_rate: max_rate,
-        kd_gain_nom,
-        kp_gain_nom,
-        locked_amount_target,
-    } = params;
     storage.write(
         &masp_last_inflation_key::<TransToken>(token),
         Amount::zero(),
@@ -36,6 +30,27 @@ where
         &masp_last_locked_amount_key::<TransToken>(token),
         Amount::zero(),
     )?;
+    update_params::<S, TransToken>(params, storage, token, denom)
+}
+
+/// Update the parameters of the token in storage, leaving the state of its
+/// rewards untouched.
+pub fn update_params<S, TransToken>(
+    params: &ShieldedParams,
+    storage: &mut S,
+    token: &Address,
+    denom: &token::Denomination,
+) -> storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+    TransToken: trans_token::Keys,
+{
+    let ShieldedParams {
+        max_reward_rate: max_rate,
+        kd_gain_nom,
+        kp_gain_nom,
+        locked_amount_target,
+    } = params;
     storage.write(&masp_max_reward_rate_key::<TransToken>(token), max_rate)?;
     storage.write(&masp_kp_gain_key::<TransToken>(token), kp_gain_nom)?;
     storage.write(&masp_kd_gain_key::<TransToken>(token), kd_gain_nom)?;
@@ -52,6 +67,50 @@ where
     Ok(())
 }
 
+/// Read the parameters of the token from storage, if it has any.
+pub fn read_params<S, TransToken>(
+    storage: &S,
+    token: &Address,
+    denom: &token::Denomination,
+) -> storage::Result<Option<ShieldedParams>>
+where
+    S: StorageRead,
+    TransToken: trans_token::Keys,
+{
+    let (
+        Some(max_reward_rate),
+        Some(kd_gain_nom),
+        Some(kp_gain_nom),
+        Some(raw_target),
+    ) = (
+        storage.read(&masp_max_reward_rate_key::<TransToken>(token))?,
+        storage.read(&masp_kd_gain_key::<TransToken>(token))?,
+        storage.read(&masp_kp_gain_key::<TransToken>(token))?,
+        storage.read::<Amount>(
+            &masp_locked_amount_target_key::<TransToken>(token),
+        )?,
+    )
+    else {
+        return Ok(None);
+    };
+
+    let locked_amount_target = checked!(
+        raw_target.raw_amount() / (Uint::from(10) ^ Uint::from(denom.0))
+    )?;
+    if locked_amount_target.bits() > 64 {
+        return Err(storage::Error::new_const(
+            "The locked amount target doesn't fit in 64 bits",
+        ));
+    }
+
+    Ok(Some(ShieldedParams {
+        max_reward_rate,
+        kd_gain_nom,
+        kp_gain_nom,
+        locked_amount_target: locked_amount_target.low_u64(),
+    }))
+}
+
 /// Mint MASP rewards tokens and increment the stored total rewards.
 pub fn mint_rewards<S, TransToken>(
     storage: &mut S,
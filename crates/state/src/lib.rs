@@ -777,6 +777,7 @@ mod tests {
                 gas_scale: 10_000_000,
                 minimum_gas_price: BTreeMap::default(),
                 is_native_token_transferable: true,
+                base_fee_params: None,
             };
             // Initialize pred_epochs to the current height
             let height = state.in_mem().block.height;
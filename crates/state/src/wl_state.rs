@@ -123,21 +123,40 @@ where
         storage_read_past_height_limit: Option<u64>,
         diff_key_filter: fn(&storage::Key) -> bool,
     ) -> Self {
-        let write_log = WriteLog::default();
         let db = D::open(db_path, cache);
+        let mut state = Self::from_db(
+            db,
+            chain_id,
+            native_token,
+            storage_read_past_height_limit,
+            diff_key_filter,
+        );
+        state.load_last_state();
+        state
+    }
+
+    /// Instantiate a full-access state from an opened DB. Unlike
+    /// [`FullAccessState::open`], this doesn't load the last state from the
+    /// DB, which can be done with [`FullAccessState::reload_last_state`].
+    pub fn from_db(
+        db: D,
+        chain_id: ChainId,
+        native_token: Address,
+        storage_read_past_height_limit: Option<u64>,
+        diff_key_filter: fn(&storage::Key) -> bool,
+    ) -> Self {
+        let write_log = WriteLog::default();
         let in_mem = InMemory::new(
             chain_id,
             native_token,
             storage_read_past_height_limit,
         );
-        let mut state = Self(WlState {
+        Self(WlState {
             write_log,
             db,
             in_mem,
             diff_key_filter,
-        });
-        state.load_last_state();
-        state
+        })
     }
 
     #[allow(dead_code)]
@@ -231,13 +250,22 @@ where
     /// Commit the current block's write log to the storage and commit the block
     /// to DB. Starts a new block write log.
     pub fn commit_block(&mut self) -> StorageResult<()> {
+        self.commit_block_with_batch(D::batch())
+    }
+
+    /// Commit the current block's write log to the storage and commit the block
+    /// to DB, together with the given batch of writes that are not a part of
+    /// the write log. Starts a new block write log.
+    pub fn commit_block_with_batch(
+        &mut self,
+        mut batch: D::WriteBatch,
+    ) -> StorageResult<()> {
         if self.in_mem.last_epoch != self.in_mem.block.epoch {
             self.in_mem_mut()
                 .update_epoch_in_merkle_tree()
                 .into_storage_result()?;
         }
 
-        let mut batch = D::batch();
         self.commit_write_log_block(&mut batch)
             .into_storage_result()?;
         self.commit_block_from_batch(batch).into_storage_result()
@@ -487,6 +515,15 @@ where
     /// Load the full state at the last committed height, if any. Returns the
     /// Merkle root hash and the height of the committed block.
     fn load_last_state(&mut self) {
+        self.reload_last_state()
+            .expect("Loading the last committed state must not fail")
+    }
+
+    /// (Re)load the full state at the last committed height from the DB, if
+    /// any. Unlike the loading done on [`FullAccessState::open`], this
+    /// doesn't panic on failure, so it can be used after the DB has been
+    /// written to externally (e.g. when restoring from a snapshot).
+    pub fn reload_last_state(&mut self) -> Result<()> {
         if let Some(BlockStateRead {
             height,
             time,
@@ -501,11 +538,7 @@ where
             ethereum_height,
             eth_events_queue,
             commit_only_data,
-        }) = self
-            .0
-            .db
-            .read_last_block()
-            .expect("Read block call must not fail")
+        }) = self.0.db.read_last_block()?
         {
             {
                 let in_mem = &mut self.0.in_mem;
@@ -524,11 +557,9 @@ where
             }
 
             // Rebuild Merkle tree - requires the values above to be set first
-            let tree = self
-                .rebuild_full_merkle_tree(height)
-                .expect("Merkle tree should be restored");
+            let tree = self.rebuild_full_merkle_tree(height)?;
 
-            tree.validate().map_err(Error::MerkleTreeError).unwrap();
+            tree.validate().map_err(Error::MerkleTreeError)?;
 
             let in_mem = &mut self.0.in_mem;
             in_mem.block.tree = tree;
@@ -539,6 +570,7 @@ where
         } else {
             tracing::info!("No state could be found");
         }
+        Ok(())
     }
 
     /// Commit the data from in-memory state into the block's merkle tree.
@@ -1097,6 +1129,16 @@ where
         Ok(tree)
     }
 
+    /// Check that the merkle tree stores and the diffs in the DB reproduce
+    /// the merkle tree committed at the given height, i.e. that the roots of
+    /// the subtrees rebuilt from the diffs match the ones committed to in the
+    /// base tree. Returns the verified merkle root.
+    pub fn verify_merkle_tree(&self, height: BlockHeight) -> Result<Hash> {
+        let tree = self.get_merkle_tree(height, None)?;
+        tree.validate()?;
+        Ok(Hash(tree.root().0))
+    }
+
     /// Get the timestamp of the last committed block, or the current timestamp
     /// if no blocks have been produced yet
     pub fn get_last_block_timestamp(&self) -> Result<DateTimeUtc> {
@@ -279,6 +279,38 @@ pub trait DB: Debug {
         height: BlockHeight,
     ) -> Result<()>;
 
+    /// Prune the diffs and block data of all the heights below the given
+    /// height. The DB may remove the data in the background, but it must
+    /// report the new oldest height from [`DB::read_oldest_height`] as soon
+    /// as this returns.
+    fn prune_history(&self, _oldest_height: BlockHeight) -> Result<()> {
+        Ok(())
+    }
+
+    /// Read the oldest height whose diffs have not been pruned, if any
+    /// history has been pruned
+    fn read_oldest_height(&self) -> Result<Option<BlockHeight>> {
+        Ok(None)
+    }
+
+    /// Read the value of the given key of the events index. The events index
+    /// is local to a node and it's not a part of the state.
+    fn read_events_index(&self, key: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Batch write the given key-vals to the events index.
+    fn batch_write_events_index(
+        &self,
+        batch: &mut Self::WriteBatch,
+        entries: impl IntoIterator<Item = (String, Vec<u8>)>,
+    ) -> Result<()>;
+
+    /// Batch delete the given keys from the events index.
+    fn batch_delete_events_index(
+        &self,
+        batch: &mut Self::WriteBatch,
+        keys: impl IntoIterator<Item = String>,
+    ) -> Result<()>;
+
     /// Overwrite a new value in storage, taking into
     /// account values stored at a previous height
     fn overwrite_entry(
@@ -346,6 +378,15 @@ pub trait DBIter<'iter> {
 
     /// Read replay protection storage from the current bucket
     fn iter_current_replay_protection(&'iter self) -> Self::PrefixIter;
+
+    /// Read the key-vals of the events index with the given prefix, ordered
+    /// by their keys, starting at the given key if any. The keys are not
+    /// stripped of the prefix.
+    fn iter_events_index(
+        &'iter self,
+        prefix: &str,
+        start: Option<&str>,
+    ) -> Result<Self::PrefixIter>;
 }
 
 /// Atomic batch write.
@@ -356,6 +356,11 @@ pub mod testing {
         ) {
             self.mock_block_headers.insert(height, header);
         }
+
+        /// Set the mock block epoch in [`TestStorage`].
+        pub fn set_mock_block_epoch(&mut self, epoch: Epoch) {
+            self.epoch = epoch;
+        }
     }
 
     impl StorageRead for TestStorage {
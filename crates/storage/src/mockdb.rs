@@ -10,7 +10,8 @@ use itertools::Either;
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::hash::Hash;
 use namada_core::storage::{
-    BlockHeight, DbColFam, Epoch, Header, Key, KeySeg, KEY_SEGMENT_SEPARATOR,
+    BlockHeight, DbColFam, Epoch, Header, Key, KeySeg, EVENTS_CF,
+    KEY_SEGMENT_SEPARATOR,
 };
 use namada_core::{decode, encode, ethereum_events};
 use namada_merkle_tree::{
@@ -614,6 +615,34 @@ impl DB for MockDB {
         Ok(())
     }
 
+    fn read_events_index(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(self.0.borrow().get(&format!("{EVENTS_CF}/{key}")).cloned())
+    }
+
+    fn batch_write_events_index(
+        &self,
+        _batch: &mut Self::WriteBatch,
+        entries: impl IntoIterator<Item = (String, Vec<u8>)>,
+    ) -> Result<()> {
+        let mut db = self.0.borrow_mut();
+        for (key, value) in entries {
+            db.insert(format!("{EVENTS_CF}/{key}"), value);
+        }
+        Ok(())
+    }
+
+    fn batch_delete_events_index(
+        &self,
+        _batch: &mut Self::WriteBatch,
+        keys: impl IntoIterator<Item = String>,
+    ) -> Result<()> {
+        let mut db = self.0.borrow_mut();
+        for key in keys {
+            db.remove(&format!("{EVENTS_CF}/{key}"));
+        }
+        Ok(())
+    }
+
     fn overwrite_entry(
         &self,
         _batch: &mut Self::WriteBatch,
@@ -722,6 +751,27 @@ impl<'iter> DBIter<'iter> for MockDB {
         let iter = self.0.borrow().clone().into_iter();
         MockPrefixIterator::new(MockIterator { prefix, iter }, stripped_prefix)
     }
+
+    fn iter_events_index(
+        &'iter self,
+        prefix: &str,
+        start: Option<&str>,
+    ) -> Result<MockPrefixIterator> {
+        let stripped_prefix = format!("{EVENTS_CF}/");
+        let start = start.map_or(prefix, |start| start.max(prefix));
+        let prefix = format!("{stripped_prefix}{prefix}");
+        let iter = self
+            .0
+            .borrow()
+            .range(format!("{stripped_prefix}{start}")..)
+            .map(|(key, val)| (key.clone(), val.clone()))
+            .collect::<BTreeMap<_, _>>()
+            .into_iter();
+        Ok(MockPrefixIterator::new(
+            MockIterator { prefix, iter },
+            stripped_prefix,
+        ))
+    }
 }
 
 /// A prefix iterator base for the [`MockPrefixIterator`].
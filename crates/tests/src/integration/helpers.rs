@@ -7,9 +7,11 @@ use namada_node::shell::testing::client::run;
 use namada_node::shell::testing::node::MockNode;
 use namada_node::shell::testing::utils::{Bin, CapturedOutput};
 
+use super::setup::MockNodeDB;
+
 /// Query the wallet to get an address from a given alias.
 pub fn find_address(
-    node: &MockNode,
+    node: &MockNode<MockNodeDB>,
     alias: impl AsRef<str>,
 ) -> eyre::Result<Address> {
     let captured = CapturedOutput::of(|| {
@@ -32,8 +32,16 @@ use crate::e2e::setup::{copy_wasm_to_chain_dir, SINGLE_NODE_NET_GENESIS};
 /// Env. var for keeping temporary files created by the integration tests
 const ENV_VAR_KEEP_TEMP: &str = "NAMADA_INT_KEEP_TEMP";
 
+/// The DB backend of the mock node. The in-memory DB avoids the cost of
+/// starting up RocksDB in every test.
+#[cfg(not(feature = "integration-rocksdb"))]
+pub type MockNodeDB = namada_node::storage::MemDB;
+/// The DB backend of the mock node.
+#[cfg(feature = "integration-rocksdb")]
+pub type MockNodeDB = namada_node::storage::PersistentDB;
+
 /// Setup a network with a single genesis validator node.
-pub fn setup() -> Result<(MockNode, MockServicesController)> {
+pub fn setup() -> Result<(MockNode<MockNodeDB>, MockServicesController)> {
     initialize_genesis(|genesis| genesis)
 }
 
@@ -42,7 +50,7 @@ pub fn initialize_genesis(
     mut update_genesis: impl FnMut(
         templates::All<templates::Unvalidated>,
     ) -> templates::All<templates::Unvalidated>,
-) -> Result<(MockNode, MockServicesController)> {
+) -> Result<(MockNode<MockNodeDB>, MockServicesController)> {
     let working_dir = std::fs::canonicalize("../..").unwrap();
     let keep_temp = match std::env::var(ENV_VAR_KEEP_TEMP) {
         Ok(val) => val.to_ascii_lowercase() != "false",
@@ -182,7 +190,7 @@ fn create_node(
     global_args: args::Global,
     keep_temp: bool,
     services_cfg: MockServicesCfg,
-) -> Result<(MockNode, MockServicesController)> {
+) -> Result<(MockNode<MockNodeDB>, MockServicesController)> {
     // look up the chain id from the global file.
     let chain_id = global_args.chain_id.unwrap_or_default();
 
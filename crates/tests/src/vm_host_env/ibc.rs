@@ -634,6 +634,7 @@ pub fn msg_transfer(
     MsgTransfer {
         message,
         transfer: None,
+        fee: None,
     }
 }
 
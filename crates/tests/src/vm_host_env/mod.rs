@@ -26,7 +26,7 @@ mod tests {
     use itertools::Itertools;
     use namada_core::storage::testing::get_dummy_header;
     use namada_sdk::account::pks_handle;
-    use namada_sdk::hash::Hash;
+    use namada_sdk::hash::{Hash, HashAlgorithm};
     use namada_sdk::ibc::context::nft_transfer_mod::testing::DummyNftTransferModule;
     use namada_sdk::ibc::context::transfer_mod::testing::DummyTransferModule;
     use namada_sdk::ibc::primitives::ToProto;
@@ -365,6 +365,44 @@ mod tests {
         assert_eq!(expected, pred_epochs);
     }
 
+    #[test]
+    fn test_tx_hash_and_verify_signature() {
+        // The environment must be initialized first
+        tx_host_env::init();
+
+        let data = [1, 2, 3, 4].repeat(10);
+        for algorithm in [
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Keccak256,
+            HashAlgorithm::Blake2b256,
+        ] {
+            assert_eq!(
+                namada_tx_prelude::hash_bytes(algorithm, &data),
+                algorithm.digest(&data)
+            );
+        }
+
+        let keypair = key::testing::keypair_1();
+        let signature = common::SigScheme::sign(&keypair, &data);
+        assert!(namada_tx_prelude::verify_signature(
+            &keypair.ref_to(),
+            &data,
+            &signature
+        ));
+        // A signature over different data is rejected
+        assert!(!namada_tx_prelude::verify_signature(
+            &keypair.ref_to(),
+            &data[1..],
+            &signature
+        ));
+        // A signature from a different key is rejected
+        assert!(!namada_tx_prelude::verify_signature(
+            &key::testing::keypair_2().ref_to(),
+            &data,
+            &signature
+        ));
+    }
+
     /// An example how to write a VP host environment integration test
     #[test]
     fn test_vp_host_env() {
@@ -589,6 +627,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_vp_hash_and_verify_signature() {
+        // The environment must be initialized first
+        vp_host_env::init();
+
+        let data = [1, 2, 3, 4].repeat(10);
+        for algorithm in [
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Keccak256,
+            HashAlgorithm::Blake2b256,
+        ] {
+            assert_eq!(
+                namada_vp_prelude::hash_bytes(algorithm, &data),
+                algorithm.digest(&data)
+            );
+        }
+
+        let keypair = key::testing::keypair_1();
+        let signature = common::SigScheme::sign(&keypair, &data);
+        assert!(namada_vp_prelude::verify_signature(
+            &keypair.ref_to(),
+            &data,
+            &signature
+        ));
+        // A signature from a different key is rejected
+        assert!(!namada_vp_prelude::verify_signature(
+            &key::testing::keypair_2().ref_to(),
+            &data,
+            &signature
+        ));
+    }
+
     #[test]
     fn test_vp_get_metadata() {
         // The environment must be initialized first
@@ -526,6 +526,20 @@ mod native_tx_host_env {
         public_keys_map_len: u64,
         threshold: u8,
     ) -> i64);
+    native_host_fn!(tx_hash(
+        algorithm: u8,
+        data_ptr: u64,
+        data_len: u64,
+        result_ptr: u64,
+    ));
+    native_host_fn!(tx_verify_signature(
+        public_key_ptr: u64,
+        public_key_len: u64,
+        data_ptr: u64,
+        data_len: u64,
+        signature_ptr: u64,
+        signature_len: u64,
+    ) -> i64);
     native_host_fn!(tx_yield_value(
         buf_ptr: u64,
         buf_len: u64,
@@ -345,6 +345,20 @@ mod native_vp_host_env {
         signer_len: u64,
         threshold: u8,
     ));
+    native_host_fn!(vp_hash(
+        algorithm: u8,
+        data_ptr: u64,
+        data_len: u64,
+        result_ptr: u64,
+    ));
+    native_host_fn!(vp_verify_signature(
+        public_key_ptr: u64,
+        public_key_len: u64,
+        data_ptr: u64,
+        data_len: u64,
+        signature_ptr: u64,
+        signature_len: u64,
+    ) -> i64);
     native_host_fn!(vp_charge_gas(used_gas: u64));
     native_host_fn!(vp_yield_value(buf_ptr: u64, buf_len: u64));
 }
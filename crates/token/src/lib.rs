@@ -86,6 +86,7 @@ use std::collections::BTreeMap;
 
 use namada_core::address::Address;
 use namada_core::masp::TxId;
+use namada_core::storage::Epoch;
 use namada_events::EmitEvents;
 use namada_storage::{StorageRead, StorageWrite};
 
@@ -266,6 +267,206 @@ impl Transfer {
     }
 }
 
+/// Arguments for creating a new token with the token factory
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct CreateToken {
+    /// The admin of the new token. The token's address is derived from the
+    /// admin and the symbol in the metadata.
+    pub admin: Address,
+    /// The minter of the new token. Defaults to the admin if not set.
+    pub minter: Option<Address>,
+    /// Descriptive metadata of the token
+    pub metadata: TokenMetadata,
+    /// Denomination of the token
+    pub denom: Denomination,
+    /// Optional cap on the total supply of the token
+    pub supply_cap: Option<Amount>,
+}
+
+impl CreateToken {
+    /// Get the address of the token to be created
+    pub fn token(&self) -> Address {
+        factory_token(&self.admin, &self.metadata.symbol)
+    }
+}
+
+/// Arguments for minting or burning a token issued with the token factory
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct MintTokens {
+    /// The factory token
+    pub token: Address,
+    /// The minter or the admin of the token authorizing the supply change
+    pub authority: Address,
+    /// The account whose balance is credited when minting or debited when
+    /// burning
+    pub owner: Address,
+    /// The amount to mint or burn
+    pub amount: Amount,
+}
+
+/// Arguments for burning a token issued with the token factory
+pub type BurnTokens = MintTokens;
+
+/// Arguments for updating the roles or the metadata of a token issued with
+/// the token factory
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct UpdateToken {
+    /// The factory token
+    pub token: Address,
+    /// The current admin of the token
+    pub admin: Address,
+    /// The new admin, if it is to be rotated
+    pub new_admin: Option<Address>,
+    /// The new minter, if it is to be rotated
+    pub new_minter: Option<Address>,
+    /// The new metadata, if it is to be updated
+    pub new_metadata: Option<TokenMetadata>,
+}
+
+/// Arguments for granting, updating or revoking an allowance of a spender
+/// to transfer tokens out of the owner's balance
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct Approve {
+    /// The token of the allowance
+    pub token: Address,
+    /// The owner of the balance that can be spent
+    pub owner: Address,
+    /// The account allowed to spend from the owner's balance
+    pub spender: Address,
+    /// The allowed amount. A zero amount revokes the allowance.
+    pub amount: Amount,
+    /// The last epoch in which the allowance can be spent. Never expires if
+    /// not set.
+    pub expiry: Option<Epoch>,
+}
+
+/// Arguments for transferring tokens out of an owner's balance on behalf of a
+/// spender with an allowance
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct TransferFrom {
+    /// The token to transfer
+    pub token: Address,
+    /// The account spending its allowance
+    pub spender: Address,
+    /// The owner of the debited balance
+    pub owner: Address,
+    /// The account receiving the tokens
+    pub target: Address,
+    /// The amount to transfer
+    pub amount: Amount,
+}
+
+/// Arguments for granting tokens that are released to the target over time
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct VestTokens {
+    /// The token to grant
+    pub token: Address,
+    /// The account granting the tokens
+    pub source: Address,
+    /// The account receiving the vesting tokens
+    pub target: Address,
+    /// The schedule by which the tokens are released
+    pub schedule: VestingSchedule,
+}
+
+/// Arguments for granting, updating or revoking the sponsorship of the
+/// wrapper fees of a grantee
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct GrantFee {
+    /// The token of the fees
+    pub token: Address,
+    /// The account paying the fees
+    pub sponsor: Address,
+    /// The account whose fees are paid
+    pub grantee: Address,
+    /// The grant. A zero amount revokes it.
+    pub grant: FeeGrant,
+}
+
 #[cfg(any(test, feature = "testing"))]
 /// Testing helpers and strategies for tokens
 pub mod testing {
@@ -1,10 +1,15 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use namada_core::address::{Address, InternalAddress};
+use namada_core::address::{Address, InternalAddress, HASH_LEN};
 use namada_core::collections::HashSet;
+use namada_core::hash::Hash;
 use namada_core::hints;
+use namada_core::storage::Epoch;
 pub use namada_core::storage::Key;
-use namada_core::token::{self, Amount, AmountError, DenominatedAmount};
+use namada_core::token::{
+    self, Allowance, Amount, AmountError, DenominatedAmount, FactoryTokenHash,
+    FeeGrant, TokenMetadata, VestingSchedule,
+};
 use namada_storage as storage;
 use namada_storage::{StorageRead, StorageWrite};
 use storage::ResultExt;
@@ -262,6 +267,281 @@ where
     }
 }
 
+/// Set the allowance of the `spender` to transfer tokens out of the `owner`'s
+/// balance. An allowance with a zero amount is removed.
+pub fn approve<S>(
+    storage: &mut S,
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+    amount: token::Amount,
+    expiry: Option<Epoch>,
+) -> storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if owner == spender {
+        return Err(storage::Error::new_const(
+            "An account cannot grant an allowance to itself",
+        ));
+    }
+    let key = allowance_key(token, owner, spender);
+    if amount.is_zero() {
+        storage.delete(&key)
+    } else {
+        storage.write(&key, Allowance { amount, expiry })
+    }
+}
+
+/// Read the allowance of the `spender` to transfer tokens out of the
+/// `owner`'s balance.
+pub fn read_allowance<S>(
+    storage: &S,
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+) -> storage::Result<Option<Allowance>>
+where
+    S: StorageRead,
+{
+    storage.read(&allowance_key(token, owner, spender))
+}
+
+/// Transfer tokens out of the `owner`'s balance on behalf of the `spender`,
+/// deducting the amount from the spender's allowance. Returns an `Err` if the
+/// allowance is missing, expired or insufficient.
+pub fn transfer_from<S>(
+    storage: &mut S,
+    token: &Address,
+    spender: &Address,
+    owner: &Address,
+    dest: &Address,
+    amount: token::Amount,
+) -> storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let allowance = read_allowance(storage, token, owner, spender)?
+        .ok_or_else(|| {
+            storage::Error::new_alloc(format!(
+                "{spender} has no allowance for {token} from {owner}"
+            ))
+        })?;
+    if allowance.is_expired(storage.get_block_epoch()?) {
+        return Err(storage::Error::new_alloc(format!(
+            "The allowance of {spender} for {token} from {owner} has expired"
+        )));
+    }
+    let remaining = allowance.amount.checked_sub(amount).ok_or_else(|| {
+        storage::Error::new_alloc(format!(
+            "{spender} has insufficient allowance for {token} from {owner}"
+        ))
+    })?;
+    // The allowance is kept, even when fully spent, to tell apart spending
+    // from a revocation by the owner
+    storage.write(
+        &allowance_key(token, owner, spender),
+        Allowance {
+            amount: remaining,
+            ..allowance
+        },
+    )?;
+    transfer(storage, token, owner, dest, amount)
+}
+
+/// Set the grant of the `sponsor` to pay the wrapper fees of the `grantee`. A
+/// grant with a zero amount is removed.
+pub fn grant_fee<S>(
+    storage: &mut S,
+    token: &Address,
+    sponsor: &Address,
+    grantee: &Address,
+    grant: FeeGrant,
+) -> storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if sponsor == grantee {
+        return Err(storage::Error::new_const(
+            "An account cannot grant fees to itself",
+        ));
+    }
+    let key = fee_grant_key(token, sponsor, grantee);
+    if grant.amount.is_zero() {
+        storage.delete(&key)
+    } else {
+        storage.write(&key, grant)
+    }
+}
+
+/// Read the grant of the `sponsor` to pay the wrapper fees of the `grantee`.
+pub fn read_fee_grant<S>(
+    storage: &S,
+    token: &Address,
+    sponsor: &Address,
+    grantee: &Address,
+) -> storage::Result<Option<FeeGrant>>
+where
+    S: StorageRead,
+{
+    storage.read(&fee_grant_key(token, sponsor, grantee))
+}
+
+/// Check that the grant of the `sponsor` can pay the given amount of wrapper
+/// fees of the `grantee` for the txs with the given code hashes. Returns the
+/// grant with the remaining amount, or an `Err` if the grant is missing,
+/// expired, insufficient or if it doesn't allow any of the txs.
+pub fn check_fee_grant<S>(
+    storage: &S,
+    token: &Address,
+    sponsor: &Address,
+    grantee: &Address,
+    amount: token::Amount,
+    code_hashes: &[Hash],
+) -> storage::Result<FeeGrant>
+where
+    S: StorageRead,
+{
+    let grant =
+        read_fee_grant(storage, token, sponsor, grantee)?.ok_or_else(|| {
+            storage::Error::new_alloc(format!(
+                "{sponsor} has no fee grant in {token} for {grantee}"
+            ))
+        })?;
+    if grant.is_expired(storage.get_block_epoch()?) {
+        return Err(storage::Error::new_alloc(format!(
+            "The fee grant of {sponsor} for {grantee} has expired"
+        )));
+    }
+    if let Some(code_hash) =
+        code_hashes.iter().find(|hash| !grant.is_code_allowed(hash))
+    {
+        return Err(storage::Error::new_alloc(format!(
+            "The fee grant of {sponsor} for {grantee} doesn't allow the tx \
+             {code_hash}"
+        )));
+    }
+    let remaining = grant.amount.checked_sub(amount).ok_or_else(|| {
+        storage::Error::new_alloc(format!(
+            "The fee grant of {sponsor} for {grantee} is insufficient"
+        ))
+    })?;
+    Ok(FeeGrant {
+        amount: remaining,
+        ..grant
+    })
+}
+
+/// Deduct the given amount of wrapper fees of the `grantee` from the grant of
+/// the `sponsor`. The grant is kept, even when fully spent, to tell apart
+/// spending from a revocation by the sponsor.
+pub fn spend_fee_grant<S>(
+    storage: &mut S,
+    token: &Address,
+    sponsor: &Address,
+    grantee: &Address,
+    amount: token::Amount,
+    code_hashes: &[Hash],
+) -> storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let grant =
+        check_fee_grant(storage, token, sponsor, grantee, amount, code_hashes)?;
+    storage.write(&fee_grant_key(token, sponsor, grantee), grant)
+}
+
+/// Transfer tokens from `src` to `owner` and lock them in the owner's balance
+/// with the given vesting schedule. Returns an `Err` if the schedule is
+/// invalid or if the owner already has a schedule granted by `src` that is not
+/// fully vested.
+pub fn vest_tokens<S>(
+    storage: &mut S,
+    token: &Address,
+    src: &Address,
+    owner: &Address,
+    schedule: VestingSchedule,
+) -> storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if src == owner {
+        return Err(storage::Error::new_const(
+            "An account cannot vest tokens to itself",
+        ));
+    }
+    if !schedule.is_valid() {
+        return Err(storage::Error::new_const(
+            "A vesting schedule must have a non-zero amount and its start, \
+             cliff and end epochs must be ordered",
+        ));
+    }
+    if let Some(current) = read_vesting_schedule(storage, token, owner, src)? {
+        if !current.is_fully_vested(storage.get_block_epoch()?) {
+            return Err(storage::Error::new_alloc(format!(
+                "{owner} already has tokens of {token} from {src} vesting"
+            )));
+        }
+    }
+    transfer(storage, token, src, owner, schedule.amount)?;
+    storage.write(&vesting_key(token, owner, src), schedule)
+}
+
+/// Read the vesting schedule of the tokens granted by the `grantor` to the
+/// `owner`.
+pub fn read_vesting_schedule<S>(
+    storage: &S,
+    token: &Address,
+    owner: &Address,
+    grantor: &Address,
+) -> storage::Result<Option<VestingSchedule>>
+where
+    S: StorageRead,
+{
+    storage.read(&vesting_key(token, owner, grantor))
+}
+
+/// Read the amount of the `owner`'s balance that is still locked by all of
+/// its vesting schedules in the current epoch.
+pub fn read_locked_balance<S>(
+    storage: &S,
+    token: &Address,
+    owner: &Address,
+) -> storage::Result<token::Amount>
+where
+    S: StorageRead,
+{
+    let current_epoch = storage.get_block_epoch()?;
+    let prefix = vesting_owner_prefix(token, owner);
+    storage::iter_prefix::<VestingSchedule>(storage, &prefix)?.try_fold(
+        token::Amount::zero(),
+        |locked, schedule| {
+            let (_, schedule) = schedule?;
+            schedule
+                .locked_amount(current_epoch)
+                .and_then(|amount| locked.checked_add(amount))
+                .ok_or_else(|| {
+                    storage::Error::new_const("Locked amount overflow")
+                })
+        },
+    )
+}
+
+/// Read the balance of a given token and owner that can be spent, i.e.
+/// excluding the tokens still locked by a vesting schedule.
+pub fn read_spendable_balance<S>(
+    storage: &S,
+    token: &Address,
+    owner: &Address,
+) -> storage::Result<token::Amount>
+where
+    S: StorageRead,
+{
+    let balance = read_balance(storage, token, owner)?;
+    let locked = read_locked_balance(storage, token, owner)?;
+    Ok(balance.checked_sub(locked).unwrap_or_default())
+}
+
 /// Transfer tokens from `sources` to `dests`. Returns an `Err` if any source
 /// has insufficient balance or if the transfer to any destination would
 /// overflow (This can only happen if the total supply doesn't fit in
@@ -383,6 +663,88 @@ where
     decrement_total_supply(storage, token, amount_to_burn)
 }
 
+/// Derive the address of a token issued with the token factory from its
+/// issuer and symbol.
+pub fn factory_token(issuer: &Address, symbol: impl AsRef<str>) -> Address {
+    let hash = Hash::sha256(format!("{issuer}/{}", symbol.as_ref()));
+    let mut output = [0; HASH_LEN];
+    output.copy_from_slice(&hash.0[..HASH_LEN]);
+    Address::Internal(InternalAddress::FactoryToken(FactoryTokenHash(output)))
+}
+
+/// Create a new token with the token factory. The token's address must have
+/// been derived with [`factory_token`].
+pub fn create_token<S>(
+    storage: &mut S,
+    token: &Address,
+    admin: &Address,
+    minter: &Address,
+    metadata: &TokenMetadata,
+    denom: token::Denomination,
+    supply_cap: Option<token::Amount>,
+) -> storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if storage.has_key(&admin_key(token))? {
+        return Err(storage::Error::new_alloc(format!(
+            "The token {token} already exists"
+        )));
+    }
+    write_denom(storage, token, denom)?;
+    storage.write(&admin_key(token), admin)?;
+    storage.write(&minter_key(token), minter)?;
+    storage.write(&metadata_key(token), metadata)?;
+    if let Some(supply_cap) = supply_cap {
+        storage.write(&supply_cap_key(token), supply_cap)?;
+    }
+    write_params(storage, token)
+}
+
+/// Read the admin of a factory token, if any.
+pub fn read_token_admin<S>(
+    storage: &S,
+    token: &Address,
+) -> storage::Result<Option<Address>>
+where
+    S: StorageRead,
+{
+    storage.read(&admin_key(token))
+}
+
+/// Read the minter of a token, if any.
+pub fn read_token_minter<S>(
+    storage: &S,
+    token: &Address,
+) -> storage::Result<Option<Address>>
+where
+    S: StorageRead,
+{
+    storage.read(&minter_key(token))
+}
+
+/// Read the supply cap of a factory token, if any.
+pub fn read_supply_cap<S>(
+    storage: &S,
+    token: &Address,
+) -> storage::Result<Option<token::Amount>>
+where
+    S: StorageRead,
+{
+    storage.read(&supply_cap_key(token))
+}
+
+/// Read the metadata of a factory token, if any.
+pub fn read_token_metadata<S>(
+    storage: &S,
+    token: &Address,
+) -> storage::Result<Option<TokenMetadata>>
+where
+    S: StorageRead,
+{
+    storage.read(&metadata_key(token))
+}
+
 /// Add denomination info if it exists in storage.
 pub fn denominated(
     amount: token::Amount,
@@ -418,7 +780,7 @@ mod testing {
     use namada_core::{address, token};
     use namada_storage::testing::TestStorage;
 
-    use super::{burn_tokens, credit_tokens, read_balance, read_total_supply};
+    use super::*;
 
     #[test]
     fn test_burn_native_tokens() {
@@ -476,4 +838,356 @@ mod testing {
         let total_supply = read_total_supply(&storage, &native_token).unwrap();
         assert_eq!(total_supply, balance3);
     }
+
+    #[test]
+    fn test_create_factory_token() {
+        let mut storage = TestStorage::default();
+        let admin = address::testing::established_address_1();
+        let minter = address::testing::established_address_2();
+        let metadata = TokenMetadata {
+            name: "Test token".to_string(),
+            symbol: "TEST".to_string(),
+            description: None,
+        };
+        let token = factory_token(&admin, &metadata.symbol);
+        let supply_cap = token::Amount::native_whole(1_000);
+
+        // The token address is derived from the issuer and the symbol
+        assert_eq!(token, factory_token(&admin, "TEST"));
+        assert_ne!(token, factory_token(&admin, "OTHER"));
+        assert_ne!(token, factory_token(&minter, "TEST"));
+
+        create_token(
+            &mut storage,
+            &token,
+            &admin,
+            &minter,
+            &metadata,
+            6.into(),
+            Some(supply_cap),
+        )
+        .unwrap();
+        assert_eq!(read_token_admin(&storage, &token).unwrap(), Some(admin));
+        assert_eq!(
+            read_token_minter(&storage, &token).unwrap(),
+            Some(minter.clone())
+        );
+        assert_eq!(
+            read_token_metadata(&storage, &token).unwrap(),
+            Some(metadata.clone())
+        );
+        assert_eq!(
+            read_supply_cap(&storage, &token).unwrap(),
+            Some(supply_cap)
+        );
+        assert_eq!(read_denom(&storage, &token).unwrap(), Some(6.into()));
+        assert_eq!(
+            read_total_supply(&storage, &token).unwrap(),
+            token::Amount::zero()
+        );
+
+        // The same token cannot be created twice
+        let result = create_token(
+            &mut storage,
+            &token,
+            &minter,
+            &minter,
+            &metadata,
+            6.into(),
+            None,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_allowance_transfer_from() {
+        let mut storage = TestStorage::default();
+        let token = address::testing::nam();
+        let owner = address::testing::established_address_1();
+        let spender = address::testing::established_address_2();
+        let target = address::testing::established_address_3();
+
+        let balance = token::Amount::native_whole(10);
+        credit_tokens(&mut storage, &token, &owner, balance).unwrap();
+
+        // Cannot spend without an allowance
+        let result = transfer_from(
+            &mut storage,
+            &token,
+            &spender,
+            &owner,
+            &target,
+            token::Amount::native_whole(1),
+        );
+        assert!(result.is_err());
+
+        approve(
+            &mut storage,
+            &token,
+            &owner,
+            &spender,
+            token::Amount::native_whole(3),
+            None,
+        )
+        .unwrap();
+        transfer_from(
+            &mut storage,
+            &token,
+            &spender,
+            &owner,
+            &target,
+            token::Amount::native_whole(2),
+        )
+        .unwrap();
+        assert_eq!(
+            read_balance(&storage, &token, &owner).unwrap(),
+            token::Amount::native_whole(8)
+        );
+        assert_eq!(
+            read_balance(&storage, &token, &target).unwrap(),
+            token::Amount::native_whole(2)
+        );
+        assert_eq!(
+            read_allowance(&storage, &token, &owner, &spender).unwrap(),
+            Some(Allowance {
+                amount: token::Amount::native_whole(1),
+                expiry: None,
+            })
+        );
+
+        // Cannot spend more than the remaining allowance
+        let result = transfer_from(
+            &mut storage,
+            &token,
+            &spender,
+            &owner,
+            &target,
+            token::Amount::native_whole(2),
+        );
+        assert!(result.is_err());
+
+        // A zero allowance revokes it
+        approve(
+            &mut storage,
+            &token,
+            &owner,
+            &spender,
+            token::Amount::zero(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            read_allowance(&storage, &token, &owner, &spender).unwrap(),
+            None
+        );
+
+        // The allowance cannot be spent after its expiry
+        let allowance = Allowance {
+            amount: token::Amount::native_whole(1),
+            expiry: Some(Epoch(2)),
+        };
+        assert!(!allowance.is_expired(Epoch(2)));
+        assert!(allowance.is_expired(Epoch(3)));
+    }
+
+    #[test]
+    fn test_fee_grant() {
+        let mut storage = TestStorage::default();
+        let token = address::testing::nam();
+        let sponsor = address::testing::established_address_1();
+        let grantee = address::testing::established_address_2();
+        let allowed = Hash::sha256(b"tx_transfer.wasm");
+        let other = Hash::sha256(b"tx_bond.wasm");
+
+        // Cannot spend without a grant
+        let result = spend_fee_grant(
+            &mut storage,
+            &token,
+            &sponsor,
+            &grantee,
+            token::Amount::native_whole(1),
+            &[],
+        );
+        assert!(result.is_err());
+
+        grant_fee(
+            &mut storage,
+            &token,
+            &sponsor,
+            &grantee,
+            FeeGrant {
+                amount: token::Amount::native_whole(3),
+                allowed_code_hashes: BTreeSet::from([allowed]),
+                expiry: Some(Epoch(2)),
+            },
+        )
+        .unwrap();
+        spend_fee_grant(
+            &mut storage,
+            &token,
+            &sponsor,
+            &grantee,
+            token::Amount::native_whole(2),
+            &[allowed],
+        )
+        .unwrap();
+        assert_eq!(
+            read_fee_grant(&storage, &token, &sponsor, &grantee)
+                .unwrap()
+                .unwrap()
+                .amount,
+            token::Amount::native_whole(1)
+        );
+
+        // Cannot pay the fees of a tx that isn't allowed
+        let result = check_fee_grant(
+            &storage,
+            &token,
+            &sponsor,
+            &grantee,
+            token::Amount::native_whole(1),
+            &[allowed, other],
+        );
+        assert!(result.is_err());
+
+        // Cannot spend more than the remaining grant
+        let result = check_fee_grant(
+            &storage,
+            &token,
+            &sponsor,
+            &grantee,
+            token::Amount::native_whole(2),
+            &[allowed],
+        );
+        assert!(result.is_err());
+
+        // Cannot spend after the expiry
+        storage.set_mock_block_epoch(Epoch(3));
+        let result = check_fee_grant(
+            &storage,
+            &token,
+            &sponsor,
+            &grantee,
+            token::Amount::native_whole(1),
+            &[allowed],
+        );
+        assert!(result.is_err());
+
+        // A zero grant revokes it
+        grant_fee(
+            &mut storage,
+            &token,
+            &sponsor,
+            &grantee,
+            FeeGrant::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            read_fee_grant(&storage, &token, &sponsor, &grantee).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_vest_tokens() {
+        let mut storage = TestStorage::default();
+        let token = address::testing::nam();
+        let grantor = address::testing::established_address_1();
+        let owner = address::testing::established_address_2();
+
+        credit_tokens(
+            &mut storage,
+            &token,
+            &grantor,
+            token::Amount::native_whole(100),
+        )
+        .unwrap();
+        credit_tokens(
+            &mut storage,
+            &token,
+            &owner,
+            token::Amount::native_whole(5),
+        )
+        .unwrap();
+
+        let schedule = VestingSchedule {
+            amount: token::Amount::native_whole(40),
+            start_epoch: Epoch(0),
+            cliff_epoch: Epoch(0),
+            end_epoch: Epoch(4),
+        };
+        // An invalid schedule is rejected
+        let result = vest_tokens(
+            &mut storage,
+            &token,
+            &grantor,
+            &owner,
+            VestingSchedule {
+                cliff_epoch: Epoch(5),
+                ..schedule
+            },
+        );
+        assert!(result.is_err());
+
+        vest_tokens(&mut storage, &token, &grantor, &owner, schedule).unwrap();
+        assert_eq!(
+            read_balance(&storage, &token, &owner).unwrap(),
+            token::Amount::native_whole(45)
+        );
+        assert_eq!(
+            read_locked_balance(&storage, &token, &owner).unwrap(),
+            token::Amount::native_whole(40)
+        );
+        assert_eq!(
+            read_spendable_balance(&storage, &token, &owner).unwrap(),
+            token::Amount::native_whole(5)
+        );
+
+        // Another schedule cannot be granted by the same grantor while the
+        // tokens are vesting
+        let result =
+            vest_tokens(&mut storage, &token, &grantor, &owner, schedule);
+        assert!(result.is_err());
+
+        // The schedules of other grantors lock their own tokens
+        let other_grantor = address::testing::established_address_3();
+        credit_tokens(
+            &mut storage,
+            &token,
+            &other_grantor,
+            token::Amount::native_whole(10),
+        )
+        .unwrap();
+        let other_schedule = VestingSchedule {
+            amount: token::Amount::native_whole(10),
+            end_epoch: Epoch(2),
+            ..schedule
+        };
+        vest_tokens(
+            &mut storage,
+            &token,
+            &other_grantor,
+            &owner,
+            other_schedule,
+        )
+        .unwrap();
+        assert_eq!(
+            read_locked_balance(&storage, &token, &owner).unwrap(),
+            token::Amount::native_whole(50)
+        );
+
+        storage.set_mock_block_epoch(Epoch(1));
+        assert_eq!(
+            read_spendable_balance(&storage, &token, &owner).unwrap(),
+            token::Amount::native_whole(20)
+        );
+
+        // Once fully vested, a new schedule can be granted
+        storage.set_mock_block_epoch(Epoch(4));
+        assert_eq!(
+            read_spendable_balance(&storage, &token, &owner).unwrap(),
+            token::Amount::native_whole(55)
+        );
+        vest_tokens(&mut storage, &token, &grantor, &owner, schedule).unwrap();
+    }
 }
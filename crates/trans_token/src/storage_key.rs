@@ -13,6 +13,18 @@ pub const MINTER_STORAGE_KEY: &str = "minter";
 pub const MINTED_STORAGE_KEY: &str = "minted";
 /// Key segment for token parameters
 pub const PARAMETERS_STORAGE_KEY: &str = "parameters";
+/// Key segment for the admin of a factory token
+pub const ADMIN_STORAGE_KEY: &str = "admin";
+/// Key segment for the supply cap of a factory token
+pub const SUPPLY_CAP_STORAGE_KEY: &str = "supply_cap";
+/// Key segment for the metadata of a factory token
+pub const METADATA_STORAGE_KEY: &str = "metadata";
+/// Key segment for spending allowances
+pub const ALLOWANCE_STORAGE_KEY: &str = "allowance";
+/// Key segment for vesting schedules
+pub const VESTING_STORAGE_KEY: &str = "vesting";
+/// Key segment for fee grants
+pub const FEE_GRANT_STORAGE_KEY: &str = "fee_grant";
 
 /// Gets the key for the given token address, error with the given
 /// message to expect if the key is not in the address
@@ -66,6 +78,123 @@ pub fn minter_key(token_addr: &Address) -> storage::Key {
     .expect("Cannot obtain a storage key")
 }
 
+/// Obtain a storage key for the admin of a factory token.
+pub fn admin_key(token_addr: &Address) -> storage::Key {
+    storage::Key::from(
+        Address::Internal(InternalAddress::Multitoken).to_db_key(),
+    )
+    .push(&token_addr.to_db_key())
+    .expect("Cannot obtain a storage key")
+    .push(&ADMIN_STORAGE_KEY.to_owned())
+    .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key for the supply cap of a factory token.
+pub fn supply_cap_key(token_addr: &Address) -> storage::Key {
+    storage::Key::from(
+        Address::Internal(InternalAddress::Multitoken).to_db_key(),
+    )
+    .push(&token_addr.to_db_key())
+    .expect("Cannot obtain a storage key")
+    .push(&SUPPLY_CAP_STORAGE_KEY.to_owned())
+    .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key for the metadata of a factory token.
+pub fn metadata_key(token_addr: &Address) -> storage::Key {
+    storage::Key::from(
+        Address::Internal(InternalAddress::Multitoken).to_db_key(),
+    )
+    .push(&token_addr.to_db_key())
+    .expect("Cannot obtain a storage key")
+    .push(&METADATA_STORAGE_KEY.to_owned())
+    .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key for the allowance of a spender to transfer tokens
+/// out of the owner's balance.
+pub fn allowance_key(
+    token_addr: &Address,
+    owner: &Address,
+    spender: &Address,
+) -> storage::Key {
+    allowance_prefix(token_addr)
+        .push(&owner.to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&spender.to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key prefix for all the allowances of a token.
+pub fn allowance_prefix(token_addr: &Address) -> storage::Key {
+    storage::Key::from(
+        Address::Internal(InternalAddress::Multitoken).to_db_key(),
+    )
+    .push(&token_addr.to_db_key())
+    .expect("Cannot obtain a storage key")
+    .push(&ALLOWANCE_STORAGE_KEY.to_owned())
+    .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key for the vesting schedule of the tokens granted by a
+/// grantor to an owner.
+pub fn vesting_key(
+    token_addr: &Address,
+    owner: &Address,
+    grantor: &Address,
+) -> storage::Key {
+    vesting_owner_prefix(token_addr, owner)
+        .push(&grantor.to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key prefix for all the vesting schedules of an owner's
+/// balance.
+pub fn vesting_owner_prefix(
+    token_addr: &Address,
+    owner: &Address,
+) -> storage::Key {
+    vesting_prefix(token_addr)
+        .push(&owner.to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key prefix for all the vesting schedules of a token.
+pub fn vesting_prefix(token_addr: &Address) -> storage::Key {
+    storage::Key::from(
+        Address::Internal(InternalAddress::Multitoken).to_db_key(),
+    )
+    .push(&token_addr.to_db_key())
+    .expect("Cannot obtain a storage key")
+    .push(&VESTING_STORAGE_KEY.to_owned())
+    .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key for the grant of a sponsor to pay the wrapper fees of
+/// a grantee.
+pub fn fee_grant_key(
+    token_addr: &Address,
+    sponsor: &Address,
+    grantee: &Address,
+) -> storage::Key {
+    fee_grant_prefix(token_addr)
+        .push(&sponsor.to_db_key())
+        .expect("Cannot obtain a storage key")
+        .push(&grantee.to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key prefix for all the fee grants of a token.
+pub fn fee_grant_prefix(token_addr: &Address) -> storage::Key {
+    storage::Key::from(
+        Address::Internal(InternalAddress::Multitoken).to_db_key(),
+    )
+    .push(&token_addr.to_db_key())
+    .expect("Cannot obtain a storage key")
+    .push(&FEE_GRANT_STORAGE_KEY.to_owned())
+    .expect("Cannot obtain a storage key")
+}
+
 /// Obtain a storage key for the minted multitoken balance.
 pub fn minted_balance_key(token_addr: &Address) -> storage::Key {
     balance_prefix(token_addr)
@@ -157,6 +286,19 @@ pub fn is_denom_key(token_addr: &Address, key: &storage::Key) -> bool {
         ] if key == DENOM_STORAGE_KEY && addr == token_addr)
 }
 
+/// Check if the given storage key is a denomination key for an unspecified
+/// token. If it is, returns the token.
+pub fn is_any_denom_key(key: &storage::Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(token), DbKeySeg::StringSeg(denom)]
+            if denom == DENOM_STORAGE_KEY =>
+        {
+            Some(token)
+        }
+        _ => None,
+    }
+}
+
 /// Check if the given storage key is for a minter of a unspecified token.
 /// If it is, returns the token.
 pub fn is_any_minter_key(key: &storage::Key) -> Option<&Address> {
@@ -192,3 +334,79 @@ pub fn is_any_minted_balance_key(key: &storage::Key) -> Option<&Address> {
         _ => None,
     }
 }
+
+/// Check if the given storage key is for the admin, the supply cap or the
+/// metadata of an unspecified factory token. If it is, returns the token.
+pub fn is_any_factory_token_key(key: &storage::Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::AddressSeg(token),
+            DbKeySeg::StringSeg(segment),
+        ] if *addr == Address::Internal(InternalAddress::Multitoken)
+            && (segment == ADMIN_STORAGE_KEY
+                || segment == SUPPLY_CAP_STORAGE_KEY
+                || segment == METADATA_STORAGE_KEY) =>
+        {
+            Some(token)
+        }
+        _ => None,
+    }
+}
+
+/// Check if the given storage key is for an allowance of an unspecified
+/// token. If it is, returns the token, the owner and the spender.
+pub fn is_any_allowance_key(key: &storage::Key) -> Option<[&Address; 3]> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::AddressSeg(token),
+            DbKeySeg::StringSeg(allowance),
+            DbKeySeg::AddressSeg(owner),
+            DbKeySeg::AddressSeg(spender),
+        ] if *addr == Address::Internal(InternalAddress::Multitoken)
+            && allowance == ALLOWANCE_STORAGE_KEY =>
+        {
+            Some([token, owner, spender])
+        }
+        _ => None,
+    }
+}
+
+/// Check if the given storage key is a vesting schedule key. If it is, return
+/// the token, the owner and the grantor.
+pub fn is_any_vesting_key(key: &storage::Key) -> Option<[&Address; 3]> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::AddressSeg(token),
+            DbKeySeg::StringSeg(vesting),
+            DbKeySeg::AddressSeg(owner),
+            DbKeySeg::AddressSeg(grantor),
+        ] if *addr == Address::Internal(InternalAddress::Multitoken)
+            && vesting == VESTING_STORAGE_KEY =>
+        {
+            Some([token, owner, grantor])
+        }
+        _ => None,
+    }
+}
+
+/// Check if the given storage key is for a fee grant of an unspecified token.
+/// If it is, returns the token, the sponsor and the grantee.
+pub fn is_any_fee_grant_key(key: &storage::Key) -> Option<[&Address; 3]> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::AddressSeg(token),
+            DbKeySeg::StringSeg(fee_grant),
+            DbKeySeg::AddressSeg(sponsor),
+            DbKeySeg::AddressSeg(grantee),
+        ] if *addr == Address::Internal(InternalAddress::Multitoken)
+            && fee_grant == FEE_GRANT_STORAGE_KEY =>
+        {
+            Some([token, sponsor, grantee])
+        }
+        _ => None,
+    }
+}
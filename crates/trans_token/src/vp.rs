@@ -7,7 +7,9 @@ use namada_core::address::{Address, InternalAddress, GOV, POS};
 use namada_core::booleans::BoolResultUnitExt;
 use namada_core::collections::HashMap;
 use namada_core::storage::{Key, KeySeg};
-use namada_core::token::Amount;
+use namada_core::token::{
+    Allowance, Amount, Denomination, TokenMetadata, VestingSchedule,
+};
 use namada_state::StateRead;
 use namada_storage::StorageRead;
 use namada_systems::{governance, parameters};
@@ -21,9 +23,13 @@ use namada_vp::native_vp::{
 use namada_vp::VpEnv;
 use thiserror::Error;
 
+use crate::storage::{factory_token, read_locked_balance};
 use crate::storage_key::{
-    is_any_minted_balance_key, is_any_minter_key, is_any_token_balance_key,
-    is_any_token_parameter_key, minter_key,
+    admin_key, balance_key, denom_key, is_any_allowance_key, is_any_denom_key,
+    is_any_factory_token_key, is_any_fee_grant_key, is_any_minted_balance_key,
+    is_any_minter_key, is_any_token_balance_key, is_any_token_parameter_key,
+    is_any_vesting_key, metadata_key, minted_balance_key, minter_key,
+    supply_cap_key,
 };
 
 /// The owner of some balance change.
@@ -124,6 +130,9 @@ where
         let mut dec_changes: HashMap<Address, Amount> = HashMap::new();
         let mut inc_mints: HashMap<Address, Amount> = HashMap::new();
         let mut dec_mints: HashMap<Address, Amount> = HashMap::new();
+        let mut factory_tokens: BTreeSet<Address> = BTreeSet::new();
+        // The debited or vesting balances, by token and owner
+        let mut vesting_checks: BTreeSet<(Address, Address)> = BTreeSet::new();
         for key in keys_changed {
             if let Some([token, owner]) = is_any_token_balance_key(key) {
                 let pre: Amount = self.ctx.read_pre(key)?.unwrap_or_default();
@@ -162,6 +171,7 @@ where
                                 ),
                             ));
                         }
+                        vesting_checks.insert((token.clone(), owner.clone()));
                         let diff = pre
                             .checked_sub(post)
                             .expect("Underflow shouldn't happen here");
@@ -216,10 +226,37 @@ where
                         })?;
                     }
                 }
-                // Check if the minter is set
-                self.is_valid_minter(token, verifiers)?;
+                if is_factory_token(token) {
+                    factory_tokens.insert(token.clone());
+                } else {
+                    // Check if the minter is set
+                    self.is_valid_minter(token, verifiers)?;
+                }
             } else if let Some(token) = is_any_minter_key(key) {
-                self.is_valid_minter(token, verifiers)?;
+                if is_factory_token(token) {
+                    factory_tokens.insert(token.clone());
+                } else {
+                    self.is_valid_minter(token, verifiers)?;
+                }
+            } else if let Some(token) =
+                is_any_factory_token_key(key).or_else(|| {
+                    is_any_denom_key(key)
+                        .filter(|token| is_factory_token(token))
+                })
+            {
+                factory_tokens.insert(token.clone());
+            } else if let Some([token, owner, spender]) =
+                is_any_allowance_key(key)
+            {
+                self.is_valid_allowance_change(
+                    key, token, owner, spender, verifiers,
+                )?;
+            } else if let Some([_token, sponsor, grantee]) =
+                is_any_fee_grant_key(key)
+            {
+                self.is_valid_fee_grant_change(sponsor, grantee, verifiers)?;
+            } else if let Some([token, owner, _]) = is_any_vesting_key(key) {
+                vesting_checks.insert((token.clone(), owner.clone()));
             } else if is_any_token_parameter_key(key).is_some() {
                 return self.is_valid_parameter(tx_data);
             } else if key.segments.first()
@@ -236,6 +273,19 @@ where
             }
         }
 
+        for token in &factory_tokens {
+            self.is_valid_factory_token_change(token, keys_changed, verifiers)?;
+        }
+
+        for (token, owner) in &vesting_checks {
+            self.is_valid_vesting_change(
+                token,
+                owner,
+                keys_changed,
+                verifiers,
+            )?;
+        }
+
         let mut all_tokens = BTreeSet::new();
         all_tokens.extend(inc_changes.keys().cloned());
         all_tokens.extend(dec_changes.keys().cloned());
@@ -339,6 +389,318 @@ where
         }
     }
 
+    /// Check that the changes to a token issued with the token factory were
+    /// authorized by its admin or minter and that its supply cap is respected
+    pub fn is_valid_factory_token_change(
+        &self,
+        token: &Address,
+        keys_changed: &BTreeSet<Key>,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        if !is_factory_token(token) {
+            return Err(native_vp::Error::new_alloc(format!(
+                "{token} is not a factory token"
+            ))
+            .into());
+        }
+
+        match self.ctx.read_pre::<Address>(&admin_key(token))? {
+            None => self.is_valid_token_creation(token, verifiers)?,
+            Some(admin) => {
+                let is_admin = verifiers.contains(&admin);
+                if keys_changed.contains(&denom_key(token))
+                    || keys_changed.contains(&supply_cap_key(token))
+                {
+                    return Err(native_vp::Error::new_const(
+                        "The denomination and the supply cap of a factory \
+                         token cannot be changed",
+                    )
+                    .into());
+                }
+                let is_role_or_metadata_change =
+                    [admin_key(token), minter_key(token), metadata_key(token)]
+                        .iter()
+                        .any(|key| keys_changed.contains(key));
+                if is_role_or_metadata_change && !is_admin {
+                    return Err(native_vp::Error::new_const(
+                        "Only the admin can update the roles or the metadata \
+                         of a factory token",
+                    )
+                    .into());
+                }
+                if self.ctx.read_post::<Address>(&admin_key(token))?.is_none()
+                    || self
+                        .ctx
+                        .read_post::<Address>(&minter_key(token))?
+                        .is_none()
+                {
+                    return Err(native_vp::Error::new_const(
+                        "The admin and the minter of a factory token cannot \
+                         be removed",
+                    )
+                    .into());
+                }
+                if keys_changed.contains(&minted_balance_key(token))
+                    && !is_admin
+                {
+                    let minter =
+                        self.ctx.read_pre::<Address>(&minter_key(token))?;
+                    minter
+                        .is_some_and(|minter| verifiers.contains(&minter))
+                        .ok_or_else(|| {
+                            native_vp::Error::new_const(
+                                "Only the minter or the admin can mint or \
+                                 burn a factory token",
+                            )
+                        })?;
+                }
+            }
+        }
+
+        if let Some(supply_cap) =
+            self.ctx.read_post::<Amount>(&supply_cap_key(token))?
+        {
+            let total_supply: Amount = self
+                .ctx
+                .read_post(&minted_balance_key(token))?
+                .unwrap_or_default();
+            if total_supply > supply_cap {
+                return Err(native_vp::Error::new_alloc(format!(
+                    "The total supply {total_supply} of {token} exceeds its \
+                     supply cap {supply_cap}"
+                ))
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that a new token was correctly set up by the token factory and
+    /// that its creation was authorized by its admin
+    fn is_valid_token_creation(
+        &self,
+        token: &Address,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let admin = self
+            .ctx
+            .read_post::<Address>(&admin_key(token))?
+            .ok_or_else(|| {
+                native_vp::Error::new_alloc(format!(
+                    "The factory token {token} does not exist"
+                ))
+            })?;
+        let metadata = self
+            .ctx
+            .read_post::<TokenMetadata>(&metadata_key(token))?
+            .ok_or_else(|| {
+                native_vp::Error::new_const(
+                    "A factory token must be created with its metadata",
+                )
+            })?;
+        if self.ctx.read_post::<Address>(&minter_key(token))?.is_none()
+            || self
+                .ctx
+                .read_post::<Denomination>(&denom_key(token))?
+                .is_none()
+        {
+            return Err(native_vp::Error::new_const(
+                "A factory token must be created with a minter and a \
+                 denomination",
+            )
+            .into());
+        }
+        if *token != factory_token(&admin, &metadata.symbol) {
+            return Err(native_vp::Error::new_alloc(format!(
+                "The address of the factory token {token} doesn't match its \
+                 admin and symbol"
+            ))
+            .into());
+        }
+        verifiers.contains(&admin).ok_or_else(|| {
+            native_vp::Error::new_const(
+                "The creation of a factory token must be authorized by its \
+                 admin",
+            )
+            .into()
+        })
+    }
+
+    /// Check that an allowance is granted, updated or revoked with the
+    /// authorization of its owner and that it's only spent before its expiry
+    /// with the authorization of both its owner and its spender. An allowance
+    /// is spent when its amount is lowered along with a debit of the owner's
+    /// balance, otherwise the owner is lowering it. The owner's VP is
+    /// responsible for checking that the spent allowance covers the debit
+    /// from its balance.
+    pub fn is_valid_allowance_change(
+        &self,
+        key: &Key,
+        token: &Address,
+        owner: &Address,
+        spender: &Address,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        if owner == spender {
+            return Err(native_vp::Error::new_const(
+                "An account cannot grant an allowance to itself",
+            )
+            .into());
+        }
+        let pre: Option<Allowance> = self.ctx.read_pre(key)?;
+        let post: Option<Allowance> = self.ctx.read_post(key)?;
+        let balance_key = balance_key(token, owner);
+        let pre_balance: Amount =
+            self.ctx.read_pre(&balance_key)?.unwrap_or_default();
+        let post_balance: Amount =
+            self.ctx.read_post(&balance_key)?.unwrap_or_default();
+        let is_debited = post_balance < pre_balance;
+        match (pre, post) {
+            (Some(pre), Some(post))
+                if is_debited
+                    && post.amount < pre.amount
+                    && post.expiry == pre.expiry =>
+            {
+                if pre.is_expired(self.ctx.get_block_epoch()?) {
+                    return Err(native_vp::Error::new_alloc(format!(
+                        "The allowance of {spender} from {owner} has expired"
+                    ))
+                    .into());
+                }
+                (verifiers.contains(owner) && verifiers.contains(spender))
+                    .ok_or_else(|| {
+                        native_vp::Error::new_const(
+                            "Spending an allowance must be verified by both \
+                             its owner and its spender",
+                        )
+                        .into()
+                    })
+            }
+            _ => verifiers.contains(owner).ok_or_else(|| {
+                native_vp::Error::new_const(
+                    "An allowance can only be granted, updated or revoked by \
+                     its owner",
+                )
+                .into()
+            }),
+        }
+    }
+
+    /// Check that a fee grant is granted, updated or revoked with the
+    /// authorization of its sponsor. A fee grant is only spent by the protocol
+    /// when it pays the fees of a wrapper tx, so no tx can spend it.
+    pub fn is_valid_fee_grant_change(
+        &self,
+        sponsor: &Address,
+        grantee: &Address,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        if sponsor == grantee {
+            return Err(native_vp::Error::new_const(
+                "An account cannot grant fees to itself",
+            )
+            .into());
+        }
+        verifiers.contains(sponsor).ok_or_else(|| {
+            native_vp::Error::new_const(
+                "A fee grant can only be granted, updated or revoked by its \
+                 sponsor",
+            )
+            .into()
+        })
+    }
+
+    /// Check that a vesting schedule is only granted by its grantor along
+    /// with the credit of its tokens to the owner's balance, that it is only
+    /// replaced or removed once fully vested and that the owner's balance
+    /// never drops below the tokens that are still locked by all of its
+    /// schedules. Bonds debit the owner's balance like any other transfer, so
+    /// vesting tokens cannot be bonded until released.
+    pub fn is_valid_vesting_change(
+        &self,
+        token: &Address,
+        owner: &Address,
+        keys_changed: &BTreeSet<Key>,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let balance_key = balance_key(token, owner);
+        let current_epoch = self.ctx.get_block_epoch()?;
+        let post_balance: Amount =
+            self.ctx.read_post(&balance_key)?.unwrap_or_default();
+
+        // The total amount of the schedules granted by the tx
+        let mut granted = Amount::zero();
+        for key in keys_changed {
+            let Some([key_token, key_owner, grantor]) = is_any_vesting_key(key)
+            else {
+                continue;
+            };
+            if key_token != token || key_owner != owner {
+                continue;
+            }
+            if grantor == owner {
+                return Err(native_vp::Error::new_const(
+                    "An account cannot vest tokens to itself",
+                )
+                .into());
+            }
+            let pre: Option<VestingSchedule> = self.ctx.read_pre(key)?;
+            if pre.is_some_and(|pre| !pre.is_fully_vested(current_epoch)) {
+                return Err(native_vp::Error::new_alloc(format!(
+                    "The vesting schedule of {owner} for {token} from \
+                     {grantor} cannot be changed before it is fully vested"
+                ))
+                .into());
+            }
+            let post: Option<VestingSchedule> = self.ctx.read_post(key)?;
+            if let Some(post) = post {
+                if !post.is_valid() {
+                    return Err(native_vp::Error::new_const(
+                        "Invalid vesting schedule",
+                    )
+                    .into());
+                }
+                if !verifiers.contains(grantor) {
+                    return Err(native_vp::Error::new_const(
+                        "A vesting schedule can only be granted by its \
+                         grantor",
+                    )
+                    .into());
+                }
+                granted =
+                    granted.checked_add(post.amount).ok_or_else(|| {
+                        native_vp::Error::new_const(
+                            "Overflowed in vesting check",
+                        )
+                    })?;
+            }
+        }
+
+        if !granted.is_zero() {
+            let pre_balance: Amount =
+                self.ctx.read_pre(&balance_key)?.unwrap_or_default();
+            let credit =
+                post_balance.checked_sub(pre_balance).unwrap_or_default();
+            if credit < granted {
+                return Err(native_vp::Error::new_const(
+                    "A vesting schedule must be granted along with the \
+                     credit of its tokens",
+                )
+                .into());
+            }
+        }
+
+        let locked = read_locked_balance(&self.ctx.post(), token, owner)?;
+        if post_balance < locked {
+            return Err(native_vp::Error::new_alloc(format!(
+                "The balance of {owner} cannot drop below its {locked} \
+                 locked tokens of {token}"
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
     /// Return if the parameter change was done via a governance proposal
     pub fn is_valid_parameter(
         &'view self,
@@ -366,6 +728,11 @@ where
     }
 }
 
+/// Check if the given token was issued with the token factory
+fn is_factory_token(token: &Address) -> bool {
+    matches!(token, Address::Internal(InternalAddress::FactoryToken(_)))
+}
+
 fn has_bal_inc_protocol_action(action: &Action, owner: Owner<'_>) -> bool {
     match action {
         Action::Pos(
@@ -378,6 +745,13 @@ fn has_bal_inc_protocol_action(action: &Action, owner: Owner<'_>) -> bool {
             // NB: pos or gov's balance can increase
             Owner::Protocol => true,
         },
+        Action::Gov(GovAction::CancelProposal { id: _, author }) => {
+            match owner {
+                Owner::Account(owner) => author == owner,
+                // NB: gov's balance can't increase on a refund
+                Owner::Protocol => false,
+            }
+        }
         // NB: only pos or gov balances can decrease with these actions
         Action::Pos(PosAction::Bond(Bond { .. }))
         | Action::Gov(GovAction::InitProposal { .. }) => {
@@ -410,7 +784,10 @@ fn has_bal_dec_protocol_action(action: &Action, owner: Owner<'_>) -> bool {
         Action::Pos(
             PosAction::ClaimRewards(ClaimRewards { .. })
             | PosAction::Withdraw(Withdraw { .. }),
-        ) => owner == Owner::Protocol,
+        )
+        | Action::Gov(GovAction::CancelProposal { .. }) => {
+            owner == Owner::Protocol
+        }
         // NB: every other case is invalid
         _ => false,
     }
@@ -422,10 +799,13 @@ mod tests {
 
     use assert_matches::assert_matches;
     use namada_core::address::testing::{
-        established_address_1, established_address_2, nam,
+        established_address_1, established_address_2, established_address_3,
+        nam,
     };
     use namada_core::borsh::BorshSerializeExt;
     use namada_core::key::testing::keypair_1;
+    use namada_core::storage::Epoch;
+    use namada_core::token::FeeGrant;
     use namada_gas::{TxGasMeter, VpGasMeter};
     use namada_ibc::trace::ibc_token;
     use namada_parameters::storage::get_native_token_transferable_key;
@@ -441,7 +821,10 @@ mod tests {
     use namada_vm::WasmCacheRwAccess;
 
     use super::*;
-    use crate::storage_key::{balance_key, minted_balance_key};
+    use crate::storage_key::{
+        allowance_key, balance_key, fee_grant_key, minted_balance_key,
+        vesting_key,
+    };
 
     const ADDRESS: Address = Address::Internal(InternalAddress::Multitoken);
 
@@ -1133,4 +1516,473 @@ mod tests {
             Ok(_)
         );
     }
+
+    /// Write the keys of a new factory token into the tx write log and
+    /// return the changed keys
+    fn write_factory_token(
+        state: &mut TestState,
+        token: &Address,
+        admin: &Address,
+        supply_cap: Option<Amount>,
+    ) -> BTreeSet<Key> {
+        let metadata = TokenMetadata {
+            name: "Test token".to_string(),
+            symbol: "TEST".to_string(),
+            description: None,
+        };
+        let mut writes = vec![
+            (denom_key(token), Denomination(6).serialize_to_vec()),
+            (admin_key(token), admin.serialize_to_vec()),
+            (minter_key(token), admin.serialize_to_vec()),
+            (metadata_key(token), metadata.serialize_to_vec()),
+            (minted_balance_key(token), Amount::zero().serialize_to_vec()),
+        ];
+        if let Some(supply_cap) = supply_cap {
+            writes.push((supply_cap_key(token), supply_cap.serialize_to_vec()));
+        }
+        let mut keys_changed = BTreeSet::new();
+        for (key, value) in writes {
+            state
+                .write_log_mut()
+                .write(&key, value)
+                .expect("write failed");
+            keys_changed.insert(key);
+        }
+        keys_changed
+    }
+
+    fn validate_multitoken_tx(
+        state: &TestState,
+        keys_changed: &BTreeSet<Key>,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let tx_index = TxIndex::default();
+        let BatchedTx { tx, cmt } = dummy_tx(state);
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_vp_cache, _vp_cache_dir) = vp_cache();
+        let ctx = Ctx::new(
+            &ADDRESS,
+            state,
+            &tx,
+            &cmt,
+            &tx_index,
+            &gas_meter,
+            keys_changed,
+            verifiers,
+            vp_vp_cache,
+        );
+
+        let vp = MultitokenVp::new(ctx);
+        vp.validate_tx(&tx.batch_ref_tx(&cmt), keys_changed, verifiers)
+    }
+
+    #[test]
+    fn test_factory_token_creation() {
+        let mut state = init_state();
+        let admin = established_address_1();
+        let token = factory_token(&admin, "TEST");
+        let keys_changed =
+            write_factory_token(&mut state, &token, &admin, None);
+
+        // The admin must authorize the creation
+        let verifiers = BTreeSet::from([token.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+        let verifiers = BTreeSet::from([admin.clone(), token]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+
+        // The token address must be derived from the admin and symbol
+        let mut state = init_state();
+        let token = factory_token(&admin, "OTHER");
+        let keys_changed =
+            write_factory_token(&mut state, &token, &admin, None);
+        let verifiers = BTreeSet::from([admin, token]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+    }
+
+    #[test]
+    fn test_factory_token_mint() {
+        let mut state = init_state();
+        let admin = established_address_1();
+        let minter = established_address_2();
+        let token = factory_token(&admin, "TEST");
+        write_factory_token(
+            &mut state,
+            &token,
+            &admin,
+            Some(Amount::native_whole(100)),
+        );
+        state
+            .write_log_mut()
+            .write(&minter_key(&token), minter.serialize_to_vec())
+            .expect("write failed");
+        state.write_log_mut().commit_batch();
+        state.commit_block().unwrap();
+
+        let mint = |state: &mut TestState, amount: Amount| {
+            let target_key = balance_key(&token, &established_address_3());
+            let minted_key = minted_balance_key(&token);
+            for key in [&target_key, &minted_key] {
+                state
+                    .write_log_mut()
+                    .write(key, amount.serialize_to_vec())
+                    .expect("write failed");
+            }
+            BTreeSet::from([target_key, minted_key])
+        };
+
+        // Minting must be authorized by the minter or the admin
+        let keys_changed = mint(&mut state, Amount::native_whole(100));
+        for verifiers in [
+            BTreeSet::from([minter.clone(), token.clone()]),
+            BTreeSet::from([admin.clone(), token.clone()]),
+        ] {
+            assert_matches!(
+                validate_multitoken_tx(&state, &keys_changed, &verifiers),
+                Ok(_)
+            );
+        }
+        let verifiers = BTreeSet::from([token.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+
+        // The supply cap cannot be exceeded
+        let keys_changed = mint(&mut state, Amount::native_whole(101));
+        let verifiers = BTreeSet::from([minter.clone(), token.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+
+        // Only the admin can rotate the minter
+        state.write_log_mut().drop_tx();
+        let key = minter_key(&token);
+        state
+            .write_log_mut()
+            .write(&key, established_address_3().serialize_to_vec())
+            .expect("write failed");
+        let keys_changed = BTreeSet::from([key]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+        let verifiers = BTreeSet::from([admin, token]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+    }
+
+    #[test]
+    fn test_allowance_changes() {
+        let mut state = init_state();
+        let owner = established_address_1();
+        let spender = established_address_2();
+        let key = allowance_key(&nam(), &owner, &spender);
+        let allowance = Allowance {
+            amount: Amount::native_whole(10),
+            expiry: Some(Epoch(1)),
+        };
+
+        // Granting an allowance must be authorized by the owner
+        state
+            .write_log_mut()
+            .write(&key, allowance.serialize_to_vec())
+            .expect("write failed");
+        let keys_changed = BTreeSet::from([key.clone()]);
+        let verifiers = BTreeSet::from([spender.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+        let verifiers = BTreeSet::from([owner.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+        state.write_log_mut().commit_batch();
+        state.commit_block().unwrap();
+
+        // Lowering an allowance without debiting the owner must be
+        // authorized by the owner only
+        let spent = Allowance {
+            amount: Amount::native_whole(4),
+            ..allowance
+        };
+        state
+            .write_log_mut()
+            .write(&key, spent.serialize_to_vec())
+            .expect("write failed");
+        let verifiers = BTreeSet::from([spender.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+        let verifiers = BTreeSet::from([owner.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+
+        // Spending an allowance along with a debit of the owner must be
+        // authorized by the owner and the spender
+        let mut keys_changed = transfer(&mut state, &owner, &spender);
+        keys_changed.insert(key.clone());
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+        let verifiers = BTreeSet::from([owner.clone(), spender.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+
+        // An expired allowance cannot be spent
+        state.in_mem_mut().block.epoch = Epoch(2);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+
+        // Revoking an allowance must be authorized by the owner
+        state.write_log_mut().drop_tx();
+        state.write_log_mut().delete(&key).expect("delete failed");
+        let keys_changed = BTreeSet::from([key.clone()]);
+        let verifiers = BTreeSet::from([spender]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+        let verifiers = BTreeSet::from([owner]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+    }
+
+    #[test]
+    fn test_fee_grant_changes() {
+        let mut state = init_state();
+        let sponsor = established_address_1();
+        let grantee = established_address_2();
+        let key = fee_grant_key(&nam(), &sponsor, &grantee);
+        let grant = FeeGrant {
+            amount: Amount::native_whole(10),
+            allowed_code_hashes: Default::default(),
+            expiry: None,
+        };
+
+        // Granting fees must be authorized by the sponsor
+        state
+            .write_log_mut()
+            .write(&key, grant.serialize_to_vec())
+            .expect("write failed");
+        let keys_changed = BTreeSet::from([key.clone()]);
+        let verifiers = BTreeSet::from([grantee.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+        let verifiers = BTreeSet::from([sponsor.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+        state.write_log_mut().commit_batch();
+        state.commit_block().unwrap();
+
+        // A tx cannot spend the grant without the sponsor
+        let spent = FeeGrant {
+            amount: Amount::native_whole(4),
+            ..grant
+        };
+        state
+            .write_log_mut()
+            .write(&key, spent.serialize_to_vec())
+            .expect("write failed");
+        let verifiers = BTreeSet::from([grantee]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+
+        // A sponsor cannot grant fees to itself
+        let key = fee_grant_key(&nam(), &sponsor, &sponsor);
+        state
+            .write_log_mut()
+            .write(&key, spent.serialize_to_vec())
+            .expect("write failed");
+        let keys_changed = BTreeSet::from([key]);
+        let verifiers = BTreeSet::from([sponsor]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+    }
+
+    #[test]
+    fn test_vesting_changes() {
+        let mut state = init_state();
+        let grantor = established_address_1();
+        let owner = established_address_2();
+        let target = established_address_3();
+        let key = vesting_key(&nam(), &owner, &grantor);
+        let schedule = VestingSchedule {
+            amount: Amount::native_whole(10),
+            start_epoch: Epoch(0),
+            cliff_epoch: Epoch(0),
+            end_epoch: Epoch(10),
+        };
+
+        // A schedule must be granted along with the credit of its tokens
+        state
+            .write_log_mut()
+            .write(&key, schedule.serialize_to_vec())
+            .expect("write failed");
+        let keys_changed = BTreeSet::from([key.clone()]);
+        let verifiers = BTreeSet::from([grantor.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+        let mut keys_changed = transfer(&mut state, &grantor, &owner);
+        keys_changed.insert(key.clone());
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+        state.write_log_mut().commit_batch();
+        state.commit_block().unwrap();
+
+        // The locked tokens cannot be spent
+        let owner_key = balance_key(&nam(), &owner);
+        let target_key = balance_key(&nam(), &target);
+        let amount = Amount::native_whole(5);
+        state
+            .write_log_mut()
+            .write(&owner_key, amount.serialize_to_vec())
+            .expect("write failed");
+        state
+            .write_log_mut()
+            .write(&target_key, amount.serialize_to_vec())
+            .expect("write failed");
+        let keys_changed = BTreeSet::from([owner_key, target_key]);
+        let verifiers = BTreeSet::from([owner.clone()]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+
+        // Half of the tokens are released half-way through the schedule
+        state.in_mem_mut().block.epoch = Epoch(5);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+
+        // The schedule cannot be removed before it's fully vested
+        state.write_log_mut().drop_tx();
+        state.write_log_mut().delete(&key).expect("delete failed");
+        let keys_changed = BTreeSet::from([key]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+        state.in_mem_mut().block.epoch = Epoch(10);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+    }
+
+    /// Test that crediting an owner doesn't allow to attach a schedule on
+    /// behalf of another grantor, which would block the grants of the latter.
+    #[test]
+    fn test_vesting_griefing() {
+        let mut state = init_state();
+        let grantor = established_address_1();
+        let owner = established_address_2();
+        let griefer = established_address_3();
+        let schedule = VestingSchedule {
+            amount: Amount::native_whole(10),
+            start_epoch: Epoch(0),
+            cliff_epoch: Epoch(0),
+            end_epoch: Epoch(10),
+        };
+        let verifiers = BTreeSet::from([griefer.clone()]);
+
+        // The griefer cannot grant a schedule in the name of the grantor
+        let mut keys_changed = transfer(&mut state, &griefer, &owner);
+        let grantor_key = vesting_key(&nam(), &owner, &grantor);
+        state
+            .write_log_mut()
+            .write(&grantor_key, schedule.serialize_to_vec())
+            .expect("write failed");
+        keys_changed.insert(grantor_key.clone());
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Err(_)
+        );
+
+        // The griefer's own schedule only locks the tokens it granted
+        state.write_log_mut().drop_tx();
+        let mut keys_changed = transfer(&mut state, &griefer, &owner);
+        let griefer_key = vesting_key(&nam(), &owner, &griefer);
+        state
+            .write_log_mut()
+            .write(&griefer_key, schedule.serialize_to_vec())
+            .expect("write failed");
+        keys_changed.insert(griefer_key);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+        state.write_log_mut().commit_batch();
+        state.commit_block().unwrap();
+
+        // The grantor can still grant its own schedule to the owner
+        let grantor_balance_key = balance_key(&nam(), &grantor);
+        state
+            .db_write(
+                &grantor_balance_key,
+                Amount::native_whole(100).serialize_to_vec(),
+            )
+            .expect("write failed");
+        state
+            .write_log_mut()
+            .write(
+                &grantor_balance_key,
+                Amount::native_whole(90).serialize_to_vec(),
+            )
+            .expect("write failed");
+        let owner_key = balance_key(&nam(), &owner);
+        state
+            .write_log_mut()
+            .write(&owner_key, Amount::native_whole(20).serialize_to_vec())
+            .expect("write failed");
+        state
+            .write_log_mut()
+            .write(&grantor_key, schedule.serialize_to_vec())
+            .expect("write failed");
+        let keys_changed =
+            BTreeSet::from([grantor_balance_key, owner_key, grantor_key]);
+        let verifiers = BTreeSet::from([grantor]);
+        assert_matches!(
+            validate_multitoken_tx(&state, &keys_changed, &verifiers),
+            Ok(_)
+        );
+    }
 }
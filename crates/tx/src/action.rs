@@ -13,7 +13,7 @@ use namada_core::storage::KeySeg;
 use namada_core::{address, storage};
 
 pub use crate::data::pos::{
-    Bond, ClaimRewards, Redelegation, Unbond, Withdraw,
+    AutoRestake, Bond, ClaimRewards, Redelegation, Unbond, Withdraw,
 };
 
 /// Actions applied from txs.
@@ -28,6 +28,7 @@ pub enum Action {
     Pgf(PgfAction),
     Masp(MaspAction),
     IbcShielding,
+    Ibc(IbcAction),
 }
 
 /// PoS tx actions.
@@ -43,6 +44,7 @@ pub enum PosAction {
     Withdraw(Withdraw),
     Redelegation(Redelegation),
     ClaimRewards(ClaimRewards),
+    AutoRestake(AutoRestake),
     CommissionChange(Address),
     MetadataChange(Address),
     ConsensusKeyChange(Address),
@@ -54,6 +56,8 @@ pub enum PosAction {
 pub enum GovAction {
     InitProposal { author: Address },
     VoteProposal { id: u64, voter: Address },
+    CancelProposal { id: u64, author: Address },
+    AmendProposal { id: u64, author: Address },
 }
 
 /// PGF tx actions.
@@ -73,6 +77,16 @@ pub enum MaspAction {
     MaspAuthorizer(Address),
 }
 
+/// IBC tx actions.
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub enum IbcAction {
+    /// The owner of an interchain account controller port registered or
+    /// drove its account on a counterparty chain
+    ControlInterchainAccount(Address),
+    /// A relayer registered its payee for the relayer fees
+    RegisterPayee(Address),
+}
+
 /// Read actions from temporary storage
 pub trait Read {
     /// Storage access errors
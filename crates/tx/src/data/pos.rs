@@ -126,6 +126,30 @@ pub struct ClaimRewards {
     pub source: Option<Address>,
 }
 
+/// An update of a bond's automatic compounding of its rewards.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct AutoRestake {
+    /// Validator address
+    pub validator: Address,
+    /// Source address of the bond. For self-bonds, the validator is also the
+    /// source
+    pub source: Option<Address>,
+    /// Whether the bond's rewards should be restaked at every new epoch
+    pub enabled: bool,
+}
+
 /// A redelegation of bonded tokens from one validator to another.
 #[derive(
     Debug,
@@ -265,6 +289,21 @@ pub mod tests {
         }
     }
 
+    prop_compose! {
+        /// Generate an arbitrary auto-restake update
+        pub fn arb_auto_restake()(
+            validator in arb_non_internal_address(),
+            source in option::of(arb_non_internal_address()),
+            enabled in proptest::bool::ANY,
+        ) -> AutoRestake {
+            AutoRestake {
+                validator,
+                source,
+                enabled,
+            }
+        }
+    }
+
     prop_compose! {
         /// Generate an arbitrary commission change
         pub fn arb_commission_change()(
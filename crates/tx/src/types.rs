@@ -788,6 +788,8 @@ pub enum Section {
     MaspBuilder(MaspBuilder),
     /// Wrap a header with a section for the purposes of computing hashes
     Header(Header),
+    /// The account sponsoring the fees of the wrapper with a fee grant
+    FeeSponsor(Address),
 }
 
 impl Section {
@@ -809,6 +811,10 @@ impl Section {
                 hasher
             }
             Self::Header(header) => header.hash(hasher),
+            Self::FeeSponsor(sponsor) => {
+                hasher.update(sponsor.serialize_to_vec());
+                hasher
+            }
         }
     }
 
@@ -1449,6 +1455,25 @@ impl Tx {
         signatures
     }
 
+    /// Verify a signature produced by [`Tx::compute_section_signature`]
+    /// against the public key it carries
+    pub fn verify_section_signature(
+        &self,
+        signature: &SignatureIndex,
+    ) -> std::result::Result<(), VerifySigError> {
+        let section = Authorization {
+            targets: vec![self.raw_header_hash()],
+            signatures: BTreeMap::new(),
+            signer: Signer::PubKeys(vec![]),
+        };
+        common::SigScheme::verify_signature(
+            &signature.pubkey,
+            &section.get_raw_hash(),
+            &signature.signature,
+        )?;
+        Ok(())
+    }
+
     /// Determines the type of the input Tx
     ///
     /// If it is a raw Tx, signed or not, the Tx is
@@ -1628,6 +1653,21 @@ impl Tx {
         self
     }
 
+    /// Add the account sponsoring the fees of the wrapper. It must be added
+    /// before the wrapper is signed.
+    pub fn add_fee_sponsor(&mut self, sponsor: Address) -> &mut Self {
+        self.add_section(Section::FeeSponsor(sponsor));
+        self
+    }
+
+    /// Get the account sponsoring the fees of the wrapper, if any
+    pub fn fee_sponsor(&self) -> Option<&Address> {
+        self.sections.iter().find_map(|section| match section {
+            Section::FeeSponsor(sponsor) => Some(sponsor),
+            _ => None,
+        })
+    }
+
     /// Add fee payer keypair to the tx builder
     pub fn sign_wrapper(&mut self, keypair: common::SecretKey) -> &mut Self {
         self.protocol_filter();
@@ -1841,4 +1881,71 @@ mod test {
             Tx::try_from(tmp.as_ref()).unwrap();
         }
     }
+
+    /// Signatures computed offline must verify against the tx they were
+    /// computed for only
+    #[test]
+    fn test_verify_section_signature() {
+        use namada_core::address::testing::established_address_1;
+        use namada_core::key::testing::{keypair_1, keypair_2};
+
+        let owner = established_address_1();
+        let public_keys_map = AccountPublicKeysMap::from_iter([
+            keypair_1().ref_to(),
+            keypair_2().ref_to(),
+        ]);
+        let mut tx = Tx::new(ChainId::default(), None);
+        tx.add_serialized_data(vec![1, 2, 3]);
+        let signatures = tx.compute_section_signature(
+            &[keypair_1(), keypair_2()],
+            &public_keys_map,
+            Some(owner),
+        );
+        assert_eq!(signatures.len(), 2);
+        for signature in &signatures {
+            assert!(tx.verify_section_signature(signature).is_ok());
+        }
+
+        // A signature with a wrong public key
+        let mut signature = signatures[0].clone();
+        signature.pubkey = keypair_2().ref_to();
+        assert!(tx.verify_section_signature(&signature).is_err());
+
+        // A signature over a different tx
+        let mut other_tx = Tx::new(ChainId::default(), None);
+        other_tx.add_serialized_data(vec![4, 5, 6]);
+        assert!(other_tx.verify_section_signature(&signatures[0]).is_err());
+    }
+
+    /// The fee sponsor must be covered by the wrapper signature
+    #[test]
+    fn test_fee_sponsor_signed() {
+        use namada_core::address::testing::{established_address_1, nam};
+        use namada_core::key::testing::keypair_1;
+        use namada_core::token::DenominatedAmount;
+
+        let sponsor = established_address_1();
+        let mut wrapper = Tx::new(ChainId::default(), None);
+        wrapper.add_serialized_data(vec![1, 2, 3]);
+        wrapper.add_wrapper(
+            Fee {
+                amount_per_gas_unit: DenominatedAmount::native(1.into()),
+                token: nam(),
+            },
+            keypair_1().ref_to(),
+            GasLimit::from(100_000),
+        );
+        assert!(wrapper.fee_sponsor().is_none());
+
+        let mut sponsored = wrapper.clone();
+        sponsored.add_fee_sponsor(sponsor.clone());
+        sponsored.sign_wrapper(keypair_1());
+        assert_eq!(sponsored.fee_sponsor(), Some(&sponsor));
+        assert!(sponsored.validate_tx().is_ok());
+
+        // A sponsor added after the signature is rejected
+        wrapper.sign_wrapper(keypair_1());
+        wrapper.add_fee_sponsor(sponsor);
+        assert!(wrapper.validate_tx().is_err());
+    }
 }
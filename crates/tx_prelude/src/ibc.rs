@@ -12,8 +12,9 @@ pub use namada_ibc::storage::{
 };
 pub use namada_ibc::trace::ibc_token;
 pub use namada_ibc::{
-    IbcActions, IbcCommonContext, IbcStorageContext, NftTransferModule,
-    ProofSpec, TransferModule,
+    decode_message, IbcActions, IbcCommonContext, IbcMessage,
+    IbcStorageContext, InterchainAccountControllerModule,
+    InterchainAccountHostModule, NftTransferModule, ProofSpec, TransferModule,
 };
 use namada_tx_env::TxEnv;
 
@@ -32,7 +33,15 @@ pub fn ibc_actions(
     let mut actions = IbcActions::new(ctx.clone(), verifiers.clone());
     let module = TransferModule::new(ctx.clone(), verifiers);
     actions.add_transfer_module(module);
-    let module = NftTransferModule::<Ctx, crate::token::Store<Ctx>>::new(ctx);
+    let module =
+        NftTransferModule::<Ctx, crate::token::Store<Ctx>>::new(ctx.clone());
+    actions.add_transfer_module(module);
+    let module =
+        InterchainAccountHostModule::<Ctx, crate::token::Store<Ctx>>::new(
+            ctx.clone(),
+        );
+    actions.add_transfer_module(module);
+    let module = InterchainAccountControllerModule::new(ctx);
     actions.add_transfer_module(module);
     actions
 }
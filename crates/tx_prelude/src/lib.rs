@@ -31,6 +31,7 @@ pub use namada_core::borsh::{
 };
 use namada_core::chain::CHAIN_ID_LENGTH;
 pub use namada_core::ethereum_events::EthAddress;
+use namada_core::hash::{HashAlgorithm, HASH_LENGTH};
 use namada_core::internal::HostEnvResult;
 use namada_core::key::common;
 use namada_core::storage::TxIndex;
@@ -460,6 +461,43 @@ pub fn verify_signatures_of_pks(
     Ok(HostEnvResult::is_success(valid))
 }
 
+/// Hash some data with the given algorithm in the host environment
+pub fn hash_bytes(algorithm: HashAlgorithm, data: &[u8]) -> [u8; HASH_LENGTH] {
+    let mut result = [0u8; HASH_LENGTH];
+    unsafe {
+        namada_tx_hash(
+            algorithm as u8,
+            data.as_ptr() as _,
+            data.len() as _,
+            result.as_mut_ptr() as _,
+        );
+    }
+    result
+}
+
+/// Verify a signature over some arbitrary data in the host environment
+pub fn verify_signature(
+    public_key: &common::PublicKey,
+    data: &[u8],
+    signature: &common::Signature,
+) -> bool {
+    let public_key = public_key.serialize_to_vec();
+    let signature = signature.serialize_to_vec();
+
+    let valid = unsafe {
+        namada_tx_verify_signature(
+            public_key.as_ptr() as _,
+            public_key.len() as _,
+            data.as_ptr() as _,
+            data.len() as _,
+            signature.as_ptr() as _,
+            signature.len() as _,
+        )
+    };
+
+    HostEnvResult::is_success(valid)
+}
+
 /// Update the masp note commitment tree in storage with the new notes
 pub fn update_masp_note_commitment_tree(
     transaction: &Transaction,
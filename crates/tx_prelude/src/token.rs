@@ -6,11 +6,14 @@ use namada_core::address::Address;
 use namada_core::collections::HashSet;
 use namada_events::extend::UserAccount;
 use namada_events::{EmitEvents, EventLevel};
+use namada_storage::{Error, StorageWrite};
 use namada_token::event::{TokenEvent, TokenOperation};
 #[cfg(any(test, feature = "testing"))]
 pub use namada_token::testing;
 pub use namada_token::{
-    storage_key, utils, Amount, DenominatedAmount, Store, Transfer,
+    storage_key, utils, Amount, Approve, BurnTokens, CreateToken,
+    DenominatedAmount, FeeGrant, GrantFee, MintTokens, Store, Transfer,
+    TransferFrom, UpdateToken, VestTokens, VestingSchedule,
 };
 use namada_tx_env::TxEnv;
 
@@ -112,3 +115,255 @@ pub fn multi_transfer(
 
     Ok(debited_accounts)
 }
+
+/// Grant, update or revoke the allowance of a spender to transfer tokens out
+/// of the owner's balance.
+pub fn approve(ctx: &mut Ctx, data: &Approve) -> TxResult {
+    // The tx must be authorized by the owner of the balance
+    ctx.insert_verifier(&data.owner)?;
+
+    namada_token::approve(
+        ctx,
+        &data.token,
+        &data.owner,
+        &data.spender,
+        data.amount,
+        data.expiry,
+    )
+}
+
+/// Transfer tokens out of the owner's balance on behalf of a spender with an
+/// allowance.
+pub fn transfer_from(ctx: &mut Ctx, data: &TransferFrom) -> TxResult {
+    // The tx must be authorized by the spender and the owner's VP has to
+    // check that the debit is covered by the spent allowance
+    ctx.insert_verifier(&data.spender)?;
+    ctx.insert_verifier(&data.owner)?;
+    if data.token.is_internal() {
+        // Established address tokens do not have VPs themselves, their
+        // validation is handled by the `Multitoken` internal address, but
+        // internal token addresses have to verify the transfer
+        ctx.insert_verifier(&data.token)?;
+    }
+
+    namada_token::transfer_from(
+        ctx,
+        &data.token,
+        &data.spender,
+        &data.owner,
+        &data.target,
+        data.amount,
+    )?;
+
+    ctx.emit(TokenEvent {
+        descriptor: "transfer-from-allowance-wasm".into(),
+        level: EventLevel::Tx,
+        operation: TokenOperation::transfer(
+            UserAccount::Internal(data.owner.clone()),
+            UserAccount::Internal(data.target.clone()),
+            data.token.clone(),
+            data.amount.into(),
+            namada_token::read_balance(ctx, &data.token, &data.owner)?.into(),
+            Some(
+                namada_token::read_balance(ctx, &data.token, &data.target)?
+                    .into(),
+            ),
+        ),
+    });
+
+    Ok(())
+}
+
+/// Grant, update or revoke the sponsorship of the wrapper fees of a grantee.
+pub fn grant_fee(ctx: &mut Ctx, data: &GrantFee) -> TxResult {
+    // The tx must be authorized by the sponsor
+    ctx.insert_verifier(&data.sponsor)?;
+
+    namada_token::grant_fee(
+        ctx,
+        &data.token,
+        &data.sponsor,
+        &data.grantee,
+        data.grant.clone(),
+    )
+}
+
+/// Grant tokens to the target that are locked in its balance and released
+/// by the given vesting schedule.
+pub fn vest_tokens(ctx: &mut Ctx, data: &VestTokens) -> TxResult {
+    // The tx must be authorized by the source of the tokens
+    ctx.insert_verifier(&data.source)?;
+    if data.token.is_internal() {
+        // Established address tokens do not have VPs themselves, their
+        // validation is handled by the `Multitoken` internal address, but
+        // internal token addresses have to verify the transfer
+        ctx.insert_verifier(&data.token)?;
+    }
+
+    namada_token::vest_tokens(
+        ctx,
+        &data.token,
+        &data.source,
+        &data.target,
+        data.schedule,
+    )?;
+
+    ctx.emit(TokenEvent {
+        descriptor: "vest-tokens-wasm".into(),
+        level: EventLevel::Tx,
+        operation: TokenOperation::transfer(
+            UserAccount::Internal(data.source.clone()),
+            UserAccount::Internal(data.target.clone()),
+            data.token.clone(),
+            data.schedule.amount.into(),
+            namada_token::read_balance(ctx, &data.token, &data.source)?.into(),
+            Some(
+                namada_token::read_balance(ctx, &data.token, &data.target)?
+                    .into(),
+            ),
+        ),
+    });
+
+    Ok(())
+}
+
+/// Create a new token with the token factory. Returns the address of the new
+/// token.
+pub fn create_token(
+    ctx: &mut Ctx,
+    data: &CreateToken,
+) -> crate::EnvResult<Address> {
+    let token = data.token();
+    // The tx must be authorized by the admin of the new token
+    ctx.insert_verifier(&data.admin)?;
+    ctx.insert_verifier(&token)?;
+
+    namada_token::create_token(
+        ctx,
+        &token,
+        &data.admin,
+        data.minter.as_ref().unwrap_or(&data.admin),
+        &data.metadata,
+        data.denom,
+        data.supply_cap,
+    )?;
+
+    Ok(token)
+}
+
+/// Mint tokens issued with the token factory.
+pub fn mint(ctx: &mut Ctx, data: &MintTokens) -> TxResult {
+    check_supply_authority(ctx, data)?;
+    ctx.insert_verifier(&data.token)?;
+
+    namada_token::credit_tokens(ctx, &data.token, &data.owner, data.amount)?;
+    if let Some(supply_cap) = namada_token::read_supply_cap(ctx, &data.token)? {
+        let total_supply = namada_token::read_total_supply(ctx, &data.token)?;
+        if total_supply > supply_cap {
+            return Err(Error::new_alloc(format!(
+                "Minting {} of {} would exceed its supply cap",
+                data.amount, data.token
+            )));
+        }
+    }
+
+    ctx.emit(TokenEvent {
+        descriptor: "mint-from-wasm".into(),
+        level: EventLevel::Tx,
+        operation: TokenOperation::Mint {
+            token: data.token.clone(),
+            amount: data.amount.into(),
+            post_balance: namada_token::read_balance(
+                ctx,
+                &data.token,
+                &data.owner,
+            )?
+            .into(),
+            target_account: UserAccount::Internal(data.owner.clone()),
+        },
+    });
+
+    Ok(())
+}
+
+/// Burn tokens issued with the token factory.
+pub fn burn(ctx: &mut Ctx, data: &BurnTokens) -> TxResult {
+    check_supply_authority(ctx, data)?;
+    // The tx must be authorized by the owner of the burned tokens
+    ctx.insert_verifier(&data.owner)?;
+    ctx.insert_verifier(&data.token)?;
+
+    namada_token::decrement_balance(
+        ctx,
+        &data.token,
+        &data.owner,
+        data.amount,
+    )?;
+    namada_token::decrement_total_supply(ctx, &data.token, data.amount)?;
+
+    ctx.emit(TokenEvent {
+        descriptor: "burn-from-wasm".into(),
+        level: EventLevel::Tx,
+        operation: TokenOperation::Burn {
+            token: data.token.clone(),
+            amount: data.amount.into(),
+            post_balance: namada_token::read_balance(
+                ctx,
+                &data.token,
+                &data.owner,
+            )?
+            .into(),
+            target_account: UserAccount::Internal(data.owner.clone()),
+        },
+    });
+
+    Ok(())
+}
+
+/// Update the roles or the metadata of a token issued with the token factory.
+pub fn update_token(ctx: &mut Ctx, data: &UpdateToken) -> TxResult {
+    if namada_token::read_token_admin(ctx, &data.token)?.as_ref()
+        != Some(&data.admin)
+    {
+        return Err(Error::new_alloc(format!(
+            "{} is not the admin of {}",
+            data.admin, data.token
+        )));
+    }
+    // The tx must be authorized by the current admin
+    ctx.insert_verifier(&data.admin)?;
+
+    if let Some(new_admin) = &data.new_admin {
+        ctx.write(&storage_key::admin_key(&data.token), new_admin)?;
+    }
+    if let Some(new_minter) = &data.new_minter {
+        ctx.write(&storage_key::minter_key(&data.token), new_minter)?;
+    }
+    if let Some(new_metadata) = &data.new_metadata {
+        ctx.write(&storage_key::metadata_key(&data.token), new_metadata)?;
+    }
+    Ok(())
+}
+
+/// Check that the authority of a supply change is the minter or the admin of
+/// the token and require its authorization.
+fn check_supply_authority(ctx: &mut Ctx, data: &MintTokens) -> TxResult {
+    let minter = namada_token::read_token_minter(ctx, &data.token)?;
+    let admin = namada_token::read_token_admin(ctx, &data.token)?;
+    if admin.is_none() {
+        return Err(Error::new_alloc(format!(
+            "{} is not a factory token",
+            data.token
+        )));
+    }
+    if minter.as_ref() != Some(&data.authority)
+        && admin.as_ref() != Some(&data.authority)
+    {
+        return Err(Error::new_alloc(format!(
+            "{} is neither the minter nor the admin of {}",
+            data.authority, data.token
+        )));
+    }
+    ctx.insert_verifier(&data.authority)?;
+    Ok(())
+}
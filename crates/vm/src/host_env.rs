@@ -9,8 +9,9 @@ use std::num::TryFromIntError;
 use namada_core::address::{self, Address, ESTABLISHED_ADDRESS_BYTES_LEN};
 use namada_core::arith::{self, checked};
 use namada_core::borsh::{BorshDeserialize, BorshSerializeExt};
-use namada_core::hash::Hash;
+use namada_core::hash::{Hash, HashAlgorithm};
 use namada_core::internal::{HostEnvResult, KeyVal};
+use namada_core::key::{common, SigScheme};
 use namada_core::storage::{BlockHeight, Key, TxIndex, TX_INDEX_LENGTH};
 use namada_events::{Event, EventTypeBuilder};
 use namada_gas::{
@@ -78,6 +79,8 @@ pub enum TxRuntimeError {
     NoValueInResultBuffer,
     #[error("VP code is not allowed in allowlist parameter.")]
     DisallowedVp,
+    #[error("Invalid hash algorithm: {0}")]
+    InvalidHashAlgorithm(u8),
     #[error("Arithmetic {0}")]
     Arith(#[from] arith::Error),
 }
@@ -2011,6 +2014,88 @@ where
     }
 }
 
+/// Hash some data with the given algorithm in the host environment for better
+/// performance. The digest is written to the `result_ptr`.
+pub fn vp_hash<MEM, D, H, EVAL, CA>(
+    env: &mut VpVmEnv<MEM, D, H, EVAL, CA>,
+    algorithm: u8,
+    data_ptr: u64,
+    data_len: u64,
+    result_ptr: u64,
+) -> vp_host_fns::EnvResult<()>
+where
+    MEM: VmMemory,
+    D: 'static + DB + for<'iter> DBIter<'iter>,
+    H: 'static + StorageHasher,
+    EVAL: VpEvaluator,
+    CA: WasmCacheAccess,
+{
+    let algorithm = HashAlgorithm::try_from(algorithm).map_err(|_| {
+        vp_host_fns::RuntimeError::InvalidHashAlgorithm(algorithm)
+    })?;
+    let gas_meter = env.ctx.gas_meter();
+    vp_host_fns::add_gas(gas_meter, hash_gas(algorithm, data_len)?)?;
+
+    let (data, gas) = env
+        .memory
+        .read_bytes(data_ptr, data_len.try_into()?)
+        .map_err(|e| vp_host_fns::RuntimeError::MemoryError(Box::new(e)))?;
+    vp_host_fns::add_gas(gas_meter, gas)?;
+
+    let gas = env
+        .memory
+        .write_bytes(result_ptr, algorithm.digest(data))
+        .map_err(|e| vp_host_fns::RuntimeError::MemoryError(Box::new(e)))?;
+    vp_host_fns::add_gas(gas_meter, gas)
+}
+
+/// Verify a signature over some arbitrary data in the host environment for
+/// better performance. The public key and the signature are expected to be
+/// borsh encoded [`common`](namada_core::key::common) values.
+pub fn vp_verify_signature<MEM, D, H, EVAL, CA>(
+    env: &mut VpVmEnv<MEM, D, H, EVAL, CA>,
+    public_key_ptr: u64,
+    public_key_len: u64,
+    data_ptr: u64,
+    data_len: u64,
+    signature_ptr: u64,
+    signature_len: u64,
+) -> vp_host_fns::EnvResult<i64>
+where
+    MEM: VmMemory,
+    D: 'static + DB + for<'iter> DBIter<'iter>,
+    H: 'static + StorageHasher,
+    EVAL: VpEvaluator,
+    CA: WasmCacheAccess,
+{
+    let gas_meter = env.ctx.gas_meter();
+    vp_host_fns::add_gas(gas_meter, verify_signature_gas(data_len)?)?;
+
+    let (public_key, gas) = env
+        .memory
+        .read_bytes(public_key_ptr, public_key_len.try_into()?)
+        .map_err(|e| vp_host_fns::RuntimeError::MemoryError(Box::new(e)))?;
+    vp_host_fns::add_gas(gas_meter, gas)?;
+    let public_key = common::PublicKey::try_from_slice(&public_key)
+        .map_err(vp_host_fns::RuntimeError::EncodingError)?;
+
+    let (data, gas) = env
+        .memory
+        .read_bytes(data_ptr, data_len.try_into()?)
+        .map_err(|e| vp_host_fns::RuntimeError::MemoryError(Box::new(e)))?;
+    vp_host_fns::add_gas(gas_meter, gas)?;
+
+    let (signature, gas) = env
+        .memory
+        .read_bytes(signature_ptr, signature_len.try_into()?)
+        .map_err(|e| vp_host_fns::RuntimeError::MemoryError(Box::new(e)))?;
+    vp_host_fns::add_gas(gas_meter, gas)?;
+    let signature = common::Signature::try_from_slice(&signature)
+        .map_err(vp_host_fns::RuntimeError::EncodingError)?;
+
+    Ok(verify_signature(&public_key, &data, &signature).to_i64())
+}
+
 /// Log a string from exposed to the wasm VM Tx environment. The message will be
 /// printed at the [`tracing::Level::INFO`]. This function is for development
 /// only.
@@ -2167,6 +2252,114 @@ where
     }
 }
 
+/// Hash some data with the given algorithm in the host environment for better
+/// performance. The digest is written to the `result_ptr`.
+pub fn tx_hash<MEM, D, H, CA>(
+    env: &mut TxVmEnv<MEM, D, H, CA>,
+    algorithm: u8,
+    data_ptr: u64,
+    data_len: u64,
+    result_ptr: u64,
+) -> TxResult<()>
+where
+    MEM: VmMemory,
+    D: 'static + DB + for<'iter> DBIter<'iter>,
+    H: 'static + StorageHasher,
+    CA: WasmCacheAccess,
+{
+    let algorithm = HashAlgorithm::try_from(algorithm)
+        .map_err(|_| TxRuntimeError::InvalidHashAlgorithm(algorithm))?;
+    tx_charge_gas::<MEM, D, H, CA>(env, hash_gas(algorithm, data_len)?)?;
+
+    let (data, gas) = env
+        .memory
+        .read_bytes(data_ptr, data_len.try_into()?)
+        .map_err(|e| TxRuntimeError::MemoryError(Box::new(e)))?;
+    tx_charge_gas::<MEM, D, H, CA>(env, gas)?;
+
+    let gas = env
+        .memory
+        .write_bytes(result_ptr, algorithm.digest(data))
+        .map_err(|e| TxRuntimeError::MemoryError(Box::new(e)))?;
+    tx_charge_gas::<MEM, D, H, CA>(env, gas)
+}
+
+/// Verify a signature over some arbitrary data in the host environment for
+/// better performance. The public key and the signature are expected to be
+/// borsh encoded [`common`](namada_core::key::common) values.
+pub fn tx_verify_signature<MEM, D, H, CA>(
+    env: &mut TxVmEnv<MEM, D, H, CA>,
+    public_key_ptr: u64,
+    public_key_len: u64,
+    data_ptr: u64,
+    data_len: u64,
+    signature_ptr: u64,
+    signature_len: u64,
+) -> TxResult<i64>
+where
+    MEM: VmMemory,
+    D: 'static + DB + for<'iter> DBIter<'iter>,
+    H: 'static + StorageHasher,
+    CA: WasmCacheAccess,
+{
+    tx_charge_gas::<MEM, D, H, CA>(env, verify_signature_gas(data_len)?)?;
+
+    let (public_key, gas) = env
+        .memory
+        .read_bytes(public_key_ptr, public_key_len.try_into()?)
+        .map_err(|e| TxRuntimeError::MemoryError(Box::new(e)))?;
+    tx_charge_gas::<MEM, D, H, CA>(env, gas)?;
+    let public_key = common::PublicKey::try_from_slice(&public_key)
+        .map_err(TxRuntimeError::EncodingError)?;
+
+    let (data, gas) = env
+        .memory
+        .read_bytes(data_ptr, data_len.try_into()?)
+        .map_err(|e| TxRuntimeError::MemoryError(Box::new(e)))?;
+    tx_charge_gas::<MEM, D, H, CA>(env, gas)?;
+
+    let (signature, gas) = env
+        .memory
+        .read_bytes(signature_ptr, signature_len.try_into()?)
+        .map_err(|e| TxRuntimeError::MemoryError(Box::new(e)))?;
+    tx_charge_gas::<MEM, D, H, CA>(env, gas)?;
+    let signature = common::Signature::try_from_slice(&signature)
+        .map_err(TxRuntimeError::EncodingError)?;
+
+    Ok(verify_signature(&public_key, &data, &signature).to_i64())
+}
+
+/// The gas cost of hashing `data_len` bytes with the given algorithm
+fn hash_gas(
+    algorithm: HashAlgorithm,
+    data_len: u64,
+) -> Result<u64, arith::Error> {
+    let gas_per_byte = match algorithm {
+        HashAlgorithm::Sha256 => gas::SHA256_GAS_PER_BYTE,
+        HashAlgorithm::Keccak256 => gas::KECCAK256_GAS_PER_BYTE,
+        HashAlgorithm::Blake2b256 => gas::BLAKE2B256_GAS_PER_BYTE,
+    };
+    checked!(gas::HASH_FIXED_GAS + gas_per_byte * data_len)
+}
+
+/// The gas cost of verifying a signature over `data_len` bytes, which includes
+/// the cost of hashing the data to be verified
+fn verify_signature_gas(data_len: u64) -> Result<u64, arith::Error> {
+    let hash_gas = hash_gas(HashAlgorithm::Sha256, data_len)?;
+    checked!(gas::VERIFY_SIG_GAS + hash_gas)
+}
+
+/// Verify a signature over some arbitrary data
+fn verify_signature(
+    public_key: &common::PublicKey,
+    data: &[u8],
+    signature: &common::Signature,
+) -> HostEnvResult {
+    common::SigScheme::verify_signature(public_key, &data, signature)
+        .is_ok()
+        .into()
+}
+
 /// Appends the new note commitments to the tree in storage
 pub fn tx_update_masp_note_commitment_tree<MEM, D, H, CA>(
     env: &mut TxVmEnv<MEM, D, H, CA>,
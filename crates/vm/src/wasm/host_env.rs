@@ -40,6 +40,7 @@ where
             "namada_tx_get_native_token" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_1(host_env::tx_get_native_token)),
             "namada_tx_get_pred_epochs" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_0(host_env::tx_get_pred_epochs)),
             "namada_tx_get_tx_index" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_0(host_env::tx_get_tx_index)),
+            "namada_tx_hash" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_4(host_env::tx_hash)),
             "namada_tx_has_key" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_2(host_env::tx_has_key)),
             "namada_tx_init_account" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_7(host_env::tx_init_account)),
             "namada_tx_insert_verifier" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_2(host_env::tx_insert_verifier)),
@@ -52,6 +53,7 @@ where
             "namada_tx_set_commitment_sentinel" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_0(host_env::tx_set_commitment_sentinel)),
             "namada_tx_update_masp_note_commitment_tree" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_2(host_env::tx_update_masp_note_commitment_tree)),
             "namada_tx_update_validity_predicate" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_6(host_env::tx_update_validity_predicate)),
+            "namada_tx_verify_signature" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_6(host_env::tx_verify_signature)),
             "namada_tx_verify_tx_section_signature" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_5(host_env::tx_verify_tx_section_signature)),
             "namada_tx_write" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_4(host_env::tx_write)),
             "namada_tx_write_temp" => Function::new_typed_with_env(wasm_store, &env, wrap_tx::_4(host_env::tx_write_temp)),
@@ -89,6 +91,7 @@ where
             "namada_vp_get_pred_epochs" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_0(host_env::vp_get_pred_epochs)),
             "namada_vp_get_tx_code_hash" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_1(host_env::vp_get_tx_code_hash)),
             "namada_vp_get_tx_index" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_0(host_env::vp_get_tx_index)),
+            "namada_vp_hash" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_4(host_env::vp_hash)),
             "namada_vp_has_key_post" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_2(host_env::vp_has_key_post)),
             "namada_vp_has_key_pre" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_2(host_env::vp_has_key_pre)),
             "namada_vp_iter_next" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_1(host_env::vp_iter_next)),
@@ -99,6 +102,7 @@ where
             "namada_vp_read_pre" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_2(host_env::vp_read_pre)),
             "namada_vp_read_temp" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_2(host_env::vp_read_temp)),
             "namada_vp_result_buffer" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_1(host_env::vp_result_buffer)),
+            "namada_vp_verify_signature" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_6(host_env::vp_verify_signature)),
             "namada_vp_verify_tx_section_signature" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_7(host_env::vp_verify_tx_section_signature)),
             "namada_vp_yield_value" => Function::new_typed_with_env(wasm_store, &env, wrap_vp::_2(host_env::vp_yield_value)),
         },
@@ -371,6 +375,50 @@ mod wrap_vp {
         }
     }
 
+    pub(super) fn _6<
+        F,
+        ARG0,
+        ARG1,
+        ARG2,
+        ARG3,
+        ARG4,
+        ARG5,
+        RET,
+        D,
+        H,
+        EVAL,
+        CA,
+    >(
+        f: F,
+    ) -> impl Fn(
+        FunctionEnvMut<'_, VpVmEnv<WasmMemory, D, H, EVAL, CA>>,
+        ARG0,
+        ARG1,
+        ARG2,
+        ARG3,
+        ARG4,
+        ARG5,
+    ) -> RET
+    where
+        D: DB + for<'iter> DBIter<'iter> + 'static,
+        H: StorageHasher + 'static,
+        CA: WasmCacheAccess + 'static,
+        EVAL: VpEvaluator<Db = D, H = H, Eval = EVAL, CA = CA> + 'static,
+        F: Fn(
+            &mut VpVmEnv<WasmMemory, D, H, EVAL, CA>,
+            ARG0,
+            ARG1,
+            ARG2,
+            ARG3,
+            ARG4,
+            ARG5,
+        ) -> RET,
+    {
+        move |mut env, arg0, arg1, arg2, arg3, arg4, arg5| {
+            f(env.data_mut(), arg0, arg1, arg2, arg3, arg4, arg5)
+        }
+    }
+
     pub(super) fn _7<
         F,
         ARG0,
@@ -146,6 +146,25 @@ pub mod tx {
             threshold: u8,
         ) -> i64;
 
+        /// Hash the given data with the given algorithm, the digest is written
+        /// to the result pointer
+        pub fn namada_tx_hash(
+            algorithm: u8,
+            data_ptr: u64,
+            data_len: u64,
+            result_ptr: u64,
+        );
+
+        /// Verify a signature over the given data
+        pub fn namada_tx_verify_signature(
+            public_key_ptr: u64,
+            public_key_len: u64,
+            data_ptr: u64,
+            data_len: u64,
+            signature_ptr: u64,
+            signature_len: u64,
+        ) -> i64;
+
         /// Update the masp note commitment tree with the new notes
         pub fn namada_tx_update_masp_note_commitment_tree(
             transaction_ptr: u64,
@@ -260,6 +279,25 @@ pub mod vp {
             threshold: u8,
         );
 
+        /// Hash the given data with the given algorithm, the digest is written
+        /// to the result pointer
+        pub fn namada_vp_hash(
+            algorithm: u8,
+            data_ptr: u64,
+            data_len: u64,
+            result_ptr: u64,
+        );
+
+        /// Verify a signature over the given data
+        pub fn namada_vp_verify_signature(
+            public_key_ptr: u64,
+            public_key_len: u64,
+            data_ptr: u64,
+            data_len: u64,
+            signature_ptr: u64,
+            signature_len: u64,
+        ) -> i64;
+
         /// Evaluate a validity-predicate
         pub fn namada_vp_eval(
             vp_code_hash_ptr: u64,
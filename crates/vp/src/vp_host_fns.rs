@@ -42,6 +42,8 @@ pub enum RuntimeError {
     NoValueInResultBuffer,
     #[error("The section signature is invalid: {0}")]
     InvalidSectionSignature(String),
+    #[error("Invalid hash algorithm: {0}")]
+    InvalidHashAlgorithm(u8),
     #[error("{0}")]
     Erased(String), // type erased error
     #[error("Arithmetic {0}")]
@@ -30,8 +30,9 @@ pub use namada_core::borsh::{
 };
 use namada_core::chain::CHAIN_ID_LENGTH;
 pub use namada_core::collections::HashSet;
-use namada_core::hash::{Hash, HASH_LENGTH};
+use namada_core::hash::{Hash, HashAlgorithm, HASH_LENGTH};
 use namada_core::internal::HostEnvResult;
+use namada_core::key::common;
 use namada_core::storage::{BlockHeight, Epoch, Epochs, Header, TxIndex};
 pub use namada_core::validity_predicate::{VpError, VpErrorExtResult};
 pub use namada_core::*;
@@ -61,6 +62,43 @@ pub fn sha256(bytes: &[u8]) -> Hash {
     Hash(*digest.as_ref())
 }
 
+/// Hash some data with the given algorithm in the host environment
+pub fn hash_bytes(algorithm: HashAlgorithm, data: &[u8]) -> [u8; HASH_LENGTH] {
+    let mut result = [0u8; HASH_LENGTH];
+    unsafe {
+        namada_vp_hash(
+            algorithm as u8,
+            data.as_ptr() as _,
+            data.len() as _,
+            result.as_mut_ptr() as _,
+        );
+    }
+    result
+}
+
+/// Verify a signature over some arbitrary data in the host environment
+pub fn verify_signature(
+    public_key: &common::PublicKey,
+    data: &[u8],
+    signature: &common::Signature,
+) -> bool {
+    let public_key = public_key.serialize_to_vec();
+    let signature = signature.serialize_to_vec();
+
+    let valid = unsafe {
+        namada_vp_verify_signature(
+            public_key.as_ptr() as _,
+            public_key.len() as _,
+            data.as_ptr() as _,
+            data.len() as _,
+            signature.as_ptr() as _,
+            signature.len() as _,
+        )
+    };
+
+    HostEnvResult::is_success(valid)
+}
+
 /// Log a string. The message will be printed at the `tracing::Level::Info`.
 pub fn log_string<T: AsRef<str>>(msg: T) {
     let msg = msg.as_ref();
@@ -0,0 +1,20 @@
+//! A tx for a user to enable or disable the automatic restaking of PoS
+//! inflationary rewards of a bond.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let auto_restake = transaction::pos::AutoRestake::try_from_slice(&data[..])
+        .wrap_err("Failed to decode AutoRestake value")?;
+
+    ctx.set_auto_restake(
+        auto_restake.source.as_ref(),
+        &auto_restake.validator,
+        auto_restake.enabled,
+    )
+    .wrap_err("Failed to update auto-restake")?;
+
+    Ok(())
+}
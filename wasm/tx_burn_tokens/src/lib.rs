@@ -0,0 +1,13 @@
+//! A tx to burn tokens issued with the token factory.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let burn = token::BurnTokens::try_from_slice(&data[..])
+        .wrap_err("Failed to decode BurnTokens tx data")?;
+    debug_log!("apply_tx called to burn tokens: {:#?}", burn);
+
+    token::burn(ctx, &burn).wrap_err("Failed to burn tokens")
+}
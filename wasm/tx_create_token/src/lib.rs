@@ -0,0 +1,17 @@
+//! A tx to create a new token with the token factory.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let create_token = token::CreateToken::try_from_slice(&data[..])
+        .wrap_err("Failed to decode CreateToken tx data")?;
+    debug_log!("apply_tx called to create a token: {:#?}", create_token);
+
+    let token = token::create_token(ctx, &create_token)
+        .wrap_err("Failed to create a new token")?;
+    debug_log!("Created token {}", token);
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+//! A tx to grant, update or revoke the sponsorship of wrapper fees.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let grant_fee = token::GrantFee::try_from_slice(&data[..])
+        .wrap_err("Failed to decode GrantFee tx data")?;
+    debug_log!("apply_tx called to grant fees: {:#?}", grant_fee);
+
+    token::grant_fee(ctx, &grant_fee).wrap_err("Failed to grant fees")
+}
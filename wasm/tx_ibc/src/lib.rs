@@ -5,7 +5,7 @@
 
 use std::collections::BTreeMap;
 
-use namada_tx_prelude::action::{Action, MaspAction, Write};
+use namada_tx_prelude::action::{Action, IbcAction, MaspAction, Write};
 use namada_tx_prelude::*;
 
 #[transaction]
@@ -15,6 +15,24 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
         .execute::<token::Transfer>(&data)
         .into_storage_result()?;
 
+    match ibc::decode_message::<token::Transfer>(&data).into_storage_result()? {
+        // The owner of an interchain account has to authorize it
+        ibc::IbcMessage::InterchainAccount(msg) => {
+            ctx.push_action(Action::Ibc(IbcAction::ControlInterchainAccount(
+                msg.owner().clone(),
+            )))?;
+        }
+        // The relayer has to authorize the registration of its payee
+        ibc::IbcMessage::Fee(msg) => {
+            if let Some(relayer) = msg.relayer() {
+                ctx.push_action(Action::Ibc(IbcAction::RegisterPayee(
+                    relayer.clone(),
+                )))?;
+            }
+        }
+        _ => {}
+    }
+
     let masp_section_ref = if let Some(transfers) = transfer {
         // Prepare the sources of the multi-transfer
         let sources = transfers
@@ -0,0 +1,13 @@
+//! A tx to mint tokens issued with the token factory.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let mint = token::MintTokens::try_from_slice(&data[..])
+        .wrap_err("Failed to decode MintTokens tx data")?;
+    debug_log!("apply_tx called to mint tokens: {:#?}", mint);
+
+    token::mint(ctx, &mint).wrap_err("Failed to mint tokens")
+}
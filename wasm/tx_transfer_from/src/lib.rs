@@ -0,0 +1,18 @@
+//! A tx to transfer tokens out of an owner's balance with a spending
+//! allowance.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let transfer = token::TransferFrom::try_from_slice(&data[..])
+        .wrap_err("Failed to decode TransferFrom tx data")?;
+    debug_log!(
+        "apply_tx called to transfer from an allowance: {:#?}",
+        transfer
+    );
+
+    token::transfer_from(ctx, &transfer)
+        .wrap_err("Failed to transfer from an allowance")
+}
@@ -0,0 +1,69 @@
+//! A tx to cancel or amend a governance proposal before its voting period.
+
+use namada_tx_prelude::action::{Action, GovAction, Write};
+use namada_tx_prelude::governance::event::GovernanceEvent;
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let BatchedTx { tx, cmt: _ } = tx_data;
+    let tx_data = governance::UpdateProposalData::try_from_slice(&data[..])
+        .wrap_err("Failed to decode UpdateProposalData value")?;
+
+    // The tx must be authorized by the author address
+    ctx.insert_verifier(&tx_data.author)?;
+
+    match tx_data.update {
+        governance::ProposalUpdate::Cancel => {
+            ctx.push_action(Action::Gov(GovAction::CancelProposal {
+                id: tx_data.id,
+                author: tx_data.author.clone(),
+            }))?;
+
+            debug_log!("apply_tx called to cancel a governance proposal");
+
+            governance::cancel_proposal::<_, token::Store<_>>(
+                ctx,
+                tx_data.id,
+                &tx_data.author,
+            )
+            .wrap_err("Failed to cancel governance proposal")?;
+
+            ctx.emit_event(GovernanceEvent::cancelled_proposal(tx_data.id))
+        }
+        governance::ProposalUpdate::Amend(content_hash) => {
+            ctx.push_action(Action::Gov(GovAction::AmendProposal {
+                id: tx_data.id,
+                author: tx_data.author.clone(),
+            }))?;
+
+            // Get the content from the referred to section
+            let content = tx
+                .get_section(&content_hash)
+                .ok_or_err_msg("Missing proposal content")
+                .map_err(|err| {
+                    ctx.set_commitment_sentinel();
+                    err
+                })?
+                .extra_data()
+                .ok_or_err_msg("Missing full proposal content")
+                .map_err(|err| {
+                    ctx.set_commitment_sentinel();
+                    err
+                })?;
+
+            debug_log!("apply_tx called to amend a governance proposal");
+
+            governance::amend_proposal(
+                ctx,
+                tx_data.id,
+                &tx_data.author,
+                content,
+            )
+            .wrap_err("Failed to amend governance proposal")?;
+
+            ctx.emit_event(GovernanceEvent::amended_proposal(tx_data.id))
+        }
+    }
+}
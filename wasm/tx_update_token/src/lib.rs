@@ -0,0 +1,14 @@
+//! A tx to update the roles or the metadata of a token issued with the
+//! token factory.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let update = token::UpdateToken::try_from_slice(&data[..])
+        .wrap_err("Failed to decode UpdateToken tx data")?;
+    debug_log!("apply_tx called to update a token: {:#?}", update);
+
+    token::update_token(ctx, &update).wrap_err("Failed to update the token")
+}
@@ -0,0 +1,13 @@
+//! A tx to grant tokens released by a vesting schedule.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let vest = token::VestTokens::try_from_slice(&data[..])
+        .wrap_err("Failed to decode VestTokens tx data")?;
+    debug_log!("apply_tx called to vest tokens: {:#?}", vest);
+
+    token::vest_tokens(ctx, &vest).wrap_err("Failed to vest tokens")
+}
@@ -77,8 +77,10 @@ fn validate_tx(
                     source, validator, ..
                 })
                 | PosAction::Withdraw(Withdraw { source, validator })
-                | PosAction::ClaimRewards(ClaimRewards { validator, source }) =>
-                {
+                | PosAction::ClaimRewards(ClaimRewards { validator, source })
+                | PosAction::AutoRestake(AutoRestake {
+                    validator, source, ..
+                }) => {
                     let source = source.unwrap_or(validator);
                     gadget.verify_signatures_when(
                         || source == addr,
@@ -105,13 +107,20 @@ fn validate_tx(
                 .verify_signatures_when(|| source == addr, ctx, &tx, &addr)?,
             Action::Masp(MaspAction::MaspSectionRef(_)) => (),
             Action::IbcShielding => (),
+            Action::Ibc(IbcAction::ControlInterchainAccount(source)) => gadget
+                .verify_signatures_when(|| source == addr, ctx, &tx, &addr)?,
+            Action::Ibc(IbcAction::RegisterPayee(source)) => gadget
+                .verify_signatures_when(|| source == addr, ctx, &tx, &addr)?,
         }
     }
 
     keys_changed.iter().try_for_each(|key| {
         let key_type: KeyType = key.into();
         let mut validate_change = || match key_type {
-            KeyType::TokenBalance { owner, .. } => {
+            KeyType::TokenBalance {
+                token: token_addr,
+                owner,
+            } => {
                 if owner == &addr {
                     let pre: token::Amount =
                         ctx.read_pre(key).into_vp_error()?.unwrap_or_default();
@@ -119,9 +128,18 @@ fn validate_tx(
                         ctx.read_post(key).into_vp_error()?.unwrap_or_default();
                     let change =
                         post.change().checked_sub(pre.change()).unwrap();
+                    let is_allowance_debit = change.is_negative()
+                        && pre.checked_sub(post).unwrap_or_default()
+                            <= spent_allowance(
+                                ctx,
+                                token_addr,
+                                &addr,
+                                &keys_changed,
+                            )?;
                     gadget.verify_signatures_when(
-                        // NB: debit has to signed, credit doesn't
-                        || change.is_negative(),
+                        // NB: debit has to signed, credit doesn't, unless the
+                        // debit is covered by the spent allowances
+                        || change.is_negative() && !is_allowance_debit,
                         ctx,
                         &tx,
                         &addr,
@@ -140,6 +158,35 @@ fn validate_tx(
                 }
                 Ok(())
             }
+            KeyType::TokenAllowance {
+                token,
+                owner,
+                spender,
+            } => {
+                let is_spent = is_allowance_spent(ctx, key, token, owner)?;
+                gadget.verify_signatures_when(
+                    // NB: granting, updating or revoking an allowance has to
+                    // be signed by the owner, spending it by the spender
+                    || {
+                        if is_spent {
+                            spender == &addr
+                        } else {
+                            owner == &addr
+                        }
+                    },
+                    ctx,
+                    &tx,
+                    &addr,
+                )
+            }
+            KeyType::TokenFeeGrant(sponsor) => gadget.verify_signatures_when(
+                // NB: granting, updating or revoking the sponsorship of fees
+                // has to be signed by the sponsor
+                || sponsor == &addr,
+                ctx,
+                &tx,
+                &addr,
+            ),
             KeyType::TokenMinted => {
                 verifiers.contains(&address::MULTITOKEN).ok_or_else(|| {
                     VpError::Erased(
@@ -180,7 +227,16 @@ fn validate_tx(
 }
 
 enum KeyType<'a> {
-    TokenBalance { owner: &'a Address },
+    TokenBalance {
+        token: &'a Address,
+        owner: &'a Address,
+    },
+    TokenAllowance {
+        token: &'a Address,
+        owner: &'a Address,
+        spender: &'a Address,
+    },
+    TokenFeeGrant(&'a Address),
     TokenMinted,
     TokenMinter(&'a Address),
     Vp(&'a Address),
@@ -191,10 +247,22 @@ enum KeyType<'a> {
 
 impl<'a> From<&'a storage::Key> for KeyType<'a> {
     fn from(key: &'a storage::Key) -> KeyType<'a> {
-        if let Some([_, owner]) =
+        if let Some([token, owner]) =
             token::storage_key::is_any_token_balance_key(key)
         {
-            Self::TokenBalance { owner }
+            Self::TokenBalance { token, owner }
+        } else if let Some([token, owner, spender]) =
+            token::storage_key::is_any_allowance_key(key)
+        {
+            Self::TokenAllowance {
+                token,
+                owner,
+                spender,
+            }
+        } else if let Some([_, sponsor, _]) =
+            token::storage_key::is_any_fee_grant_key(key)
+        {
+            Self::TokenFeeGrant(sponsor)
         } else if token::storage_key::is_any_minted_balance_key(key).is_some() {
             Self::TokenMinted
         } else if let Some(minter) = token::storage_key::is_any_minter_key(key)
@@ -212,6 +280,73 @@ impl<'a> From<&'a storage::Key> for KeyType<'a> {
     }
 }
 
+/// Check if an allowance is spent in the tx, i.e. its amount is lowered along
+/// with a debit of the owner's balance. Otherwise, its owner is granting,
+/// updating or revoking it.
+fn is_allowance_spent(
+    ctx: &Ctx,
+    key: &storage::Key,
+    token_addr: &Address,
+    owner: &Address,
+) -> VpResult<bool> {
+    let pre: Option<token::Allowance> = ctx.read_pre(key).into_vp_error()?;
+    let post: Option<token::Allowance> = ctx.read_post(key).into_vp_error()?;
+    let is_lowered = matches!(
+        (pre, post),
+        (Some(pre), Some(post))
+            if post.amount < pre.amount && post.expiry == pre.expiry
+    );
+    if !is_lowered {
+        return Ok(false);
+    }
+    let balance_key = token::storage_key::balance_key(token_addr, owner);
+    let pre_balance: token::Amount = ctx
+        .read_pre(&balance_key)
+        .into_vp_error()?
+        .unwrap_or_default();
+    let post_balance: token::Amount = ctx
+        .read_post(&balance_key)
+        .into_vp_error()?
+        .unwrap_or_default();
+    Ok(post_balance < pre_balance)
+}
+
+/// Sum up the allowances of the given token spent from the owner's balance in
+/// the tx. The Multitoken VP checks that they were spent with the
+/// authorization of their spenders.
+fn spent_allowance(
+    ctx: &Ctx,
+    token_addr: &Address,
+    owner: &Address,
+    keys_changed: &BTreeSet<storage::Key>,
+) -> VpResult<token::Amount> {
+    let mut spent = token::Amount::zero();
+    for key in keys_changed {
+        let is_owner_allowance = matches!(
+            token::storage_key::is_any_allowance_key(key),
+            Some([allowance_token, allowance_owner, _])
+                if allowance_token == token_addr && allowance_owner == owner
+        );
+        if !is_owner_allowance {
+            continue;
+        }
+        let pre: Option<token::Allowance> =
+            ctx.read_pre(key).into_vp_error()?;
+        let post: Option<token::Allowance> =
+            ctx.read_post(key).into_vp_error()?;
+        if let (Some(pre), Some(post)) = (pre, post) {
+            if post.expiry == pre.expiry {
+                let diff =
+                    pre.amount.checked_sub(post.amount).unwrap_or_default();
+                spent = spent.checked_add(diff).ok_or_else(|| {
+                    VpError::Erased("Overflowed in allowance check".into())
+                })?;
+            }
+        }
+    }
+    Ok(spent)
+}
+
 #[cfg(test)]
 mod tests {
     use std::panic;
@@ -469,6 +604,134 @@ mod tests {
         );
     }
 
+    /// Test that a debit transfer without a valid signature is accepted when
+    /// it's covered by a spent allowance.
+    #[test]
+    fn test_unsigned_allowance_debit_transfer_accepted() {
+        // Initialize a tx environment
+        let mut tx_env = TestTxEnv::default();
+
+        let vp_owner = address::testing::established_address_1();
+        let spender = address::testing::established_address_2();
+        let target = address::testing::established_address_3();
+        let token = address::testing::nam();
+        let amount = token::Amount::from_uint(10_098_123, 0).unwrap();
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&vp_owner, &spender, &target, &token]);
+
+        // Credit the tokens to the VP owner and grant an allowance to the
+        // spender before running the transaction to be able to transfer from
+        // it
+        tx_env.credit_tokens(&vp_owner, &token, amount);
+        token::approve(
+            &mut tx_env.state,
+            &token,
+            &vp_owner,
+            &spender,
+            amount,
+            None,
+        )
+        .unwrap();
+
+        // Initialize VP environment from a transaction
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+            // Apply transfer in a transaction
+            tx_host_env::token::transfer_from(
+                tx::ctx(),
+                &token::TransferFrom {
+                    token: token.clone(),
+                    spender: spender.clone(),
+                    owner: address.clone(),
+                    target: target.clone(),
+                    amount,
+                },
+            )
+            .unwrap();
+        });
+
+        let vp_env = vp_host_env::take();
+        let mut tx_data = Tx::from_type(TxType::Raw);
+        tx_data.set_data(Data::new(vec![]));
+        let keys_changed: BTreeSet<storage::Key> =
+            vp_env.all_touched_storage_keys();
+        let verifiers: BTreeSet<Address> = BTreeSet::default();
+        vp_host_env::set(vp_env);
+        assert!(
+            validate_tx(
+                &CTX,
+                tx_data.batch_first_tx(),
+                vp_owner,
+                keys_changed,
+                verifiers
+            )
+            .is_ok()
+        );
+    }
+
+    /// Test that the owner lowering an allowance without debiting its balance
+    /// doesn't need the signature of the spender.
+    #[test]
+    fn test_unsigned_allowance_lowered_by_owner_accepted() {
+        // Initialize a tx environment
+        let mut tx_env = TestTxEnv::default();
+
+        let owner = address::testing::established_address_1();
+        let vp_owner = address::testing::established_address_2();
+        let token = address::testing::nam();
+        let amount = token::Amount::from_uint(10_098_123, 0).unwrap();
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&owner, &vp_owner, &token]);
+
+        // Credit the tokens to the owner and grant an allowance to the VP
+        // owner before running the transaction
+        tx_env.credit_tokens(&owner, &token, amount);
+        token::approve(
+            &mut tx_env.state,
+            &token,
+            &owner,
+            &vp_owner,
+            amount,
+            None,
+        )
+        .unwrap();
+
+        // Initialize VP environment from a transaction
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+            // Lower the allowance in a transaction
+            tx_host_env::token::approve(
+                tx::ctx(),
+                &token::Approve {
+                    token: token.clone(),
+                    owner: owner.clone(),
+                    spender: address.clone(),
+                    amount: token::Amount::from_uint(1, 0).unwrap(),
+                    expiry: None,
+                },
+            )
+            .unwrap();
+        });
+
+        let vp_env = vp_host_env::take();
+        let mut tx_data = Tx::from_type(TxType::Raw);
+        tx_data.set_data(Data::new(vec![]));
+        let keys_changed: BTreeSet<storage::Key> =
+            vp_env.all_touched_storage_keys();
+        let verifiers: BTreeSet<Address> = BTreeSet::default();
+        vp_host_env::set(vp_env);
+        assert!(
+            validate_tx(
+                &CTX,
+                tx_data.batch_first_tx(),
+                vp_owner,
+                keys_changed,
+                verifiers
+            )
+            .is_ok()
+        );
+    }
+
     /// Test that a non-validator PoS action that must be authorized is rejected
     /// without a valid signature.
     #[test]